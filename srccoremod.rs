@@ -1,2 +1,2 @@
-pub mod math;
+pub mod math;
 pub mod color;
\ No newline at end of file
@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+
+use crate::gpu::buffer::{BufferAccess, BufferUsage};
+use crate::gpu::command_buffer::CommandBuffer;
+use crate::gpu::error::GraphisError;
+use crate::gpu::framebuffer::FramebufferDescriptor;
+use crate::gpu::memory::HostMemory;
+use crate::gpu::render_pass::RenderPassDescriptor;
+use crate::gpu::resource::ResourceRegistry;
+use crate::gpu::sampler::SamplerDescriptor;
+use crate::gpu::texture::{TextureDataLayout, TextureDescriptor, TextureRegion};
+
+// Platforma özel bir grafik arka ucunu (Vulkan, Metal, yazılım, null vb.) temsil eden trait.
+// Device, Queue, Buffer gibi genel tipler, yerel nesneleri bu trait'in ilişkili tipleri
+// aracılığıyla tutar; böylece kütüphane çatallanmadan yeni arka uçlar eklenebilir.
+// Arka uç tipleri durum tutmayan işaretçi (marker) tiplerdir; tüm durum ilişkili tiplerde yaşar.
+pub trait Backend: Debug + Clone + Copy + Default + Sized + 'static {
+    type Device: Debug;
+    type Queue: Debug;
+    // Tampon ve doku tanımlayıcıları paylaşımlıdır: cihazın kaynak kayıt defteri bir kopyasını tutar
+    // ve kuyruk, komutlardaki tanımlayıcıları çalıştırma sırasında bu kopyalara çözümler.
+    type Buffer: Debug + Clone;
+    type Texture: Debug + Clone;
+    type Sampler: Debug + Clone;
+    type RenderPass: Debug;
+    type Framebuffer: Debug;
+
+    fn kind() -> BackendKind;
+
+    fn create_device() -> Result<Self::Device, GraphisError>;
+    fn device_name(device: &Self::Device) -> String;
+    fn create_queue(device: &Self::Device) -> Result<Self::Queue, GraphisError>;
+
+    fn create_buffer(
+        device: &Self::Device,
+        size: usize,
+        usage: BufferUsage,
+        access: BufferAccess,
+    ) -> Result<Self::Buffer, GraphisError>;
+    fn destroy_buffer(device: &Self::Device, buffer: Self::Buffer);
+    // Tamponun CPU'dan erişilebilen (host-visible) belleği. Yükleme ve geri okuma bunun üzerinden yapılır.
+    fn buffer_memory(buffer: &Self::Buffer) -> &RefCell<HostMemory>;
+
+    fn create_texture(device: &Self::Device, descriptor: &TextureDescriptor) -> Result<Self::Texture, GraphisError>;
+    fn destroy_texture(device: &Self::Device, texture: Self::Texture);
+    // Doku verisinin yüklenmesi ve geri okunması. Bölge ve düzen çağrılmadan önce doğrulanmıştır
+    // (bkz. TextureDescriptor::validate_copy).
+    fn write_texture(
+        texture: &Self::Texture,
+        region: &TextureRegion,
+        data: &[u8],
+        layout: &TextureDataLayout,
+    ) -> Result<(), GraphisError>;
+    fn read_texture(
+        texture: &Self::Texture,
+        region: &TextureRegion,
+        data: &mut [u8],
+        layout: &TextureDataLayout,
+    ) -> Result<(), GraphisError>;
+
+    // Tanımlayıcı çağrılmadan önce doğrulanmıştır (bkz. SamplerDescriptor::validate).
+    fn create_sampler(device: &Self::Device, descriptor: &SamplerDescriptor) -> Result<Self::Sampler, GraphisError>;
+    fn destroy_sampler(device: &Self::Device, sampler: Self::Sampler);
+
+    fn create_render_pass(descriptor: &RenderPassDescriptor) -> Result<Self::RenderPass, GraphisError>;
+    fn create_framebuffer(descriptor: &FramebufferDescriptor<Self>) -> Result<Self::Framebuffer, GraphisError>;
+
+    fn submit(
+        queue: &Self::Queue,
+        resources: &ResourceRegistry<Self>,
+        command_buffers: &[CommandBuffer],
+    ) -> Result<(), GraphisError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackendKind {
+    Software, // CPU referans arka ucu (gpu::software)
+    Null,     // Hiçbir şey çalıştırmaz, yalnızca gönderilen komutları kaydeder (gpu::null)
+}
+
+// Genel tiplerde arka uç belirtilmediğinde kullanılan varsayılan arka uç.
+// `null-backend` özelliği ile derlendiğinde null arka uç seçilir; diğer arka uçlar tip parametresiyle de
+// seçilebilir, örn. `Device::<NullBackend>::with_backend()`.
+#[cfg(not(feature = "null-backend"))]
+pub type DefaultBackend = crate::gpu::software::SoftwareBackend;
+#[cfg(feature = "null-backend")]
+pub type DefaultBackend = crate::gpu::null::NullBackend;
\ No newline at end of file
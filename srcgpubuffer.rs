@@ -1,70 +1,292 @@
-#[derive(Debug, Copy, Clone)]
-pub enum BufferUsage {
-    Vertex,
-    Index,
-    Uniform,
-    Storage,
-    // İhtiyaca göre diğer kullanım türleri eklenebilir.
-}
-
-#[derive(Debug, Copy, Clone)]
-pub enum BufferAccess {
-    Read,
-    Write,
-    ReadWrite,
-}
-
-#[derive(Debug)]
-pub struct Buffer {
-    pub size: usize,        // Tamponun boyutu (byte cinsinden)
-    pub usage: BufferUsage, // Tamponun kullanım amacı
-    pub access: BufferAccess, // Tamponun erişim şekli (isteğe bağlı olabilir)
-    // İleride GPU'ya özgü bir "handle" veya tanımlayıcı tutulabilir.
-}
-
-impl Buffer {
-    pub fn new(size: usize, usage: BufferUsage, access: BufferAccess) -> Self {
-        Buffer { size, usage, access }
-    }
-
-    // Kavramsal olarak veri yükleme metodu
-    pub fn upload_data(&mut self, offset: usize, data: &[u8]) -> Result<(), &'static str> {
-        if offset + data.len() > self.size {
-            return Err("Veri, tamponun sınırlarını aşıyor.");
-        }
-        // **ÖNEMLİ:** Bu kısım platforma özel GPU API'leri (Vulkan, DirectX, Metal vb.) kullanılarak
-        // implemente edilmelidir. Bu kütüphane platformdan bağımsız olduğu için burada soyut bir işlem
-        // gerçekleştiriliyor. Örneğin, bir trait veya farklı platformlara özgü modüller aracılığıyla
-        // bu işlevsellik sağlanabilir.
-        println!(
-            "Tampona (boyut: {}) {} byte veri yüklendi (kaydırma: {}). Kullanım: {:?}, Erişim: {:?}",
-            self.size,
-            data.len(),
-            offset,
-            self.usage,
-            self.access
-        );
-        Ok(())
-    }
-
-    // Kavramsal olarak veri okuma metodu
-    pub fn read_data(&self, offset: usize, length: usize) -> Result<&[u8], &'static str> {
-        if offset + length > self.size {
-            return Err("Okuma aralığı, tamponun sınırlarını aşıyor.");
-        }
-        // **ÖNEMLİ:** Bu kısım da platforma özel GPU API'leri kullanılarak implemente edilmelidir.
-        // Bu kütüphane platformdan bağımsız olduğu için burada soyut bir işlem gerçekleştiriliyor.
-        // Gerçek bir implementasyonda, GPU'dan CPU adres alanına veri kopyalanması gerekebilir.
-        println!(
-            "Tampondan (boyut: {}) {} byte veri okundu (kaydırma: {}). Kullanım: {:?}, Erişim: {:?}",
-            self.size,
-            length,
-            offset,
-            self.usage,
-            self.access
-        );
-        // Gerçekte burada tamponun içeriğini döndürmeniz gerekecektir.
-        // Şimdilik boş bir slice döndürüyoruz.
-        Ok(&)
-    }
+use std::cell::{Ref, RefMut};
+use std::ops::{Deref, DerefMut};
+
+use crate::gpu::backend::{Backend, DefaultBackend};
+use crate::gpu::error::GraphisError;
+use crate::gpu::memory::{HostMemory, Pod};
+use crate::gpu::resource::BufferHandle;
+
+#[derive(Debug, Copy, Clone)]
+pub enum BufferUsage {
+    Vertex,
+    Index,
+    Uniform,
+    Storage,
+    // İhtiyaca göre diğer kullanım türleri eklenebilir.
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum BufferAccess {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+impl BufferAccess {
+    // Tamponun erişim şeklinin verilen eşleme (map) kipine izin verip vermediği.
+    pub fn allows(&self, mode: MapMode) -> bool {
+        matches!(
+            (self, mode),
+            (BufferAccess::Read, MapMode::Read)
+                | (BufferAccess::Write, MapMode::Write)
+                | (BufferAccess::ReadWrite, _)
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MapMode {
+    Read,  // Eşlenen aralık yalnızca okunabilir
+    Write, // Eşlenen aralığa yazılabilir (örneğin kare başına değişen dinamik veri)
+}
+
+#[derive(Debug)]
+pub struct Buffer<B: Backend = DefaultBackend> {
+    handle: BufferHandle,                // Komutlarda tamponu temsil eden tanımlayıcı
+    pub(crate) native_buffer: B::Buffer, // Arka uca özgü tampon nesnesi
+    pub size: usize,        // Tamponun boyutu (byte cinsinden)
+    pub usage: BufferUsage, // Tamponun kullanım amacı
+    pub access: BufferAccess, // Tamponun erişim şekli (isteğe bağlı olabilir)
+}
+
+impl<B: Backend> Buffer<B> {
+    // Tamponlar Device::create_buffer ile oluşturulur; yerel nesne arka uç tarafından sağlanır.
+    pub(crate) fn new(
+        handle: BufferHandle,
+        native_buffer: B::Buffer,
+        size: usize,
+        usage: BufferUsage,
+        access: BufferAccess,
+    ) -> Self {
+        Buffer { handle, native_buffer, size, usage, access }
+    }
+
+    // BindVertexBuffer/BindIndexBuffer komutlarında kullanılan tanımlayıcı.
+    pub fn handle(&self) -> BufferHandle {
+        self.handle
+    }
+
+    // Veriyi tamponun CPU'dan erişilebilen belleğine kopyalar.
+    pub fn upload_data(&mut self, offset: usize, data: &[u8]) -> Result<(), GraphisError> {
+        GraphisError::check_range(offset, data.len(), self.size)?;
+        // Platforma özel kısım arka uç tarafından sağlanır (bkz. Backend::buffer_memory).
+        // GPU arka uçlarında bu bellek, GPU'ya aktarılan bir ara (staging) bellek olabilir.
+        self.memory_mut()?.as_bytes_mut()[offset..offset + data.len()].copy_from_slice(data);
+        log::trace!(
+            size = self.size, len = data.len(), offset, usage:? = self.usage, access:? = self.access;
+            "Tampona veri yüklendi"
+        );
+        Ok(())
+    }
+
+    // Tamponun içeriğini geri okur. Dönen görünüm yaşadığı sürece tampona yazılamaz.
+    pub fn read_data(&self, offset: usize, length: usize) -> Result<Ref<'_, [u8]>, GraphisError> {
+        GraphisError::check_range(offset, length, self.size)?;
+        // Gerçek bir GPU arka ucunda, burada GPU'dan CPU adres alanına veri kopyalanması gerekebilir.
+        log::trace!(
+            size = self.size, len = length, offset, usage:? = self.usage, access:? = self.access;
+            "Tampondan veri okundu"
+        );
+        let memory = self.memory()?;
+        Ok(Ref::map(memory, |memory| &memory.as_bytes()[offset..offset + length]))
+    }
+
+    // Tamponun içeriğini tipli bir dizi olarak okur (örneğin &[f32] veya &[Vec3]).
+    // `offset` byte cinsindendir ve T'nin hizalamasına uymalıdır.
+    pub fn read_as<T: Pod>(&self, offset: usize, count: usize) -> Result<Ref<'_, [T]>, GraphisError> {
+        let memory = self.memory()?;
+        // Aralık ve hizalama önce doğrulanır; böylece aşağıdaki görünüm başarısız olamaz.
+        memory.view::<T>(offset, count)?;
+        Ok(Ref::map(memory, |memory| memory.view::<T>(offset, count).unwrap_or_default()))
+    }
+
+    // Tipli bir diziyi tampona yükler.
+    pub fn upload_as<T: Pod>(&mut self, offset: usize, values: &[T]) -> Result<(), GraphisError> {
+        self.memory_mut()?.view_mut::<T>(offset, values.len())?.copy_from_slice(values);
+        log::trace!(
+            size = self.size, len = std::mem::size_of_val(values), offset, usage:? = self.usage, access:? = self.access;
+            "Tampona veri yüklendi"
+        );
+        Ok(())
+    }
+
+    // Okuma için belleği ödünç alır; yazmak için eşlenmiş bir aralık yaşarken hata verir.
+    fn memory(&self) -> Result<Ref<'_, HostMemory>, GraphisError> {
+        B::buffer_memory(&self.native_buffer)
+            .try_borrow()
+            .map_err(|_| GraphisError::InvalidState("buffer is mapped for writing".to_string()))
+    }
+
+    // Yükleme için belleği ödünç alır; okunan bir görünüm veya eşlenmiş bir aralık yaşarken hata verir.
+    fn memory_mut(&self) -> Result<RefMut<'_, HostMemory>, GraphisError> {
+        B::buffer_memory(&self.native_buffer)
+            .try_borrow_mut()
+            .map_err(|_| GraphisError::InvalidState("buffer is being read or is mapped".to_string()))
+    }
+
+    // Tamponun bir alt aralığını okumak için eşler. Eşleme, dönen nesne düşürüldüğünde (drop) kalkar.
+    pub fn map(&self, offset: usize, size: usize) -> Result<MappedRange<'_>, GraphisError> {
+        self.check_map(MapMode::Read, offset, size)?;
+        let memory = self.memory()?;
+        Ok(MappedRange {
+            bytes: Ref::map(memory, |memory| &memory.as_bytes()[offset..offset + size]),
+        })
+    }
+
+    // Tamponun bir alt aralığını yazmak için eşler. Eşleme, dönen nesne düşürüldüğünde (drop) kalkar.
+    pub fn map_mut(&mut self, offset: usize, size: usize) -> Result<MappedRangeMut<'_>, GraphisError> {
+        self.check_map(MapMode::Write, offset, size)?;
+        let memory = B::buffer_memory(&self.native_buffer)
+            .try_borrow_mut()
+            .map_err(|_| GraphisError::InvalidState("buffer is already mapped".to_string()))?;
+        Ok(MappedRangeMut {
+            bytes: RefMut::map(memory, |memory| &mut memory.as_bytes_mut()[offset..offset + size]),
+        })
+    }
+
+    // Eşlemeyi talep eder ve eşleme hazır olduğunda `callback`'i çağırır.
+    // CPU'dan erişilebilen bellek kullanan arka uçlarda eşleme hemen hazırdır; GPU arka uçlarında
+    // bu çağrı, bekleyen aktarımlar tamamlandıktan sonra gerçekleşir.
+    pub fn map_async<F>(&mut self, mode: MapMode, offset: usize, size: usize, callback: F)
+    where
+        F: FnOnce(Result<BufferMapping<'_>, GraphisError>),
+    {
+        match mode {
+            MapMode::Read => callback(self.map(offset, size).map(BufferMapping::Read)),
+            MapMode::Write => callback(self.map_mut(offset, size).map(BufferMapping::Write)),
+        }
+    }
+
+    fn check_map(&self, mode: MapMode, offset: usize, size: usize) -> Result<(), GraphisError> {
+        if !self.access.allows(mode) {
+            return Err(GraphisError::ValidationFailed(format!(
+                "buffer access {:?} does not allow {:?} mapping",
+                self.access, mode
+            )));
+        }
+        GraphisError::check_range(offset, size, self.size)
+    }
+}
+
+// Okuma için eşlenmiş tampon aralığı. Düşürüldüğünde eşleme kalkar.
+#[derive(Debug)]
+pub struct MappedRange<'a> {
+    bytes: Ref<'a, [u8]>,
+}
+
+impl Deref for MappedRange<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+// Yazma için eşlenmiş tampon aralığı. Düşürüldüğünde eşleme kalkar.
+#[derive(Debug)]
+pub struct MappedRangeMut<'a> {
+    bytes: RefMut<'a, [u8]>,
+}
+
+impl Deref for MappedRangeMut<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl DerefMut for MappedRangeMut<'_> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+#[derive(Debug)]
+pub enum BufferMapping<'a> {
+    Read(MappedRange<'a>),
+    Write(MappedRangeMut<'a>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gpu::device::Device;
+
+    #[test]
+    fn reads_fail_while_the_memory_is_borrowed_for_writing() {
+        let device = Device::new().unwrap();
+        let buffer = device.create_buffer(16, BufferUsage::Vertex, BufferAccess::ReadWrite).unwrap();
+        let resources = device.resources();
+        // Kayıt defterindeki kopya aynı belleği paylaşır; yürütücü bir komutu işlerken olduğu gibi.
+        let registered = resources.buffer(buffer.handle()).unwrap();
+        let writer = DefaultBackend::buffer_memory(&registered.native).borrow_mut();
+        assert!(matches!(buffer.read_data(0, 4), Err(GraphisError::InvalidState(_))));
+        assert!(matches!(buffer.read_as::<f32>(0, 1), Err(GraphisError::InvalidState(_))));
+        assert!(matches!(buffer.map(0, 4), Err(GraphisError::InvalidState(_))));
+        drop(writer);
+        assert_eq!(&*buffer.read_data(0, 4).unwrap(), &[0; 4]);
+    }
+
+    #[test]
+    fn typed_reads_check_range_and_alignment() {
+        let device = Device::new().unwrap();
+        let mut buffer = device.create_buffer(16, BufferUsage::Uniform, BufferAccess::ReadWrite).unwrap();
+        buffer.upload_as::<f32>(4, &[1.0, 2.0]).unwrap();
+        assert_eq!(&*buffer.read_as::<f32>(4, 2).unwrap(), &[1.0, 2.0]);
+        assert!(matches!(buffer.read_as::<f32>(2, 1), Err(GraphisError::ValidationFailed(_))));
+        assert!(matches!(buffer.read_as::<f32>(12, 2), Err(GraphisError::OutOfBounds { .. })));
+    }
+
+    #[test]
+    fn access_flags_decide_the_legal_map_modes() {
+        let device = Device::new().unwrap();
+        let mut read_only = device.create_buffer(8, BufferUsage::Uniform, BufferAccess::Read).unwrap();
+        assert!(read_only.map(0, 8).is_ok());
+        assert!(matches!(read_only.map_mut(0, 8), Err(GraphisError::ValidationFailed(_))));
+        let mut write_only = device.create_buffer(8, BufferUsage::Uniform, BufferAccess::Write).unwrap();
+        assert!(matches!(write_only.map(0, 8), Err(GraphisError::ValidationFailed(_))));
+        assert!(write_only.map_mut(0, 8).is_ok());
+        assert!(matches!(write_only.map_mut(4, 8), Err(GraphisError::OutOfBounds { offset: 4, len: 8, size: 8 })));
+    }
+
+    #[test]
+    fn mapped_writes_are_visible_after_unmapping() {
+        let device = Device::new().unwrap();
+        let mut buffer = device.create_buffer(8, BufferUsage::Vertex, BufferAccess::ReadWrite).unwrap();
+        {
+            let mut range = buffer.map_mut(2, 4).unwrap();
+            range.copy_from_slice(&[1, 2, 3, 4]);
+        }
+        assert_eq!(&*buffer.map(0, 8).unwrap(), &[0, 0, 1, 2, 3, 4, 0, 0]);
+        let mut mode = None;
+        buffer.map_async(MapMode::Write, 0, 2, |mapping| {
+            if let Ok(BufferMapping::Write(mut range)) = mapping {
+                range.copy_from_slice(&[9, 9]);
+                mode = Some(MapMode::Write);
+            }
+        });
+        assert_eq!(mode, Some(MapMode::Write));
+        assert_eq!(&*buffer.read_data(0, 3).unwrap(), &[9, 9, 1]);
+    }
+
+    #[test]
+    fn mapping_fails_while_the_memory_is_in_use() {
+        let device = Device::new().unwrap();
+        let mut buffer = device.create_buffer(8, BufferUsage::Vertex, BufferAccess::ReadWrite).unwrap();
+        let resources = device.resources();
+        let memory = DefaultBackend::buffer_memory(&resources.buffer(buffer.handle()).unwrap().native);
+        // Okunurken yazmak için eşlenemez ve yüklenemez; okuma için eşleme ise serbesttir.
+        let reader = memory.borrow();
+        assert!(matches!(buffer.map_mut(0, 8), Err(GraphisError::InvalidState(_))));
+        assert!(matches!(buffer.upload_data(0, &[1]), Err(GraphisError::InvalidState(_))));
+        assert!(buffer.map(0, 8).is_ok());
+        drop(reader);
+        let mut result = None;
+        let writer = memory.borrow_mut();
+        buffer.map_async(MapMode::Read, 0, 8, |mapping| result = Some(mapping.is_err()));
+        drop(writer);
+        assert_eq!(result, Some(true));
+        assert!(buffer.map_mut(0, 8).is_ok());
+    }
 }
\ No newline at end of file
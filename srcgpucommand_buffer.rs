@@ -1,66 +1,174 @@
-use crate::gpu::commands::RenderCommand; // Assuming RenderCommand is defined here
-
-#[derive(Debug)]
-pub struct CommandBuffer {
-    pub(crate) native_command_buffer: (), // Şimdilik yer tutucu
-    commands: Vec<RenderCommand>,
-    is_recording: bool,
-}
-
-impl CommandBuffer {
-    pub fn new() -> Self {
-        CommandBuffer {
-            native_command_buffer: (),
-            commands: Vec::new(),
-            is_recording: false,
-        }
-    }
-
-    pub fn begin_recording(&mut self) -> Result<(), &'static str> {
-        if self.is_recording {
-            return Err("Komut arabelleği zaten kayıtta.");
-        }
-        self.commands.clear();
-        self.is_recording = true;
-        println!("Komut arabelleği kaydı başladı (platforma özel işlemler gerekebilir).");
-        Ok(())
-    }
-
-    pub fn end_recording(&mut self) -> Result<(), &'static str> {
-        if !self.is_recording {
-            return Err("Komut arabelleği kayıtta değil.");
-        }
-        self.is_recording = false;
-        println!("Komut arabelleği kaydı sona erdi (platforma özel işlemler gerekebilir).");
-        Ok(())
-    }
-
-    pub fn submit_command(&mut self, command: RenderCommand) -> Result<(), &'static str> {
-        if !self.is_recording {
-            return Err("Kayıtta olmayan bir komut arabelleğine komut gönderilemez.");
-        }
-        self.commands.push(command);
-        println!("Gönderilen komut: {:?}", command);
-        Ok(())
-    }
-
-    // Örnek özel komut gönderme metotları
-    pub fn clear_color(&mut self, color: crate::core::color::Color8Bit) -> Result<(), &'static str> {
-        self.submit_command(RenderCommand::Clear8Bit { color })
-    }
-
-    pub fn draw(&mut self, vertex_count: u32) -> Result<(), &'static str> {
-        self.submit_command(RenderCommand::Draw { vertex_count })
-    }
-
-    pub fn set_viewport(&mut self, x: u32, y: u32, width: u32, height: u32) -> Result<(), &'static str> {
-        self.submit_command(RenderCommand::SetViewport { x, y, width, height })
-    }
-
-    // İhtiyaca göre RenderCommand enum'ındaki diğer varyantlar için de metotlar eklenebilir.
-
-    // Kaydedilen komutları almak için bir metot (platforma özel arka uç tarafından işlenmek üzere)
-    pub fn get_commands(&self) -> &[RenderCommand] {
-        &self.commands
-    }
+use crate::gpu::commands::RenderCommand; // Assuming RenderCommand is defined here
+use crate::gpu::error::GraphisError;
+use crate::gpu::backend::Backend;
+use crate::gpu::render_pass::RenderPassDescriptor;
+use crate::gpu::texture::Texture;
+
+#[derive(Debug)]
+pub struct CommandBuffer {
+    pub(crate) native_command_buffer: (), // Şimdilik yer tutucu
+    commands: Vec<RenderCommand>,
+    is_recording: bool,
+    in_render_pass: bool,
+}
+
+impl CommandBuffer {
+    pub fn new() -> Self {
+        CommandBuffer {
+            native_command_buffer: (),
+            commands: Vec::new(),
+            is_recording: false,
+            in_render_pass: false,
+        }
+    }
+
+    pub fn begin_recording(&mut self) -> Result<(), GraphisError> {
+        if self.is_recording {
+            return Err(GraphisError::InvalidState("command buffer is already recording".to_string()));
+        }
+        self.commands.clear();
+        self.is_recording = true;
+        log::trace!("Komut arabelleği kaydı başladı");
+        Ok(())
+    }
+
+    pub fn end_recording(&mut self) -> Result<(), GraphisError> {
+        if !self.is_recording {
+            return Err(GraphisError::InvalidState("command buffer is not recording".to_string()));
+        }
+        if self.in_render_pass {
+            return Err(GraphisError::InvalidState("a render pass is still open".to_string()));
+        }
+        self.is_recording = false;
+        log::trace!(command_count = self.commands.len(); "Komut arabelleği kaydı sona erdi");
+        Ok(())
+    }
+
+    pub fn submit_command(&mut self, command: RenderCommand) -> Result<(), GraphisError> {
+        if !self.is_recording {
+            return Err(GraphisError::InvalidState(
+                "commands can only be submitted to a recording command buffer".to_string(),
+            ));
+        }
+        // Çizimler yalnızca bir render geçişi içinde; hedef değiştirme, çözümleme ve mip üretimi ise yalnızca
+        // dışında geçerlidir.
+        match command {
+            RenderCommand::Draw { .. } | RenderCommand::DrawIndexed { .. } if !self.in_render_pass => {
+                return Err(GraphisError::InvalidState(
+                    "draw commands must be recorded inside a render pass".to_string(),
+                ));
+            }
+            RenderCommand::SetRenderTarget { .. }
+            | RenderCommand::SetDepthStencilTarget { .. }
+            | RenderCommand::ResolveTexture { .. }
+            | RenderCommand::GenerateMipmaps { .. }
+                if self.in_render_pass =>
+            {
+                return Err(GraphisError::InvalidState(format!(
+                    "{:?} cannot be recorded inside a render pass",
+                    command
+                )));
+            }
+            _ => {}
+        }
+        self.push(command);
+        Ok(())
+    }
+
+    // Geçişin ekleri bağlanır ve LoadOp işlemleri kaydedilir. Dönen kodlayıcı düşürüldüğünde (veya `end` ile)
+    // geçiş kapanır; çözümleme hedefleri ve StoreOp işlemleri o anda kaydedilir.
+    pub fn begin_render_pass(
+        &mut self,
+        descriptor: &RenderPassDescriptor,
+    ) -> Result<RenderPassEncoder<'_>, GraphisError> {
+        if !self.is_recording {
+            return Err(GraphisError::InvalidState(
+                "render passes can only be recorded into a recording command buffer".to_string(),
+            ));
+        }
+        let begin_commands = descriptor.begin_commands()?;
+        log::trace!(
+            color_attachments = descriptor.color_attachments.len(),
+            has_depth_stencil = descriptor.depth_stencil_attachment.is_some();
+            "Render geçişi başladı"
+        );
+        for command in begin_commands {
+            self.push(command);
+        }
+        self.in_render_pass = true;
+        Ok(RenderPassEncoder { command_buffer: self, end_commands: descriptor.end_commands() })
+    }
+
+    fn push(&mut self, command: RenderCommand) {
+        self.commands.push(command);
+        log::trace!(command:?; "Komut kaydedildi");
+    }
+
+    // Örnek özel komut gönderme metotları. Çizimler yalnızca açık bir geçişe kaydedilebildiğinden `draw`
+    // ve `draw_indexed`, begin_render_pass'in döndürdüğü RenderPassEncoder üzerindedir.
+    pub fn clear_color(&mut self, color: crate::core::color::Color8Bit) -> Result<(), GraphisError> {
+        self.submit_command(RenderCommand::Clear8Bit { color })
+    }
+
+    pub fn set_viewport(&mut self, x: u32, y: u32, width: u32, height: u32) -> Result<(), GraphisError> {
+        self.submit_command(RenderCommand::SetViewport { x, y, width, height })
+    }
+
+    // Dokunun ilk mip seviyesinden diğer tüm seviyelerini üretir. 3B dokularda derinlik dilimleri de süzülür.
+    pub fn generate_mipmaps<B: Backend>(&mut self, texture: &Texture<B>) -> Result<(), GraphisError> {
+        self.submit_command(RenderCommand::GenerateMipmaps { texture: texture.view() })
+    }
+
+    // İhtiyaca göre RenderCommand enum'ındaki diğer varyantlar için de metotlar eklenebilir.
+
+    pub fn is_recording(&self) -> bool {
+        self.is_recording
+    }
+
+    pub fn is_in_render_pass(&self) -> bool {
+        self.in_render_pass
+    }
+
+    // Kaydedilen komutları almak için bir metot (platforma özel arka uç tarafından işlenmek üzere)
+    pub fn get_commands(&self) -> &[RenderCommand] {
+        &self.commands
+    }
+}
+
+// Açık bir render geçişine komut kaydeden kodlayıcı. Komut arabelleğini geçiş boyunca ödünç alır.
+#[derive(Debug)]
+pub struct RenderPassEncoder<'a> {
+    command_buffer: &'a mut CommandBuffer,
+    end_commands: Vec<RenderCommand>,
+}
+
+impl RenderPassEncoder<'_> {
+    pub fn submit_command(&mut self, command: RenderCommand) -> Result<(), GraphisError> {
+        self.command_buffer.submit_command(command)
+    }
+
+    pub fn draw(&mut self, vertex_count: u32) -> Result<(), GraphisError> {
+        self.submit_command(RenderCommand::Draw { vertex_count })
+    }
+
+    pub fn draw_indexed(&mut self, index_count: u32) -> Result<(), GraphisError> {
+        self.submit_command(RenderCommand::DrawIndexed { index_count })
+    }
+
+    pub fn set_viewport(&mut self, x: u32, y: u32, width: u32, height: u32) -> Result<(), GraphisError> {
+        self.submit_command(RenderCommand::SetViewport { x, y, width, height })
+    }
+
+    // Geçişi kapatır; kodlayıcıyı düşürmekle aynıdır.
+    pub fn end(self) {}
+}
+
+impl Drop for RenderPassEncoder<'_> {
+    fn drop(&mut self) {
+        for command in self.end_commands.drain(..) {
+            self.command_buffer.push(command);
+        }
+        self.command_buffer.in_render_pass = false;
+        log::trace!("Render geçişi sona erdi");
+    }
 }
\ No newline at end of file
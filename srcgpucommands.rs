@@ -1,157 +1,234 @@
-#[derive(Debug)]
-pub enum RenderCommand {
-    Clear8Bit {
-        color: crate::core::color::Color8Bit,
-    },
-    Clear10Bit {
-        color: crate::core::color::Color10Bit,
-    },
-    Clear12Bit {
-        color: crate::core::color::Color12Bit,
-    },
-    ClearHDR {
-        color: crate::core::color::ColorHDR,
-    },
-    Draw {
-        vertex_count: u32,
-    },
-    DrawIndexed {
-        index_count: u32,
-    },
-    SetViewport {
-        x: u32,
-        y: u32,
-        width: u32,
-        height: u32,
-    },
-    SetScissorRect {
-        x: u32,
-        y: u32,
-        width: u32,
-        height: u32,
-    },
-    BindVertexBuffer {
-        buffer: u32, // GPU buffer ID veya referansı
-        slot: u32,   // Vertex buffer'ın bağlanacağı slot
-        offset: u64, // Tampon içindeki başlangıç ofseti
-    },
-    BindIndexBuffer {
-        buffer: u32, // GPU buffer ID veya referansı
-        index_type: IndexType,
-        offset: u64, // Tampon içindeki başlangıç ofseti
-    },
-    BindShaderProgram {
-        program: u32, // Shader program ID veya referansı
-    },
-    SetUniformInt {
-        location: u32, // Uniform değişkenin konumu
-        value: i32,
-    },
-    SetUniformFloat {
-        location: u32, // Uniform değişkenin konumu
-        value: f32,
-    },
-    SetUniformVec2 {
-        location: u32, // Uniform değişkenin konumu
-        value: crate::core::math::Vec2,
-    },
-    SetUniformVec3 {
-        location: u32, // Uniform değişkenin konumu
-        value: crate::core::math::Vec3,
-    },
-    SetUniformVec4 {
-        location: u32, // Uniform değişkenin konumu
-        value: (f32, f32, f32, f32), // Veya kendi Vec4 yapınızı kullanabilirsiniz
-    },
-    SetUniformMat4 {
-        location: u32, // Uniform değişkenin konumu
-        value: crate::core::math::Mat4,
-    },
-    BindTexture {
-        texture: u32,     // Texture ID veya referansı
-        unit: u32,        // Texture unit (sampler slot)
-    },
-    SetRenderTarget {
-        render_target: u32, // Render target ID veya referansı (0 genellikle varsayılan framebuffer'ı temsil eder)
-    },
-    SetDepthTestEnabled {
-        enabled: bool,
-    },
-    SetDepthMaskEnabled {
-        enabled: bool,
-    },
-    SetDepthCompareFunction {
-        function: CompareFunction,
-    },
-    SetBlendEnabled {
-        enabled: bool,
-    },
-    SetBlendFunction {
-        src_factor: BlendFactor,
-        dst_factor: BlendFactor,
-        operation: BlendOperation,
-    },
-    SetPrimitiveTopology {
-        topology: PrimitiveTopology,
-    },
-    // İleride eklenebilecek diğer komutlar...
-}
-
-#[derive(Debug)]
-pub enum IndexType {
-    Uint16,
-    Uint32,
-}
-
-#[derive(Debug)]
-pub enum CompareFunction {
-    Never,
-    Less,
-    Equal,
-    LessOrEqual,
-    Greater,
-    NotEqual,
-    GreaterOrEqual,
-    Always,
-}
-
-#[derive(Debug)]
-pub enum BlendFactor {
-    Zero,
-    One,
-    SrcColor,
-    OneMinusSrcColor,
-    DstColor,
-    OneMinusDstColor,
-    SrcAlpha,
-    OneMinusSrcAlpha,
-    DstAlpha,
-    OneMinusDstAlpha,
-    ConstantColor,
-    OneMinusConstantColor,
-    ConstantAlpha,
-    OneMinusConstantAlpha,
-    SrcAlphaSaturate,
-    Src1Color,
-    OneMinusSrc1Color,
-    Src1Alpha,
-    OneMinusSrc1Alpha,
-}
-
-#[derive(Debug)]
-pub enum BlendOperation {
-    Add,
-    Subtract,
-    ReverseSubtract,
-    Min,
-    Max,
-}
-
-#[derive(Debug)]
-pub enum PrimitiveTopology {
-    PointList,
-    LineList,
-    LineStrip,
-    TriangleList,
-    TriangleStrip,
+use crate::gpu::resource::{BufferHandle, PipelineHandle, SamplerHandle};
+use crate::gpu::texture::TextureView;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RenderCommand {
+    Clear8Bit {
+        color: crate::core::color::Color8Bit,
+    },
+    Clear10Bit {
+        color: crate::core::color::Color10Bit,
+    },
+    Clear12Bit {
+        color: crate::core::color::Color12Bit,
+    },
+    ClearHDR {
+        color: crate::core::color::ColorHDR,
+    },
+    Draw {
+        vertex_count: u32,
+    },
+    DrawIndexed {
+        index_count: u32,
+    },
+    SetViewport {
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    },
+    SetScissorRect {
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    },
+    BindVertexBuffer {
+        buffer: BufferHandle, // Device::create_buffer ile alınan tampon
+        slot: u32,   // Vertex buffer'ın bağlanacağı slot
+        offset: u64, // Tampon içindeki başlangıç ofseti
+    },
+    BindIndexBuffer {
+        buffer: BufferHandle, // Device::create_buffer ile alınan tampon
+        index_type: IndexType,
+        offset: u64, // Tampon içindeki başlangıç ofseti
+    },
+    BindShaderProgram {
+        program: PipelineHandle, // Device::create_pipeline ile oluşturulan hat
+    },
+    SetUniformInt {
+        location: u32, // Uniform değişkenin konumu
+        value: i32,
+    },
+    SetUniformFloat {
+        location: u32, // Uniform değişkenin konumu
+        value: f32,
+    },
+    SetUniformVec2 {
+        location: u32, // Uniform değişkenin konumu
+        value: crate::core::math::Vec2,
+    },
+    SetUniformVec3 {
+        location: u32, // Uniform değişkenin konumu
+        value: crate::core::math::Vec3,
+    },
+    SetUniformVec4 {
+        location: u32, // Uniform değişkenin konumu
+        value: (f32, f32, f32, f32), // Veya kendi Vec4 yapınızı kullanabilirsiniz
+    },
+    SetUniformMat4 {
+        location: u32, // Uniform değişkenin konumu
+        value: crate::core::math::Mat4,
+    },
+    BindTexture {
+        texture: TextureView, // Texture::view veya Texture::create_view ile alınan görünüm
+        unit: u32,            // Texture unit (sampler slot)
+    },
+    // Aynı birime bağlı dokunun nasıl örnekleneceği. Sampler bağlanmamış birimler varsayılan
+    // SamplerDescriptor ile (en yakın texel, kenara kırpma) örneklenir.
+    BindSampler {
+        sampler: SamplerHandle, // Sampler::handle ile alınır
+        unit: u32,
+    },
+    SetRenderTarget {
+        render_target: RenderTargetBinding,
+    },
+    SetDepthStencilTarget {
+        // None: varsayılan framebuffer'ın derinlik tamponu (yalnızca varsayılan renk hedefiyle kullanılır)
+        depth_stencil_target: Option<TextureView>,
+    },
+    ClearDepth {
+        depth: f32, // 0.0 (yakın) ile 1.0 (uzak) arasında
+    },
+    ClearStencil {
+        stencil: u32, // Yalnızca alt 8 bit kullanılır
+    },
+    SetDepthTestEnabled {
+        enabled: bool,
+    },
+    SetDepthMaskEnabled {
+        enabled: bool,
+    },
+    SetDepthCompareFunction {
+        function: CompareFunction,
+    },
+    SetStencilReference {
+        reference: u32, // Replace işlemi ve stencil karşılaştırması için; yalnızca alt 8 bit kullanılır
+    },
+    SetBlendEnabled {
+        enabled: bool,
+    },
+    SetBlendFunction {
+        src_factor: BlendFactor,
+        dst_factor: BlendFactor,
+        operation: BlendOperation,
+    },
+    SetBlendConstant {
+        color: crate::core::color::ColorHDRA, // Constant* faktörlerinin kullandığı renk
+    },
+    SetPrimitiveTopology {
+        topology: PrimitiveTopology,
+    },
+    ResolveTexture {
+        source: TextureView,      // Çok örnekli dokunun tek bir alt kaynağı
+        destination: TextureView, // Aynı boyut ve formatta tek örnekli bir alt kaynak
+    },
+    // Görünümün taban mip seviyesinden sonraki seviyelerini sırayla bir öncekinden üretir. D3 görünümlerinde
+    // derinlik de yarıya iner; diğer görünümlerde her katman ayrı işlenir.
+    GenerateMipmaps {
+        texture: TextureView,
+    },
+    DiscardAttachments {
+        // Bağlı hedeflerin içeriği tanımsızlaşır (LoadOp/StoreOp::DontCare); hata ayıklama derlemelerinde zehirlenir
+        color: bool,
+        depth: bool,
+        stencil: bool,
+    },
+    // İleride eklenebilecek diğer komutlar...
+}
+
+// SetRenderTarget ile seçilen renk hedefi.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RenderTargetBinding {
+    #[default]
+    Framebuffer, // Varsayılan framebuffer (ve onun derinlik tamponu)
+    Texture(TextureView),
+    // Renk eki yok (gölge haritası, derinlik ön geçişi); fragment renkleri atılır ve çizimler bağlı
+    // derinlik-stencil dokusunun boyutunu kullanır.
+    Disabled,
+}
+
+impl RenderTargetBinding {
+    pub fn view(&self) -> Option<TextureView> {
+        match self {
+            RenderTargetBinding::Texture(view) => Some(*view),
+            RenderTargetBinding::Framebuffer | RenderTargetBinding::Disabled => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexType {
+    Uint16,
+    Uint32,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CompareFunction {
+    Never,
+    Less,
+    Equal,
+    LessOrEqual,
+    Greater,
+    NotEqual,
+    GreaterOrEqual,
+    #[default]
+    Always,
+}
+
+impl CompareFunction {
+    // Gelen değeri hedefte saklı değerle karşılaştırır (ör. Less: gelen < saklı ise geçer).
+    pub fn passes<T: PartialOrd>(&self, incoming: T, stored: T) -> bool {
+        match self {
+            CompareFunction::Never => false,
+            CompareFunction::Less => incoming < stored,
+            CompareFunction::Equal => incoming == stored,
+            CompareFunction::LessOrEqual => incoming <= stored,
+            CompareFunction::Greater => incoming > stored,
+            CompareFunction::NotEqual => incoming != stored,
+            CompareFunction::GreaterOrEqual => incoming >= stored,
+            CompareFunction::Always => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendFactor {
+    Zero,
+    One,
+    SrcColor,
+    OneMinusSrcColor,
+    DstColor,
+    OneMinusDstColor,
+    SrcAlpha,
+    OneMinusSrcAlpha,
+    DstAlpha,
+    OneMinusDstAlpha,
+    ConstantColor,
+    OneMinusConstantColor,
+    ConstantAlpha,
+    OneMinusConstantAlpha,
+    SrcAlphaSaturate,
+    Src1Color,
+    OneMinusSrc1Color,
+    Src1Alpha,
+    OneMinusSrc1Alpha,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendOperation {
+    Add,
+    Subtract,
+    ReverseSubtract,
+    Min,
+    Max,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrimitiveTopology {
+    PointList,
+    LineList,
+    LineStrip,
+    TriangleList,
+    TriangleStrip,
 }
\ No newline at end of file
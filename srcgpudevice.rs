@@ -1,71 +1,187 @@
-use crate::gpu::buffer::{Buffer, BufferUsage, BufferAccess};
-use crate::gpu::texture::{Texture, TextureDescriptor};
-use crate::gpu::queue::Queue;
-// Potentially include shader and other resource types later
-
-#[derive(Debug)]
-pub struct Device {
-    // Platform-specific device handle (e.g., VkPhysicalDevice for Vulkan)
-    // This would likely be a void pointer or an opaque type
-    // In a real implementation, this would hold the native GPU device object.
-    pub(crate) native_device: (), // Placeholder for now
-}
-
-impl Device {
-    // This function would be responsible for creating a Device instance.
-    // The implementation would be platform-specific.
-    pub fn new() -> Result<Self, &'static str> {
-        // In a real implementation, this would involve:
-        // 1. Initializing the graphics API (e.g., Vulkan instance).
-        // 2. Enumerating available physical devices (GPUs).
-        // 3. Selecting a suitable physical device based on criteria.
-        // 4. Creating a logical device with necessary features and extensions.
-
-        // For now, we'll just return a placeholder.
-        println!("Creating a GPU device (platform-specific implementation needed).");
-        Ok(Device { native_device: () })
-    }
-
-    pub fn create_buffer(&self, size: usize, usage: BufferUsage, access: BufferAccess) -> Result<Buffer, &'static str> {
-        // Platform-specific buffer creation logic here.
-        // This would involve allocating memory on the GPU.
-        println!(
-            "Creating a buffer of size {} with usage {:?} and access {:?}",
-            size, usage, access
-        );
-        Ok(Buffer::new(size, usage, access))
-    }
-
-    pub fn destroy_buffer(&self, buffer: Buffer) {
-        // Platform-specific buffer destruction logic here.
-        // This would involve freeing the allocated GPU memory.
-        println!("Destroying a buffer of size {}", buffer.size);
-    }
-
-    pub fn create_texture(&self, descriptor: &TextureDescriptor) -> Result<Texture, &'static str> {
-        // Platform-specific texture creation logic here.
-        // This would involve allocating memory and setting up the texture on the GPU.
-        println!("Creating a texture with descriptor: {:?}", descriptor);
-        Ok(Texture::new(descriptor))
-    }
-
-    pub fn destroy_texture(&self, texture: Texture) {
-        // Platform-specific texture destruction logic here.
-        // This would involve freeing the allocated GPU memory.
-        println!("Destroying a texture with descriptor: {:?}", texture.descriptor);
-    }
-
-    pub fn get_queue(&self) -> Result<Queue, &'static str> {
-        // Return a queue for graphics operations.
-        // In a real implementation, this would involve selecting a queue family
-        // and creating a queue from it.
-        println!("Getting a graphics queue (platform-specific implementation needed).");
-        Ok(Queue {}) // Placeholder
-    }
-
-    // Example of querying device capabilities (this would be much more detailed)
-    pub fn get_device_name(&self) -> String {
-        // Platform-specific way to get the device name.
-        "Generic GPU Device (Platform-Specific Implementation Needed)".to_string()
-    }
+use std::cell::{Ref, RefCell};
+use std::rc::Rc;
+
+use crate::gpu::backend::{Backend, BackendKind, DefaultBackend};
+use crate::gpu::buffer::{Buffer, BufferUsage, BufferAccess};
+use crate::gpu::error::GraphisError;
+use crate::gpu::texture::{Texture, TextureDescriptor, TextureDimension};
+use crate::gpu::queue::Queue;
+use crate::gpu::resource::{RegisteredBuffer, RegisteredSampler, RegisteredTexture, ResourceRegistry};
+use crate::gpu::sampler::{Sampler, SamplerDescriptor};
+use crate::rendering::pipeline::{Pipeline, PipelineDescriptor};
+// Potentially include shader and other resource types later
+
+#[derive(Debug)]
+pub struct Device<B: Backend = DefaultBackend> {
+    // Platform-specific device handle (e.g., VkPhysicalDevice for Vulkan)
+    // The concrete type is chosen by the backend (see gpu::backend).
+    pub(crate) native_device: B::Device,
+    // Resources created by this device, addressed by the handles used in commands.
+    // Queues obtained from the device share the registry to resolve those handles.
+    pub(crate) resources: Rc<RefCell<ResourceRegistry<B>>>,
+}
+
+impl Device {
+    // Creates a device on the default backend. The default is the software backend, or the null
+    // backend when built with the `null-backend` feature; see `with_backend` for an explicit choice.
+    pub fn new() -> Result<Self, GraphisError> {
+        Self::with_backend()
+    }
+}
+
+impl<B: Backend> Device<B> {
+    // Creates a device on an explicitly chosen backend, e.g. `Device::<NullBackend>::with_backend()`.
+    pub fn with_backend() -> Result<Self, GraphisError> {
+        // In a real implementation, this would involve:
+        // 1. Initializing the graphics API (e.g., Vulkan instance).
+        // 2. Enumerating available physical devices (GPUs).
+        // 3. Selecting a suitable physical device based on criteria.
+        // 4. Creating a logical device with necessary features and extensions.
+        log::debug!(backend:? = B::kind(); "Creating a GPU device");
+        Ok(Device {
+            native_device: B::create_device()?,
+            resources: Rc::new(RefCell::new(ResourceRegistry::default())),
+        })
+    }
+
+    pub fn backend_kind(&self) -> BackendKind {
+        B::kind()
+    }
+
+    // Access to the backend-specific device object (e.g. the software executor).
+    pub fn native(&self) -> &B::Device {
+        &self.native_device
+    }
+
+    // Read access to the resources registered on this device, e.g. to resolve handles
+    // when driving a software executor directly.
+    pub fn resources(&self) -> Ref<'_, ResourceRegistry<B>> {
+        self.resources.borrow()
+    }
+
+    pub fn create_buffer(&self, size: usize, usage: BufferUsage, access: BufferAccess) -> Result<Buffer<B>, GraphisError> {
+        // This would involve allocating memory on the GPU.
+        log::debug!(size, usage:?, access:?; "Creating a buffer");
+        let native_buffer = B::create_buffer(&self.native_device, size, usage, access)?;
+        let handle = self.resources.borrow_mut().insert_buffer(RegisteredBuffer {
+            native: native_buffer.clone(),
+            size,
+            usage,
+            access,
+        });
+        Ok(Buffer::new(handle, native_buffer, size, usage, access))
+    }
+
+    // Destroying a buffer invalidates its handle; commands still referring to it fail when they run.
+    pub fn destroy_buffer(&self, buffer: Buffer<B>) {
+        // This would involve freeing the allocated GPU memory.
+        log::debug!(size = buffer.size, usage:? = buffer.usage, handle:? = buffer.handle(); "Destroying a buffer");
+        self.resources.borrow_mut().remove_buffer(buffer.handle());
+        B::destroy_buffer(&self.native_device, buffer.native_buffer);
+    }
+
+    pub fn create_texture(&self, descriptor: &TextureDescriptor) -> Result<Texture<B>, GraphisError> {
+        // This would involve allocating memory and setting up the texture on the GPU.
+        log::debug!(
+            format:? = descriptor.format, width = descriptor.width, height = descriptor.height, usage:? = descriptor.usage;
+            "Creating a texture"
+        );
+        if descriptor.width == 0
+            || descriptor.height == 0
+            || descriptor.depth_or_array_layers == 0
+            || descriptor.mip_level_count == 0
+            || descriptor.sample_count == 0
+        {
+            return Err(GraphisError::ValidationFailed(format!(
+                "texture dimensions, mip level count and sample count must be non-zero: {:?}",
+                descriptor
+            )));
+        }
+        // Only the standard sample patterns are supported, and multisampled textures cannot be mipmapped.
+        if !matches!(descriptor.sample_count, 1 | 2 | 4 | 8) {
+            return Err(GraphisError::ValidationFailed(format!(
+                "texture sample count must be 1, 2, 4 or 8, got {}",
+                descriptor.sample_count
+            )));
+        }
+        if descriptor.sample_count > 1 && descriptor.mip_level_count > 1 {
+            return Err(GraphisError::ValidationFailed(
+                "multisampled textures must have a single mip level".to_string(),
+            ));
+        }
+        if descriptor.sample_count > 1 && descriptor.dimension == TextureDimension::D3 {
+            return Err(GraphisError::ValidationFailed("3D textures cannot be multisampled".to_string()));
+        }
+        let native_texture = B::create_texture(&self.native_device, descriptor)?;
+        let handle = self.resources.borrow_mut().insert_texture(RegisteredTexture {
+            native: native_texture.clone(),
+            descriptor: descriptor.clone(),
+        });
+        Ok(Texture::new(handle, native_texture, descriptor))
+    }
+
+    pub fn destroy_texture(&self, texture: Texture<B>) {
+        // This would involve freeing the allocated GPU memory.
+        log::debug!(
+            format:? = texture.descriptor.format, width = texture.descriptor.width, height = texture.descriptor.height;
+            "Destroying a texture"
+        );
+        self.resources.borrow_mut().remove_texture(texture.handle());
+        B::destroy_texture(&self.native_device, texture.native_texture);
+    }
+
+    pub fn create_sampler(&self, descriptor: &SamplerDescriptor) -> Result<Sampler<B>, GraphisError> {
+        descriptor.validate()?;
+        log::debug!(
+            min_filter:? = descriptor.min_filter, mag_filter:? = descriptor.mag_filter, compare:? = descriptor.compare;
+            "Creating a sampler"
+        );
+        let native_sampler = B::create_sampler(&self.native_device, descriptor)?;
+        let handle = self.resources.borrow_mut().insert_sampler(RegisteredSampler {
+            native: native_sampler.clone(),
+            descriptor: *descriptor,
+        });
+        Ok(Sampler::new(handle, native_sampler, descriptor))
+    }
+
+    pub fn destroy_sampler(&self, sampler: Sampler<B>) {
+        log::debug!(handle:? = sampler.handle(); "Destroying a sampler");
+        self.resources.borrow_mut().remove_sampler(sampler.handle());
+        B::destroy_sampler(&self.native_device, sampler.native_sampler);
+    }
+
+    // Validates the descriptor and creates a pipeline holding its resolved state.
+    pub fn create_pipeline(&self, descriptor: &PipelineDescriptor<B>) -> Result<Pipeline<B>, GraphisError> {
+        descriptor.validate()?;
+        log::debug!(
+            topology:? = descriptor.primitive.topology,
+            vertex_buffers = descriptor.vertex.buffers.len(),
+            color_targets = descriptor.fragment.as_ref().map_or(0, |fragment| fragment.targets.len());
+            "Creating a pipeline"
+        );
+        let mut resources = self.resources.borrow_mut();
+        let handle = resources.insert_pipeline(|handle| Pipeline::resolve(handle, descriptor));
+        Ok(resources.pipeline(handle)?.clone())
+    }
+
+    pub fn destroy_pipeline(&self, pipeline: Pipeline<B>) {
+        log::debug!(handle:? = pipeline.handle(); "Destroying a pipeline");
+        self.resources.borrow_mut().remove_pipeline(pipeline.handle());
+    }
+
+    pub fn get_queue(&self) -> Result<Queue<B>, GraphisError> {
+        // Return a queue for graphics operations.
+        // In a real implementation, this would involve selecting a queue family
+        // and creating a queue from it.
+        log::debug!(backend:? = B::kind(); "Getting a graphics queue");
+        Ok(Queue {
+            native_queue: B::create_queue(&self.native_device)?,
+            resources: Rc::clone(&self.resources),
+        })
+    }
+
+    // Example of querying device capabilities (this would be much more detailed)
+    pub fn get_device_name(&self) -> String {
+        B::device_name(&self.native_device)
+    }
 }
\ No newline at end of file
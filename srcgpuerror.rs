@@ -0,0 +1,131 @@
+use std::fmt;
+
+use crate::gpu::frontend::Diagnostic;
+
+// gpu modülündeki tüm hata verebilen işlemlerin döndürdüğü hata tipi.
+// Varyantlar eşleştirilebilir (match) ve `?` ile bağlamı kaybetmeden yukarı taşınabilir.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphisError {
+    // Bir erişim, kaynağın sınırlarını aşıyor (byte cinsinden).
+    OutOfBounds { offset: usize, len: usize, size: usize },
+    // İşlem, nesnenin o anki durumunda geçerli değil (örneğin kayıtta olmayan komut arabelleği).
+    InvalidState(String),
+    // Kaynak için bellek ayrılamadı.
+    OutOfMemory { requested: usize },
+    // Arka uç veya cihaz bu özelliği desteklemiyor.
+    Unsupported(String),
+    // Tanımlayıcı (descriptor) veya parametreler doğrulamadan geçemedi.
+    ValidationFailed(String),
+    // Tanımlayıcı (handle) silinmiş bir kaynağa ait ya da hiç verilmemiş.
+    StaleHandle { resource: &'static str, index: u32, generation: u32 },
+    // Shader kaynağı derlenemedi; tanılar kaynaktaki sıralarıyla.
+    CompilationFailed(Vec<Diagnostic>),
+}
+
+impl fmt::Display for GraphisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphisError::OutOfBounds { offset, len, size } => write!(
+                f,
+                "access of {} byte(s) at offset {} is out of bounds for a resource of {} byte(s)",
+                len, offset, size
+            ),
+            GraphisError::InvalidState(message) => write!(f, "invalid state: {}", message),
+            GraphisError::OutOfMemory { requested } => write!(f, "out of memory while allocating {} byte(s)", requested),
+            GraphisError::Unsupported(message) => write!(f, "unsupported: {}", message),
+            GraphisError::ValidationFailed(message) => write!(f, "validation failed: {}", message),
+            GraphisError::StaleHandle { resource, index, generation } => write!(
+                f,
+                "{} handle {}v{} does not refer to a live resource",
+                resource, index, generation
+            ),
+            GraphisError::CompilationFailed(diagnostics) => {
+                write!(f, "shader compilation failed: ")?;
+                for (index, diagnostic) in diagnostics.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", diagnostic)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for GraphisError {}
+
+impl GraphisError {
+    // `offset..offset + len` aralığı `size` byte'lık bir kaynağa sığmıyorsa OutOfBounds döndürür.
+    pub(crate) fn check_range(offset: usize, len: usize, size: usize) -> Result<(), GraphisError> {
+        match offset.checked_add(len) {
+            Some(end) if end <= size => Ok(()),
+            _ => Err(GraphisError::OutOfBounds { offset, len, size }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_range_accepts_ranges_that_end_at_the_size() {
+        assert_eq!(GraphisError::check_range(0, 16, 16), Ok(()));
+        assert_eq!(GraphisError::check_range(16, 0, 16), Ok(()));
+        assert_eq!(GraphisError::check_range(8, 9, 16), Err(GraphisError::OutOfBounds { offset: 8, len: 9, size: 16 }));
+        assert_eq!(
+            GraphisError::check_range(17, 0, 16),
+            Err(GraphisError::OutOfBounds { offset: 17, len: 0, size: 16 })
+        );
+    }
+
+    #[test]
+    fn check_range_reports_overflowing_ranges_as_out_of_bounds() {
+        assert_eq!(
+            GraphisError::check_range(usize::MAX, 2, 16),
+            Err(GraphisError::OutOfBounds { offset: usize::MAX, len: 2, size: 16 })
+        );
+    }
+
+    #[test]
+    fn display_describes_each_variant() {
+        let cases = [
+            (
+                GraphisError::OutOfBounds { offset: 4, len: 8, size: 10 },
+                "access of 8 byte(s) at offset 4 is out of bounds for a resource of 10 byte(s)",
+            ),
+            (GraphisError::InvalidState("not recording".to_string()), "invalid state: not recording"),
+            (GraphisError::OutOfMemory { requested: 64 }, "out of memory while allocating 64 byte(s)"),
+            (GraphisError::Unsupported("compute".to_string()), "unsupported: compute"),
+            (GraphisError::ValidationFailed("bad size".to_string()), "validation failed: bad size"),
+            (
+                GraphisError::StaleHandle { resource: "buffer", index: 3, generation: 2 },
+                "buffer handle 3v2 does not refer to a live resource",
+            ),
+        ];
+        for (error, message) in cases {
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn compilation_failures_list_every_diagnostic() {
+        let diagnostics = vec![
+            Diagnostic { line: 1, column: 5, message: "unexpected token".to_string() },
+            Diagnostic { line: 3, column: 1, message: "unknown type".to_string() },
+        ];
+        let error = GraphisError::CompilationFailed(diagnostics);
+        assert_eq!(error.to_string(), "shader compilation failed: 1:5: unexpected token; 3:1: unknown type");
+    }
+
+    #[test]
+    fn errors_propagate_as_boxed_std_errors() {
+        fn fails() -> Result<(), Box<dyn std::error::Error>> {
+            GraphisError::check_range(1, 4, 4)?;
+            Ok(())
+        }
+        let error = fails().unwrap_err();
+        assert_eq!(error.to_string(), "access of 4 byte(s) at offset 1 is out of bounds for a resource of 4 byte(s)");
+    }
+}
\ No newline at end of file
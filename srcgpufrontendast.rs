@@ -0,0 +1,329 @@
+use crate::gpu::shader::ShaderStage;
+pub use crate::gpu::spirv::Interpolation;
+
+// Kaynaktaki konum; satır ve sütun 1'den başlar, sütun karakter cinsindendir.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    pub line: u32,
+    pub column: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scalar {
+    Bool,
+    Int,
+    Uint,
+    Float,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextureDimension {
+    D2,
+    D3,
+    Cube,
+}
+
+// Örneklenen dokunun biçimi; `kind`, örneklemenin döndürdüğü skaler türdür (derinlik dokularında Float).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Texture {
+    pub dimension: TextureDimension,
+    pub arrayed: bool,
+    pub depth: bool,
+    pub kind: Scalar,
+}
+
+// İki dilin ortak tip sistemi. Matrisler yalnızca kayan noktalıdır; yapılar modüldeki sıralarıyla anılır.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Type {
+    Void,
+    Scalar(Scalar),
+    Vector(Scalar, u32),
+    Matrix { columns: u32, rows: u32 },
+    Array(Box<Type>, u32),
+    Struct(usize),
+    Texture(Texture),
+    Sampler { comparison: bool },
+    SampledTexture(Texture), // GLSL'deki birleşik doku ve sampler (sampler2D vb.)
+}
+
+impl Type {
+    pub fn scalar(&self) -> Option<Scalar> {
+        match self {
+            Type::Scalar(scalar) | Type::Vector(scalar, _) => Some(*scalar),
+            Type::Matrix { .. } => Some(Scalar::Float),
+            _ => None,
+        }
+    }
+
+    // Skalerlerde 1, vektörlerde bileşen sayısı; diğer tiplerde None.
+    pub fn width(&self) -> Option<u32> {
+        match self {
+            Type::Scalar(_) => Some(1),
+            Type::Vector(_, count) => Some(*count),
+            _ => None,
+        }
+    }
+
+    // Aynı biçimde, skaler türü `scalar` olan tip.
+    pub fn with_scalar(&self, scalar: Scalar) -> Type {
+        match self {
+            Type::Vector(_, count) => Type::Vector(scalar, *count),
+            _ => Type::Scalar(scalar),
+        }
+    }
+
+    pub fn is_opaque(&self) -> bool {
+        match self {
+            Type::Texture(_) | Type::Sampler { .. } | Type::SampledTexture(_) => true,
+            Type::Array(element, _) => element.is_opaque(),
+            _ => false,
+        }
+    }
+}
+
+// Kaynakta yazıldığı haliyle tip; yapı adları ve dizi uzunlukları çözümlemede değerlendirilir.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeExpr {
+    Builtin(Type),
+    Named(String, Position),
+    Array(Box<TypeExpr>, Option<Box<Expr>>, Position), // Uzunluğu verilmemiş dizi: None
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Negate,
+    Not,
+    BitNot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Shl,
+    Shr,
+    BitAnd,
+    BitOr,
+    BitXor,
+    And, // Kısa devreli mantıksal VE
+    Or,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+impl BinaryOp {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Rem => "%",
+            BinaryOp::Shl => "<<",
+            BinaryOp::Shr => ">>",
+            BinaryOp::BitAnd => "&",
+            BinaryOp::BitOr => "|",
+            BinaryOp::BitXor => "^",
+            BinaryOp::And => "&&",
+            BinaryOp::Or => "||",
+            BinaryOp::Equal => "==",
+            BinaryOp::NotEqual => "!=",
+            BinaryOp::Less => "<",
+            BinaryOp::LessEqual => "<=",
+            BinaryOp::Greater => ">",
+            BinaryOp::GreaterEqual => ">=",
+        }
+    }
+
+    // İkili işleç ve önceliği; büyük değerler daha sıkı bağlanır. İki dilde de C'deki sıra kullanılır.
+    pub fn from_punct(punct: &str) -> Option<(BinaryOp, u32)> {
+        let parsed = match punct {
+            "||" => (BinaryOp::Or, 1),
+            "&&" => (BinaryOp::And, 2),
+            "|" => (BinaryOp::BitOr, 3),
+            "^" => (BinaryOp::BitXor, 4),
+            "&" => (BinaryOp::BitAnd, 5),
+            "==" => (BinaryOp::Equal, 6),
+            "!=" => (BinaryOp::NotEqual, 6),
+            "<" => (BinaryOp::Less, 7),
+            "<=" => (BinaryOp::LessEqual, 7),
+            ">" => (BinaryOp::Greater, 7),
+            ">=" => (BinaryOp::GreaterEqual, 7),
+            "<<" => (BinaryOp::Shl, 8),
+            ">>" => (BinaryOp::Shr, 8),
+            "+" => (BinaryOp::Add, 9),
+            "-" => (BinaryOp::Sub, 9),
+            "*" => (BinaryOp::Mul, 10),
+            "/" => (BinaryOp::Div, 10),
+            "%" => (BinaryOp::Rem, 10),
+            _ => return None,
+        };
+        Some(parsed)
+    }
+
+    // Bileşik atama işlecinin (`+=` vb.) işlemi.
+    pub fn from_assignment(punct: &str) -> Option<BinaryOp> {
+        let op = match punct {
+            "+=" => BinaryOp::Add,
+            "-=" => BinaryOp::Sub,
+            "*=" => BinaryOp::Mul,
+            "/=" => BinaryOp::Div,
+            "%=" => BinaryOp::Rem,
+            "&=" => BinaryOp::BitAnd,
+            "|=" => BinaryOp::BitOr,
+            "^=" => BinaryOp::BitXor,
+            "<<=" => BinaryOp::Shl,
+            ">>=" => BinaryOp::Shr,
+            _ => return None,
+        };
+        Some(op)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
+    Bool(bool),
+    // Son ekli (1u, 1i) değişmezler o türdedir; eksiz değişmezler kullanıldıkları yere göre tür alır.
+    Int(u64, Option<Scalar>),
+    Float(f64),
+    Name(String),
+    Unary(UnaryOp, Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    // Fonksiyon, yerleşik fonksiyon veya yapı kurucusu çağrısı.
+    Call(String, Vec<Expr>),
+    // Tip kurucusu ve dönüşümü. WGSL'de bileşen tipi verilmemiş kurucular (`vec3(..)`, `array(..)`) Call olarak
+    // kalır; bileşen tipi argümanlardan çıkarılır.
+    Construct(TypeExpr, Vec<Expr>),
+    Bitcast(TypeExpr, Box<Expr>),
+    Member(Box<Expr>, String), // Yapı üyesi veya bileşen seçimi (swizzle)
+    Index(Box<Expr>, Box<Expr>),
+    Conditional(Box<Expr>, Box<Expr>, Box<Expr>), // GLSL'deki `a ? b : c`
+}
+
+impl Expr {
+    pub fn new(kind: ExprKind, position: Position) -> Self {
+        Expr { kind, position }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stmt {
+    pub kind: StmtKind,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StmtKind {
+    Declare { name: String, ty: Option<TypeExpr>, value: Option<Expr>, mutable: bool },
+    // `op` bileşik atamalarda (`+=` vb.) kullanılan işlemdir.
+    Assign { target: Expr, op: Option<BinaryOp>, value: Expr },
+    Expr(Expr),
+    Block(Vec<Stmt>),
+    If { condition: Expr, accept: Vec<Stmt>, reject: Vec<Stmt> },
+    // for, while, do-while ve WGSL'deki loop bu biçime indirgenir. `condition` her yinelemenin başında denetlenir;
+    // `continuing`, gövdeden sonra ve `continue` ile çalışır ve BreakIf ile bitebilir.
+    Loop { condition: Option<Expr>, body: Vec<Stmt>, continuing: Vec<Stmt> },
+    Switch { selector: Expr, cases: Vec<Case> },
+    Break,
+    Continue,
+    BreakIf(Expr),
+    Return(Option<Expr>),
+    Discard,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Case {
+    pub selectors: Vec<Option<Expr>>, // None: default
+    pub body: Vec<Stmt>,
+    pub fallthrough: bool, // GLSL: gövde break ile bitmiyorsa bir sonraki duruma düşer
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IoBinding {
+    Location { location: Option<u32>, index: u32 }, // GLSL'de konum verilmemişse bildirim sırasıyla atanır
+    BuiltIn(String),
+}
+
+// Giriş/çıkış değişkenlerinin, WGSL'de parametre, dönüş değeri ve yapı üyelerinin bağlandığı yer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IoAttribute {
+    pub binding: IoBinding,
+    pub interpolation: Option<Interpolation>,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member {
+    pub name: String,
+    pub ty: TypeExpr,
+    pub io: Option<IoAttribute>,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructDecl {
+    pub name: String,
+    pub members: Vec<Member>,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GlobalKind {
+    Input(IoAttribute),
+    Output(IoAttribute),
+    Uniform { location: Option<u32> }, // OpenGL tarzı, konumla bağlanan tekil uniform
+    Resource { group: u32, binding: Option<u32> }, // Doku ve sampler'lar
+    UniformBlock { group: u32, binding: Option<u32> },
+    Private(Option<Expr>),
+    Constant(Expr),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Global {
+    pub name: String,
+    pub ty: Option<TypeExpr>, // Yalnızca sabitlerde verilmeyebilir
+    pub kind: GlobalKind,
+    pub position: Position,
+    // GLSL'de örnek adı verilmemiş uniform bloklarının üyeleri doğrudan adlarıyla kullanılır.
+    pub anonymous: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    pub name: String,
+    pub ty: TypeExpr,
+    pub io: Option<IoAttribute>,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    pub name: String,
+    pub params: Vec<Param>,
+    pub result: Option<TypeExpr>,
+    pub result_io: Option<IoAttribute>,
+    pub stage: Option<ShaderStage>, // Giriş noktalarında aşama
+    pub body: Vec<Stmt>,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Module {
+    pub structs: Vec<StructDecl>,
+    pub globals: Vec<Global>,
+    pub functions: Vec<Function>,
+}
\ No newline at end of file
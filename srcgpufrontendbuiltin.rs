@@ -0,0 +1,824 @@
+use crate::gpu::frontend::ast::{BinaryOp, Expr, Position, Scalar, Texture, TextureDimension, Type};
+use crate::gpu::frontend::emit::capability;
+use crate::gpu::frontend::lower::{Language, Literal, Lowered, Lowerer, Reported, Value};
+use crate::gpu::shader::ShaderStage;
+use crate::gpu::spirv::{glsl, op};
+
+// Örnekleme komutlarının görüntü işlenenleri (Image Operands) maskesi.
+const BIAS: u32 = 0x1;
+const LOD: u32 = 0x2;
+const GRAD: u32 = 0x4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sample {
+    Implicit,
+    Bias,
+    Level,
+    Grad,
+    Compare,
+    CompareLevel,
+    Projected,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Builtin {
+    // Float skaler ve vektörlerde bileşen bazında GLSL.std.450 komutu ve argüman sayısı.
+    Float(u32, usize),
+    // Float, int ve uint için ayrı komutu olan fonksiyonlar: [F, S, U].
+    Numeric([u32; 3], usize),
+    Abs,
+    Sign,
+    Atan,
+    Mix,
+    Step,
+    SmoothStep,
+    Saturate,
+    Mod,
+    Length,
+    Distance,
+    Dot,
+    Cross,
+    Refract,
+    Determinant,
+    Inverse,
+    Transpose,
+    Derivative(u16),
+    Any,
+    All,
+    Not,
+    Select,
+    Compare(BinaryOp),
+    IsNan,
+    IsInf,
+    Bitcast(Scalar, Scalar), // floatBitsToInt vb.: kaynak ve hedef bileşen türü
+    BitCount,
+    BitReverse,
+    FindLsb,
+    FindMsb,
+    Pack(u32, u32), // GLSL.std.450 numarası ve float vektörün genişliği
+    Unpack(u32, u32),
+    Sample(Sample),
+    TextureLoad,
+    TextureSize,
+    TextureLevels,
+    TextureLayers,
+}
+
+// İki dilde aynı adı taşıyan fonksiyonlar.
+fn common(name: &str) -> Option<Builtin> {
+    let float = |number| Some(Builtin::Float(number, 1));
+    match name {
+        "sin" => float(glsl::SIN),
+        "cos" => float(glsl::COS),
+        "tan" => float(glsl::TAN),
+        "asin" => float(glsl::ASIN),
+        "acos" => float(glsl::ACOS),
+        "sinh" => float(glsl::SINH),
+        "cosh" => float(glsl::COSH),
+        "tanh" => float(glsl::TANH),
+        "asinh" => float(glsl::ASINH),
+        "acosh" => float(glsl::ACOSH),
+        "atanh" => float(glsl::ATANH),
+        "exp" => float(glsl::EXP),
+        "log" => float(glsl::LOG),
+        "exp2" => float(glsl::EXP2),
+        "log2" => float(glsl::LOG2),
+        "sqrt" => float(glsl::SQRT),
+        "floor" => float(glsl::FLOOR),
+        "ceil" => float(glsl::CEIL),
+        "fract" => float(glsl::FRACT),
+        "trunc" => float(glsl::TRUNC),
+        "radians" => float(glsl::RADIANS),
+        "degrees" => float(glsl::DEGREES),
+        "normalize" => float(glsl::NORMALIZE),
+        "pow" => Some(Builtin::Float(glsl::POW, 2)),
+        "reflect" => Some(Builtin::Float(glsl::REFLECT, 2)),
+        "fma" => Some(Builtin::Float(glsl::FMA, 3)),
+        "atan" => Some(Builtin::Atan),
+        "min" => Some(Builtin::Numeric([glsl::F_MIN, glsl::S_MIN, glsl::U_MIN], 2)),
+        "max" => Some(Builtin::Numeric([glsl::F_MAX, glsl::S_MAX, glsl::U_MAX], 2)),
+        "clamp" => Some(Builtin::Numeric([glsl::F_CLAMP, glsl::S_CLAMP, glsl::U_CLAMP], 3)),
+        "abs" => Some(Builtin::Abs),
+        "sign" => Some(Builtin::Sign),
+        "mix" => Some(Builtin::Mix),
+        "step" => Some(Builtin::Step),
+        "smoothstep" => Some(Builtin::SmoothStep),
+        "length" => Some(Builtin::Length),
+        "distance" => Some(Builtin::Distance),
+        "dot" => Some(Builtin::Dot),
+        "cross" => Some(Builtin::Cross),
+        "refract" => Some(Builtin::Refract),
+        "determinant" => Some(Builtin::Determinant),
+        "transpose" => Some(Builtin::Transpose),
+        "fwidth" => Some(Builtin::Derivative(op::FWIDTH)),
+        "any" => Some(Builtin::Any),
+        "all" => Some(Builtin::All),
+        _ => None,
+    }
+}
+
+fn wgsl(name: &str) -> Option<Builtin> {
+    match name {
+        "atan2" => Some(Builtin::Float(glsl::ATAN2, 2)),
+        "inverseSqrt" => Some(Builtin::Float(glsl::INVERSE_SQRT, 1)),
+        "round" => Some(Builtin::Float(glsl::ROUND_EVEN, 1)),
+        "faceForward" => Some(Builtin::Float(glsl::FACE_FORWARD, 3)),
+        "saturate" => Some(Builtin::Saturate),
+        "select" => Some(Builtin::Select),
+        "dpdx" => Some(Builtin::Derivative(op::DPDX)),
+        "dpdy" => Some(Builtin::Derivative(op::DPDY)),
+        "dpdxFine" => Some(Builtin::Derivative(op::DPDX_FINE)),
+        "dpdyFine" => Some(Builtin::Derivative(op::DPDY_FINE)),
+        "fwidthFine" => Some(Builtin::Derivative(op::FWIDTH_FINE)),
+        "dpdxCoarse" => Some(Builtin::Derivative(op::DPDX_COARSE)),
+        "dpdyCoarse" => Some(Builtin::Derivative(op::DPDY_COARSE)),
+        "fwidthCoarse" => Some(Builtin::Derivative(op::FWIDTH_COARSE)),
+        "countOneBits" => Some(Builtin::BitCount),
+        "reverseBits" => Some(Builtin::BitReverse),
+        "firstTrailingBit" => Some(Builtin::FindLsb),
+        "firstLeadingBit" => Some(Builtin::FindMsb),
+        "pack4x8snorm" => Some(Builtin::Pack(glsl::PACK_SNORM_4X8, 4)),
+        "pack4x8unorm" => Some(Builtin::Pack(glsl::PACK_UNORM_4X8, 4)),
+        "pack2x16snorm" => Some(Builtin::Pack(glsl::PACK_SNORM_2X16, 2)),
+        "pack2x16unorm" => Some(Builtin::Pack(glsl::PACK_UNORM_2X16, 2)),
+        "pack2x16float" => Some(Builtin::Pack(glsl::PACK_HALF_2X16, 2)),
+        "unpack4x8snorm" => Some(Builtin::Unpack(glsl::UNPACK_SNORM_4X8, 4)),
+        "unpack4x8unorm" => Some(Builtin::Unpack(glsl::UNPACK_UNORM_4X8, 4)),
+        "unpack2x16snorm" => Some(Builtin::Unpack(glsl::UNPACK_SNORM_2X16, 2)),
+        "unpack2x16unorm" => Some(Builtin::Unpack(glsl::UNPACK_UNORM_2X16, 2)),
+        "unpack2x16float" => Some(Builtin::Unpack(glsl::UNPACK_HALF_2X16, 2)),
+        "textureSample" => Some(Builtin::Sample(Sample::Implicit)),
+        "textureSampleBias" => Some(Builtin::Sample(Sample::Bias)),
+        "textureSampleLevel" => Some(Builtin::Sample(Sample::Level)),
+        "textureSampleGrad" => Some(Builtin::Sample(Sample::Grad)),
+        "textureSampleCompare" => Some(Builtin::Sample(Sample::Compare)),
+        "textureSampleCompareLevel" => Some(Builtin::Sample(Sample::CompareLevel)),
+        "textureLoad" => Some(Builtin::TextureLoad),
+        "textureDimensions" => Some(Builtin::TextureSize),
+        "textureNumLevels" => Some(Builtin::TextureLevels),
+        "textureNumLayers" => Some(Builtin::TextureLayers),
+        _ => common(name),
+    }
+}
+
+fn glsl(name: &str) -> Option<Builtin> {
+    match name {
+        "inversesqrt" => Some(Builtin::Float(glsl::INVERSE_SQRT, 1)),
+        "round" => Some(Builtin::Float(glsl::ROUND, 1)),
+        "roundEven" => Some(Builtin::Float(glsl::ROUND_EVEN, 1)),
+        "faceforward" => Some(Builtin::Float(glsl::FACE_FORWARD, 3)),
+        "mod" => Some(Builtin::Mod),
+        "inverse" => Some(Builtin::Inverse),
+        "dFdx" => Some(Builtin::Derivative(op::DPDX)),
+        "dFdy" => Some(Builtin::Derivative(op::DPDY)),
+        "dFdxFine" => Some(Builtin::Derivative(op::DPDX_FINE)),
+        "dFdyFine" => Some(Builtin::Derivative(op::DPDY_FINE)),
+        "fwidthFine" => Some(Builtin::Derivative(op::FWIDTH_FINE)),
+        "dFdxCoarse" => Some(Builtin::Derivative(op::DPDX_COARSE)),
+        "dFdyCoarse" => Some(Builtin::Derivative(op::DPDY_COARSE)),
+        "fwidthCoarse" => Some(Builtin::Derivative(op::FWIDTH_COARSE)),
+        "not" => Some(Builtin::Not),
+        "lessThan" => Some(Builtin::Compare(BinaryOp::Less)),
+        "lessThanEqual" => Some(Builtin::Compare(BinaryOp::LessEqual)),
+        "greaterThan" => Some(Builtin::Compare(BinaryOp::Greater)),
+        "greaterThanEqual" => Some(Builtin::Compare(BinaryOp::GreaterEqual)),
+        "equal" => Some(Builtin::Compare(BinaryOp::Equal)),
+        "notEqual" => Some(Builtin::Compare(BinaryOp::NotEqual)),
+        "isnan" => Some(Builtin::IsNan),
+        "isinf" => Some(Builtin::IsInf),
+        "floatBitsToInt" => Some(Builtin::Bitcast(Scalar::Float, Scalar::Int)),
+        "floatBitsToUint" => Some(Builtin::Bitcast(Scalar::Float, Scalar::Uint)),
+        "intBitsToFloat" => Some(Builtin::Bitcast(Scalar::Int, Scalar::Float)),
+        "uintBitsToFloat" => Some(Builtin::Bitcast(Scalar::Uint, Scalar::Float)),
+        "bitCount" => Some(Builtin::BitCount),
+        "bitfieldReverse" => Some(Builtin::BitReverse),
+        "findLSB" => Some(Builtin::FindLsb),
+        "findMSB" => Some(Builtin::FindMsb),
+        "packSnorm4x8" => Some(Builtin::Pack(glsl::PACK_SNORM_4X8, 4)),
+        "packUnorm4x8" => Some(Builtin::Pack(glsl::PACK_UNORM_4X8, 4)),
+        "packSnorm2x16" => Some(Builtin::Pack(glsl::PACK_SNORM_2X16, 2)),
+        "packUnorm2x16" => Some(Builtin::Pack(glsl::PACK_UNORM_2X16, 2)),
+        "packHalf2x16" => Some(Builtin::Pack(glsl::PACK_HALF_2X16, 2)),
+        "unpackSnorm4x8" => Some(Builtin::Unpack(glsl::UNPACK_SNORM_4X8, 4)),
+        "unpackUnorm4x8" => Some(Builtin::Unpack(glsl::UNPACK_UNORM_4X8, 4)),
+        "unpackSnorm2x16" => Some(Builtin::Unpack(glsl::UNPACK_SNORM_2X16, 2)),
+        "unpackUnorm2x16" => Some(Builtin::Unpack(glsl::UNPACK_UNORM_2X16, 2)),
+        "unpackHalf2x16" => Some(Builtin::Unpack(glsl::UNPACK_HALF_2X16, 2)),
+        // Eski GLSL'nin dokuya göre adlandırılmış örnekleme fonksiyonları da kabul edilir.
+        "texture" | "texture2D" | "texture3D" | "textureCube" => Some(Builtin::Sample(Sample::Implicit)),
+        "textureLod" | "texture2DLod" | "texture3DLod" | "textureCubeLod" => Some(Builtin::Sample(Sample::Level)),
+        "textureGrad" => Some(Builtin::Sample(Sample::Grad)),
+        "textureProj" | "texture2DProj" => Some(Builtin::Sample(Sample::Projected)),
+        "texelFetch" => Some(Builtin::TextureLoad),
+        "textureSize" => Some(Builtin::TextureSize),
+        "textureQueryLevels" => Some(Builtin::TextureLevels),
+        _ => common(name),
+    }
+}
+
+// Dokunun koordinat bileşeni sayısı; dizi katmanı dahil değildir.
+fn coordinate_count(texture: &Texture) -> u32 {
+    match texture.dimension {
+        TextureDimension::D2 => 2,
+        TextureDimension::D3 | TextureDimension::Cube => 3,
+    }
+}
+
+// Boyut sorgusunun bileşen sayısı; küp dokular iki boyutludur.
+fn size_count(texture: &Texture) -> u32 {
+    match texture.dimension {
+        TextureDimension::D3 => 3,
+        _ => 2,
+    }
+}
+
+impl<'a> Lowerer<'a> {
+    // Yerleşik fonksiyon çağrısı; `name` dilin yerleşik fonksiyonu değilse None.
+    pub(super) fn builtin(&mut self, name: &str, arguments: &'a [Expr], position: Position) -> Option<Lowered<Value>> {
+        let builtin = match self.language {
+            Language::Wgsl => wgsl(name)?,
+            Language::Glsl => glsl(name)?,
+        };
+        Some(self.arguments(arguments).and_then(|values| self.apply(builtin, name, values, position)))
+    }
+
+    fn apply(&mut self, builtin: Builtin, name: &str, values: Vec<Value>, position: Position) -> Lowered<Value> {
+        const FLOAT: &[Scalar] = &[Scalar::Float];
+        const NUMERIC: &[Scalar] = &[Scalar::Float, Scalar::Int, Scalar::Uint];
+        const INTEGER: &[Scalar] = &[Scalar::Int, Scalar::Uint];
+        let float = Type::Scalar(Scalar::Float);
+        match builtin {
+            Builtin::Float(number, arity) => {
+                self.arity(name, &values, arity, position)?;
+                let (values, ty) = self.uniform(name, values, FLOAT, false, position)?;
+                Ok(self.ext(number, &ty, &values))
+            }
+            Builtin::Atan => {
+                let number = match (values.len(), self.language) {
+                    (2, Language::Glsl) => glsl::ATAN2,
+                    _ => glsl::ATAN,
+                };
+                let arity = if number == glsl::ATAN2 { 2 } else { 1 };
+                self.arity(name, &values, arity, position)?;
+                let (values, ty) = self.uniform(name, values, FLOAT, false, position)?;
+                Ok(self.ext(number, &ty, &values))
+            }
+            Builtin::Numeric(numbers, arity) => {
+                self.arity(name, &values, arity, position)?;
+                let splat = self.language == Language::Glsl;
+                let (values, ty) = self.uniform(name, values, NUMERIC, splat, position)?;
+                let number = match ty.scalar() {
+                    Some(Scalar::Float) => numbers[0],
+                    Some(Scalar::Int) => numbers[1],
+                    _ => numbers[2],
+                };
+                Ok(self.ext(number, &ty, &values))
+            }
+            Builtin::Abs | Builtin::Sign => {
+                self.arity(name, &values, 1, position)?;
+                let scalars = if matches!(builtin, Builtin::Abs) { NUMERIC } else { &[Scalar::Float, Scalar::Int] };
+                let (values, ty) = self.uniform(name, values, scalars, false, position)?;
+                let abs = matches!(builtin, Builtin::Abs);
+                match ty.scalar() {
+                    Some(Scalar::Float) => Ok(self.ext(if abs { glsl::F_ABS } else { glsl::F_SIGN }, &ty, &values)),
+                    Some(Scalar::Int) => Ok(self.ext(if abs { glsl::S_ABS } else { glsl::S_SIGN }, &ty, &values)),
+                    _ => Ok(values[0].clone()),
+                }
+            }
+            Builtin::Mix | Builtin::Step | Builtin::SmoothStep => {
+                let (number, arity) = match builtin {
+                    Builtin::Mix => (glsl::F_MIX, 3),
+                    Builtin::Step => (glsl::STEP, 2),
+                    _ => (glsl::SMOOTH_STEP, 3),
+                };
+                self.arity(name, &values, arity, position)?;
+                let (values, ty) = self.uniform(name, values, FLOAT, true, position)?;
+                Ok(self.ext(number, &ty, &values))
+            }
+            Builtin::Saturate => {
+                self.arity(name, &values, 1, position)?;
+                let (mut values, ty) = self.uniform(name, values, FLOAT, false, position)?;
+                let width = ty.width().unwrap_or(1);
+                for bound in [0.0, 1.0] {
+                    let bound = Value { id: self.constant_float(bound), ty: float.clone(), literal: None };
+                    values.push(if width > 1 { self.splat(bound, width) } else { bound });
+                }
+                Ok(self.ext(glsl::F_CLAMP, &ty, &values))
+            }
+            Builtin::Mod => {
+                self.arity(name, &values, 2, position)?;
+                let (values, ty) = self.uniform(name, values, FLOAT, true, position)?;
+                Ok(self.op(op::F_MOD, &ty, &[values[0].id, values[1].id]))
+            }
+            Builtin::Length | Builtin::Distance => {
+                let (number, arity) = match builtin {
+                    Builtin::Length => (glsl::LENGTH, 1),
+                    _ => (glsl::DISTANCE, 2),
+                };
+                self.arity(name, &values, arity, position)?;
+                let (values, _) = self.uniform(name, values, FLOAT, false, position)?;
+                Ok(self.ext(number, &float, &values))
+            }
+            Builtin::Dot => {
+                self.arity(name, &values, 2, position)?;
+                let (values, ty) = self.uniform(name, values, NUMERIC, false, position)?;
+                self.vector(name, &ty, position)?;
+                let scalar = ty.scalar().unwrap_or(Scalar::Float);
+                if scalar == Scalar::Float {
+                    return Ok(self.op(op::DOT, &float, &[values[0].id, values[1].id]));
+                }
+                // Tamsayı vektörlerin iç çarpımı bileşen bazında çarpım ve toplamdır.
+                let product = self.op(op::I_MUL, &ty, &[values[0].id, values[1].id]);
+                let mut sum = self.op(op::COMPOSITE_EXTRACT, &Type::Scalar(scalar), &[product.id, 0]);
+                for component in 1..ty.width().unwrap_or(1) {
+                    let term = self.op(op::COMPOSITE_EXTRACT, &Type::Scalar(scalar), &[product.id, component]);
+                    sum = self.op(op::I_ADD, &Type::Scalar(scalar), &[sum.id, term.id]);
+                }
+                Ok(sum)
+            }
+            Builtin::Cross => {
+                self.arity(name, &values, 2, position)?;
+                let (values, ty) = self.uniform(name, values, FLOAT, false, position)?;
+                if ty.width() != Some(3) {
+                    return Err(self.no_overload(name, &values, position));
+                }
+                Ok(self.ext(glsl::CROSS, &ty, &values))
+            }
+            Builtin::Refract => {
+                self.arity(name, &values, 3, position)?;
+                let mut values = values;
+                let eta = values.pop().expect("three arguments");
+                let (mut values, ty) = self.uniform(name, values, FLOAT, false, position)?;
+                self.vector(name, &ty, position)?;
+                values.push(self.coerce(eta, &float, position)?);
+                Ok(self.ext(glsl::REFRACT, &ty, &values))
+            }
+            Builtin::Determinant | Builtin::Inverse => {
+                self.arity(name, &values, 1, position)?;
+                let ty = values[0].ty.clone();
+                if !matches!(ty, Type::Matrix { columns, rows } if columns == rows) {
+                    return Err(self.no_overload(name, &values, position));
+                }
+                match builtin {
+                    Builtin::Determinant => Ok(self.ext(glsl::DETERMINANT, &float, &values)),
+                    _ => Ok(self.ext(glsl::MATRIX_INVERSE, &ty, &values)),
+                }
+            }
+            Builtin::Transpose => {
+                self.arity(name, &values, 1, position)?;
+                let Type::Matrix { columns, rows } = values[0].ty else {
+                    return Err(self.no_overload(name, &values, position));
+                };
+                Ok(self.op(op::TRANSPOSE, &Type::Matrix { columns: rows, rows: columns }, &[values[0].id]))
+            }
+            Builtin::Derivative(opcode) => {
+                self.fragment_only(name, position)?;
+                self.arity(name, &values, 1, position)?;
+                let (values, ty) = self.uniform(name, values, FLOAT, false, position)?;
+                Ok(self.op(opcode, &ty, &[values[0].id]))
+            }
+            Builtin::Any | Builtin::All | Builtin::Not => {
+                self.arity(name, &values, 1, position)?;
+                let (values, ty) = self.uniform(name, values, &[Scalar::Bool], false, position)?;
+                let boolean = Type::Scalar(Scalar::Bool);
+                match (builtin, ty.width()) {
+                    (Builtin::Not, Some(width)) if width > 1 => Ok(self.op(op::LOGICAL_NOT, &ty, &[values[0].id])),
+                    (Builtin::Any, Some(width)) if width > 1 => Ok(self.op(op::ANY, &boolean, &[values[0].id])),
+                    (Builtin::All, Some(width)) if width > 1 => Ok(self.op(op::ALL, &boolean, &[values[0].id])),
+                    // WGSL'de skaler bool için any ve all değerin kendisidir.
+                    (Builtin::Any | Builtin::All, _) if self.language == Language::Wgsl => Ok(values[0].clone()),
+                    _ => Err(self.no_overload(name, &values, position)),
+                }
+            }
+            Builtin::Select => {
+                self.arity(name, &values, 3, position)?;
+                let mut values = values;
+                let condition = values.pop().expect("three arguments");
+                let scalars = [Scalar::Bool, Scalar::Int, Scalar::Uint, Scalar::Float];
+                let (values, ty) = self.uniform(name, values, &scalars, false, position)?;
+                let width = ty.width().unwrap_or(1);
+                let condition = match (&condition.ty, condition.ty.width()) {
+                    (ty, Some(1)) if ty.scalar() == Some(Scalar::Bool) && width > 1 => self.splat(condition, width),
+                    (ty, Some(count)) if ty.scalar() == Some(Scalar::Bool) && count == width => condition,
+                    _ => {
+                        let mut all = values.clone();
+                        all.push(condition);
+                        return Err(self.no_overload(name, &all, position));
+                    }
+                };
+                Ok(self.op(op::SELECT, &ty, &[condition.id, values[1].id, values[0].id]))
+            }
+            Builtin::Compare(comparison) => {
+                self.arity(name, &values, 2, position)?;
+                let scalars = match comparison {
+                    BinaryOp::Equal | BinaryOp::NotEqual => &[Scalar::Bool, Scalar::Int, Scalar::Uint, Scalar::Float],
+                    _ => NUMERIC,
+                };
+                let (values, ty) = self.uniform(name, values, scalars, false, position)?;
+                self.vector(name, &ty, position)?;
+                let [left, right] = <[Value; 2]>::try_from(values).expect("two arguments");
+                self.compare(comparison, left, right, position)
+            }
+            Builtin::IsNan | Builtin::IsInf => {
+                self.arity(name, &values, 1, position)?;
+                let (values, ty) = self.uniform(name, values, FLOAT, false, position)?;
+                let opcode = if matches!(builtin, Builtin::IsNan) { op::IS_NAN } else { op::IS_INF };
+                Ok(self.op(opcode, &ty.with_scalar(Scalar::Bool), &[values[0].id]))
+            }
+            Builtin::Bitcast(from, to) => {
+                self.arity(name, &values, 1, position)?;
+                let (values, ty) = self.uniform(name, values, &[from], false, position)?;
+                Ok(self.op(op::BITCAST, &ty.with_scalar(to), &[values[0].id]))
+            }
+            Builtin::BitCount | Builtin::BitReverse | Builtin::FindLsb | Builtin::FindMsb => {
+                self.arity(name, &values, 1, position)?;
+                let (values, ty) = self.uniform(name, values, INTEGER, false, position)?;
+                // GLSL'de bitCount, findLSB ve findMSB her zaman int döndürür.
+                let result = match (builtin, self.language) {
+                    (Builtin::BitReverse, _) | (_, Language::Wgsl) => ty.clone(),
+                    _ => ty.with_scalar(Scalar::Int),
+                };
+                let signed = ty.scalar() == Some(Scalar::Int);
+                match builtin {
+                    Builtin::BitCount => Ok(self.op(op::BIT_COUNT, &result, &[values[0].id])),
+                    Builtin::BitReverse => Ok(self.op(op::BIT_REVERSE, &result, &[values[0].id])),
+                    Builtin::FindLsb => Ok(self.ext(glsl::FIND_I_LSB, &result, &values)),
+                    _ => Ok(self.ext(if signed { glsl::FIND_S_MSB } else { glsl::FIND_U_MSB }, &result, &values)),
+                }
+            }
+            Builtin::Pack(number, width) => {
+                self.arity(name, &values, 1, position)?;
+                let [value] = <[Value; 1]>::try_from(values).expect("one argument");
+                let value = self.coerce(value, &Type::Vector(Scalar::Float, width), position)?;
+                Ok(self.ext(number, &Type::Scalar(Scalar::Uint), &[value]))
+            }
+            Builtin::Unpack(number, width) => {
+                self.arity(name, &values, 1, position)?;
+                let [value] = <[Value; 1]>::try_from(values).expect("one argument");
+                let value = self.coerce(value, &Type::Scalar(Scalar::Uint), position)?;
+                Ok(self.ext(number, &Type::Vector(Scalar::Float, width), &[value]))
+            }
+            Builtin::Sample(sample) => match self.language {
+                Language::Wgsl => self.wgsl_sample(name, sample, values, position),
+                Language::Glsl => self.glsl_sample(name, sample, values, position),
+            },
+            Builtin::TextureLoad => self.texture_load(name, values, position),
+            Builtin::TextureSize | Builtin::TextureLevels | Builtin::TextureLayers => {
+                self.texture_query(name, builtin, values, position)
+            }
+        }
+    }
+
+    fn ext(&mut self, number: u32, ty: &Type, values: &[Value]) -> Value {
+        let set = self.builder.glsl();
+        let operands: Vec<u32> = [set, number].into_iter().chain(values.iter().map(|value| value.id)).collect();
+        self.op(op::EXT_INST, ty, &operands)
+    }
+
+    fn arity(&mut self, name: &str, values: &[Value], expected: usize, position: Position) -> Lowered<()> {
+        if values.len() != expected {
+            let message = format!("`{}` expects {} argument(s), found {}", name, expected, values.len());
+            return Err(self.error(position, message));
+        }
+        Ok(())
+    }
+
+    fn no_overload(&mut self, name: &str, values: &[Value], position: Position) -> Reported {
+        let types: Vec<String> = values.iter().map(|value| self.type_name(&value.ty)).collect();
+        self.error(position, format!("no overload of `{}` accepts ({})", name, types.join(", ")))
+    }
+
+    fn vector(&mut self, name: &str, ty: &Type, position: Position) -> Lowered<()> {
+        match ty {
+            Type::Vector(..) => Ok(()),
+            _ => Err(self.error(position, format!("`{}` expects vectors, found `{}`", name, self.type_name(ty)))),
+        }
+    }
+
+    fn fragment_only(&mut self, name: &str, position: Position) -> Lowered<()> {
+        if self.stage == Some(ShaderStage::Vertex) {
+            return Err(self.error(position, format!("`{}` is only available in fragment shaders", name)));
+        }
+        Ok(())
+    }
+
+    // Argümanları ortak bir skaler veya vektör tipine uydurur. Değişmezler diğer argümanların türünü alır,
+    // GLSL'de tamsayılar gerekirse float'a dönüştürülür; `splat` açıksa skaler argümanlar vektöre çoğaltılır.
+    fn uniform(
+        &mut self,
+        name: &str,
+        values: Vec<Value>,
+        scalars: &[Scalar],
+        splat: bool,
+        position: Position,
+    ) -> Lowered<(Vec<Value>, Type)> {
+        let typed = values.iter().filter(|value| value.literal.is_none());
+        let widest = typed.max_by_key(|value| value.ty.width().unwrap_or(0));
+        let (scalar, width) = match widest {
+            Some(widest) => (widest.ty.scalar(), widest.ty.width()),
+            None if values.iter().any(|value| matches!(value.literal, Some(Literal::Float(_)))) => {
+                (Some(Scalar::Float), Some(1))
+            }
+            None => (values.first().and_then(|value| value.ty.scalar()), Some(1)),
+        };
+        let (Some(scalar), Some(width)) = (scalar, width) else {
+            return Err(self.no_overload(name, &values, position));
+        };
+        let all_literal = widest.is_none();
+        let scalar = match scalar {
+            _ if scalars.contains(&scalar) => scalar,
+            Scalar::Int | Scalar::Uint
+                if scalars.contains(&Scalar::Float) && (all_literal || self.language == Language::Glsl) =>
+            {
+                Scalar::Float
+            }
+            _ => return Err(self.no_overload(name, &values, position)),
+        };
+        let ty = if width == 1 { Type::Scalar(scalar) } else { Type::Vector(scalar, width) };
+        let mut result = Vec::new();
+        for value in &values {
+            let mut value = value.clone();
+            if let (Some(literal), Type::Scalar(_)) = (value.literal, &value.ty) {
+                value = match self.literal_as(literal, scalar) {
+                    Some(value) => value,
+                    None => return Err(self.no_overload(name, &values, position)),
+                };
+            }
+            if value.ty.scalar() != Some(scalar) && self.language == Language::Glsl && scalar == Scalar::Float {
+                value = self.convert(value, scalar);
+            }
+            if splat && width > 1 && value.ty.width() == Some(1) && value.ty.scalar() == Some(scalar) {
+                value = self.splat(value, width);
+            }
+            if value.ty != ty {
+                return Err(self.no_overload(name, &values, position));
+            }
+            result.push(value);
+        }
+        Ok((result, ty))
+    }
+
+    // Tamsayı argüman (dizi katmanı, mip seviyesi, koordinat) istenen türde.
+    fn integer(&mut self, value: Value, to: &Type, position: Position) -> Lowered<Value> {
+        if let (Some(literal), Type::Scalar(scalar)) = (value.literal, to) {
+            if let Some(value) = self.literal_as(literal, *scalar) {
+                return Ok(value);
+            }
+        }
+        let integer = matches!(value.ty.scalar(), Some(Scalar::Int | Scalar::Uint));
+        if !integer || value.ty.width() != to.width() {
+            let message = format!("expected `{}`, found `{}`", self.type_name(to), self.type_name(&value.ty));
+            return Err(self.error(position, message));
+        }
+        Ok(self.convert(value, to.scalar().unwrap_or(Scalar::Int)))
+    }
+
+    fn float_vector(&mut self, value: Value, width: u32, position: Position) -> Lowered<Value> {
+        let ty = if width == 1 { Type::Scalar(Scalar::Float) } else { Type::Vector(Scalar::Float, width) };
+        self.coerce(value, &ty, position)
+    }
+
+    // Örnekleme komutu; `dref` derinlik karşılaştırması, `operands` görüntü işlenenleridir. Seviye veya gradyan
+    // verilen örnekleme açık seviyelidir (ExplicitLod).
+    fn sample(
+        &mut self,
+        texture: &Texture,
+        sampled: u32,
+        coordinates: u32,
+        dref: Option<u32>,
+        projected: bool,
+        operands: &[u32],
+    ) -> Value {
+        let explicit = operands.first().is_some_and(|mask| mask & (LOD | GRAD) != 0);
+        let opcode = match (dref.is_some(), explicit, projected) {
+            (false, false, false) => op::IMAGE_SAMPLE_IMPLICIT_LOD,
+            (false, true, false) => op::IMAGE_SAMPLE_EXPLICIT_LOD,
+            (true, false, false) => op::IMAGE_SAMPLE_DREF_IMPLICIT_LOD,
+            (true, true, false) => op::IMAGE_SAMPLE_DREF_EXPLICIT_LOD,
+            (false, _, true) => op::IMAGE_SAMPLE_PROJ_IMPLICIT_LOD,
+            (true, _, true) => op::IMAGE_SAMPLE_PROJ_DREF_IMPLICIT_LOD,
+        };
+        let ty = match dref {
+            Some(_) => Type::Scalar(Scalar::Float),
+            None => Type::Vector(texture.kind, 4),
+        };
+        let words: Vec<u32> = [sampled, coordinates].into_iter().chain(dref).chain(operands.iter().copied()).collect();
+        self.op(opcode, &ty, &words)
+    }
+
+    // WGSL: textureSample(t, s, coords[, array_index], ...) ailesi.
+    fn wgsl_sample(&mut self, name: &str, sample: Sample, values: Vec<Value>, position: Position) -> Lowered<Value> {
+        let (texture, comparison) = match values.as_slice() {
+            [Value { ty: Type::Texture(texture), .. }, Value { ty: Type::Sampler { comparison }, .. }, ..] => {
+                (*texture, *comparison)
+            }
+            _ => return Err(self.error(position, format!("`{}` expects a texture and a sampler", name))),
+        };
+        let compare = matches!(sample, Sample::Compare | Sample::CompareLevel);
+        if compare != comparison || (compare && !texture.depth) {
+            return Err(self.no_overload(name, &values, position));
+        }
+        let extra = match sample {
+            Sample::Implicit => 0,
+            Sample::Grad => 2,
+            _ => 1,
+        };
+        let layer = texture.arrayed as usize;
+        self.arity(name, &values, 3 + layer + extra, position)?;
+        if matches!(sample, Sample::Implicit | Sample::Bias | Sample::Compare) {
+            self.fragment_only(name, position)?;
+        }
+        let count = coordinate_count(&texture);
+        let mut coordinates = self.float_vector(values[2].clone(), count, position)?;
+        if texture.arrayed {
+            let layer = self.integer(values[3].clone(), &Type::Scalar(Scalar::Int), position)?;
+            let layer = self.convert(layer, Scalar::Float);
+            let ty = Type::Vector(Scalar::Float, count + 1);
+            coordinates = self.op(op::COMPOSITE_CONSTRUCT, &ty, &[coordinates.id, layer.id]);
+        }
+        let rest = values[3 + layer..].to_vec();
+        let sampled = self.op(op::SAMPLED_IMAGE, &Type::SampledTexture(texture), &[values[0].id, values[1].id]).id;
+        let result = match sample {
+            Sample::Implicit => self.sample(&texture, sampled, coordinates.id, None, false, &[]),
+            Sample::Bias => {
+                let bias = self.float_vector(rest[0].clone(), 1, position)?;
+                self.sample(&texture, sampled, coordinates.id, None, false, &[BIAS, bias.id])
+            }
+            Sample::Level => {
+                // Derinlik dokularında seviye tamsayıdır.
+                let level = match texture.depth {
+                    true => {
+                        let level = self.integer(rest[0].clone(), &Type::Scalar(Scalar::Int), position)?;
+                        self.convert(level, Scalar::Float)
+                    }
+                    false => self.float_vector(rest[0].clone(), 1, position)?,
+                };
+                self.sample(&texture, sampled, coordinates.id, None, false, &[LOD, level.id])
+            }
+            Sample::Grad => {
+                let x = self.float_vector(rest[0].clone(), count, position)?;
+                let y = self.float_vector(rest[1].clone(), count, position)?;
+                self.sample(&texture, sampled, coordinates.id, None, false, &[GRAD, x.id, y.id])
+            }
+            Sample::Compare | Sample::CompareLevel => {
+                let reference = self.float_vector(rest[0].clone(), 1, position)?;
+                let zero = self.constant_float(0.0);
+                let operands: &[u32] = if sample == Sample::CompareLevel { &[LOD, zero] } else { &[] };
+                return Ok(self.sample(&texture, sampled, coordinates.id, Some(reference.id), false, operands));
+            }
+            Sample::Projected => unreachable!(),
+        };
+        // Derinlik dokuları karşılaştırmasız örneklemede tek bir f32 döndürür.
+        match texture.depth {
+            true => Ok(self.op(op::COMPOSITE_EXTRACT, &Type::Scalar(Scalar::Float), &[result.id, 0])),
+            false => Ok(result),
+        }
+    }
+
+    // GLSL: texture, textureLod, textureGrad ve textureProj. Gölge sampler'larında karşılaştırma değeri
+    // koordinatın son bileşenidir.
+    fn glsl_sample(&mut self, name: &str, sample: Sample, values: Vec<Value>, position: Position) -> Lowered<Value> {
+        let Some(Value { ty: Type::SampledTexture(texture), id: sampled, .. }) = values.first().cloned() else {
+            return Err(self.error(position, format!("`{}` expects a sampler as its first argument", name)));
+        };
+        let arity = match sample {
+            Sample::Implicit | Sample::Projected if values.len() == 3 => 3,
+            Sample::Implicit | Sample::Projected => 2,
+            Sample::Grad => 4,
+            _ => 3,
+        };
+        self.arity(name, &values, arity, position)?;
+        if matches!(sample, Sample::Implicit | Sample::Projected) {
+            self.fragment_only(name, position)?;
+        }
+        let bias = match (sample, values.get(2)) {
+            (Sample::Implicit | Sample::Projected, Some(bias)) => {
+                Some(self.float_vector(bias.clone(), 1, position)?.id)
+            }
+            _ => None,
+        };
+        let bias_operands = |bias: Option<u32>| bias.map(|bias| vec![BIAS, bias]).unwrap_or_default();
+        let count = coordinate_count(&texture);
+        if sample == Sample::Projected {
+            if texture.dimension != TextureDimension::D2 || texture.arrayed || texture.depth {
+                return Err(self.no_overload(name, &values, position));
+            }
+            // vec4 koordinatlarda z yok sayılır; bölen w'dir.
+            let coordinates = match values[1].ty.width() {
+                Some(4) => {
+                    let value = self.float_vector(values[1].clone(), 4, position)?;
+                    self.shuffle(value, &[0, 1, 3])
+                }
+                _ => self.float_vector(values[1].clone(), 3, position)?,
+            };
+            return Ok(self.sample(&texture, sampled, coordinates.id, None, true, &bias_operands(bias)));
+        }
+        let width = count + texture.arrayed as u32;
+        let (coordinates, dref) = match texture.depth {
+            true => {
+                let value = self.float_vector(values[1].clone(), width + 1, position)?;
+                let components: Vec<u32> = (0..width).collect();
+                let coordinates = self.shuffle(value.clone(), &components);
+                let dref = self.op(op::COMPOSITE_EXTRACT, &Type::Scalar(Scalar::Float), &[value.id, width]);
+                (coordinates, Some(dref.id))
+            }
+            false => (self.float_vector(values[1].clone(), width, position)?, None),
+        };
+        match sample {
+            Sample::Level => {
+                let level = self.float_vector(values[2].clone(), 1, position)?;
+                Ok(self.sample(&texture, sampled, coordinates.id, dref, false, &[LOD, level.id]))
+            }
+            Sample::Grad => {
+                let x = self.float_vector(values[2].clone(), count, position)?;
+                let y = self.float_vector(values[3].clone(), count, position)?;
+                Ok(self.sample(&texture, sampled, coordinates.id, dref, false, &[GRAD, x.id, y.id]))
+            }
+            _ => Ok(self.sample(&texture, sampled, coordinates.id, dref, false, &bias_operands(bias))),
+        }
+    }
+
+    // Örnekleme ve sorgu fonksiyonlarının ilk argümanından doku; GLSL'de birleşik sampler'ın görüntüsü alınır.
+    fn image(&mut self, name: &str, values: &[Value], position: Position) -> Lowered<(Texture, u32)> {
+        match values.first() {
+            Some(Value { ty: Type::Texture(texture), id, .. }) => Ok((*texture, *id)),
+            Some(Value { ty: Type::SampledTexture(texture), id, .. }) => {
+                let (texture, id) = (*texture, *id);
+                Ok((texture, self.op(op::IMAGE, &Type::Texture(texture), &[id]).id))
+            }
+            _ => Err(self.error(position, format!("`{}` expects a texture as its first argument", name))),
+        }
+    }
+
+    // WGSL: textureLoad(t, coords[, array_index], level); GLSL: texelFetch(s, coords, lod).
+    fn texture_load(&mut self, name: &str, values: Vec<Value>, position: Position) -> Lowered<Value> {
+        let (texture, image) = self.image(name, &values, position)?;
+        if texture.dimension == TextureDimension::Cube {
+            return Err(self.no_overload(name, &values, position));
+        }
+        let count = coordinate_count(&texture);
+        let separate_layer = self.language == Language::Wgsl && texture.arrayed;
+        self.arity(name, &values, 3 + separate_layer as usize, position)?;
+        let int = Type::Scalar(Scalar::Int);
+        let coordinates = match (self.language, texture.arrayed) {
+            (Language::Wgsl, true) => {
+                let coordinates = self.integer(values[1].clone(), &Type::Vector(Scalar::Int, count), position)?;
+                let layer = self.integer(values[2].clone(), &int, position)?;
+                self.op(op::COMPOSITE_CONSTRUCT, &Type::Vector(Scalar::Int, count + 1), &[coordinates.id, layer.id])
+            }
+            (_, arrayed) => {
+                let ty = Type::Vector(Scalar::Int, count + arrayed as u32);
+                self.integer(values[1].clone(), &ty, position)?
+            }
+        };
+        let level = self.integer(values[values.len() - 1].clone(), &int, position)?;
+        let result = self.op(op::IMAGE_FETCH, &Type::Vector(texture.kind, 4), &[image, coordinates.id, LOD, level.id]);
+        match (texture.depth, self.language) {
+            (true, Language::Wgsl) => Ok(self.op(op::COMPOSITE_EXTRACT, &Type::Scalar(Scalar::Float), &[result.id, 0])),
+            _ => Ok(result),
+        }
+    }
+
+    // Boyut, mip seviyesi ve katman sayısı sorguları. WGSL'de sonuçlar u32, GLSL'de int'tir.
+    fn texture_query(
+        &mut self,
+        name: &str,
+        builtin: Builtin,
+        values: Vec<Value>,
+        position: Position,
+    ) -> Lowered<Value> {
+        let (texture, image) = self.image(name, &values, position)?;
+        self.builder.capability(capability::IMAGE_QUERY);
+        let scalar = match self.language {
+            Language::Wgsl => Scalar::Uint,
+            Language::Glsl => Scalar::Int,
+        };
+        if let Builtin::TextureLevels = builtin {
+            self.arity(name, &values, 1, position)?;
+            return Ok(self.op(op::IMAGE_QUERY_LEVELS, &Type::Scalar(scalar), &[image]));
+        }
+        let level = match (builtin, self.language, values.len()) {
+            (Builtin::TextureSize, Language::Wgsl, 2) | (Builtin::TextureSize, Language::Glsl, _) => {
+                self.arity(name, &values, 2, position)?;
+                self.integer(values[1].clone(), &Type::Scalar(Scalar::Int), position)?.id
+            }
+            _ => {
+                self.arity(name, &values, 1, position)?;
+                self.constant_int(0)
+            }
+        };
+        let count = size_count(&texture);
+        let full = Type::Vector(scalar, count + texture.arrayed as u32);
+        let size = self.op(op::IMAGE_QUERY_SIZE_LOD, &full, &[image, level]);
+        match builtin {
+            Builtin::TextureLayers if !texture.arrayed => Err(self.no_overload(name, &values, position)),
+            Builtin::TextureLayers => Ok(self.op(op::COMPOSITE_EXTRACT, &Type::Scalar(scalar), &[size.id, count])),
+            // WGSL'de boyutlara katman sayısı dahil değildir.
+            _ if self.language == Language::Wgsl && texture.arrayed => {
+                let components: Vec<u32> = (0..count).collect();
+                Ok(self.shuffle(size, &components))
+            }
+            _ => Ok(size),
+        }
+    }
+}
\ No newline at end of file
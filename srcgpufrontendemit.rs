@@ -0,0 +1,177 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::gpu::spirv::{op, MAGIC_NUMBER};
+
+// Üretilen modüllerin SPIR-V sürümü (1.0); giriş noktası arayüzlerinde yalnızca giriş ve çıkış değişkenleri yer alır.
+const VERSION: u32 = 0x0001_0000;
+
+pub mod capability {
+    pub const SHADER: u32 = 1;
+    pub const IMAGE_QUERY: u32 = 50;
+}
+
+// SPIR-V modülünü bölümler halinde toplayan yazıcı. Tipler ve sabitler tekilleştirilir; yapı tipleri her
+// bildirimde yenidir.
+#[derive(Debug)]
+pub struct Builder {
+    bound: u32,
+    capabilities: BTreeSet<u32>,
+    imports: Vec<u32>,
+    entry_points: Vec<u32>,
+    execution_modes: Vec<u32>,
+    names: Vec<u32>,
+    annotations: Vec<u32>,
+    declarations: Vec<u32>, // Tipler, sabitler ve global değişkenler, tanım sırasıyla
+    pub functions: Vec<u32>,
+    types: HashMap<(u16, Vec<u32>), u32>,
+    constants: HashMap<(u16, Vec<u32>), u32>,
+    glsl: Option<u32>,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Builder {
+            bound: 1,
+            capabilities: BTreeSet::from([capability::SHADER]),
+            imports: Vec::new(),
+            entry_points: Vec::new(),
+            execution_modes: Vec::new(),
+            names: Vec::new(),
+            annotations: Vec::new(),
+            declarations: Vec::new(),
+            functions: Vec::new(),
+            types: HashMap::new(),
+            constants: HashMap::new(),
+            glsl: None,
+        }
+    }
+}
+
+impl Builder {
+    pub fn id(&mut self) -> u32 {
+        self.bound += 1;
+        self.bound - 1
+    }
+
+    pub fn capability(&mut self, capability: u32) {
+        self.capabilities.insert(capability);
+    }
+
+    // GLSL.std.450 komut kümesinin kimliği; ilk kullanımda içe aktarılır.
+    pub fn glsl(&mut self) -> u32 {
+        if let Some(id) = self.glsl {
+            return id;
+        }
+        let id = self.id();
+        let mut operands = vec![id];
+        operands.extend(string("GLSL.std.450"));
+        instruction(&mut self.imports, op::EXT_INST_IMPORT, &operands);
+        self.glsl = Some(id);
+        id
+    }
+
+    // Sonuç kimliği ilk işlenen olan, yapı dışındaki tip bildirimi.
+    pub fn type_id(&mut self, opcode: u16, operands: &[u32]) -> u32 {
+        if let Some(&id) = self.types.get(&(opcode, operands.to_vec())) {
+            return id;
+        }
+        let id = self.id();
+        self.types.insert((opcode, operands.to_vec()), id);
+        let mut words = vec![id];
+        words.extend_from_slice(operands);
+        instruction(&mut self.declarations, opcode, &words);
+        id
+    }
+
+    pub fn struct_type(&mut self, members: &[u32]) -> u32 {
+        let id = self.id();
+        let mut words = vec![id];
+        words.extend_from_slice(members);
+        instruction(&mut self.declarations, op::TYPE_STRUCT, &words);
+        id
+    }
+
+    // OpConstant, OpConstantTrue ve OpConstantFalse; `operands` sonuç kimliği dışındaki işlenenlerdir.
+    pub fn constant(&mut self, opcode: u16, ty: u32, value: Option<u32>) -> u32 {
+        let operands: Vec<u32> = std::iter::once(ty).chain(value).collect();
+        if let Some(&id) = self.constants.get(&(opcode, operands.clone())) {
+            return id;
+        }
+        let id = self.id();
+        self.constants.insert((opcode, operands.clone()), id);
+        let mut words = vec![ty, id];
+        words.extend_from_slice(&operands[1..]);
+        instruction(&mut self.declarations, opcode, &words);
+        id
+    }
+
+    pub fn global_variable(&mut self, pointer_type: u32, storage_class: u32) -> u32 {
+        let id = self.id();
+        instruction(&mut self.declarations, op::VARIABLE, &[pointer_type, id, storage_class]);
+        id
+    }
+
+    pub fn name(&mut self, id: u32, name: &str) {
+        let mut operands = vec![id];
+        operands.extend(string(name));
+        instruction(&mut self.names, op::NAME, &operands);
+    }
+
+    pub fn member_name(&mut self, id: u32, member: u32, name: &str) {
+        let mut operands = vec![id, member];
+        operands.extend(string(name));
+        instruction(&mut self.names, op::MEMBER_NAME, &operands);
+    }
+
+    pub fn decorate(&mut self, id: u32, decoration: u32, operands: &[u32]) {
+        let mut words = vec![id, decoration];
+        words.extend_from_slice(operands);
+        instruction(&mut self.annotations, op::DECORATE, &words);
+    }
+
+    pub fn member_decorate(&mut self, id: u32, member: u32, decoration: u32, operands: &[u32]) {
+        let mut words = vec![id, member, decoration];
+        words.extend_from_slice(operands);
+        instruction(&mut self.annotations, op::MEMBER_DECORATE, &words);
+    }
+
+    pub fn entry_point(&mut self, model: u32, function: u32, name: &str, interface: &[u32]) {
+        let mut operands = vec![model, function];
+        operands.extend(string(name));
+        operands.extend_from_slice(interface);
+        instruction(&mut self.entry_points, op::ENTRY_POINT, &operands);
+    }
+
+    pub fn execution_mode(&mut self, function: u32, mode: u32) {
+        instruction(&mut self.execution_modes, op::EXECUTION_MODE, &[function, mode]);
+    }
+
+    // Başlık ve bölümler, SPIR-V'nin mantıksal düzeninde.
+    pub fn finish(self) -> Vec<u32> {
+        let mut words = vec![MAGIC_NUMBER, VERSION, 0, self.bound, 0];
+        for &capability in &self.capabilities {
+            instruction(&mut words, op::CAPABILITY, &[capability]);
+        }
+        words.extend(self.imports);
+        instruction(&mut words, op::MEMORY_MODEL, &[0, 1]); // Logical, GLSL450
+        for section in
+            [self.entry_points, self.execution_modes, self.names, self.annotations, self.declarations, self.functions]
+        {
+            words.extend(section);
+        }
+        words
+    }
+}
+
+pub fn instruction(words: &mut Vec<u32>, opcode: u16, operands: &[u32]) {
+    words.push(((operands.len() as u32 + 1) << 16) | opcode as u32);
+    words.extend_from_slice(operands);
+}
+
+// Sıfırla sonlanan, kelimelere doldurulmuş UTF-8 dizgesi.
+pub fn string(text: &str) -> Vec<u32> {
+    let mut bytes = text.as_bytes().to_vec();
+    bytes.push(0);
+    bytes.resize(bytes.len().div_ceil(4) * 4, 0);
+    bytes.chunks_exact(4).map(|word| u32::from_le_bytes([word[0], word[1], word[2], word[3]])).collect()
+}
\ No newline at end of file
@@ -0,0 +1,1001 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::gpu::error::GraphisError;
+use crate::gpu::frontend::ast::{
+    BinaryOp, Case, Expr, ExprKind, Function, Global, GlobalKind, Interpolation, IoAttribute, IoBinding, Member,
+    Module, Param, Position, Scalar, Stmt, StmtKind, StructDecl, Texture, TextureDimension, Type, TypeExpr, UnaryOp,
+};
+use crate::gpu::frontend::lexer::{tokenize, Cursor, Lexeme, Token};
+use crate::gpu::frontend::lower::{lower, Language};
+use crate::gpu::frontend::Diagnostic;
+use crate::gpu::shader::ShaderStage;
+
+type Parsed<T> = Result<T, Diagnostic>;
+
+// GLSL kaynağını `stage` aşamasının SPIR-V kelimelerine derler; giriş noktası `main` fonksiyonudur.
+pub fn compile(source: &str, stage: ShaderStage) -> Result<Vec<u32>, GraphisError> {
+    let module = parse(source, stage).map_err(|diagnostic| GraphisError::CompilationFailed(vec![diagnostic]))?;
+    lower(&module, Language::Glsl, Some(stage)).map_err(GraphisError::CompilationFailed)
+}
+
+// Ayrıştırma ilk hatada durur; tip hataları çevirme sırasında birlikte bildirilir.
+pub fn parse(source: &str, stage: ShaderStage) -> Parsed<Module> {
+    if stage == ShaderStage::Compute {
+        return Err(Diagnostic::new(Position { line: 1, column: 1 }, "compute shaders are not supported"));
+    }
+    let lexemes = preprocess(tokenize(source, true)?)?;
+    let mut parser = Parser { cursor: Cursor::new(lexemes), stage, structs: HashSet::new() };
+    let mut module = Module::default();
+    while *parser.cursor.peek() != Token::End {
+        parser.external(&mut module)?;
+    }
+    Ok(module)
+}
+
+// Önişlemci: #version, #extension ve #pragma yok sayılır; parametresiz makrolar açılır ve #ifdef blokları
+// değerlendirilir.
+fn preprocess(lexemes: Vec<Lexeme>) -> Parsed<Vec<Lexeme>> {
+    let mut defines: HashMap<String, Vec<Token>> = HashMap::new();
+    // Açık koşullu bloklar: (dal etkin mi, #else görüldü mü, konum)
+    let mut conditions: Vec<(bool, bool, Position)> = Vec::new();
+    let mut output = Vec::new();
+    for lexeme in lexemes {
+        let active = conditions.iter().all(|(taken, ..)| *taken);
+        match lexeme.token {
+            Token::Directive(text, position) => {
+                let mut words = text.split_whitespace();
+                let directive = words.next().unwrap_or("");
+                let name = words.next();
+                let required = |name: Option<&str>| match name {
+                    Some(name) => Ok(name.to_string()),
+                    None => Err(Diagnostic::new(position, format!("`#{}` needs a macro name", directive))),
+                };
+                match directive {
+                    "ifdef" | "ifndef" => {
+                        let defined = defines.contains_key(&required(name)?);
+                        conditions.push((defined == (directive == "ifdef"), false, lexeme.position));
+                    }
+                    "else" => match conditions.last_mut() {
+                        Some((taken, seen_else @ false, _)) => {
+                            *taken = !*taken;
+                            *seen_else = true;
+                        }
+                        Some(_) => return Err(Diagnostic::new(lexeme.position, "`#else` after `#else`")),
+                        None => return Err(Diagnostic::new(lexeme.position, "`#else` without `#ifdef`")),
+                    },
+                    "endif" => {
+                        if conditions.pop().is_none() {
+                            return Err(Diagnostic::new(lexeme.position, "`#endif` without `#ifdef`"));
+                        }
+                    }
+                    _ if !active => {}
+                    "" | "version" | "extension" | "pragma" => {}
+                    "define" => {
+                        let name = required(name)?;
+                        let body = text.trim_start()["define".len()..].trim_start()[name.len()..].to_string();
+                        if body.starts_with('(') {
+                            return Err(Diagnostic::new(position, "function-like macros are not supported"));
+                        }
+                        let body = tokenize(&body, false).map_err(|mut diagnostic| {
+                            diagnostic.line = position.line;
+                            diagnostic
+                        })?;
+                        let tokens = body.into_iter().map(|lexeme| lexeme.token).filter(|token| *token != Token::End);
+                        defines.insert(name, tokens.collect());
+                    }
+                    "undef" => {
+                        defines.remove(&required(name)?);
+                    }
+                    "error" => {
+                        let message = text.trim_start()["error".len()..].trim();
+                        return Err(Diagnostic::new(lexeme.position, format!("#error {}", message)));
+                    }
+                    "if" | "elif" => {
+                        let message = format!("`#{}` is not supported; use `#ifdef` or `#ifndef`", directive);
+                        return Err(Diagnostic::new(lexeme.position, message));
+                    }
+                    _ => {
+                        let message = format!("unsupported preprocessor directive `#{}`", directive);
+                        return Err(Diagnostic::new(lexeme.position, message));
+                    }
+                }
+            }
+            Token::End => {
+                if let Some((_, _, position)) = conditions.last() {
+                    return Err(Diagnostic::new(*position, "unterminated `#ifdef`"));
+                }
+                output.push(lexeme);
+            }
+            _ if !active => {}
+            Token::Identifier(ref name) if defines.contains_key(name) => {
+                expand(name, lexeme.position, &defines, &mut Vec::new(), &mut output);
+            }
+            _ => output.push(lexeme),
+        }
+    }
+    Ok(output)
+}
+
+// Makroyu kullanıldığı konumla açar; açılmakta olan makrolar yeniden açılmaz.
+fn expand(
+    name: &str,
+    position: Position,
+    defines: &HashMap<String, Vec<Token>>,
+    expanding: &mut Vec<String>,
+    output: &mut Vec<Lexeme>,
+) {
+    expanding.push(name.to_string());
+    for token in &defines[name] {
+        match token {
+            Token::Identifier(inner) if defines.contains_key(inner) && !expanding.contains(inner) => {
+                expand(inner, position, defines, expanding, output);
+            }
+            token => output.push(Lexeme { token: token.clone(), position }),
+        }
+    }
+    expanding.pop();
+}
+
+fn error<T>(position: Position, message: impl Into<String>) -> Parsed<T> {
+    Err(Diagnostic::new(position, message))
+}
+
+// Yerleşik tip adları; yapı adlarında ve tip olmayan adlarda None.
+fn builtin_type(name: &str, position: Position) -> Parsed<Option<Type>> {
+    let vector = |scalar, count: u8| Type::Vector(scalar, u32::from(count - b'0'));
+    let texture = |dimension, arrayed, depth, kind| Texture { dimension, arrayed, depth, kind };
+    let ty = match name.as_bytes() {
+        b"bool" => Type::Scalar(Scalar::Bool),
+        b"int" => Type::Scalar(Scalar::Int),
+        b"uint" => Type::Scalar(Scalar::Uint),
+        b"float" => Type::Scalar(Scalar::Float),
+        [b'v', b'e', b'c', n @ b'2'..=b'4'] => vector(Scalar::Float, *n),
+        [b'i', b'v', b'e', b'c', n @ b'2'..=b'4'] => vector(Scalar::Int, *n),
+        [b'u', b'v', b'e', b'c', n @ b'2'..=b'4'] => vector(Scalar::Uint, *n),
+        [b'b', b'v', b'e', b'c', n @ b'2'..=b'4'] => vector(Scalar::Bool, *n),
+        [b'm', b'a', b't', n @ b'2'..=b'4'] => Type::Matrix { columns: u32::from(n - b'0'), rows: u32::from(n - b'0') },
+        [b'm', b'a', b't', c @ b'2'..=b'4', b'x', r @ b'2'..=b'4'] => {
+            Type::Matrix { columns: u32::from(c - b'0'), rows: u32::from(r - b'0') }
+        }
+        b"sampler" => Type::Sampler { comparison: false },
+        b"samplerShadow" => Type::Sampler { comparison: true },
+        b"double" | [b'd', b'v', b'e', b'c', ..] | [b'd', b'm', b'a', b't', ..] => {
+            return error(position, "double-precision types are not supported");
+        }
+        _ => {
+            // [i|u]sampler<boyut>[Shadow], [i|u]texture<boyut> ve desteklenmeyen [i|u]image<boyut>.
+            let (kind, rest) = match name.as_bytes().first() {
+                Some(b'i') => (Scalar::Int, &name[1..]),
+                Some(b'u') => (Scalar::Uint, &name[1..]),
+                _ => (Scalar::Float, name),
+            };
+            let (combined, rest) = match ["sampler", "texture", "image"].map(|prefix| rest.strip_prefix(prefix)) {
+                [Some(rest), ..] => (Some(true), rest),
+                [_, Some(rest), _] => (Some(false), rest),
+                [.., Some(rest)] => (None, rest),
+                _ => return Ok(None),
+            };
+            let (rest, depth) = match rest.strip_suffix("Shadow") {
+                Some(rest) if combined == Some(true) && kind == Scalar::Float => (rest, true),
+                _ => (rest, false),
+            };
+            let (dimension, arrayed) = match rest {
+                "2D" => (TextureDimension::D2, false),
+                "2DArray" => (TextureDimension::D2, true),
+                "3D" => (TextureDimension::D3, false),
+                "Cube" => (TextureDimension::Cube, false),
+                "CubeArray" => (TextureDimension::Cube, true),
+                "1D" | "1DArray" | "2DRect" | "2DMS" | "2DMSArray" | "Buffer" => {
+                    return error(position, format!("`{}` is not supported", name));
+                }
+                _ => return Ok(None),
+            };
+            if depth && dimension == TextureDimension::D3 {
+                return Ok(None);
+            }
+            let texture = texture(dimension, arrayed, depth, kind);
+            match combined {
+                Some(true) => Type::SampledTexture(texture),
+                Some(false) => Type::Texture(texture),
+                None => return error(position, "storage images are not supported"),
+            }
+        }
+    };
+    Ok(Some(ty))
+}
+
+fn opaque(ty: &TypeExpr) -> bool {
+    match ty {
+        TypeExpr::Builtin(ty) => ty.is_opaque(),
+        TypeExpr::Array(element, ..) => opaque(element),
+        TypeExpr::Named(..) => false,
+    }
+}
+
+// Bildirimin önündeki niteleyiciler.
+#[derive(Debug, Default)]
+struct Qualifiers {
+    storage: Option<(String, Position)>,
+    interpolation: Option<Interpolation>,
+    location: Option<u32>,
+    binding: Option<u32>,
+    set: Option<u32>,
+    index: Option<u32>,
+    position: Option<Position>, // İlk niteleyicinin konumu
+}
+
+impl Qualifiers {
+    fn storage(&self) -> Option<&str> {
+        self.storage.as_ref().map(|(storage, _)| storage.as_str())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position.is_none()
+    }
+}
+
+#[derive(Debug)]
+struct Parser {
+    cursor: Cursor,
+    stage: ShaderStage,
+    structs: HashSet<String>,
+}
+
+impl Parser {
+    fn position(&self) -> Position {
+        self.cursor.position()
+    }
+
+    // Sıradaki sözcük bir tipin başlangıcı mı; bildirimleri ifadelerden ayırır.
+    fn at_type(&self) -> bool {
+        match self.cursor.peek() {
+            Token::Identifier(name) => {
+                name == "void"
+                    || self.structs.contains(name)
+                    || builtin_type(name, Position::default()).map_or(true, |ty| ty.is_some())
+            }
+            _ => false,
+        }
+    }
+
+    // Yerel bildirim mi; tip adıyla başlayan kurucu çağrıları (`vec4(..)`) ifadedir.
+    fn at_declaration(&self) -> bool {
+        self.at_qualifier() || (self.at_type() && !matches!(self.cursor.peek_at(1), Token::Punct("(" | ".")))
+    }
+
+    fn at_qualifier(&self) -> bool {
+        matches!(
+            self.cursor.peek(),
+            Token::Identifier(name) if matches!(
+                name.as_str(),
+                "const" | "in" | "out" | "inout" | "uniform" | "attribute" | "varying" | "buffer" | "shared"
+                    | "layout" | "flat" | "smooth" | "noperspective" | "centroid" | "sample" | "invariant" | "precise"
+                    | "highp" | "mediump" | "lowp" | "readonly" | "writeonly" | "coherent" | "volatile" | "restrict"
+            )
+        )
+    }
+
+    fn qualifiers(&mut self) -> Parsed<Qualifiers> {
+        let mut qualifiers = Qualifiers::default();
+        while self.at_qualifier() {
+            let (word, position) = self.cursor.expect_identifier("a qualifier")?;
+            qualifiers.position.get_or_insert(position);
+            match word.as_str() {
+                "layout" => self.layout(&mut qualifiers)?,
+                "flat" => qualifiers.interpolation = Some(Interpolation::Flat),
+                "noperspective" => qualifiers.interpolation = Some(Interpolation::Linear),
+                "smooth" => qualifiers.interpolation = Some(Interpolation::Perspective),
+                "buffer" | "shared" => return error(position, format!("`{}` variables are not supported", word)),
+                "const" | "in" | "out" | "inout" | "uniform" | "attribute" | "varying" => {
+                    if qualifiers.storage.is_some() {
+                        return error(position, "a declaration can have only one storage qualifier");
+                    }
+                    qualifiers.storage = Some((word, position));
+                }
+                // Duyarlık, örnekleme ve bellek niteleyicileri yazılım arka ucunda etkisizdir.
+                _ => {}
+            }
+        }
+        Ok(qualifiers)
+    }
+
+    fn layout(&mut self, qualifiers: &mut Qualifiers) -> Parsed<()> {
+        self.cursor.expect("(")?;
+        loop {
+            let (name, position) = self.cursor.expect_identifier("a layout qualifier")?;
+            let value = match self.cursor.eat("=") {
+                true => {
+                    let value_position = self.position();
+                    match self.cursor.advance().token {
+                        Token::Int(value, _) if value <= u32::MAX as u64 => Some(value as u32),
+                        _ => return error(value_position, "expected a non-negative integer literal"),
+                    }
+                }
+                false => None,
+            };
+            let slot = match name.as_str() {
+                "location" => Some(&mut qualifiers.location),
+                "binding" => Some(&mut qualifiers.binding),
+                "set" => Some(&mut qualifiers.set),
+                "index" => Some(&mut qualifiers.index),
+                // Uniform bloklarının düzeni her zaman std140'tır.
+                "std140" | "std430" | "shared" | "packed" | "row_major" | "column_major" => None,
+                "push_constant" => return error(position, "push constants are not supported"),
+                _ => return error(position, format!("unsupported layout qualifier `{}`", name)),
+            };
+            if let Some(slot) = slot {
+                let Some(value) = value else { return error(position, format!("`{}` needs a value", name)) };
+                *slot = Some(value);
+            }
+            if !self.cursor.eat(",") {
+                break;
+            }
+        }
+        self.cursor.expect(")")?;
+        Ok(())
+    }
+
+    // Tip ve isteğe bağlı dizi boyutları (`float[3]`).
+    fn type_specifier(&mut self) -> Parsed<TypeExpr> {
+        let (name, position) = self.cursor.expect_identifier("a type")?;
+        let ty = match builtin_type(&name, position)? {
+            Some(ty) => TypeExpr::Builtin(ty),
+            None if name == "void" => TypeExpr::Builtin(Type::Void),
+            None if self.structs.contains(&name) => TypeExpr::Named(name, position),
+            None => return error(position, format!("unknown type `{}`", name)),
+        };
+        self.array_suffix(ty)
+    }
+
+    // `[N]` boyutları; `x[2][3]`, üç elemanlı dizilerin iki elemanlı dizisidir.
+    fn array_suffix(&mut self, ty: TypeExpr) -> Parsed<TypeExpr> {
+        let mut lengths = Vec::new();
+        while self.cursor.is("[") {
+            let position = self.position();
+            self.cursor.advance();
+            let length = if self.cursor.is("]") { None } else { Some(Box::new(self.expr()?)) };
+            self.cursor.expect("]")?;
+            lengths.push((length, position));
+        }
+        Ok(lengths.into_iter().rev().fold(ty, |ty, (length, position)| TypeExpr::Array(Box::new(ty), length, position)))
+    }
+
+    // Modül düzeyindeki bildirim.
+    fn external(&mut self, module: &mut Module) -> Parsed<()> {
+        if self.cursor.eat(";") {
+            return Ok(());
+        }
+        if self.cursor.eat_word("precision") {
+            while !self.cursor.eat(";") {
+                if self.cursor.advance().token == Token::End {
+                    return Err(self.cursor.unexpected("`;`"));
+                }
+            }
+            return Ok(());
+        }
+        let position = self.position();
+        let qualifiers = self.qualifiers()?;
+        let block = matches!(self.cursor.peek(), Token::Identifier(_)) && *self.cursor.peek_at(1) == Token::Punct("{");
+        if block && !self.cursor.is_word("struct") {
+            return match qualifiers.storage() {
+                Some("uniform") => self.uniform_block(module, &qualifiers, position),
+                _ => error(position, "interface blocks are only supported for uniforms"),
+            };
+        }
+        if self.cursor.is(";") && !qualifiers.is_empty() {
+            // `layout(early_fragment_tests) in;` gibi yalnızca niteleyiciden oluşan bildirimler.
+            return error(position, "qualifier-only declarations are not supported");
+        }
+        if self.cursor.eat_word("struct") {
+            let declaration = self.structure(position)?;
+            module.structs.push(declaration);
+            self.cursor.expect(";")?;
+            return Ok(());
+        }
+        let ty = self.type_specifier()?;
+        let (name, name_position) = self.cursor.expect_identifier("a name")?;
+        if self.cursor.eat("(") {
+            if !qualifiers.is_empty() {
+                return error(position, "functions cannot have qualifiers");
+            }
+            if let Some(function) = self.function(ty, name, name_position)? {
+                module.functions.push(function);
+            }
+            return Ok(());
+        }
+        let mut name = (name, name_position);
+        loop {
+            let ty = self.array_suffix(ty.clone())?;
+            let value = if self.cursor.eat("=") { Some(self.expr()?) } else { None };
+            let kind = self.global_kind(&qualifiers, &ty, value, &name.0, name.1)?;
+            module.globals.push(Global { name: name.0, ty: Some(ty), kind, position: name.1, anonymous: false });
+            if !self.cursor.eat(",") {
+                break;
+            }
+            name = self.cursor.expect_identifier("a name")?;
+        }
+        self.cursor.expect(";")?;
+        Ok(())
+    }
+
+    fn global_kind(
+        &self,
+        qualifiers: &Qualifiers,
+        ty: &TypeExpr,
+        value: Option<Expr>,
+        name: &str,
+        position: Position,
+    ) -> Parsed<GlobalKind> {
+        let io = |index| IoAttribute {
+            binding: IoBinding::Location { location: qualifiers.location, index },
+            interpolation: qualifiers.interpolation,
+            position,
+        };
+        let storage = qualifiers.storage.as_ref();
+        if let (Some((storage, storage_position)), Some(_)) = (storage, &value) {
+            if storage != "const" {
+                return error(*storage_position, format!("`{}` variables cannot have an initializer", storage));
+            }
+        }
+        let vertex = self.stage == ShaderStage::Vertex;
+        let kind = match storage.map(|(storage, position)| (storage.as_str(), *position)) {
+            Some(("in", _)) => GlobalKind::Input(io(0)),
+            Some(("out", _)) => GlobalKind::Output(io(qualifiers.index.unwrap_or(0))),
+            Some(("attribute", _)) if vertex => GlobalKind::Input(io(0)),
+            Some(("varying", _)) if vertex => GlobalKind::Output(io(0)),
+            Some(("varying", _)) => GlobalKind::Input(io(0)),
+            Some(("attribute", storage_position)) => {
+                return error(storage_position, "`attribute` is only allowed in vertex shaders");
+            }
+            Some(("uniform", _)) if opaque(ty) => match (qualifiers.set, qualifiers.binding) {
+                (None, None) => GlobalKind::Uniform { location: qualifiers.location },
+                (set, binding) => GlobalKind::Resource { group: set.unwrap_or(0), binding },
+            },
+            Some(("uniform", storage_position)) => {
+                if qualifiers.binding.is_some() || qualifiers.set.is_some() {
+                    let message = "`binding` and `set` are only allowed on samplers, textures and uniform blocks";
+                    return error(storage_position, message);
+                }
+                GlobalKind::Uniform { location: qualifiers.location }
+            }
+            Some(("const", _)) => match value {
+                Some(value) => GlobalKind::Constant(value),
+                None => return error(position, format!("constant `{}` needs an initializer", name)),
+            },
+            Some((storage, storage_position)) => {
+                return error(storage_position, format!("`{}` is not allowed at global scope", storage));
+            }
+            None => GlobalKind::Private(value),
+        };
+        Ok(kind)
+    }
+
+    fn members(&mut self) -> Parsed<Vec<Member>> {
+        self.cursor.expect("{")?;
+        let mut members = Vec::new();
+        while !self.cursor.eat("}") {
+            let qualifiers = self.qualifiers()?;
+            if qualifiers.storage.is_some() || qualifiers.location.is_some() {
+                return error(qualifiers.position.unwrap_or_default(), "members cannot have storage qualifiers");
+            }
+            let ty = self.type_specifier()?;
+            loop {
+                let (name, position) = self.cursor.expect_identifier("a member name")?;
+                let ty = self.array_suffix(ty.clone())?;
+                members.push(Member { name, ty, io: None, position });
+                if !self.cursor.eat(",") {
+                    break;
+                }
+            }
+            self.cursor.expect(";")?;
+        }
+        Ok(members)
+    }
+
+    fn structure(&mut self, position: Position) -> Parsed<StructDecl> {
+        let (name, _) = self.cursor.expect_identifier("a struct name")?;
+        let members = self.members()?;
+        self.structs.insert(name.clone());
+        Ok(StructDecl { name, members, position })
+    }
+
+    // `uniform Block { ... } instance;`; örnek adı verilmemiş blokların üyeleri doğrudan kullanılır.
+    fn uniform_block(&mut self, module: &mut Module, qualifiers: &Qualifiers, position: Position) -> Parsed<()> {
+        let declaration = self.structure(position)?;
+        let ty = TypeExpr::Named(declaration.name.clone(), position);
+        let (name, anonymous) = match self.cursor.peek() {
+            Token::Identifier(_) => (self.cursor.expect_identifier("an instance name")?.0, false),
+            _ => (declaration.name.clone(), true),
+        };
+        if self.cursor.is("[") {
+            return error(self.position(), "arrays of uniform blocks are not supported");
+        }
+        self.cursor.expect(";")?;
+        module.structs.push(declaration);
+        let kind = GlobalKind::UniformBlock { group: qualifiers.set.unwrap_or(0), binding: qualifiers.binding };
+        module.globals.push(Global { name, ty: Some(ty), kind, position, anonymous });
+        Ok(())
+    }
+
+    // Fonksiyon tanımı; prototiplerde None.
+    fn function(&mut self, result: TypeExpr, name: String, position: Position) -> Parsed<Option<Function>> {
+        let mut params = Vec::new();
+        let empty = self.cursor.is_word("void") && *self.cursor.peek_at(1) == Token::Punct(")");
+        if empty {
+            self.cursor.advance();
+        }
+        while !self.cursor.eat(")") {
+            let qualifiers = self.qualifiers()?;
+            if let Some((storage @ ("out" | "inout"), storage_position)) =
+                qualifiers.storage.as_ref().map(|(storage, position)| (storage.as_str(), *position))
+            {
+                return error(storage_position, format!("`{}` parameters are not supported", storage));
+            }
+            let ty = self.type_specifier()?;
+            let param_position = self.position();
+            let param = match self.cursor.peek() {
+                Token::Identifier(_) => self.cursor.expect_identifier("a parameter name")?.0,
+                _ => String::new(),
+            };
+            let ty = self.array_suffix(ty)?;
+            params.push(Param { name: param, ty, io: None, position: param_position });
+            if !self.cursor.eat(",") {
+                self.cursor.expect(")")?;
+                break;
+            }
+        }
+        if self.cursor.eat(";") {
+            return Ok(None);
+        }
+        if let Some(param) = params.iter().find(|param| param.name.is_empty()) {
+            return error(param.position, "parameters of a function definition need names");
+        }
+        let result = match result {
+            TypeExpr::Builtin(Type::Void) => None,
+            result => Some(result),
+        };
+        let body = self.block()?;
+        Ok(Some(Function { name, params, result, result_io: None, stage: None, body, position }))
+    }
+
+    // Deyimler
+
+    fn block(&mut self) -> Parsed<Vec<Stmt>> {
+        self.cursor.expect("{")?;
+        let mut statements = Vec::new();
+        while !self.cursor.eat("}") {
+            self.statement(&mut statements)?;
+        }
+        Ok(statements)
+    }
+
+    // Tek deyimlik gövde (if, for ve while); bildirimler kendi kapsamında kalır.
+    fn body(&mut self) -> Parsed<Vec<Stmt>> {
+        if self.cursor.is("{") {
+            return self.block();
+        }
+        let mut statements = Vec::new();
+        self.statement(&mut statements)?;
+        Ok(statements)
+    }
+
+    fn statement(&mut self, statements: &mut Vec<Stmt>) -> Parsed<()> {
+        let position = self.position();
+        let mut push = |kind| statements.push(Stmt { kind, position });
+        if self.cursor.eat(";") {
+            return Ok(());
+        }
+        if self.cursor.is("{") {
+            let block = self.block()?;
+            push(StmtKind::Block(block));
+            return Ok(());
+        }
+        let keyword = match self.cursor.peek() {
+            Token::Identifier(name) => name.clone(),
+            _ => String::new(),
+        };
+        match keyword.as_str() {
+            "if" => {
+                self.cursor.advance();
+                self.cursor.expect("(")?;
+                let condition = self.expr()?;
+                self.cursor.expect(")")?;
+                let accept = self.body()?;
+                let reject = if self.cursor.eat_word("else") { self.body()? } else { Vec::new() };
+                push(StmtKind::If { condition, accept, reject });
+            }
+            "for" => {
+                let kind = self.for_statement()?;
+                push(kind);
+            }
+            "while" => {
+                self.cursor.advance();
+                self.cursor.expect("(")?;
+                let condition = self.expr()?;
+                self.cursor.expect(")")?;
+                let body = self.body()?;
+                push(StmtKind::Loop { condition: Some(condition), body, continuing: Vec::new() });
+            }
+            "do" => {
+                // Koşul `continuing` içinde denetlenir; `continue` da koşula gider.
+                self.cursor.advance();
+                let body = self.body()?;
+                if !self.cursor.eat_word("while") {
+                    return Err(self.cursor.unexpected("`while`"));
+                }
+                self.cursor.expect("(")?;
+                let condition = self.expr()?;
+                self.cursor.expect(")")?;
+                self.cursor.expect(";")?;
+                let condition_position = condition.position;
+                let exit = Expr::new(ExprKind::Unary(UnaryOp::Not, Box::new(condition)), condition_position);
+                let continuing = vec![Stmt { kind: StmtKind::BreakIf(exit), position: condition_position }];
+                push(StmtKind::Loop { condition: None, body, continuing });
+            }
+            "switch" => {
+                let kind = self.switch_statement()?;
+                push(kind);
+            }
+            "break" | "continue" | "discard" => {
+                self.cursor.advance();
+                self.cursor.expect(";")?;
+                push(match keyword.as_str() {
+                    "break" => StmtKind::Break,
+                    "continue" => StmtKind::Continue,
+                    _ => StmtKind::Discard,
+                });
+            }
+            "return" => {
+                self.cursor.advance();
+                let value = if self.cursor.is(";") { None } else { Some(self.expr()?) };
+                self.cursor.expect(";")?;
+                push(StmtKind::Return(value));
+            }
+            "case" | "default" => return error(position, format!("`{}` outside of a switch", keyword)),
+            _ if self.at_declaration() => {
+                self.declaration(statements)?;
+                self.cursor.expect(";")?;
+            }
+            _ => {
+                let kind = self.simple_statement()?;
+                self.cursor.expect(";")?;
+                push(kind);
+            }
+        }
+        Ok(())
+    }
+
+    // Yerel değişken bildirimleri; virgülle ayrılmış her ad ayrı bir deyimdir.
+    fn declaration(&mut self, statements: &mut Vec<Stmt>) -> Parsed<()> {
+        let qualifiers = self.qualifiers()?;
+        let mutable = match qualifiers.storage() {
+            None => true,
+            Some("const") => false,
+            Some(storage) => {
+                let position = qualifiers.storage.as_ref().map(|(_, position)| *position).unwrap_or_default();
+                return error(position, format!("`{}` is not allowed on local variables", storage));
+            }
+        };
+        let ty = self.type_specifier()?;
+        loop {
+            let (name, position) = self.cursor.expect_identifier("a variable name")?;
+            let ty = self.array_suffix(ty.clone())?;
+            let value = if self.cursor.eat("=") { Some(self.expr()?) } else { None };
+            if !mutable && value.is_none() {
+                return error(position, format!("constant `{}` needs an initializer", name));
+            }
+            statements.push(Stmt { kind: StmtKind::Declare { name, ty: Some(ty), value, mutable }, position });
+            if !self.cursor.eat(",") {
+                return Ok(());
+            }
+        }
+    }
+
+    // Atama, artırma/azaltma veya fonksiyon çağrısı.
+    fn simple_statement(&mut self) -> Parsed<StmtKind> {
+        let position = self.position();
+        if let Token::Punct(punct @ ("++" | "--")) = *self.cursor.peek() {
+            self.cursor.advance();
+            let target = self.unary()?;
+            let op = if punct == "++" { BinaryOp::Add } else { BinaryOp::Sub };
+            return Ok(StmtKind::Assign { target, op: Some(op), value: Expr::new(ExprKind::Int(1, None), position) });
+        }
+        let target = self.conditional()?;
+        let one = Expr::new(ExprKind::Int(1, None), self.position());
+        if self.cursor.eat("=") {
+            return Ok(StmtKind::Assign { target, op: None, value: self.assignment_value()? });
+        }
+        if let Token::Punct(punct) = *self.cursor.peek() {
+            if let Some(op) = BinaryOp::from_assignment(punct) {
+                self.cursor.advance();
+                return Ok(StmtKind::Assign { target, op: Some(op), value: self.assignment_value()? });
+            }
+            if punct == "++" || punct == "--" {
+                self.cursor.advance();
+                let op = if punct == "++" { BinaryOp::Add } else { BinaryOp::Sub };
+                return Ok(StmtKind::Assign { target, op: Some(op), value: one });
+            }
+        }
+        match target.kind {
+            ExprKind::Call(..) | ExprKind::Construct(..) => Ok(StmtKind::Expr(target)),
+            _ => error(target.position, "expected an assignment or a function call"),
+        }
+    }
+
+    fn assignment_value(&mut self) -> Parsed<Expr> {
+        let value = self.conditional()?;
+        let compound = matches!(self.cursor.peek(), Token::Punct(punct) if BinaryOp::from_assignment(punct).is_some());
+        if self.cursor.is("=") || compound {
+            return error(self.position(), "chained assignments are not supported");
+        }
+        Ok(value)
+    }
+
+    // `for (init; condition; update) body`, başlangıç deyimini içeren bir bloğa ve döngüye dönüşür.
+    fn for_statement(&mut self) -> Parsed<StmtKind> {
+        let position = self.position();
+        self.cursor.advance();
+        self.cursor.expect("(")?;
+        let mut statements = Vec::new();
+        if !self.cursor.is(";") {
+            match self.at_declaration() {
+                true => self.declaration(&mut statements)?,
+                false => {
+                    let init_position = self.position();
+                    statements.push(Stmt { kind: self.simple_statement()?, position: init_position });
+                }
+            }
+        }
+        self.cursor.expect(";")?;
+        let condition = if self.cursor.is(";") { None } else { Some(self.expr()?) };
+        self.cursor.expect(";")?;
+        let mut continuing = Vec::new();
+        if !self.cursor.is(")") {
+            let update_position = self.position();
+            continuing.push(Stmt { kind: self.simple_statement()?, position: update_position });
+        }
+        self.cursor.expect(")")?;
+        let body = self.body()?;
+        statements.push(Stmt { kind: StmtKind::Loop { condition, body, continuing }, position });
+        Ok(StmtKind::Block(statements))
+    }
+
+    // Durum gövdesi break, continue, return veya discard ile bitmiyorsa bir sonraki duruma düşer.
+    fn switch_statement(&mut self) -> Parsed<StmtKind> {
+        self.cursor.advance();
+        self.cursor.expect("(")?;
+        let selector = self.expr()?;
+        self.cursor.expect(")")?;
+        self.cursor.expect("{")?;
+        let mut cases: Vec<Case> = Vec::new();
+        while !self.cursor.eat("}") {
+            let position = self.position();
+            let selector = if self.cursor.eat_word("default") {
+                None
+            } else if self.cursor.eat_word("case") {
+                Some(self.expr()?)
+            } else {
+                return Err(self.cursor.unexpected("`case` or `default`"));
+            };
+            self.cursor.expect(":")?;
+            let mut body = Vec::new();
+            while !self.cursor.is_word("case") && !self.cursor.is_word("default") && !self.cursor.is("}") {
+                if *self.cursor.peek() == Token::End {
+                    return Err(self.cursor.unexpected("`}`"));
+                }
+                self.statement(&mut body)?;
+            }
+            let ends = matches!(
+                body.last().map(|statement| &statement.kind),
+                Some(StmtKind::Break | StmtKind::Continue | StmtKind::Return(_) | StmtKind::Discard)
+            );
+            cases.push(Case { selectors: vec![selector], body, fallthrough: !ends, position });
+        }
+        Ok(StmtKind::Switch { selector, cases })
+    }
+
+    // İfadeler
+
+    fn expr(&mut self) -> Parsed<Expr> {
+        self.conditional()
+    }
+
+    fn conditional(&mut self) -> Parsed<Expr> {
+        let condition = self.binary(1)?;
+        let position = self.position();
+        if !self.cursor.eat("?") {
+            return Ok(condition);
+        }
+        let accept = self.expr()?;
+        self.cursor.expect(":")?;
+        let reject = self.conditional()?;
+        Ok(Expr::new(ExprKind::Conditional(Box::new(condition), Box::new(accept), Box::new(reject)), position))
+    }
+
+    // Önceliği en az `min` olan işleçlerden oluşan ifade.
+    fn binary(&mut self, min: u32) -> Parsed<Expr> {
+        let mut left = self.unary()?;
+        while let Token::Punct(punct) = *self.cursor.peek() {
+            let Some((op, precedence)) = BinaryOp::from_punct(punct) else { break };
+            if precedence < min {
+                break;
+            }
+            let position = self.position();
+            self.cursor.advance();
+            let right = self.binary(precedence + 1)?;
+            left = Expr::new(ExprKind::Binary(op, Box::new(left), Box::new(right)), position);
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Parsed<Expr> {
+        let position = self.position();
+        let op = match self.cursor.peek() {
+            Token::Punct("-") => UnaryOp::Negate,
+            Token::Punct("!") => UnaryOp::Not,
+            Token::Punct("~") => UnaryOp::BitNot,
+            Token::Punct("+") => {
+                self.cursor.advance();
+                return self.unary();
+            }
+            Token::Punct(punct @ ("++" | "--")) => {
+                return error(position, format!("`{}` is only supported as a statement", punct));
+            }
+            _ => return self.postfix(),
+        };
+        self.cursor.advance();
+        let operand = self.unary()?;
+        Ok(Expr::new(ExprKind::Unary(op, Box::new(operand)), position))
+    }
+
+    fn postfix(&mut self) -> Parsed<Expr> {
+        let mut expr = self.primary()?;
+        loop {
+            let position = self.position();
+            if self.cursor.eat(".") {
+                let (member, _) = self.cursor.expect_identifier("a member name")?;
+                if self.cursor.is("(") {
+                    return error(position, format!("method `{}()` is not supported", member));
+                }
+                expr = Expr::new(ExprKind::Member(Box::new(expr), member), position);
+            } else if self.cursor.eat("[") {
+                let index = self.expr()?;
+                self.cursor.expect("]")?;
+                expr = Expr::new(ExprKind::Index(Box::new(expr), Box::new(index)), position);
+            } else if let Token::Punct(punct @ ("++" | "--")) = *self.cursor.peek() {
+                // Deyim olarak kullanılan `x++` çağıran tarafından işlenir.
+                if matches!(self.cursor.peek_at(1), Token::Punct(";" | ")")) {
+                    return Ok(expr);
+                }
+                return error(position, format!("`{}` is only supported as a statement", punct));
+            } else {
+                return Ok(expr);
+            }
+        }
+    }
+
+    fn arguments(&mut self) -> Parsed<Vec<Expr>> {
+        self.cursor.expect("(")?;
+        let mut arguments = Vec::new();
+        if self.cursor.is_word("void") && *self.cursor.peek_at(1) == Token::Punct(")") {
+            self.cursor.advance();
+        }
+        while !self.cursor.eat(")") {
+            arguments.push(self.conditional()?);
+            if !self.cursor.eat(",") {
+                self.cursor.expect(")")?;
+                break;
+            }
+        }
+        Ok(arguments)
+    }
+
+    fn primary(&mut self) -> Parsed<Expr> {
+        let position = self.position();
+        if !matches!(self.cursor.peek(), Token::Int(..) | Token::Float(_) | Token::Identifier(_) | Token::Punct("(")) {
+            return Err(self.cursor.unexpected("an expression"));
+        }
+        let kind = match self.cursor.advance().token {
+            Token::Int(value, suffix) => ExprKind::Int(value, suffix),
+            Token::Float(value) => ExprKind::Float(value),
+            Token::Identifier(name) if name == "true" || name == "false" => ExprKind::Bool(name == "true"),
+            Token::Identifier(name) => match builtin_type(&name, position)? {
+                // Dokular kurulamaz; `texture2D(..)` gibi çağrılar eski GLSL'nin örnekleme fonksiyonlarıdır.
+                Some(Type::Texture(_)) if self.cursor.is("(") => ExprKind::Call(name, self.arguments()?),
+                // Tip kurucusu; dizi kurucularında boyut boş bırakılabilir (`float[](..)`).
+                Some(ty) => {
+                    let ty = self.array_suffix(TypeExpr::Builtin(ty))?;
+                    ExprKind::Construct(ty, self.arguments()?)
+                }
+                None if self.structs.contains(&name) && self.cursor.is("[") => {
+                    let ty = self.array_suffix(TypeExpr::Named(name, position))?;
+                    ExprKind::Construct(ty, self.arguments()?)
+                }
+                None if self.cursor.is("(") => ExprKind::Call(name, self.arguments()?),
+                None => ExprKind::Name(name),
+            },
+            _ => {
+                let expr = self.expr()?;
+                self.cursor.expect(")")?;
+                return Ok(expr);
+            }
+        };
+        Ok(Expr::new(kind, position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gpu::spirv::{BindingKind, SpirvModule};
+
+    const VERTEX: &str = "#version 450
+layout(location = 0) in vec2 position;
+layout(location = 1) in uint layer;
+out vec2 uv;
+flat out uint vlayer;
+void main() {
+    uv = position * 0.5 + 0.5;
+    vlayer = layer;
+    gl_Position = vec4(position, 0.0, 1.0);
+}
+";
+
+    const FRAGMENT: &str = "#version 450
+in vec2 uv;
+flat in uint vlayer;
+layout(binding = 2) uniform sampler2D image;
+layout(location = 0) out vec4 color;
+void main() {
+    color = texture(image, uv) * float(vlayer);
+}
+";
+
+    fn diagnostics(source: &str, stage: ShaderStage) -> Vec<(u32, u32, String)> {
+        match compile(source, stage) {
+            Err(GraphisError::CompilationFailed(diagnostics)) => diagnostics
+                .into_iter()
+                .map(|diagnostic| (diagnostic.line, diagnostic.column, diagnostic.message))
+                .collect(),
+            other => panic!("expected a compilation failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn compiled_modules_parse_and_reflect() {
+        let vertex = SpirvModule::from_words(&compile(VERTEX, ShaderStage::Vertex).unwrap()).unwrap();
+        let entry_point = vertex.entry_point("main", ShaderStage::Vertex).unwrap();
+        let inputs: Vec<_> = entry_point.inputs.iter().map(|input| (input.location, input.name.as_deref())).collect();
+        assert_eq!(inputs, vec![(0, Some("position")), (1, Some("layer"))]);
+        // Konumu verilmemiş çıkışlar bildirim sırasıyla konumlanır.
+        let outputs: Vec<_> =
+            entry_point.outputs.iter().map(|output| (output.location, output.interpolation)).collect();
+        assert_eq!(outputs, vec![(0, Interpolation::Perspective), (1, Interpolation::Flat)]);
+
+        let fragment = SpirvModule::from_words(&compile(FRAGMENT, ShaderStage::Fragment).unwrap()).unwrap();
+        let entry_point = fragment.entry_point("main", ShaderStage::Fragment).unwrap();
+        let inputs: Vec<_> = entry_point.inputs.iter().map(|input| (input.location, input.interpolation)).collect();
+        assert_eq!(inputs, vec![(0, Interpolation::Perspective), (1, Interpolation::Flat)]);
+        assert_eq!(entry_point.outputs[0].name.as_deref(), Some("color"));
+        let bindings: Vec<_> =
+            fragment.bindings().iter().map(|binding| (binding.set, binding.binding, binding.kind)).collect();
+        assert_eq!(bindings, vec![(0, 2, BindingKind::CombinedTextureSampler)]);
+    }
+
+    #[test]
+    fn syntax_errors_point_at_the_token() {
+        let source = "#version 450\nout vec4 o;\nvoid main() {\n  o = vec4(1.0)\n}\n";
+        assert_eq!(diagnostics(source, ShaderStage::Fragment), vec![(5, 1, "expected `;`, found `}`".to_string())]);
+        let source = "#version 450\n#ifdef A\nvoid main() {}\n";
+        assert_eq!(diagnostics(source, ShaderStage::Fragment), vec![(2, 1, "unterminated `#ifdef`".to_string())]);
+    }
+
+    #[test]
+    fn type_errors_point_at_the_operator() {
+        let source = "#version 450\nout vec4 o;\nvoid main() {\n  o = vec4(1.0) * true;\n}\n";
+        assert_eq!(
+            diagnostics(source, ShaderStage::Fragment),
+            vec![(4, 17, "operator `*` cannot be applied to `vec4` and `bool`".to_string())]
+        );
+    }
+}
\ No newline at end of file
@@ -0,0 +1,328 @@
+use crate::gpu::frontend::ast::{Position, Scalar};
+use crate::gpu::frontend::Diagnostic;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Identifier(String),
+    Int(u64, Option<Scalar>),
+    Float(f64),
+    Punct(&'static str),
+    // GLSL önişlemci satırı; `#` ile satır sonu arasındaki metin ve metnin başladığı konum.
+    Directive(String, Position),
+    End,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lexeme {
+    pub token: Token,
+    pub position: Position,
+}
+
+// Uzunları önce denenir.
+const PUNCTUATION: &[&str] = &[
+    "<<=", ">>=", "->", "++", "--", "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=", "==", "!=", "<=", ">=", "&&", "||",
+    "<<", ">>", "+", "-", "*", "/", "%", "=", "<", ">", "!", "&", "|", "^", "~", "?", ":", ";", ",", ".", "(", ")",
+    "{", "}", "[", "]", "@",
+];
+
+// Kaynağı sözcüklere ayırır. `directives` açıksa satır başındaki `#` ile başlayan satırlar Directive olur.
+pub fn tokenize(source: &str, directives: bool) -> Result<Vec<Lexeme>, Diagnostic> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut lexemes = Vec::new();
+    let mut position = Position { line: 1, column: 1 };
+    let mut index = 0;
+    let mut line_start = true; // Satırda şimdiye kadar yalnızca boşluk var
+    let advance = |index: &mut usize, position: &mut Position, count: usize| {
+        for _ in 0..count {
+            if chars.get(*index) == Some(&'\n') {
+                position.line += 1;
+                position.column = 1;
+            } else {
+                position.column += 1;
+            }
+            *index += 1;
+        }
+    };
+    while index < chars.len() {
+        let c = chars[index];
+        let next = chars.get(index + 1).copied();
+        if c.is_whitespace() {
+            if c == '\n' {
+                line_start = true;
+            }
+            advance(&mut index, &mut position, 1);
+            continue;
+        }
+        if c == '/' && next == Some('/') {
+            while index < chars.len() && chars[index] != '\n' {
+                advance(&mut index, &mut position, 1);
+            }
+            continue;
+        }
+        if c == '/' && next == Some('*') {
+            let start = position;
+            advance(&mut index, &mut position, 2);
+            loop {
+                match (chars.get(index), chars.get(index + 1)) {
+                    (Some('*'), Some('/')) => break advance(&mut index, &mut position, 2),
+                    (Some(_), _) => advance(&mut index, &mut position, 1),
+                    (None, _) => return Err(Diagnostic::new(start, "unterminated block comment")),
+                }
+            }
+            continue;
+        }
+        let start = position;
+        if c == '#' && directives && line_start {
+            advance(&mut index, &mut position, 1);
+            let text_start = position;
+            let mut text = String::new();
+            while index < chars.len() && chars[index] != '\n' {
+                // Satır sonundaki `\` satırı bir sonrakiyle birleştirir.
+                if chars[index] == '\\' && chars.get(index + 1) == Some(&'\n') {
+                    advance(&mut index, &mut position, 2);
+                    text.push(' ');
+                    continue;
+                }
+                text.push(chars[index]);
+                advance(&mut index, &mut position, 1);
+            }
+            lexemes.push(Lexeme { token: Token::Directive(text, text_start), position: start });
+            continue;
+        }
+        line_start = false;
+        if c.is_alphabetic() || c == '_' {
+            let mut name = String::new();
+            while let Some(&c) = chars.get(index).filter(|c| c.is_alphanumeric() || **c == '_') {
+                name.push(c);
+                advance(&mut index, &mut position, 1);
+            }
+            lexemes.push(Lexeme { token: Token::Identifier(name), position: start });
+            continue;
+        }
+        if c.is_ascii_digit() || (c == '.' && next.is_some_and(|next| next.is_ascii_digit())) {
+            let (token, length) = number(&chars[index..], start)?;
+            advance(&mut index, &mut position, length);
+            lexemes.push(Lexeme { token, position: start });
+            continue;
+        }
+        let rest: String = chars[index..chars.len().min(index + 3)].iter().collect();
+        match PUNCTUATION.iter().find(|punct| rest.starts_with(**punct)) {
+            Some(punct) => {
+                advance(&mut index, &mut position, punct.len());
+                lexemes.push(Lexeme { token: Token::Punct(punct), position: start });
+            }
+            None => return Err(Diagnostic::new(start, format!("unexpected character {:?}", c))),
+        }
+    }
+    lexemes.push(Lexeme { token: Token::End, position });
+    Ok(lexemes)
+}
+
+// Sayısal değişmez ve uzunluğu. Onaltılık (0x) tamsayılar, ondalık ve üslü kayan noktalı sayılar ile u, i ve f
+// son ekleri tanınır; 0 ile başlayan tamsayılar sekizliktir (GLSL).
+fn number(chars: &[char], position: Position) -> Result<(Token, usize), Diagnostic> {
+    let mut length = 0;
+    let digits = |length: &mut usize, radix: u32| {
+        let start = *length;
+        while chars.get(*length).is_some_and(|c| c.is_digit(radix)) {
+            *length += 1;
+        }
+        *length - start
+    };
+    let text = |start: usize, end: usize| chars[start..end].iter().collect::<String>();
+    let invalid = || Diagnostic::new(position, format!("invalid number {:?}", text(0, chars.len().min(24))));
+    if chars[0] == '0' && matches!(chars.get(1), Some('x' | 'X')) {
+        length = 2;
+        if digits(&mut length, 16) == 0 {
+            return Err(invalid());
+        }
+        let value = u64::from_str_radix(&text(2, length), 16).map_err(|_| invalid())?;
+        let (suffix, suffix_length) = int_suffix(chars.get(length).copied());
+        return finish(chars, Token::Int(value, suffix), length + suffix_length, position);
+    }
+    digits(&mut length, 10);
+    let mut float = false;
+    if chars.get(length) == Some(&'.') {
+        float = true;
+        length += 1;
+        digits(&mut length, 10);
+    }
+    if matches!(chars.get(length), Some('e' | 'E')) {
+        let mut end = length + 1;
+        if matches!(chars.get(end), Some('+' | '-')) {
+            end += 1;
+        }
+        if chars.get(end).is_some_and(|c| c.is_ascii_digit()) {
+            float = true;
+            length = end;
+            digits(&mut length, 10);
+        }
+    }
+    let literal = text(0, length);
+    if float || matches!(chars.get(length), Some('f' | 'F')) {
+        let value: f64 = literal.parse().map_err(|_| invalid())?;
+        let suffix = usize::from(matches!(chars.get(length), Some('f' | 'F')));
+        if matches!(chars.get(length), Some('h' | 'l')) {
+            return Err(Diagnostic::new(position, "only 32-bit floating-point literals are supported"));
+        }
+        return finish(chars, Token::Float(value), length + suffix, position);
+    }
+    let value = match literal.len() > 1 && literal.starts_with('0') {
+        true => u64::from_str_radix(&literal, 8),
+        false => literal.parse(),
+    };
+    let value = value.map_err(|_| invalid())?;
+    let (suffix, suffix_length) = int_suffix(chars.get(length).copied());
+    finish(chars, Token::Int(value, suffix), length + suffix_length, position)
+}
+
+fn int_suffix(c: Option<char>) -> (Option<Scalar>, usize) {
+    match c {
+        Some('u' | 'U') => (Some(Scalar::Uint), 1),
+        Some('i') => (Some(Scalar::Int), 1),
+        _ => (None, 0),
+    }
+}
+
+// Değişmezin hemen ardından harf veya rakam gelmemelidir.
+fn finish(chars: &[char], token: Token, length: usize, position: Position) -> Result<(Token, usize), Diagnostic> {
+    match chars.get(length) {
+        Some(&c) if c.is_alphanumeric() || c == '_' => {
+            Err(Diagnostic::new(position, format!("invalid suffix {:?} on a numeric literal", c)))
+        }
+        _ => Ok((token, length)),
+    }
+}
+
+// Ayrıştırıcıların sözcükler üzerinde ilerlediği imleç.
+#[derive(Debug)]
+pub struct Cursor {
+    lexemes: Vec<Lexeme>,
+    index: usize,
+}
+
+impl Cursor {
+    pub fn new(lexemes: Vec<Lexeme>) -> Self {
+        Cursor { lexemes, index: 0 }
+    }
+
+    pub fn peek(&self) -> &Token {
+        self.peek_at(0)
+    }
+
+    // Sıradaki sözcükten `offset` sonraki sözcük; sonda End.
+    pub fn peek_at(&self, offset: usize) -> &Token {
+        let last = self.lexemes.len() - 1;
+        &self.lexemes[(self.index + offset).min(last)].token
+    }
+
+    pub fn position(&self) -> Position {
+        self.lexemes[self.index.min(self.lexemes.len() - 1)].position
+    }
+
+    pub fn advance(&mut self) -> Lexeme {
+        let lexeme = self.lexemes[self.index.min(self.lexemes.len() - 1)].clone();
+        if self.index < self.lexemes.len() - 1 {
+            self.index += 1;
+        }
+        lexeme
+    }
+
+    pub fn is(&self, punct: &str) -> bool {
+        matches!(self.peek(), Token::Punct(current) if *current == punct)
+    }
+
+    pub fn is_word(&self, word: &str) -> bool {
+        matches!(self.peek(), Token::Identifier(current) if current == word)
+    }
+
+    pub fn eat(&mut self, punct: &str) -> bool {
+        let found = self.is(punct);
+        if found {
+            self.advance();
+        }
+        found
+    }
+
+    pub fn eat_word(&mut self, word: &str) -> bool {
+        let found = self.is_word(word);
+        if found {
+            self.advance();
+        }
+        found
+    }
+
+    pub fn expect(&mut self, punct: &str) -> Result<Position, Diagnostic> {
+        let position = self.position();
+        match self.eat(punct) {
+            true => Ok(position),
+            false => Err(self.unexpected(&format!("`{}`", punct))),
+        }
+    }
+
+    // Şablon listelerini kapatan `>`; `>>` ve `>=` bölünerek ilk karakteri tüketilir.
+    pub fn expect_closing_angle(&mut self) -> Result<(), Diagnostic> {
+        let rest = match self.peek() {
+            Token::Punct(">") => None,
+            Token::Punct(">>") => Some(">"),
+            Token::Punct(">=") => Some("="),
+            Token::Punct(">>=") => Some(">="),
+            _ => return Err(self.unexpected("`>`")),
+        };
+        match rest {
+            Some(rest) => {
+                let lexeme = &mut self.lexemes[self.index];
+                lexeme.token = Token::Punct(rest);
+                lexeme.position.column += 1;
+            }
+            None => {
+                self.advance();
+            }
+        }
+        Ok(())
+    }
+
+    pub fn expect_identifier(&mut self, what: &str) -> Result<(String, Position), Diagnostic> {
+        let position = self.position();
+        match self.peek().clone() {
+            Token::Identifier(name) => {
+                self.advance();
+                Ok((name, position))
+            }
+            _ => Err(self.unexpected(what)),
+        }
+    }
+
+    // Beklenen yerine bulunan sözcüğü bildiren tanı.
+    pub fn unexpected(&self, expected: &str) -> Diagnostic {
+        let found = match self.peek() {
+            Token::Identifier(name) => format!("`{}`", name),
+            Token::Int(value, _) => format!("`{}`", value),
+            Token::Float(value) => format!("`{}`", value),
+            Token::Punct(punct) => format!("`{}`", punct),
+            Token::Directive(..) => "a preprocessor directive".to_string(),
+            Token::End => "the end of the source".to_string(),
+        };
+        Diagnostic::new(self.position(), format!("expected {}, found {}", expected, found))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_count_characters() {
+        let lexemes = tokenize("let ğüş = 1;\n  x", false).unwrap();
+        let positions: Vec<_> = lexemes.iter().map(|lexeme| (lexeme.position.line, lexeme.position.column)).collect();
+        assert_eq!(positions, vec![(1, 1), (1, 5), (1, 9), (1, 11), (1, 12), (2, 3), (2, 4)]);
+        assert_eq!(lexemes[1].token, Token::Identifier("ğüş".to_string()));
+    }
+
+    #[test]
+    fn unknown_characters_are_reported_at_their_position() {
+        let diagnostic = tokenize("let ğ = 1;\n  x $", false).unwrap_err();
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 5));
+        assert_eq!(diagnostic.message, "unexpected character '$'");
+    }
+}
\ No newline at end of file
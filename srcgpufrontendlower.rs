@@ -0,0 +1,2460 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::gpu::frontend::ast::{
+    BinaryOp, Case, Expr, ExprKind, Function, Global, GlobalKind, Interpolation, IoAttribute, IoBinding, Module,
+    Position, Scalar, Stmt, StmtKind, TextureDimension, Type, TypeExpr, UnaryOp,
+};
+use crate::gpu::frontend::emit::{instruction, Builder};
+use crate::gpu::frontend::Diagnostic;
+use crate::gpu::shader::ShaderStage;
+use crate::gpu::spirv::{built_in, decoration, execution_mode, op, StorageClass};
+
+// Sabitlerin birbirine başvururken açılabileceği en fazla derinlik; aşılırsa sabit kendi cinsinden tanımlanmıştır.
+const MAX_INLINE_DEPTH: u32 = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Wgsl,
+    Glsl,
+}
+
+// Tanısı eklenmiş hata; çağıranlar yalnızca o ifadeyi veya deyimi bırakır.
+#[derive(Debug, Clone, Copy)]
+pub struct Reported;
+
+pub type Lowered<T> = Result<T, Reported>;
+
+// Türü kullanıldığı yere göre belirlenen (son eksiz) değişmez.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Literal {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl Literal {
+    fn default_type(self) -> Type {
+        match self {
+            Literal::Int(_) => Type::Scalar(Scalar::Int),
+            Literal::Float(_) => Type::Scalar(Scalar::Float),
+            Literal::Bool(_) => Type::Scalar(Scalar::Bool),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Value {
+    pub id: u32,
+    pub ty: Type,
+    pub literal: Option<Literal>, // Değişmezlerde başka bir skaler türe dönüştürülebilen değer
+}
+
+#[derive(Debug, Clone)]
+enum Binding<'a> {
+    Value(Value),
+    Variable { pointer: u32, ty: Type, storage: StorageClass, writable: bool },
+    // Örnek adı verilmemiş (GLSL) veya yapı olmayan (WGSL) uniform bloğunun üyesi.
+    BlockMember { pointer: u32, index: u32, ty: Type },
+    // Modül düzeyindeki sabit; her kullanıldığı yerde yeniden hesaplanır.
+    Inline(&'a Expr, Option<Type>),
+    Poisoned, // Bildirimi hatalı; kullanımları yeni tanı üretmez
+}
+
+// Okunabilen veya yazılabilen bir ifadenin yeri.
+#[derive(Debug, Clone)]
+pub enum Place {
+    Pointer { pointer: u32, ty: Type, storage: StorageClass },
+    // Vektör değişkeninin bileşen seçimi; `ty` vektörün tipidir.
+    Swizzle { pointer: u32, ty: Type, storage: StorageClass, components: Vec<u32> },
+    Value(Value),
+}
+
+impl Place {
+    fn ty(&self) -> Type {
+        match self {
+            Place::Pointer { ty, .. } => ty.clone(),
+            Place::Swizzle { ty, components, .. } => {
+                Type::Vector(ty.scalar().unwrap_or(Scalar::Float), components.len() as u32)
+            }
+            Place::Value(value) => value.ty.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct StructInfo {
+    name: String,
+    members: Vec<(String, Type)>,
+    id: u32,
+}
+
+#[derive(Debug, Clone)]
+struct FunctionInfo {
+    index: usize,
+    id: u32,
+    params: Vec<Type>,
+    result: Type,
+    stage: Option<ShaderStage>, // Giriş noktalarında aşama; giriş noktaları çağrılamaz
+}
+
+// `break` ve `continue` hedefleri. `reached`, birleşme bloğuna dallanan bir yol olduğunu gösterir.
+#[derive(Debug)]
+struct Target {
+    merge: u32,
+    continuing: Option<u32>, // switch'lerde None
+    reached: bool,
+}
+
+// Otomatik atanan konum ve bağlama numaralarının türü.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Slot {
+    Input,
+    Output(u32), // Çıkışlarda Index
+    Uniform,
+    Binding(u32), // Tanımlayıcı kümesi
+}
+
+// Modülü SPIR-V'ye çevirir. GLSL'de `stage` `main` fonksiyonunun aşamasıdır; WGSL'de aşamalar giriş
+// noktalarının özniteliklerinden okunur. Hatalar kaynaktaki sıralarıyla döner.
+pub fn lower(module: &Module, language: Language, stage: Option<ShaderStage>) -> Result<Vec<u32>, Vec<Diagnostic>> {
+    let mut lowerer = Lowerer::new(module, language, stage);
+    lowerer.lower_module();
+    if lowerer.diagnostics.is_empty() {
+        return Ok(lowerer.builder.finish());
+    }
+    let mut diagnostics = lowerer.diagnostics;
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.line, diagnostic.column));
+    diagnostics.dedup();
+    Err(diagnostics)
+}
+
+#[derive(Debug)]
+pub struct Lowerer<'a> {
+    module: &'a Module,
+    pub(super) language: Language,
+    pub(super) stage: Option<ShaderStage>, // Çevrilen fonksiyonun aşaması; WGSL'de yardımcı fonksiyonlarda None
+    pub(super) builder: Builder,
+    diagnostics: Vec<Diagnostic>,
+    structs: Vec<StructInfo>,
+    struct_names: HashMap<String, usize>,
+    laid_out: HashSet<u32>, // std140 düzeniyle dekore edilmiş tipler
+    globals: HashMap<String, Binding<'a>>,
+    functions: HashMap<String, Option<FunctionInfo>>, // Parametre veya dönüş tipi çözümlenemeyenlerde None
+    initializers: Vec<(usize, u32, Type)>, // Modül düzeyindeki değişkenler: (global sırası, işaretçi, tip)
+    interface: Vec<u32>, // Giriş noktasının giriş ve çıkış değişkenleri
+    io_locations: HashSet<(bool, u32, u32)>, // (giriş mi, konum, Index)
+    writes_depth: bool,
+    inline_depth: u32,
+    // Çevrilmekte olan fonksiyonun durumu.
+    scopes: Vec<HashMap<String, Binding<'a>>>,
+    variables: Vec<u32>, // İlk bloğun başına yazılan OpVariable'lar
+    body: Vec<u32>,
+    block: u32,
+    terminated: bool, // Geçerli blok sonlandırıldı; ardından gelen deyimlere ulaşılamaz
+    targets: Vec<Target>,
+    result: Type,
+}
+
+impl<'a> Lowerer<'a> {
+    fn new(module: &'a Module, language: Language, stage: Option<ShaderStage>) -> Self {
+        Lowerer {
+            module,
+            language,
+            stage,
+            builder: Builder::default(),
+            diagnostics: Vec::new(),
+            structs: Vec::new(),
+            struct_names: HashMap::new(),
+            laid_out: HashSet::new(),
+            globals: HashMap::new(),
+            functions: HashMap::new(),
+            initializers: Vec::new(),
+            interface: Vec::new(),
+            io_locations: HashSet::new(),
+            writes_depth: false,
+            inline_depth: 0,
+            scopes: Vec::new(),
+            variables: Vec::new(),
+            body: Vec::new(),
+            block: 0,
+            terminated: false,
+            targets: Vec::new(),
+            result: Type::Void,
+        }
+    }
+
+    fn lower_module(&mut self) {
+        self.declare_structs();
+        self.declare_functions();
+        self.declare_globals();
+        let module = self.module;
+        for (index, function) in module.functions.iter().enumerate() {
+            let Some(Some(info)) = self.functions.get(&function.name).cloned() else { continue };
+            if info.index != index {
+                continue;
+            }
+            self.lower_function(function, &info);
+            if self.language == Language::Wgsl && function.stage.is_some() {
+                self.wgsl_entry_point(function, &info);
+            }
+        }
+        match self.language {
+            Language::Glsl => self.glsl_entry_point(),
+            Language::Wgsl if !module.functions.iter().any(|function| function.stage.is_some()) => {
+                self.error(Position { line: 1, column: 1 }, "the module has no @vertex or @fragment entry point");
+            }
+            Language::Wgsl => {}
+        }
+    }
+
+    pub(super) fn error(&mut self, position: Position, message: impl Into<String>) -> Reported {
+        self.diagnostics.push(Diagnostic::new(position, message));
+        Reported
+    }
+
+    // Tipler ve sabitler
+
+    pub(super) fn type_id(&mut self, ty: &Type) -> u32 {
+        match ty {
+            Type::Void => self.builder.type_id(op::TYPE_VOID, &[]),
+            Type::Scalar(Scalar::Bool) => self.builder.type_id(op::TYPE_BOOL, &[]),
+            Type::Scalar(Scalar::Int) => self.builder.type_id(op::TYPE_INT, &[32, 1]),
+            Type::Scalar(Scalar::Uint) => self.builder.type_id(op::TYPE_INT, &[32, 0]),
+            Type::Scalar(Scalar::Float) => self.builder.type_id(op::TYPE_FLOAT, &[32]),
+            Type::Vector(scalar, count) => {
+                let component = self.type_id(&Type::Scalar(*scalar));
+                self.builder.type_id(op::TYPE_VECTOR, &[component, *count])
+            }
+            Type::Matrix { columns, rows } => {
+                let column = self.type_id(&Type::Vector(Scalar::Float, *rows));
+                self.builder.type_id(op::TYPE_MATRIX, &[column, *columns])
+            }
+            Type::Array(element, length) => {
+                let element = self.type_id(element);
+                let length = self.constant_uint(*length);
+                self.builder.type_id(op::TYPE_ARRAY, &[element, length])
+            }
+            Type::Struct(index) => self.structs[*index].id,
+            Type::Texture(texture) => {
+                let sampled = self.type_id(&Type::Scalar(texture.kind));
+                let dimension = match texture.dimension {
+                    TextureDimension::D2 => 1,
+                    TextureDimension::D3 => 2,
+                    TextureDimension::Cube => 3,
+                };
+                let operands = [sampled, dimension, texture.depth as u32, texture.arrayed as u32, 0, 1, 0];
+                self.builder.type_id(op::TYPE_IMAGE, &operands)
+            }
+            Type::Sampler { .. } => self.builder.type_id(op::TYPE_SAMPLER, &[]),
+            Type::SampledTexture(texture) => {
+                let image = self.type_id(&Type::Texture(*texture));
+                self.builder.type_id(op::TYPE_SAMPLED_IMAGE, &[image])
+            }
+        }
+    }
+
+    fn pointer_type(&mut self, ty: &Type, storage: StorageClass) -> u32 {
+        let pointee = self.type_id(ty);
+        self.builder.type_id(op::TYPE_POINTER, &[storage.to_word(), pointee])
+    }
+
+    pub(super) fn constant_int(&mut self, value: i32) -> u32 {
+        let ty = self.type_id(&Type::Scalar(Scalar::Int));
+        self.builder.constant(op::CONSTANT, ty, Some(value as u32))
+    }
+
+    pub(super) fn constant_uint(&mut self, value: u32) -> u32 {
+        let ty = self.type_id(&Type::Scalar(Scalar::Uint));
+        self.builder.constant(op::CONSTANT, ty, Some(value))
+    }
+
+    pub(super) fn constant_float(&mut self, value: f32) -> u32 {
+        let ty = self.type_id(&Type::Scalar(Scalar::Float));
+        self.builder.constant(op::CONSTANT, ty, Some(value.to_bits()))
+    }
+
+    fn constant_bool(&mut self, value: bool) -> u32 {
+        let ty = self.type_id(&Type::Scalar(Scalar::Bool));
+        self.builder.constant(if value { op::CONSTANT_TRUE } else { op::CONSTANT_FALSE }, ty, None)
+    }
+
+    // Tipin sıfır değeri.
+    pub(super) fn null(&mut self, ty: &Type) -> u32 {
+        let ty = self.type_id(ty);
+        self.builder.constant(op::CONSTANT_NULL, ty, None)
+    }
+
+    // `scalar` türünde 0 veya 1.
+    fn unit(&mut self, scalar: Scalar, one: bool) -> u32 {
+        match scalar {
+            Scalar::Bool => self.constant_bool(one),
+            Scalar::Int => self.constant_int(one as i32),
+            Scalar::Uint => self.constant_uint(one as u32),
+            Scalar::Float => self.constant_float(if one { 1.0 } else { 0.0 }),
+        }
+    }
+
+    pub(super) fn literal(&mut self, literal: Literal) -> Value {
+        let id = match literal {
+            Literal::Int(value) => self.constant_int(value as i32),
+            Literal::Float(value) => self.constant_float(value as f32),
+            Literal::Bool(value) => self.constant_bool(value),
+        };
+        Value { id, ty: literal.default_type(), literal: Some(literal) }
+    }
+
+    // Değişmezin `scalar` türündeki hali; değer o türe sığmıyorsa None.
+    pub(super) fn literal_as(&mut self, literal: Literal, scalar: Scalar) -> Option<Value> {
+        let id = match (literal, scalar) {
+            (Literal::Int(value), Scalar::Int) if i32::try_from(value).is_ok() => self.constant_int(value as i32),
+            // GLSL'de 0xFFFFFFFF gibi değişmezler bit deseniyle int'tir.
+            (Literal::Int(value), Scalar::Int) if self.language == Language::Glsl && u32::try_from(value).is_ok() => {
+                self.constant_int(value as i32)
+            }
+            (Literal::Int(value), Scalar::Uint) => self.constant_uint(u32::try_from(value).ok()?),
+            (Literal::Int(value), Scalar::Float) => self.constant_float(value as f32),
+            (Literal::Float(value), Scalar::Float) => self.constant_float(value as f32),
+            (Literal::Bool(value), Scalar::Bool) => self.constant_bool(value),
+            _ => return None,
+        };
+        Some(Value { id, ty: Type::Scalar(scalar), literal: Some(literal) })
+    }
+
+    // Sabit tamsayı ifadesinin değeri (dizi uzunlukları ve case seçicileri için); modül düzeyindeki sabitler açılır.
+    fn const_literal(&self, expr: &Expr, depth: u32) -> Option<Literal> {
+        if depth > MAX_INLINE_DEPTH {
+            return None;
+        }
+        match &expr.kind {
+            ExprKind::Int(value, _) => Some(Literal::Int(*value as i64)),
+            ExprKind::Float(value) => Some(Literal::Float(*value)),
+            ExprKind::Bool(value) => Some(Literal::Bool(*value)),
+            ExprKind::Unary(op, operand) => match (op, self.const_literal(operand, depth + 1)?) {
+                (UnaryOp::Negate, Literal::Int(value)) => Some(Literal::Int(value.checked_neg()?)),
+                (UnaryOp::Negate, Literal::Float(value)) => Some(Literal::Float(-value)),
+                (UnaryOp::Not, Literal::Bool(value)) => Some(Literal::Bool(!value)),
+                (UnaryOp::BitNot, Literal::Int(value)) => Some(Literal::Int(!value)),
+                _ => None,
+            },
+            ExprKind::Binary(op, left, right) => {
+                fold(*op, self.const_literal(left, depth + 1)?, self.const_literal(right, depth + 1)?)
+            }
+            ExprKind::Name(name) => {
+                let global = self.module.globals.iter().find(|global| global.name == *name)?;
+                match &global.kind {
+                    GlobalKind::Constant(value) => self.const_literal(value, depth + 1),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    pub(super) fn type_name(&self, ty: &Type) -> String {
+        let wgsl = self.language == Language::Wgsl;
+        let scalar_name = |scalar: Scalar| match (scalar, wgsl) {
+            (Scalar::Bool, _) => "bool",
+            (Scalar::Int, true) => "i32",
+            (Scalar::Uint, true) => "u32",
+            (Scalar::Float, true) => "f32",
+            (Scalar::Int, false) => "int",
+            (Scalar::Uint, false) => "uint",
+            (Scalar::Float, false) => "float",
+        };
+        let dimension = |texture: &crate::gpu::frontend::ast::Texture| {
+            let name = match (texture.dimension, wgsl) {
+                (TextureDimension::D2, true) => "2d",
+                (TextureDimension::D3, true) => "3d",
+                (TextureDimension::Cube, true) => "cube",
+                (TextureDimension::D2, false) => "2D",
+                (TextureDimension::D3, false) => "3D",
+                (TextureDimension::Cube, false) => "Cube",
+            };
+            let array = match (texture.arrayed, wgsl) {
+                (false, _) => "",
+                (true, true) => "_array",
+                (true, false) => "Array",
+            };
+            format!("{}{}", name, array)
+        };
+        let prefix = |scalar: Scalar| match scalar {
+            Scalar::Int => "i",
+            Scalar::Uint => "u",
+            Scalar::Bool => "b",
+            Scalar::Float => "",
+        };
+        match (ty, wgsl) {
+            (Type::Void, _) => "void".to_string(),
+            (Type::Scalar(scalar), _) => scalar_name(*scalar).to_string(),
+            (Type::Vector(scalar, count), true) => format!("vec{}<{}>", count, scalar_name(*scalar)),
+            (Type::Vector(scalar, count), false) => format!("{}vec{}", prefix(*scalar), count),
+            (Type::Matrix { columns, rows }, true) => format!("mat{}x{}<f32>", columns, rows),
+            (Type::Matrix { columns, rows }, false) if columns == rows => format!("mat{}", columns),
+            (Type::Matrix { columns, rows }, false) => format!("mat{}x{}", columns, rows),
+            (Type::Array(element, length), true) => format!("array<{}, {}>", self.type_name(element), length),
+            (Type::Array(element, length), false) => format!("{}[{}]", self.type_name(element), length),
+            (Type::Struct(index), _) => self.structs[*index].name.clone(),
+            (Type::Texture(texture), true) if texture.depth => format!("texture_depth_{}", dimension(texture)),
+            (Type::Texture(texture), true) => format!("texture_{}<{}>", dimension(texture), scalar_name(texture.kind)),
+            (Type::Texture(texture), false) => format!("{}texture{}", prefix(texture.kind), dimension(texture)),
+            (Type::Sampler { comparison }, true) => {
+                if *comparison { "sampler_comparison" } else { "sampler" }.to_string()
+            }
+            (Type::Sampler { comparison }, false) => if *comparison { "samplerShadow" } else { "sampler" }.to_string(),
+            (Type::SampledTexture(texture), _) => {
+                let shadow = if texture.depth { "Shadow" } else { "" };
+                format!("{}sampler{}{}", prefix(texture.kind), dimension(texture), shadow)
+            }
+        }
+    }
+
+    fn resolve(&mut self, ty: &TypeExpr) -> Lowered<Type> {
+        match ty {
+            TypeExpr::Builtin(ty) => Ok(ty.clone()),
+            TypeExpr::Named(name, position) => match self.struct_names.get(name) {
+                Some(&index) => Ok(Type::Struct(index)),
+                None => Err(self.error(*position, format!("unknown type `{}`", name))),
+            },
+            TypeExpr::Array(element, length, position) => {
+                let element = self.resolve(element)?;
+                let Some(length) = length else {
+                    return Err(self.error(*position, "runtime-sized arrays are not supported"));
+                };
+                match self.const_literal(length, 0) {
+                    Some(Literal::Int(length)) if length > 0 && length <= u32::MAX as i64 => {
+                        Ok(Type::Array(Box::new(element), length as u32))
+                    }
+                    Some(Literal::Int(_)) => Err(self.error(length.position, "array length must be positive")),
+                    _ => Err(self.error(length.position, "array length must be a constant integer expression")),
+                }
+            }
+        }
+    }
+
+    // Giriş ve çıkışların kapladığı konum sayısı.
+    fn location_count(&self, ty: &Type) -> u32 {
+        match ty {
+            Type::Matrix { columns, .. } => *columns,
+            Type::Array(element, length) => self.location_count(element) * length,
+            Type::Struct(index) => self.structs[*index].members.iter().map(|(_, ty)| self.location_count(ty)).sum(),
+            _ => 1,
+        }
+    }
+
+    // std140 düzeninde (boyut, hizalama). Uniform bloklarında kullanılan yapı ve dizi tipleri ilk kullanımda Offset,
+    // ArrayStride ve MatrixStride ile dekore edilir.
+    fn layout(&mut self, ty: &Type) -> (u32, u32) {
+        match ty {
+            Type::Vector(_, 2) => (8, 8),
+            Type::Vector(_, count) => (4 * count, 16),
+            Type::Matrix { columns, .. } => (16 * columns, 16),
+            Type::Array(element, length) => {
+                let (size, align) = self.layout(element);
+                let stride = size.next_multiple_of(16).max(align);
+                let id = self.type_id(ty);
+                if self.laid_out.insert(id) {
+                    self.builder.decorate(id, decoration::ARRAY_STRIDE, &[stride]);
+                }
+                (stride * length, 16)
+            }
+            Type::Struct(index) => {
+                let id = self.structs[*index].id;
+                let members: Vec<Type> = self.structs[*index].members.iter().map(|(_, ty)| ty.clone()).collect();
+                let decorate = self.laid_out.insert(id);
+                let (mut offset, mut align) = (0, 16);
+                for (member, ty) in members.iter().enumerate() {
+                    let (size, member_align) = self.layout(ty);
+                    offset = u32::next_multiple_of(offset, member_align);
+                    if decorate {
+                        self.member_layout(id, member as u32, offset, ty);
+                    }
+                    offset += size;
+                    align = align.max(member_align);
+                }
+                (offset.next_multiple_of(align), align)
+            }
+            _ => (4, 4),
+        }
+    }
+
+    fn member_layout(&mut self, id: u32, member: u32, offset: u32, ty: &Type) {
+        self.builder.member_decorate(id, member, decoration::OFFSET, &[offset]);
+        let mut element = ty;
+        while let Type::Array(inner, _) = element {
+            element = inner;
+        }
+        if let Type::Matrix { .. } = element {
+            self.builder.member_decorate(id, member, decoration::COL_MAJOR, &[]);
+            self.builder.member_decorate(id, member, decoration::MATRIX_STRIDE, &[16]);
+        }
+    }
+
+    // Bildirimler
+
+    fn declare_structs(&mut self) {
+        let module = self.module;
+        for decl in &module.structs {
+            let mut members = Vec::new();
+            for member in &decl.members {
+                let ty = self.resolve(&member.ty).unwrap_or(Type::Scalar(Scalar::Float));
+                if ty.is_opaque() {
+                    self.error(member.position, "struct members cannot be textures or samplers");
+                }
+                members.push((member.name.clone(), ty));
+            }
+            let member_ids: Vec<u32> = members.iter().map(|(_, ty)| self.type_id(ty)).collect();
+            let id = self.builder.struct_type(&member_ids);
+            self.builder.name(id, &decl.name);
+            for (index, (name, _)) in members.iter().enumerate() {
+                self.builder.member_name(id, index as u32, name);
+            }
+            let index = self.structs.len();
+            self.structs.push(StructInfo { name: decl.name.clone(), members, id });
+            if self.struct_names.insert(decl.name.clone(), index).is_some() {
+                self.error(decl.position, format!("struct `{}` is already defined", decl.name));
+            }
+        }
+    }
+
+    fn declare_functions(&mut self) {
+        let module = self.module;
+        for (index, function) in module.functions.iter().enumerate() {
+            if self.functions.contains_key(&function.name) || self.struct_names.contains_key(&function.name) {
+                self.error(function.position, format!("`{}` is already defined", function.name));
+                continue;
+            }
+            let params: Lowered<Vec<Type>> = function.params.iter().map(|param| self.resolve(&param.ty)).collect();
+            let result = function.result.as_ref().map_or(Ok(Type::Void), |result| self.resolve(result));
+            let stage = match self.language {
+                Language::Wgsl => function.stage,
+                Language::Glsl => self.stage.filter(|_| function.name == "main"),
+            };
+            let info = match (params, result) {
+                (Ok(params), Ok(result)) => Some(FunctionInfo { index, id: self.builder.id(), params, result, stage }),
+                _ => None,
+            };
+            self.functions.insert(function.name.clone(), info);
+        }
+    }
+
+    fn declare_globals(&mut self) {
+        let module = self.module;
+        // Açıkça verilen konum ve bağlamalar; verilmeyenler bunları atlayarak bildirim sırasıyla atanır.
+        let mut used: HashMap<Slot, HashSet<u32>> = HashMap::new();
+        let reported = self.diagnostics.len();
+        for global in &module.globals {
+            let ty = global.ty.as_ref().and_then(|ty| self.resolve(ty).ok());
+            if let Some((slot, Some(start))) = ty.as_ref().and_then(|ty| slot(global, ty)) {
+                let count = ty.as_ref().map_or(1, |ty| self.slot_count(slot, ty));
+                used.entry(slot).or_default().extend(start..start + count);
+            }
+        }
+        self.diagnostics.truncate(reported);
+        for (index, global) in module.globals.iter().enumerate() {
+            // Örnek adı verilmemiş uniform blokları blok adını taşır; ad yalnızca yapı olarak tanımlıdır.
+            let named = !global.anonymous && self.struct_names.contains_key(&global.name);
+            if self.globals.contains_key(&global.name) || named {
+                self.error(global.position, format!("`{}` is already defined", global.name));
+                continue;
+            }
+            if self.declare_global(index, global, &mut used).is_err() {
+                self.globals.insert(global.name.clone(), Binding::Poisoned);
+            }
+        }
+    }
+
+    fn slot_count(&self, slot: Slot, ty: &Type) -> u32 {
+        match (slot, ty) {
+            (Slot::Input | Slot::Output(_), _) => self.location_count(ty),
+            (_, Type::Array(_, length)) => *length,
+            _ => 1,
+        }
+    }
+
+    // Boş ilk `count` numaralık aralığın başı.
+    fn take(&self, used: &mut HashMap<Slot, HashSet<u32>>, slot: Slot, ty: &Type) -> u32 {
+        let count = self.slot_count(slot, ty);
+        let used = used.entry(slot).or_default();
+        let start = (0..).find(|start| (*start..start + count).all(|number| !used.contains(&number))).unwrap_or(0);
+        used.extend(start..start + count);
+        start
+    }
+
+    fn declare_global(
+        &mut self,
+        index: usize,
+        global: &'a Global,
+        used: &mut HashMap<Slot, HashSet<u32>>,
+    ) -> Lowered<()> {
+        if let GlobalKind::Constant(value) = &global.kind {
+            let ty = global.ty.as_ref().map(|ty| self.resolve(ty)).transpose()?;
+            self.globals.insert(global.name.clone(), Binding::Inline(value, ty));
+            return Ok(());
+        }
+        let ty = match (&global.ty, &global.kind) {
+            (Some(ty), _) => self.resolve(ty)?,
+            (None, GlobalKind::Private(Some(value))) => match self.const_literal(value, 0) {
+                Some(literal) => literal.default_type(),
+                None => return Err(self.error(global.position, format!("`{}` needs an explicit type", global.name))),
+            },
+            (None, _) => return Err(self.error(global.position, format!("`{}` needs an explicit type", global.name))),
+        };
+        let binding = match &global.kind {
+            GlobalKind::Input(io) | GlobalKind::Output(io) => {
+                let input = matches!(global.kind, GlobalKind::Input(_));
+                let (storage, slot) = match (input, &io.binding) {
+                    (true, _) => (StorageClass::Input, Slot::Input),
+                    (false, IoBinding::Location { index, .. }) => (StorageClass::Output, Slot::Output(*index)),
+                    (false, IoBinding::BuiltIn(_)) => (StorageClass::Output, Slot::Output(0)),
+                };
+                let location = match io.binding {
+                    IoBinding::Location { location, .. } => {
+                        Some(location.unwrap_or_else(|| self.take(used, slot, &ty)))
+                    }
+                    IoBinding::BuiltIn(_) => None,
+                };
+                let stage = self.stage.unwrap_or(ShaderStage::Vertex);
+                let pointer = self.io_variable(&global.name, &ty, storage, io, location, stage)?;
+                self.interface.push(pointer);
+                Binding::Variable { pointer, ty, storage, writable: !input }
+            }
+            GlobalKind::Uniform { .. } if ty.is_opaque() => {
+                let binding = self.take(used, Slot::Binding(0), &ty);
+                self.resource(global, &ty, 0, binding)
+            }
+            GlobalKind::Uniform { location } => {
+                let location = location.unwrap_or_else(|| self.take(used, Slot::Uniform, &ty));
+                let storage = StorageClass::UniformConstant;
+                let pointer = self.global_variable(&global.name, &ty, storage);
+                self.builder.decorate(pointer, decoration::LOCATION, &[location]);
+                Binding::Variable { pointer, ty, storage, writable: false }
+            }
+            GlobalKind::Resource { group, binding } => {
+                if !ty.is_opaque() {
+                    return Err(self.error(
+                        global.position,
+                        format!(
+                            "`{}` must be a texture or a sampler; buffers are declared with var<uniform>",
+                            global.name
+                        ),
+                    ));
+                }
+                let binding = binding.unwrap_or_else(|| self.take(used, Slot::Binding(*group), &ty));
+                self.resource(global, &ty, *group, binding)
+            }
+            GlobalKind::UniformBlock { group, binding } => {
+                if ty.is_opaque() {
+                    return Err(self.error(global.position, "textures and samplers cannot be uniform buffers"));
+                }
+                let binding = binding.unwrap_or_else(|| self.take(used, Slot::Binding(*group), &ty));
+                return self.uniform_block(global, ty, *group, binding);
+            }
+            GlobalKind::Private(value) => {
+                if ty.is_opaque() {
+                    return Err(self.error(global.position, "textures and samplers cannot be stored in variables"));
+                }
+                let storage = StorageClass::Private;
+                let pointer = self.global_variable(&global.name, &ty, storage);
+                if value.is_some() {
+                    self.initializers.push((index, pointer, ty.clone()));
+                }
+                Binding::Variable { pointer, ty, storage, writable: true }
+            }
+            GlobalKind::Constant(_) => unreachable!(),
+        };
+        self.globals.insert(global.name.clone(), binding);
+        Ok(())
+    }
+
+    fn global_variable(&mut self, name: &str, ty: &Type, storage: StorageClass) -> u32 {
+        let pointer_type = self.pointer_type(ty, storage);
+        let pointer = self.builder.global_variable(pointer_type, storage.to_word());
+        self.builder.name(pointer, name);
+        pointer
+    }
+
+    fn resource(&mut self, global: &Global, ty: &Type, group: u32, binding: u32) -> Binding<'a> {
+        let storage = StorageClass::UniformConstant;
+        let pointer = self.global_variable(&global.name, ty, storage);
+        self.builder.decorate(pointer, decoration::DESCRIPTOR_SET, &[group]);
+        self.builder.decorate(pointer, decoration::BINDING, &[binding]);
+        Binding::Variable { pointer, ty: ty.clone(), storage, writable: false }
+    }
+
+    // Uniform bloğu. Yapı olmayan tipler tek üyeli bir yapıya sarılır; GLSL'de örnek adı verilmemiş blokların
+    // üyeleri doğrudan adlarıyla bağlanır.
+    fn uniform_block(&mut self, global: &Global, ty: Type, group: u32, binding: u32) -> Lowered<()> {
+        let (block, wrapped) = match ty {
+            Type::Struct(index) => {
+                self.layout(&ty);
+                (self.structs[index].id, false)
+            }
+            _ => {
+                self.layout(&ty);
+                let member = self.type_id(&ty);
+                let id = self.builder.struct_type(&[member]);
+                self.member_layout(id, 0, 0, &ty);
+                (id, true)
+            }
+        };
+        self.builder.decorate(block, decoration::BLOCK, &[]);
+        let storage = StorageClass::Uniform;
+        let pointer_type = self.builder.type_id(op::TYPE_POINTER, &[storage.to_word(), block]);
+        let pointer = self.builder.global_variable(pointer_type, storage.to_word());
+        self.builder.name(pointer, &global.name);
+        self.builder.decorate(pointer, decoration::DESCRIPTOR_SET, &[group]);
+        self.builder.decorate(pointer, decoration::BINDING, &[binding]);
+        match (&ty, wrapped, global.anonymous) {
+            (_, true, _) => {
+                self.globals.insert(global.name.clone(), Binding::BlockMember { pointer, index: 0, ty });
+            }
+            (Type::Struct(index), _, true) => {
+                for (member, (name, member_ty)) in self.structs[*index].members.clone().into_iter().enumerate() {
+                    if self.globals.contains_key(&name) {
+                        self.error(global.position, format!("`{}` is already defined", name));
+                    }
+                    let member = Binding::BlockMember { pointer, index: member as u32, ty: member_ty };
+                    self.globals.insert(name, member);
+                }
+            }
+            _ => {
+                let binding = Binding::Variable { pointer, ty, storage, writable: false };
+                self.globals.insert(global.name.clone(), binding);
+            }
+        }
+        Ok(())
+    }
+
+    // Giriş veya çıkış değişkeni. Tamsayı tipli fragment girişleri ve vertex çıkışları ara değerlendirilmez (Flat).
+    fn io_variable(
+        &mut self,
+        name: &str,
+        ty: &Type,
+        storage: StorageClass,
+        io: &IoAttribute,
+        location: Option<u32>,
+        stage: ShaderStage,
+    ) -> Lowered<u32> {
+        let input = storage == StorageClass::Input;
+        let direction = if input { "input" } else { "output" };
+        match &io.binding {
+            IoBinding::BuiltIn(builtin) => {
+                let Some((word, expected)) = wgsl_builtin(builtin, stage, input) else {
+                    let message =
+                        format!("built-in `{}` is not available as a {:?} shader {}", builtin, stage, direction);
+                    return Err(self.error(io.position, message));
+                };
+                if *ty != expected {
+                    let message = format!("built-in `{}` must have type `{}`", builtin, self.type_name(&expected));
+                    return Err(self.error(io.position, message));
+                }
+                let pointer = self.global_variable(name, ty, storage);
+                self.builder.decorate(pointer, decoration::BUILT_IN, &[word]);
+                self.writes_depth |= word == built_in::FRAG_DEPTH;
+                Ok(pointer)
+            }
+            IoBinding::Location { index, .. } => {
+                let mut element = ty;
+                while let (Type::Array(inner, _), Language::Glsl) = (element, self.language) {
+                    element = inner;
+                }
+                let valid = match element {
+                    Type::Scalar(scalar) | Type::Vector(scalar, _) => *scalar != Scalar::Bool,
+                    Type::Matrix { .. } => self.language == Language::Glsl,
+                    _ => false,
+                };
+                if !valid {
+                    let message = format!("`{}` cannot be used as a shader {}", self.type_name(ty), direction);
+                    return Err(self.error(io.position, message));
+                }
+                if *index != 0 && (input || stage != ShaderStage::Fragment) {
+                    return Err(self.error(io.position, "a blend source index is only allowed on fragment outputs"));
+                }
+                let location = location.unwrap_or(0);
+                if !self.io_locations.insert((input, location, *index)) {
+                    let message =
+                        format!("location {} is used by more than one {:?} shader {}", location, stage, direction);
+                    return Err(self.error(io.position, message));
+                }
+                let pointer = self.global_variable(name, ty, storage);
+                self.builder.decorate(pointer, decoration::LOCATION, &[location]);
+                if *index != 0 {
+                    self.builder.decorate(pointer, decoration::INDEX, &[*index]);
+                }
+                let varying = (stage == ShaderStage::Fragment) == input;
+                let integer = matches!(element.scalar(), Some(Scalar::Int | Scalar::Uint));
+                match io.interpolation {
+                    _ if !varying => {}
+                    Some(Interpolation::Flat) => self.builder.decorate(pointer, decoration::FLAT, &[]),
+                    _ if integer => self.builder.decorate(pointer, decoration::FLAT, &[]),
+                    Some(Interpolation::Linear) => self.builder.decorate(pointer, decoration::NO_PERSPECTIVE, &[]),
+                    _ => {}
+                }
+                Ok(pointer)
+            }
+        }
+    }
+
+    // GLSL'nin gl_ ile başlayan yerleşik değişkenleri; ilk kullanıldıklarında oluşturulur. Eski GLSL'nin
+    // gl_FragColor'ı yerleşik değil, 0 konumundaki renk çıkışıdır.
+    fn glsl_builtin(&mut self, name: &str, position: Position) -> Lowered<Option<Binding<'a>>> {
+        let Some(stage) = self.stage else { return Ok(None) };
+        let (word, ty, builtin_stage, input) = match name {
+            "gl_Position" => (Some(built_in::POSITION), Type::Vector(Scalar::Float, 4), ShaderStage::Vertex, false),
+            "gl_PointSize" => (Some(built_in::POINT_SIZE), Type::Scalar(Scalar::Float), ShaderStage::Vertex, false),
+            "gl_VertexIndex" => (Some(built_in::VERTEX_INDEX), Type::Scalar(Scalar::Int), ShaderStage::Vertex, true),
+            "gl_InstanceIndex" => {
+                (Some(built_in::INSTANCE_INDEX), Type::Scalar(Scalar::Int), ShaderStage::Vertex, true)
+            }
+            "gl_VertexID" => (Some(built_in::VERTEX_ID), Type::Scalar(Scalar::Int), ShaderStage::Vertex, true),
+            "gl_InstanceID" => (Some(built_in::INSTANCE_ID), Type::Scalar(Scalar::Int), ShaderStage::Vertex, true),
+            "gl_FragCoord" => (Some(built_in::FRAG_COORD), Type::Vector(Scalar::Float, 4), ShaderStage::Fragment, true),
+            "gl_FrontFacing" => (Some(built_in::FRONT_FACING), Type::Scalar(Scalar::Bool), ShaderStage::Fragment, true),
+            "gl_PointCoord" => {
+                (Some(built_in::POINT_COORD), Type::Vector(Scalar::Float, 2), ShaderStage::Fragment, true)
+            }
+            "gl_FragDepth" => (Some(built_in::FRAG_DEPTH), Type::Scalar(Scalar::Float), ShaderStage::Fragment, false),
+            "gl_FragColor" => (None, Type::Vector(Scalar::Float, 4), ShaderStage::Fragment, false),
+            _ => return Ok(None),
+        };
+        if builtin_stage != stage {
+            return Err(self.error(position, format!("`{}` is not available in {:?} shaders", name, stage)));
+        }
+        let storage = if input { StorageClass::Input } else { StorageClass::Output };
+        let pointer = self.global_variable(name, &ty, storage);
+        match word {
+            Some(word) => self.builder.decorate(pointer, decoration::BUILT_IN, &[word]),
+            None => self.builder.decorate(pointer, decoration::LOCATION, &[0]),
+        }
+        self.interface.push(pointer);
+        self.writes_depth |= word == Some(built_in::FRAG_DEPTH);
+        let binding = Binding::Variable { pointer, ty, storage, writable: !input };
+        self.globals.insert(name.to_string(), binding.clone());
+        Ok(Some(binding))
+    }
+
+    // Fonksiyonlar ve giriş noktaları
+
+    fn begin_function(&mut self, result: Type) {
+        self.scopes = vec![HashMap::new()];
+        self.variables.clear();
+        self.body.clear();
+        self.targets.clear();
+        self.result = result;
+    }
+
+    // Fonksiyonun başlığını (OpFunction, parametreler ve ilk etiket), yerel değişkenlerini ve gövdesini yazar.
+    fn end_function(&mut self, header: Vec<u32>) {
+        self.builder.functions.extend(header);
+        self.builder.functions.append(&mut self.variables);
+        self.builder.functions.append(&mut self.body);
+        instruction(&mut self.builder.functions, op::FUNCTION_END, &[]);
+    }
+
+    fn function_header(&mut self, id: u32, result: &Type, params: &[Type]) -> (Vec<u32>, Vec<u32>) {
+        let result = self.type_id(result);
+        let param_types: Vec<u32> = params.iter().map(|ty| self.type_id(ty)).collect();
+        let function_type = self.builder.type_id(op::TYPE_FUNCTION, &[&[result], param_types.as_slice()].concat());
+        let mut header = Vec::new();
+        instruction(&mut header, op::FUNCTION, &[result, id, 0, function_type]);
+        let mut ids = Vec::new();
+        for ty in param_types {
+            let id = self.builder.id();
+            instruction(&mut header, op::FUNCTION_PARAMETER, &[ty, id]);
+            ids.push(id);
+        }
+        let label = self.builder.id();
+        instruction(&mut header, op::LABEL, &[label]);
+        self.block = label;
+        self.terminated = false;
+        (header, ids)
+    }
+
+    fn lower_function(&mut self, function: &'a Function, info: &FunctionInfo) {
+        if self.language == Language::Wgsl {
+            self.stage = function.stage;
+        }
+        self.begin_function(info.result.clone());
+        let (header, ids) = self.function_header(info.id, &info.result, &info.params);
+        self.builder.name(info.id, &function.name);
+        for ((param, ty), id) in function.params.iter().zip(&info.params).zip(ids) {
+            self.builder.name(id, &param.name);
+            let value = Value { id, ty: ty.clone(), literal: None };
+            // GLSL parametreleri değiştirilebilir; opak olmayanlar yerel değişkenlere kopyalanır.
+            let binding = match self.language {
+                Language::Glsl if !ty.is_opaque() => {
+                    let pointer = self.local_variable(ty);
+                    self.builder.name(pointer, &param.name);
+                    instruction(&mut self.body, op::STORE, &[pointer, id]);
+                    Binding::Variable { pointer, ty: ty.clone(), storage: StorageClass::Function, writable: true }
+                }
+                _ => Binding::Value(value),
+            };
+            let _ = self.define(&param.name, binding, param.position);
+        }
+        if self.language == Language::Glsl && info.stage.is_some() {
+            self.initialize_globals();
+        }
+        self.statements(&function.body);
+        if !self.terminated {
+            if info.result == Type::Void {
+                self.emit(op::RETURN, &[]);
+            } else {
+                let message = format!(
+                    "function `{}` must return a value of type `{}` on every path",
+                    function.name,
+                    self.type_name(&info.result)
+                );
+                self.error(function.position, message);
+            }
+        }
+        self.end_function(header);
+    }
+
+    // Modül düzeyindeki değişkenlerin ilk değerleri giriş noktasının başında atanır.
+    fn initialize_globals(&mut self) {
+        let module = self.module;
+        for (index, pointer, ty) in self.initializers.clone() {
+            let GlobalKind::Private(Some(value)) = &module.globals[index].kind else { continue };
+            if let Ok(value) = self.expr(value).and_then(|initial| self.coerce(initial, &ty, value.position)) {
+                self.emit(op::STORE, &[pointer, value.id]);
+            }
+        }
+    }
+
+    // WGSL giriş noktası: girişleri okuyup kullanıcının fonksiyonunu çağıran ve sonucunu çıkışlara yazan sarmalayıcı.
+    fn wgsl_entry_point(&mut self, function: &'a Function, info: &FunctionInfo) {
+        let Some(stage) = function.stage else { return };
+        self.stage = Some(stage);
+        self.interface.clear();
+        self.io_locations.clear();
+        self.writes_depth = false;
+        self.begin_function(Type::Void);
+        let wrapper = self.builder.id();
+        let (header, _) = self.function_header(wrapper, &Type::Void, &[]);
+        self.initialize_globals();
+        if self.entry_point_body(function, info, stage).is_ok() {
+            self.emit(op::RETURN, &[]);
+        }
+        self.end_function(header);
+        let interface = std::mem::take(&mut self.interface);
+        self.builder.entry_point(execution_model(stage), wrapper, &function.name, &interface);
+        self.execution_modes(wrapper, stage);
+    }
+
+    fn entry_point_body(&mut self, function: &'a Function, info: &FunctionInfo, stage: ShaderStage) -> Lowered<()> {
+        let module = self.module;
+        let mut arguments = vec![info.id];
+        for (param, ty) in function.params.iter().zip(&info.params) {
+            let argument = match (&param.io, ty) {
+                (Some(io), _) => {
+                    let pointer = self.io_variable(&param.name, ty, StorageClass::Input, io, io_location(io), stage)?;
+                    self.interface.push(pointer);
+                    self.op(op::LOAD, ty, &[pointer]).id
+                }
+                (None, Type::Struct(index)) => {
+                    let mut members = Vec::new();
+                    for (member, (name, member_ty)) in
+                        module.structs[*index].members.iter().zip(self.struct_members(*index))
+                    {
+                        let Some(io) = &member.io else {
+                            let message =
+                                format!("member `{}` of an entry point input needs @location or @builtin", name);
+                            return Err(self.error(member.position, message));
+                        };
+                        let name = format!("{}.{}", param.name, name);
+                        let pointer =
+                            self.io_variable(&name, &member_ty, StorageClass::Input, io, io_location(io), stage)?;
+                        self.interface.push(pointer);
+                        members.push(self.op(op::LOAD, &member_ty, &[pointer]).id);
+                    }
+                    self.op(op::COMPOSITE_CONSTRUCT, ty, &members).id
+                }
+                (None, _) => {
+                    let message = format!("entry point parameter `{}` needs @location or @builtin", param.name);
+                    return Err(self.error(param.position, message));
+                }
+            };
+            arguments.push(argument);
+        }
+        let result = self.op(op::FUNCTION_CALL, &info.result, &arguments);
+        match (&function.result_io, &info.result) {
+            (_, Type::Void) => {}
+            (Some(io), ty) => {
+                let pointer = self.io_variable(&function.name, ty, StorageClass::Output, io, io_location(io), stage)?;
+                self.interface.push(pointer);
+                self.emit(op::STORE, &[pointer, result.id]);
+            }
+            (None, Type::Struct(index)) => {
+                for (member, (name, member_ty)) in
+                    module.structs[*index].members.iter().zip(self.struct_members(*index))
+                {
+                    let Some(io) = &member.io else {
+                        let message = format!("member `{}` of an entry point output needs @location or @builtin", name);
+                        return Err(self.error(member.position, message));
+                    };
+                    let index = self.struct_members(*index).iter().position(|(other, _)| *other == name).unwrap_or(0);
+                    let pointer =
+                        self.io_variable(&name, &member_ty, StorageClass::Output, io, io_location(io), stage)?;
+                    self.interface.push(pointer);
+                    let value = self.op(op::COMPOSITE_EXTRACT, &member_ty, &[result.id, index as u32]);
+                    self.emit(op::STORE, &[pointer, value.id]);
+                }
+            }
+            (None, _) => {
+                let message = format!("the result of entry point `{}` needs @location or @builtin", function.name);
+                return Err(self.error(function.position, message));
+            }
+        }
+        Ok(())
+    }
+
+    fn struct_members(&self, index: usize) -> Vec<(String, Type)> {
+        self.structs[index].members.clone()
+    }
+
+    fn glsl_entry_point(&mut self) {
+        let module = self.module;
+        let Some(stage) = self.stage else { return };
+        let Some(main) = module.functions.iter().find(|function| function.name == "main") else {
+            self.error(Position { line: 1, column: 1 }, "the shader has no `main` function");
+            return;
+        };
+        if !main.params.is_empty() || main.result.is_some() {
+            self.error(main.position, "`main` must take no parameters and return `void`");
+            return;
+        }
+        let Some(Some(info)) = self.functions.get("main").cloned() else { return };
+        let interface = self.interface.clone();
+        self.builder.entry_point(execution_model(stage), info.id, "main", &interface);
+        self.execution_modes(info.id, stage);
+    }
+
+    fn execution_modes(&mut self, function: u32, stage: ShaderStage) {
+        if stage == ShaderStage::Fragment {
+            self.builder.execution_mode(function, execution_mode::ORIGIN_UPPER_LEFT);
+            if self.writes_depth {
+                self.builder.execution_mode(function, execution_mode::DEPTH_REPLACING);
+            }
+        }
+    }
+
+    // Deyimler
+
+    pub(super) fn emit(&mut self, opcode: u16, operands: &[u32]) {
+        instruction(&mut self.body, opcode, operands);
+    }
+
+    // Sonuç kimliği ve tipi olan komut.
+    pub(super) fn op(&mut self, opcode: u16, ty: &Type, operands: &[u32]) -> Value {
+        let result_type = self.type_id(ty);
+        let id = self.builder.id();
+        let mut words = vec![result_type, id];
+        words.extend_from_slice(operands);
+        self.emit(opcode, &words);
+        Value { id, ty: ty.clone(), literal: None }
+    }
+
+    fn label(&mut self, id: u32) {
+        self.emit(op::LABEL, &[id]);
+        self.block = id;
+        self.terminated = false;
+    }
+
+    fn branch(&mut self, target: u32) {
+        if !self.terminated {
+            self.emit(op::BRANCH, &[target]);
+            self.terminated = true;
+        }
+    }
+
+    fn terminate(&mut self, opcode: u16, operands: &[u32]) {
+        self.emit(opcode, operands);
+        self.terminated = true;
+    }
+
+    // Birleşme bloğu; hiçbir yol ulaşmıyorsa blok OpUnreachable ile biter.
+    fn merge_block(&mut self, merge: u32, reached: bool) {
+        self.label(merge);
+        if !reached {
+            self.terminate(op::UNREACHABLE, &[]);
+        }
+    }
+
+    fn local_variable(&mut self, ty: &Type) -> u32 {
+        let pointer_type = self.pointer_type(ty, StorageClass::Function);
+        let id = self.builder.id();
+        instruction(&mut self.variables, op::VARIABLE, &[pointer_type, id, StorageClass::Function.to_word()]);
+        id
+    }
+
+    fn define(&mut self, name: &str, binding: Binding<'a>, position: Position) -> Lowered<()> {
+        let scope = self.scopes.last_mut().expect("function scope");
+        if scope.insert(name.to_string(), binding).is_some() {
+            return Err(self.error(position, format!("`{}` is already declared in this scope", name)));
+        }
+        Ok(())
+    }
+
+    fn statements(&mut self, statements: &'a [Stmt]) {
+        for statement in statements {
+            // Sonlandırılmış bloktan sonraki deyimlere ulaşılamaz.
+            if self.terminated {
+                break;
+            }
+            let _ = self.statement(statement);
+        }
+    }
+
+    fn scoped(&mut self, statements: &'a [Stmt]) {
+        self.scopes.push(HashMap::new());
+        self.statements(statements);
+        self.scopes.pop();
+    }
+
+    fn statement(&mut self, statement: &'a Stmt) -> Lowered<()> {
+        let position = statement.position;
+        match &statement.kind {
+            StmtKind::Declare { name, ty, value, mutable } => {
+                let binding = self.declaration(name, ty.as_ref(), value.as_ref(), *mutable, position);
+                let defined = self.define(name, binding.clone().unwrap_or(Binding::Poisoned), position);
+                binding.and(defined)
+            }
+            StmtKind::Assign { target, op, value } => self.assignment(target, *op, value, position),
+            StmtKind::Expr(expr) => self.expr(expr).map(|_| ()),
+            StmtKind::Block(statements) => {
+                self.scoped(statements);
+                Ok(())
+            }
+            StmtKind::If { condition, accept, reject } => self.if_statement(condition, accept, reject),
+            StmtKind::Loop { condition, body, continuing } => self.loop_statement(condition.as_ref(), body, continuing),
+            StmtKind::Switch { selector, cases } => self.switch_statement(selector, cases),
+            StmtKind::Break => {
+                let Some(target) = self.targets.last_mut() else {
+                    return Err(self.error(position, "`break` outside of a loop or switch"));
+                };
+                target.reached = true;
+                let merge = target.merge;
+                self.branch(merge);
+                Ok(())
+            }
+            StmtKind::Continue => {
+                let Some(continuing) = self.targets.iter().rev().find_map(|target| target.continuing) else {
+                    return Err(self.error(position, "`continue` outside of a loop"));
+                };
+                self.branch(continuing);
+                Ok(())
+            }
+            StmtKind::BreakIf(_) => Err(self.error(position, "`break if` must be the last statement of `continuing`")),
+            StmtKind::Return(value) => self.return_statement(value.as_ref(), position),
+            StmtKind::Discard => {
+                if self.stage == Some(ShaderStage::Vertex) {
+                    return Err(self.error(position, "`discard` is only available in fragment shaders"));
+                }
+                self.terminate(op::KILL, &[]);
+                Ok(())
+            }
+        }
+    }
+
+    fn declaration(
+        &mut self,
+        name: &str,
+        ty: Option<&TypeExpr>,
+        value: Option<&'a Expr>,
+        mutable: bool,
+        position: Position,
+    ) -> Lowered<Binding<'a>> {
+        let ty = ty.map(|ty| self.resolve(ty)).transpose()?;
+        let value = match value {
+            Some(value) => {
+                let initial = self.expr(value)?;
+                Some(match &ty {
+                    Some(ty) => self.coerce(initial, ty, value.position)?,
+                    None => initial,
+                })
+            }
+            None => None,
+        };
+        let ty = match (ty, &value) {
+            (Some(ty), _) => ty,
+            (None, Some(value)) => value.ty.clone(),
+            (None, None) => return Err(self.error(position, format!("`{}` needs a type or an initial value", name))),
+        };
+        if !mutable {
+            let Some(mut value) = value else {
+                return Err(self.error(position, format!("`{}` needs an initial value", name)));
+            };
+            value.literal = None;
+            return Ok(Binding::Value(value));
+        }
+        if ty.is_opaque() {
+            return Err(self.error(position, "textures and samplers cannot be stored in variables"));
+        }
+        let pointer = self.local_variable(&ty);
+        self.builder.name(pointer, name);
+        let initial = match value {
+            Some(value) => value.id,
+            None => self.null(&ty),
+        };
+        self.emit(op::STORE, &[pointer, initial]);
+        Ok(Binding::Variable { pointer, ty, storage: StorageClass::Function, writable: true })
+    }
+
+    fn assignment(
+        &mut self,
+        target: &'a Expr,
+        op: Option<BinaryOp>,
+        value: &'a Expr,
+        position: Position,
+    ) -> Lowered<()> {
+        // WGSL'de `_ = e;` değeri yalnızca hesaplar.
+        if self.language == Language::Wgsl && matches!(&target.kind, ExprKind::Name(name) if name == "_") {
+            return self.expr(value).map(|_| ());
+        }
+        let place = self.place(target, true)?;
+        let result = match op {
+            Some(op) => {
+                let current = self.load(place.clone());
+                let operand = self.expr(value)?;
+                self.binary(op, current, operand, position)?
+            }
+            None => self.expr(value)?,
+        };
+        let result = self.coerce(result, &place.ty(), value.position)?;
+        self.store(place, result);
+        Ok(())
+    }
+
+    fn condition(&mut self, condition: &'a Expr) -> Lowered<Value> {
+        let value = self.expr(condition)?;
+        if value.ty != Type::Scalar(Scalar::Bool) {
+            let message = format!("condition must be `bool`, found `{}`", self.type_name(&value.ty));
+            return Err(self.error(condition.position, message));
+        }
+        Ok(value)
+    }
+
+    fn if_statement(&mut self, condition: &'a Expr, accept: &'a [Stmt], reject: &'a [Stmt]) -> Lowered<()> {
+        let condition = self.condition(condition)?;
+        let merge = self.builder.id();
+        let accept_label = self.builder.id();
+        let reject_label = if reject.is_empty() { merge } else { self.builder.id() };
+        self.emit(op::SELECTION_MERGE, &[merge, 0]);
+        self.terminate(op::BRANCH_CONDITIONAL, &[condition.id, accept_label, reject_label]);
+        self.label(accept_label);
+        self.scoped(accept);
+        let mut reached = !self.terminated || reject.is_empty();
+        self.branch(merge);
+        if !reject.is_empty() {
+            self.label(reject_label);
+            self.scoped(reject);
+            reached |= !self.terminated;
+            self.branch(merge);
+        }
+        self.merge_block(merge, reached);
+        Ok(())
+    }
+
+    // Döngü başlığı LoopMerge ile koşul bloğuna dallanır; koşul yanlışsa döngüden çıkılır. Gövdeden sonra ve
+    // `continue` ile `continuing` bloğu çalışır; sonundaki `break if` döngüyü bitirebilir.
+    fn loop_statement(&mut self, condition: Option<&'a Expr>, body: &'a [Stmt], continuing: &'a [Stmt]) -> Lowered<()> {
+        let header = self.builder.id();
+        let check = self.builder.id();
+        let body_label = self.builder.id();
+        let continue_label = self.builder.id();
+        let merge = self.builder.id();
+        self.branch(header);
+        self.label(header);
+        self.emit(op::LOOP_MERGE, &[merge, continue_label, 0]);
+        self.terminate(op::BRANCH, &[check]);
+        self.label(check);
+        self.targets.push(Target { merge, continuing: Some(continue_label), reached: false });
+        let mut result = Ok(());
+        match condition.map(|condition| self.condition(condition)) {
+            Some(Ok(condition)) => {
+                self.terminate(op::BRANCH_CONDITIONAL, &[condition.id, body_label, merge]);
+                self.targets.last_mut().expect("loop target").reached = true;
+            }
+            Some(Err(reported)) => {
+                result = Err(reported);
+                self.branch(body_label);
+            }
+            None => self.branch(body_label),
+        }
+        self.label(body_label);
+        // `continuing`, gövdenin kapsamındaki adları görür.
+        self.scopes.push(HashMap::new());
+        self.statements(body);
+        self.branch(continue_label);
+        self.label(continue_label);
+        match continuing.split_last() {
+            Some((Stmt { kind: StmtKind::BreakIf(condition), .. }, rest)) => {
+                self.statements(rest);
+                if !self.terminated {
+                    if let Ok(condition) = self.condition(condition) {
+                        self.terminate(op::BRANCH_CONDITIONAL, &[condition.id, merge, header]);
+                        self.targets.last_mut().expect("loop target").reached = true;
+                    }
+                }
+            }
+            _ => self.statements(continuing),
+        }
+        self.branch(header);
+        self.scopes.pop();
+        let target = self.targets.pop().expect("loop target");
+        self.merge_block(merge, target.reached);
+        result
+    }
+
+    fn switch_statement(&mut self, selector: &'a Expr, cases: &'a [Case]) -> Lowered<()> {
+        let value = self.expr(selector)?;
+        let value = match value.literal {
+            Some(literal) => self.literal_as(literal, Scalar::Int).unwrap_or(value),
+            None => value,
+        };
+        let Type::Scalar(scalar @ (Scalar::Int | Scalar::Uint)) = value.ty else {
+            let message = format!("switch selector must be an integer, found `{}`", self.type_name(&value.ty));
+            return Err(self.error(selector.position, message));
+        };
+        let merge = self.builder.id();
+        let labels: Vec<u32> = cases.iter().map(|_| self.builder.id()).collect();
+        let mut default = None;
+        let mut targets = Vec::new();
+        let mut seen = HashSet::new();
+        for (case, &label) in cases.iter().zip(&labels) {
+            for selector in &case.selectors {
+                let Some(selector) = selector else {
+                    if default.replace(label).is_some() {
+                        self.error(case.position, "switch has more than one default case");
+                    }
+                    continue;
+                };
+                let word = match (self.const_literal(selector, 0), scalar) {
+                    (Some(Literal::Int(value)), Scalar::Int) if i32::try_from(value).is_ok() => value as i32 as u32,
+                    (Some(Literal::Int(value)), Scalar::Uint) if u32::try_from(value).is_ok() => value as u32,
+                    _ => {
+                        let message =
+                            format!("case selector must be a constant `{}`", self.type_name(&Type::Scalar(scalar)));
+                        self.error(selector.position, message);
+                        continue;
+                    }
+                };
+                if !seen.insert(word) {
+                    self.error(selector.position, "duplicate case selector");
+                }
+                targets.extend([word, label]);
+            }
+        }
+        self.emit(op::SELECTION_MERGE, &[merge, 0]);
+        let default_label = default.unwrap_or(merge);
+        self.terminate(op::SWITCH, &[&[value.id, default_label], targets.as_slice()].concat());
+        self.targets.push(Target { merge, continuing: None, reached: default.is_none() });
+        for (index, case) in cases.iter().enumerate() {
+            self.label(labels[index]);
+            self.scoped(&case.body);
+            match labels.get(index + 1) {
+                Some(&next) if case.fallthrough => self.branch(next),
+                _ => {
+                    if !self.terminated {
+                        self.targets.last_mut().expect("switch target").reached = true;
+                    }
+                    self.branch(merge);
+                }
+            }
+        }
+        let target = self.targets.pop().expect("switch target");
+        self.merge_block(merge, target.reached);
+        Ok(())
+    }
+
+    fn return_statement(&mut self, value: Option<&'a Expr>, position: Position) -> Lowered<()> {
+        let result = self.result.clone();
+        match (value, &result) {
+            (None, Type::Void) => self.terminate(op::RETURN, &[]),
+            (Some(value), Type::Void) => {
+                return Err(self.error(value.position, "a function without a return type cannot return a value"));
+            }
+            (None, ty) => {
+                let message = format!("the function must return a value of type `{}`", self.type_name(ty));
+                self.terminate(op::UNREACHABLE, &[]);
+                return Err(self.error(position, message));
+            }
+            (Some(value), ty) => {
+                match self.expr(value).and_then(|returned| self.coerce(returned, ty, value.position)) {
+                    Ok(returned) => self.terminate(op::RETURN_VALUE, &[returned.id]),
+                    // Hatalı dönüş de bloğu sonlandırır; eksik dönüş ayrıca bildirilmez.
+                    Err(reported) => {
+                        self.terminate(op::UNREACHABLE, &[]);
+                        return Err(reported);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // İfadeler
+
+    pub(super) fn expr(&mut self, expr: &'a Expr) -> Lowered<Value> {
+        let position = expr.position;
+        match &expr.kind {
+            ExprKind::Bool(value) => Ok(self.literal(Literal::Bool(*value))),
+            ExprKind::Int(value, suffix) => self.int_literal(*value, *suffix, position),
+            ExprKind::Float(value) => Ok(self.literal(Literal::Float(*value))),
+            ExprKind::Name(_) | ExprKind::Member(..) | ExprKind::Index(..) => {
+                let place = self.place(expr, false)?;
+                Ok(self.load(place))
+            }
+            ExprKind::Unary(op, operand) => {
+                let operand = self.expr(operand)?;
+                self.unary(*op, operand, position)
+            }
+            ExprKind::Binary(op @ (BinaryOp::And | BinaryOp::Or), left, right) => {
+                self.short_circuit(*op, left, right, position)
+            }
+            ExprKind::Binary(op, left, right) => {
+                let left = self.expr(left)?;
+                let right = self.expr(right)?;
+                self.binary(*op, left, right, position)
+            }
+            ExprKind::Call(name, arguments) => self.call(name, arguments, position),
+            ExprKind::Construct(ty, arguments) => self.construct(ty, arguments, position),
+            ExprKind::Bitcast(ty, operand) => {
+                let ty = self.resolve(ty)?;
+                let value = self.expr(operand)?;
+                self.bitcast(value, &ty, position)
+            }
+            ExprKind::Conditional(condition, accept, reject) => self.conditional(condition, accept, reject),
+        }
+    }
+
+    fn int_literal(&mut self, value: u64, suffix: Option<Scalar>, position: Position) -> Lowered<Value> {
+        let Ok(word) = u32::try_from(value) else {
+            return Err(self.error(position, format!("integer literal `{}` does not fit in 32 bits", value)));
+        };
+        match suffix {
+            Some(Scalar::Uint) => {
+                Ok(Value { id: self.constant_uint(word), ty: Type::Scalar(Scalar::Uint), literal: None })
+            }
+            // `-2147483648i` için 2^31 de kabul edilir.
+            Some(Scalar::Int) if word <= 1 << 31 || self.language == Language::Glsl => {
+                Ok(Value { id: self.constant_int(word as i32), ty: Type::Scalar(Scalar::Int), literal: None })
+            }
+            Some(_) => Err(self.error(position, format!("integer literal `{}` does not fit in `i32`", value))),
+            None => Ok(self.literal(Literal::Int(value as i64))),
+        }
+    }
+
+    fn lookup(&mut self, name: &str, position: Position) -> Lowered<Binding<'a>> {
+        if let Some(binding) = self.scopes.iter().rev().find_map(|scope| scope.get(name)) {
+            return Ok(binding.clone());
+        }
+        if let Some(binding) = self.globals.get(name) {
+            return Ok(binding.clone());
+        }
+        if self.language == Language::Glsl && name.starts_with("gl_") {
+            if let Some(binding) = self.glsl_builtin(name, position)? {
+                return Ok(binding);
+            }
+        }
+        let message = match self.functions.contains_key(name) {
+            true => format!("`{}` is a function", name),
+            false => format!("unknown identifier `{}`", name),
+        };
+        let reported = self.error(position, message);
+        // Aynı ad için tekrar tanı üretilmez.
+        self.globals.insert(name.to_string(), Binding::Poisoned);
+        Err(reported)
+    }
+
+    // İfadenin yeri. `write` açıkken yalnızca değiştirilebilir değişkenler ve onların öğeleri kabul edilir.
+    pub(super) fn place(&mut self, expr: &'a Expr, write: bool) -> Lowered<Place> {
+        let position = expr.position;
+        let not_writable =
+            |lowerer: &mut Self, name: &str| Err(lowerer.error(position, format!("cannot assign to `{}`", name)));
+        match &expr.kind {
+            ExprKind::Name(name) => match self.lookup(name, position)? {
+                Binding::Variable { pointer, ty, storage, writable } => {
+                    if write && !writable {
+                        return not_writable(self, name);
+                    }
+                    Ok(Place::Pointer { pointer, ty, storage })
+                }
+                Binding::BlockMember { pointer, index, ty } => {
+                    if write {
+                        return not_writable(self, name);
+                    }
+                    let index = self.constant_int(index as i32);
+                    let storage = StorageClass::Uniform;
+                    Ok(Place::Pointer { pointer: self.access(pointer, storage, &ty, index), ty, storage })
+                }
+                Binding::Value(value) => match write {
+                    true => not_writable(self, name),
+                    false => Ok(Place::Value(value)),
+                },
+                Binding::Inline(value, ty) => {
+                    if write {
+                        return not_writable(self, name);
+                    }
+                    Ok(Place::Value(self.inline(name, value, ty.as_ref(), position)?))
+                }
+                Binding::Poisoned => Err(Reported),
+            },
+            ExprKind::Member(base, member) => {
+                let base = self.place(base, write)?;
+                self.member(base, member, write, position)
+            }
+            ExprKind::Index(base, index) => {
+                let base = self.place(base, write)?;
+                let index = self.index_value(index)?;
+                self.index(base, index, write, position)
+            }
+            _ if write => Err(self.error(position, "cannot assign to this expression")),
+            _ => Ok(Place::Value(self.expr(expr)?)),
+        }
+    }
+
+    fn inline(&mut self, name: &str, value: &'a Expr, ty: Option<&Type>, position: Position) -> Lowered<Value> {
+        if self.inline_depth >= MAX_INLINE_DEPTH {
+            return Err(self.error(position, format!("constant `{}` is defined in terms of itself", name)));
+        }
+        self.inline_depth += 1;
+        let result = self.expr(value);
+        self.inline_depth -= 1;
+        match ty {
+            Some(ty) => self.coerce(result?, ty, value.position),
+            None => result,
+        }
+    }
+
+    fn access(&mut self, pointer: u32, storage: StorageClass, ty: &Type, index: u32) -> u32 {
+        let pointer_type = self.pointer_type(ty, storage);
+        let id = self.builder.id();
+        self.emit(op::ACCESS_CHAIN, &[pointer_type, id, pointer, index]);
+        id
+    }
+
+    pub(super) fn load(&mut self, place: Place) -> Value {
+        match place {
+            Place::Pointer { pointer, ty, .. } => self.op(op::LOAD, &ty, &[pointer]),
+            Place::Swizzle { pointer, ty, components, .. } => {
+                let vector = self.op(op::LOAD, &ty, &[pointer]);
+                self.shuffle(vector, &components)
+            }
+            Place::Value(value) => value,
+        }
+    }
+
+    fn store(&mut self, place: Place, value: Value) {
+        match place {
+            Place::Pointer { pointer, .. } => self.emit(op::STORE, &[pointer, value.id]),
+            Place::Swizzle { pointer, ty, components, .. } => {
+                let vector = self.op(op::LOAD, &ty, &[pointer]);
+                let width = ty.width().unwrap_or(0);
+                let selection: Vec<u32> = (0..width)
+                    .map(|component| match components.iter().position(|selected| *selected == component) {
+                        Some(position) => width + position as u32,
+                        None => component,
+                    })
+                    .collect();
+                let updated =
+                    self.op(op::VECTOR_SHUFFLE, &ty, &[&[vector.id, value.id], selection.as_slice()].concat());
+                self.emit(op::STORE, &[pointer, updated.id]);
+            }
+            Place::Value(_) => {}
+        }
+    }
+
+    pub(super) fn shuffle(&mut self, vector: Value, components: &[u32]) -> Value {
+        let scalar = vector.ty.scalar().unwrap_or(Scalar::Float);
+        if let [component] = components {
+            return self.op(op::COMPOSITE_EXTRACT, &Type::Scalar(scalar), &[vector.id, *component]);
+        }
+        let ty = Type::Vector(scalar, components.len() as u32);
+        self.op(op::VECTOR_SHUFFLE, &ty, &[&[vector.id, vector.id], components].concat())
+    }
+
+    fn swizzle(&mut self, name: &str, width: u32, position: Position) -> Lowered<Vec<u32>> {
+        let sets: &[&str] = match self.language {
+            Language::Wgsl => &["xyzw", "rgba"],
+            Language::Glsl => &["xyzw", "rgba", "stpq"],
+        };
+        let components = sets.iter().find_map(|set| {
+            name.chars().map(|c| set.find(c).map(|component| component as u32)).collect::<Option<Vec<u32>>>()
+        });
+        match components {
+            Some(components)
+                if (1..=4).contains(&components.len()) && components.iter().all(|component| *component < width) =>
+            {
+                Ok(components)
+            }
+            _ => {
+                Err(self.error(position, format!("`{}` is not a valid swizzle of a {}-component vector", name, width)))
+            }
+        }
+    }
+
+    fn member(&mut self, base: Place, name: &str, write: bool, position: Position) -> Lowered<Place> {
+        let ty = base.ty();
+        match &ty {
+            Type::Struct(index) => {
+                let Some(member) = self.structs[*index].members.iter().position(|(member, _)| member == name) else {
+                    let message = format!("`{}` has no member `{}`", self.type_name(&ty), name);
+                    return Err(self.error(position, message));
+                };
+                let member_ty = self.structs[*index].members[member].1.clone();
+                match base {
+                    Place::Pointer { pointer, storage, .. } => {
+                        let index = self.constant_int(member as i32);
+                        let pointer = self.access(pointer, storage, &member_ty, index);
+                        Ok(Place::Pointer { pointer, ty: member_ty, storage })
+                    }
+                    base => {
+                        let value = self.load(base);
+                        Ok(Place::Value(self.op(op::COMPOSITE_EXTRACT, &member_ty, &[value.id, member as u32])))
+                    }
+                }
+            }
+            Type::Vector(scalar, width) => {
+                let components = self.swizzle(name, *width, position)?;
+                let repeated = components.iter().enumerate().any(|(i, c)| components[..i].contains(c));
+                if write && repeated {
+                    return Err(self.error(position, "cannot assign to a swizzle with repeated components"));
+                }
+                match base {
+                    Place::Pointer { pointer, storage, .. } if components.len() == 1 => {
+                        let index = self.constant_int(components[0] as i32);
+                        let ty = Type::Scalar(*scalar);
+                        Ok(Place::Pointer { pointer: self.access(pointer, storage, &ty, index), ty, storage })
+                    }
+                    Place::Pointer { pointer, ty, storage } => Ok(Place::Swizzle { pointer, ty, storage, components }),
+                    _ if write => Err(self.error(position, "cannot assign to a swizzle of a swizzle")),
+                    base => {
+                        let value = self.load(base);
+                        Ok(Place::Value(self.shuffle(value, &components)))
+                    }
+                }
+            }
+            _ => {
+                let message = format!("`{}` has no member `{}`", self.type_name(&ty), name);
+                Err(self.error(position, message))
+            }
+        }
+    }
+
+    fn index_value(&mut self, index: &'a Expr) -> Lowered<Value> {
+        let value = self.expr(index)?;
+        let value = match value.literal {
+            Some(literal) => self.literal_as(literal, Scalar::Int).unwrap_or(value),
+            None => value,
+        };
+        match value.ty {
+            Type::Scalar(Scalar::Int | Scalar::Uint) => Ok(value),
+            _ => {
+                let message = format!("index must be an integer, found `{}`", self.type_name(&value.ty));
+                Err(self.error(index.position, message))
+            }
+        }
+    }
+
+    fn index(&mut self, base: Place, index: Value, write: bool, position: Position) -> Lowered<Place> {
+        let ty = base.ty();
+        let (element, length) = match &ty {
+            Type::Array(element, length) => ((**element).clone(), *length),
+            Type::Vector(scalar, width) => (Type::Scalar(*scalar), *width),
+            Type::Matrix { columns, rows } => (Type::Vector(Scalar::Float, *rows), *columns),
+            _ => return Err(self.error(position, format!("`{}` cannot be indexed", self.type_name(&ty)))),
+        };
+        let constant = match index.literal {
+            Some(Literal::Int(value)) if value < 0 || value >= length as i64 => {
+                let message = format!("index {} is out of bounds for `{}`", value, self.type_name(&ty));
+                return Err(self.error(position, message));
+            }
+            Some(Literal::Int(value)) => Some(value as u32),
+            _ => None,
+        };
+        match base {
+            Place::Pointer { pointer, storage, .. } => {
+                Ok(Place::Pointer { pointer: self.access(pointer, storage, &element, index.id), ty: element, storage })
+            }
+            _ if write => Err(self.error(position, "cannot assign to an element of a swizzle")),
+            base => {
+                let value = self.load(base);
+                let element = match (constant, &ty) {
+                    (Some(constant), _) => self.op(op::COMPOSITE_EXTRACT, &element, &[value.id, constant]),
+                    (None, Type::Vector(..)) => self.op(op::VECTOR_EXTRACT_DYNAMIC, &element, &[value.id, index.id]),
+                    // Dizi ve matris değerleri dinamik indis için geçici bir değişkene yazılır.
+                    (None, _) => {
+                        let temporary = self.local_variable(&ty);
+                        self.emit(op::STORE, &[temporary, value.id]);
+                        let pointer = self.access(temporary, StorageClass::Function, &element, index.id);
+                        self.op(op::LOAD, &element, &[pointer])
+                    }
+                };
+                Ok(Place::Value(element))
+            }
+        }
+    }
+
+    // Tip denetimi ve dönüşümler
+
+    // Değeri `target` tipine uydurur: değişmezler o türde yeniden oluşturulur, GLSL'de örtük dönüşümler yapılır.
+    pub(super) fn coerce(&mut self, value: Value, target: &Type, position: Position) -> Lowered<Value> {
+        if value.ty == *target {
+            return Ok(value);
+        }
+        if let (Some(literal), Type::Scalar(scalar)) = (value.literal, target) {
+            if let Some(value) = self.literal_as(literal, *scalar) {
+                return Ok(value);
+            }
+        }
+        if self.language == Language::Glsl && implicitly_converts(&value.ty, target) {
+            let scalar = target.scalar().unwrap_or(Scalar::Float);
+            return Ok(self.convert(value, scalar));
+        }
+        let message = format!("expected `{}`, found `{}`", self.type_name(target), self.type_name(&value.ty));
+        Err(self.error(position, message))
+    }
+
+    // Skaler veya vektörün bileşen türünü değiştirir (kurucular ve GLSL örtük dönüşümleri).
+    pub(super) fn convert(&mut self, value: Value, to: Scalar) -> Value {
+        let Some(from) = value.ty.scalar() else { return value };
+        if from == to {
+            return value;
+        }
+        if let (Some(literal), Type::Scalar(_)) = (value.literal, &value.ty) {
+            if let Some(converted) = self.literal_as(literal, to) {
+                return converted;
+            }
+        }
+        let ty = value.ty.with_scalar(to);
+        let width = value.ty.width().unwrap_or(1);
+        let opcode = match (from, to) {
+            (Scalar::Float, Scalar::Int) => op::CONVERT_F_TO_S,
+            (Scalar::Float, Scalar::Uint) => op::CONVERT_F_TO_U,
+            (Scalar::Int, Scalar::Float) => op::CONVERT_S_TO_F,
+            (Scalar::Uint, Scalar::Float) => op::CONVERT_U_TO_F,
+            (Scalar::Int | Scalar::Uint, Scalar::Int | Scalar::Uint) => op::BITCAST,
+            (Scalar::Bool, _) => {
+                let one = self.unit(to, true);
+                let zero = self.unit(to, false);
+                let one = self.splat_id(one, to, width);
+                let zero = self.splat_id(zero, to, width);
+                return self.op(op::SELECT, &ty, &[value.id, one, zero]);
+            }
+            (_, Scalar::Bool) => {
+                let zero = self.unit(from, false);
+                let zero = self.splat_id(zero, from, width);
+                let opcode = if from == Scalar::Float { op::F_UNORD_NOT_EQUAL } else { op::I_NOT_EQUAL };
+                return self.op(opcode, &ty, &[value.id, zero]);
+            }
+            _ => return value,
+        };
+        self.op(opcode, &ty, &[value.id])
+    }
+
+    fn splat_id(&mut self, id: u32, scalar: Scalar, width: u32) -> u32 {
+        match width {
+            1 => id,
+            _ => self.op(op::COMPOSITE_CONSTRUCT, &Type::Vector(scalar, width), &vec![id; width as usize]).id,
+        }
+    }
+
+    pub(super) fn splat(&mut self, value: Value, width: u32) -> Value {
+        let scalar = value.ty.scalar().unwrap_or(Scalar::Float);
+        self.op(op::COMPOSITE_CONSTRUCT, &Type::Vector(scalar, width), &vec![value.id; width as usize])
+    }
+
+    // Değişmez işlenen, diğer işlenenin bileşen türünü alır.
+    pub(super) fn unify_literals(&mut self, left: Value, right: Value) -> (Value, Value) {
+        let retyped =
+            |lowerer: &mut Self, value: Value, other: &Value| match (value.literal, other.literal, other.ty.scalar()) {
+                (Some(literal), None, Some(scalar)) => lowerer.literal_as(literal, scalar).unwrap_or(value),
+                _ => value,
+            };
+        let left = retyped(self, left, &right);
+        let right = retyped(self, right, &left);
+        (left, right)
+    }
+
+    // GLSL örtük dönüşümleri: int → uint → float.
+    fn promote(&mut self, left: Value, right: Value) -> (Value, Value) {
+        if self.language != Language::Glsl {
+            return (left, right);
+        }
+        let rank = |ty: &Type| match ty.scalar() {
+            Some(Scalar::Int) => 1,
+            Some(Scalar::Uint) => 2,
+            Some(Scalar::Float) if !matches!(ty, Type::Matrix { .. }) => 3,
+            _ => 0,
+        };
+        let (left_rank, right_rank) = (rank(&left.ty), rank(&right.ty));
+        if left_rank == 0 || right_rank == 0 || left_rank == right_rank {
+            return (left, right);
+        }
+        let target = if left_rank > right_rank { left.ty.scalar() } else { right.ty.scalar() };
+        let target = target.unwrap_or(Scalar::Float);
+        (self.convert(left, target), self.convert(right, target))
+    }
+
+    fn operator_error(&mut self, op: BinaryOp, left: &Type, right: &Type, position: Position) -> Reported {
+        let message = format!(
+            "operator `{}` cannot be applied to `{}` and `{}`",
+            op.symbol(),
+            self.type_name(left),
+            self.type_name(right)
+        );
+        self.error(position, message)
+    }
+
+    fn unary(&mut self, op: UnaryOp, operand: Value, position: Position) -> Lowered<Value> {
+        if let Some(literal) = operand.literal.filter(|literal| literal.default_type() == operand.ty) {
+            let folded = match (op, literal) {
+                (UnaryOp::Negate, Literal::Int(value)) => Some(Literal::Int(-value)),
+                (UnaryOp::Negate, Literal::Float(value)) => Some(Literal::Float(-value)),
+                (UnaryOp::Not, Literal::Bool(value)) => Some(Literal::Bool(!value)),
+                (UnaryOp::BitNot, Literal::Int(value)) => Some(Literal::Int(!value)),
+                _ => None,
+            };
+            if let Some(folded) = folded {
+                return Ok(self.literal(folded));
+            }
+        }
+        let ty = operand.ty.clone();
+        match (op, &ty, ty.scalar()) {
+            (UnaryOp::Negate, Type::Matrix { .. }, _) => {
+                let factor = self.constant_float(-1.0);
+                Ok(self.op(op::MATRIX_TIMES_SCALAR, &ty, &[operand.id, factor]))
+            }
+            (UnaryOp::Negate, _, Some(Scalar::Float)) => Ok(self.op(op::F_NEGATE, &ty, &[operand.id])),
+            (UnaryOp::Negate, _, Some(Scalar::Int | Scalar::Uint)) => Ok(self.op(op::S_NEGATE, &ty, &[operand.id])),
+            (UnaryOp::Not, _, Some(Scalar::Bool)) => Ok(self.op(op::LOGICAL_NOT, &ty, &[operand.id])),
+            (UnaryOp::BitNot, _, Some(Scalar::Int | Scalar::Uint)) => Ok(self.op(op::NOT, &ty, &[operand.id])),
+            _ => {
+                let symbol = match op {
+                    UnaryOp::Negate => "-",
+                    UnaryOp::Not => "!",
+                    UnaryOp::BitNot => "~",
+                };
+                let message = format!("operator `{}` cannot be applied to `{}`", symbol, self.type_name(&ty));
+                Err(self.error(position, message))
+            }
+        }
+    }
+
+    pub(super) fn binary(&mut self, op: BinaryOp, left: Value, right: Value, position: Position) -> Lowered<Value> {
+        if let (Some(a), Some(b)) = (left.literal, right.literal) {
+            if left.ty == a.default_type() && right.ty == b.default_type() {
+                if let Some(folded) = fold(op, a, b) {
+                    return Ok(self.literal(folded));
+                }
+            }
+        }
+        let (left, right) = self.unify_literals(left, right);
+        let (left, right) = self.promote(left, right);
+        match op {
+            BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem => {
+                self.arithmetic(op, left, right, position)
+            }
+            BinaryOp::BitAnd | BinaryOp::BitOr | BinaryOp::BitXor => self.bitwise(op, left, right, position),
+            BinaryOp::Shl | BinaryOp::Shr => self.shift(op, left, right, position),
+            BinaryOp::And | BinaryOp::Or => {
+                if left.ty != Type::Scalar(Scalar::Bool) || right.ty != left.ty {
+                    return Err(self.operator_error(op, &left.ty, &right.ty, position));
+                }
+                let opcode = if op == BinaryOp::And { op::LOGICAL_AND } else { op::LOGICAL_OR };
+                Ok(self.op(opcode, &left.ty, &[left.id, right.id]))
+            }
+            _ => self.comparison(op, left, right, position),
+        }
+    }
+
+    // Skaler işlenen gerekiyorsa diğer işlenenin genişliğine çoğaltılır.
+    fn same_width(&mut self, left: Value, right: Value) -> Option<(Value, Value)> {
+        match (left.ty.width()?, right.ty.width()?) {
+            (a, b) if a == b => Some((left, right)),
+            (1, width) => Some((self.splat(left, width), right)),
+            (width, 1) => {
+                let right = self.splat(right, width);
+                Some((left, right))
+            }
+            _ => None,
+        }
+    }
+
+    fn arithmetic(&mut self, op: BinaryOp, left: Value, right: Value, position: Position) -> Lowered<Value> {
+        let scalar = match (left.ty.scalar(), right.ty.scalar()) {
+            (Some(a), Some(b)) if a == b && a != Scalar::Bool => a,
+            _ => return Err(self.operator_error(op, &left.ty, &right.ty, position)),
+        };
+        let matrix = |ty: &Type| matches!(ty, Type::Matrix { .. });
+        if matrix(&left.ty) || matrix(&right.ty) {
+            return self.matrix_arithmetic(op, left, right, position);
+        }
+        if op == BinaryOp::Mul && scalar == Scalar::Float {
+            match (left.ty.width(), right.ty.width()) {
+                (Some(width), Some(1)) if width > 1 => {
+                    return Ok(self.op(op::VECTOR_TIMES_SCALAR, &left.ty.clone(), &[left.id, right.id]));
+                }
+                (Some(1), Some(width)) if width > 1 => {
+                    return Ok(self.op(op::VECTOR_TIMES_SCALAR, &right.ty.clone(), &[right.id, left.id]));
+                }
+                _ => {}
+            }
+        }
+        let (left_ty, right_ty) = (left.ty.clone(), right.ty.clone());
+        let Some((left, right)) = self.same_width(left, right) else {
+            return Err(self.operator_error(op, &left_ty, &right_ty, position));
+        };
+        let float = scalar == Scalar::Float;
+        let opcode = match (op, scalar) {
+            (BinaryOp::Add, _) => {
+                if float {
+                    op::F_ADD
+                } else {
+                    op::I_ADD
+                }
+            }
+            (BinaryOp::Sub, _) => {
+                if float {
+                    op::F_SUB
+                } else {
+                    op::I_SUB
+                }
+            }
+            (BinaryOp::Mul, _) => {
+                if float {
+                    op::F_MUL
+                } else {
+                    op::I_MUL
+                }
+            }
+            (BinaryOp::Div, Scalar::Float) => op::F_DIV,
+            (BinaryOp::Div, Scalar::Int) => op::S_DIV,
+            (BinaryOp::Div, _) => op::U_DIV,
+            (_, Scalar::Float) => op::F_REM,
+            (_, Scalar::Int) => op::S_REM,
+            _ => op::U_MOD,
+        };
+        Ok(self.op(opcode, &left.ty.clone(), &[left.id, right.id]))
+    }
+
+    fn matrix_arithmetic(&mut self, op: BinaryOp, left: Value, right: Value, position: Position) -> Lowered<Value> {
+        let (left_ty, right_ty) = (left.ty.clone(), right.ty.clone());
+        match (&left_ty, &right_ty, op) {
+            (
+                Type::Matrix { columns, rows },
+                Type::Matrix { columns: right_columns, rows: right_rows },
+                BinaryOp::Mul,
+            ) if columns == right_rows => {
+                let ty = Type::Matrix { columns: *right_columns, rows: *rows };
+                Ok(self.op(op::MATRIX_TIMES_MATRIX, &ty, &[left.id, right.id]))
+            }
+            (Type::Matrix { columns, rows }, Type::Vector(_, width), BinaryOp::Mul) if columns == width => {
+                Ok(self.op(op::MATRIX_TIMES_VECTOR, &Type::Vector(Scalar::Float, *rows), &[left.id, right.id]))
+            }
+            (Type::Vector(_, width), Type::Matrix { columns, rows }, BinaryOp::Mul) if width == rows => {
+                Ok(self.op(op::VECTOR_TIMES_MATRIX, &Type::Vector(Scalar::Float, *columns), &[left.id, right.id]))
+            }
+            (Type::Matrix { .. }, Type::Scalar(_), BinaryOp::Mul) => {
+                Ok(self.op(op::MATRIX_TIMES_SCALAR, &left_ty, &[left.id, right.id]))
+            }
+            (Type::Scalar(_), Type::Matrix { .. }, BinaryOp::Mul) => {
+                Ok(self.op(op::MATRIX_TIMES_SCALAR, &right_ty, &[right.id, left.id]))
+            }
+            // Toplama ve çıkarma sütun sütun yapılır.
+            (Type::Matrix { columns, rows }, _, BinaryOp::Add | BinaryOp::Sub) if left_ty == right_ty => {
+                let column = Type::Vector(Scalar::Float, *rows);
+                let opcode = if op == BinaryOp::Add { op::F_ADD } else { op::F_SUB };
+                let mut results = Vec::new();
+                for index in 0..*columns {
+                    let a = self.op(op::COMPOSITE_EXTRACT, &column, &[left.id, index]);
+                    let b = self.op(op::COMPOSITE_EXTRACT, &column, &[right.id, index]);
+                    results.push(self.op(opcode, &column, &[a.id, b.id]).id);
+                }
+                Ok(self.op(op::COMPOSITE_CONSTRUCT, &left_ty, &results))
+            }
+            _ => Err(self.operator_error(op, &left_ty, &right_ty, position)),
+        }
+    }
+
+    fn bitwise(&mut self, op: BinaryOp, left: Value, right: Value, position: Position) -> Lowered<Value> {
+        let (left_ty, right_ty) = (left.ty.clone(), right.ty.clone());
+        let scalar = match (left.ty.scalar(), right.ty.scalar()) {
+            (Some(a), Some(b)) if a == b && !matches!(left.ty, Type::Matrix { .. }) => a,
+            _ => return Err(self.operator_error(op, &left_ty, &right_ty, position)),
+        };
+        let Some((left, right)) = self.same_width(left, right) else {
+            return Err(self.operator_error(op, &left_ty, &right_ty, position));
+        };
+        let opcode = match (op, scalar) {
+            (BinaryOp::BitAnd, Scalar::Bool) => op::LOGICAL_AND,
+            (BinaryOp::BitOr, Scalar::Bool) => op::LOGICAL_OR,
+            (_, Scalar::Bool) => op::LOGICAL_NOT_EQUAL,
+            (_, Scalar::Float) => return Err(self.operator_error(op, &left_ty, &right_ty, position)),
+            (BinaryOp::BitAnd, _) => op::BITWISE_AND,
+            (BinaryOp::BitOr, _) => op::BITWISE_OR,
+            _ => op::BITWISE_XOR,
+        };
+        Ok(self.op(opcode, &left.ty.clone(), &[left.id, right.id]))
+    }
+
+    fn shift(&mut self, op: BinaryOp, left: Value, right: Value, position: Position) -> Lowered<Value> {
+        let (left_ty, right_ty) = (left.ty.clone(), right.ty.clone());
+        let integer = |ty: &Type| matches!(ty.scalar(), Some(Scalar::Int | Scalar::Uint)) && ty.width().is_some();
+        if !integer(&left_ty) || !integer(&right_ty) {
+            return Err(self.operator_error(op, &left_ty, &right_ty, position));
+        }
+        let right = match (left_ty.width(), right_ty.width()) {
+            (Some(width), Some(1)) if width > 1 => self.splat(right, width),
+            (a, b) if a == b => right,
+            _ => return Err(self.operator_error(op, &left_ty, &right_ty, position)),
+        };
+        let opcode = match (op, left_ty.scalar()) {
+            (BinaryOp::Shl, _) => op::SHIFT_LEFT_LOGICAL,
+            (_, Some(Scalar::Int)) => op::SHIFT_RIGHT_ARITHMETIC,
+            _ => op::SHIFT_RIGHT_LOGICAL,
+        };
+        Ok(self.op(opcode, &left_ty, &[left.id, right.id]))
+    }
+
+    fn comparison(&mut self, op: BinaryOp, left: Value, right: Value, position: Position) -> Lowered<Value> {
+        let (left_ty, right_ty) = (left.ty.clone(), right.ty.clone());
+        if left_ty != right_ty || left_ty.width().is_none() {
+            return Err(self.operator_error(op, &left_ty, &right_ty, position));
+        }
+        let vector = left_ty.width() != Some(1);
+        if self.language == Language::Glsl && vector {
+            // GLSL'de vektörlerin eşitliği tek bir bool'dur; sıralama karşılaştırmaları lessThan vb. ile yapılır.
+            let reduce = match op {
+                BinaryOp::Equal => op::ALL,
+                BinaryOp::NotEqual => op::ANY,
+                _ => return Err(self.operator_error(op, &left_ty, &right_ty, position)),
+            };
+            let components = self.compare(op, left, right, position)?;
+            return Ok(self.op(reduce, &Type::Scalar(Scalar::Bool), &[components.id]));
+        }
+        self.compare(op, left, right, position)
+    }
+
+    // Bileşen bazında karşılaştırma; sonuç aynı genişlikte bool'dur.
+    pub(super) fn compare(&mut self, op: BinaryOp, left: Value, right: Value, position: Position) -> Lowered<Value> {
+        let ty = left.ty.with_scalar(Scalar::Bool);
+        let opcode = match (left.ty.scalar(), op) {
+            (Some(Scalar::Bool), BinaryOp::Equal) => op::LOGICAL_EQUAL,
+            (Some(Scalar::Bool), BinaryOp::NotEqual) => op::LOGICAL_NOT_EQUAL,
+            (Some(Scalar::Int | Scalar::Uint), BinaryOp::Equal) => op::I_EQUAL,
+            (Some(Scalar::Int | Scalar::Uint), BinaryOp::NotEqual) => op::I_NOT_EQUAL,
+            (Some(Scalar::Int), BinaryOp::Less) => op::S_LESS_THAN,
+            (Some(Scalar::Int), BinaryOp::LessEqual) => op::S_LESS_THAN_EQUAL,
+            (Some(Scalar::Int), BinaryOp::Greater) => op::S_GREATER_THAN,
+            (Some(Scalar::Int), BinaryOp::GreaterEqual) => op::S_GREATER_THAN_EQUAL,
+            (Some(Scalar::Uint), BinaryOp::Less) => op::U_LESS_THAN,
+            (Some(Scalar::Uint), BinaryOp::LessEqual) => op::U_LESS_THAN_EQUAL,
+            (Some(Scalar::Uint), BinaryOp::Greater) => op::U_GREATER_THAN,
+            (Some(Scalar::Uint), BinaryOp::GreaterEqual) => op::U_GREATER_THAN_EQUAL,
+            (Some(Scalar::Float), BinaryOp::Equal) => op::F_ORD_EQUAL,
+            (Some(Scalar::Float), BinaryOp::NotEqual) => op::F_UNORD_NOT_EQUAL,
+            (Some(Scalar::Float), BinaryOp::Less) => op::F_ORD_LESS_THAN,
+            (Some(Scalar::Float), BinaryOp::LessEqual) => op::F_ORD_LESS_THAN_EQUAL,
+            (Some(Scalar::Float), BinaryOp::Greater) => op::F_ORD_GREATER_THAN,
+            (Some(Scalar::Float), BinaryOp::GreaterEqual) => op::F_ORD_GREATER_THAN_EQUAL,
+            _ => return Err(self.operator_error(op, &left.ty, &right.ty, position)),
+        };
+        Ok(self.op(opcode, &ty, &[left.id, right.id]))
+    }
+
+    // `&&` ve `||`: sağ işlenen yalnızca gerektiğinde hesaplanır.
+    fn short_circuit(&mut self, op: BinaryOp, left: &'a Expr, right: &'a Expr, position: Position) -> Lowered<Value> {
+        let bool_type = Type::Scalar(Scalar::Bool);
+        let left_value = self.expr(left)?;
+        if left_value.ty != bool_type {
+            let message =
+                format!("operator `{}` expects `bool`, found `{}`", op.symbol(), self.type_name(&left_value.ty));
+            return Err(self.error(left.position, message));
+        }
+        let start = self.block;
+        let right_label = self.builder.id();
+        let merge = self.builder.id();
+        self.emit(op::SELECTION_MERGE, &[merge, 0]);
+        let targets = if op == BinaryOp::And { [right_label, merge] } else { [merge, right_label] };
+        self.terminate(op::BRANCH_CONDITIONAL, &[left_value.id, targets[0], targets[1]]);
+        self.label(right_label);
+        let right_value = self.expr(right)?;
+        if right_value.ty != bool_type {
+            let message =
+                format!("operator `{}` expects `bool`, found `{}`", op.symbol(), self.type_name(&right_value.ty));
+            return Err(self.error(right.position, message));
+        }
+        let end = self.block;
+        self.branch(merge);
+        self.label(merge);
+        let _ = position;
+        Ok(self.op(op::PHI, &bool_type, &[left_value.id, start, right_value.id, end]))
+    }
+
+    // GLSL'deki `c ? a : b`; yalnızca seçilen dal hesaplanır.
+    fn conditional(&mut self, condition: &'a Expr, accept: &'a Expr, reject: &'a Expr) -> Lowered<Value> {
+        let condition = self.condition(condition)?;
+        let accept_label = self.builder.id();
+        let reject_label = self.builder.id();
+        let merge = self.builder.id();
+        self.emit(op::SELECTION_MERGE, &[merge, 0]);
+        self.terminate(op::BRANCH_CONDITIONAL, &[condition.id, accept_label, reject_label]);
+        self.label(accept_label);
+        let accept_value = self.expr(accept)?;
+        let accept_end = self.block;
+        self.branch(merge);
+        self.label(reject_label);
+        let reject_value = self.expr(reject)?;
+        // Değişmezler sabit olduğundan diğer dalın türüne dallardan sonra da dönüştürülebilir.
+        let (accept_value, reject_value) = self.unify_literals(accept_value, reject_value);
+        if accept_value.ty != reject_value.ty {
+            let message = format!(
+                "both branches of `?:` must have the same type, found `{}` and `{}`",
+                self.type_name(&accept_value.ty),
+                self.type_name(&reject_value.ty)
+            );
+            return Err(self.error(reject.position, message));
+        }
+        let reject_end = self.block;
+        self.branch(merge);
+        self.label(merge);
+        let ty = accept_value.ty.clone();
+        Ok(self.op(op::PHI, &ty, &[accept_value.id, accept_end, reject_value.id, reject_end]))
+    }
+
+    fn bitcast(&mut self, value: Value, ty: &Type, position: Position) -> Lowered<Value> {
+        let numeric = |ty: &Type| ty.width().is_some() && !matches!(ty.scalar(), Some(Scalar::Bool));
+        if !numeric(&value.ty) || !numeric(ty) || value.ty.width() != ty.width() {
+            let message = format!("cannot bitcast `{}` to `{}`", self.type_name(&value.ty), self.type_name(ty));
+            return Err(self.error(position, message));
+        }
+        if value.ty == *ty {
+            return Ok(value);
+        }
+        Ok(self.op(op::BITCAST, ty, &[value.id]))
+    }
+
+    fn call(&mut self, name: &str, arguments: &'a [Expr], position: Position) -> Lowered<Value> {
+        if self.struct_names.contains_key(name) {
+            return self.construct(&TypeExpr::Named(name.to_string(), position), arguments, position);
+        }
+        if self.language == Language::Wgsl {
+            if let Some(result) = self.inferred_construct(name, arguments, position) {
+                return result;
+            }
+        }
+        if let Some(info) = self.functions.get(name).cloned() {
+            let Some(info) = info else { return Err(Reported) };
+            if info.stage.is_some() {
+                return Err(self.error(position, format!("entry point `{}` cannot be called", name)));
+            }
+            if arguments.len() != info.params.len() {
+                let message =
+                    format!("function `{}` expects {} argument(s), found {}", name, info.params.len(), arguments.len());
+                return Err(self.error(position, message));
+            }
+            let mut operands = vec![info.id];
+            for (argument, ty) in arguments.iter().zip(&info.params) {
+                let value = self.expr(argument)?;
+                operands.push(self.coerce(value, ty, argument.position)?.id);
+            }
+            return Ok(self.op(op::FUNCTION_CALL, &info.result, &operands));
+        }
+        if let Some(result) = self.builtin(name, arguments, position) {
+            return result;
+        }
+        Err(self.error(position, format!("unknown function `{}`", name)))
+    }
+
+    pub(super) fn arguments(&mut self, arguments: &'a [Expr]) -> Lowered<Vec<Value>> {
+        arguments.iter().map(|argument| self.expr(argument)).collect()
+    }
+
+    // WGSL'de bileşen tipi verilmemiş kurucular: `vec3(..)`, `mat4x4(..)` ve `array(..)`.
+    fn inferred_construct(&mut self, name: &str, arguments: &'a [Expr], position: Position) -> Option<Lowered<Value>> {
+        let shape = match name.as_bytes() {
+            [b'v', b'e', b'c', n @ b'2'..=b'4'] => Some((0, u32::from(n - b'0'))),
+            [b'm', b'a', b't', c @ b'2'..=b'4', b'x', r @ b'2'..=b'4'] => {
+                Some((u32::from(c - b'0'), u32::from(r - b'0')))
+            }
+            b"array" => None,
+            _ => return None,
+        };
+        Some(self.arguments(arguments).and_then(|values| {
+            let ty = match shape {
+                Some((0, width)) => Type::Vector(infer_scalar(&values), width),
+                Some((columns, rows)) => Type::Matrix { columns, rows },
+                None => {
+                    let Some(first) = values.iter().find(|value| value.literal.is_none()).or(values.first()) else {
+                        return Err(self.error(position, "`array()` needs at least one element"));
+                    };
+                    let element = match first.literal {
+                        Some(_) => Type::Scalar(infer_scalar(&values)),
+                        None => first.ty.clone(),
+                    };
+                    Type::Array(Box::new(element), values.len() as u32)
+                }
+            };
+            self.construct_type(&ty, values, position)
+        }))
+    }
+
+    fn construct(&mut self, ty: &TypeExpr, arguments: &'a [Expr], position: Position) -> Lowered<Value> {
+        let ty = match ty {
+            TypeExpr::Array(element, None, _) => Type::Array(Box::new(self.resolve(element)?), arguments.len() as u32),
+            ty => self.resolve(ty)?,
+        };
+        let values = self.arguments(arguments)?;
+        self.construct_type(&ty, values, position)
+    }
+
+    fn construct_type(&mut self, ty: &Type, mut values: Vec<Value>, position: Position) -> Lowered<Value> {
+        if values.is_empty() && !ty.is_opaque() {
+            return Ok(Value { id: self.null(ty), ty: ty.clone(), literal: None });
+        }
+        let mismatch = |lowerer: &mut Self, values: &[Value]| -> Reported {
+            let found: Vec<String> = values.iter().map(|value| format!("`{}`", lowerer.type_name(&value.ty))).collect();
+            let message = format!("`{}` cannot be constructed from {}", lowerer.type_name(ty), found.join(", "));
+            lowerer.error(position, message)
+        };
+        match ty {
+            Type::Scalar(scalar) => {
+                let [value] = <[Value; 1]>::try_from(values.clone()).map_err(|_| mismatch(self, &values))?;
+                let value = match (&value.ty, self.language) {
+                    (Type::Scalar(_), _) => value,
+                    (Type::Vector(..), Language::Glsl) => self.shuffle(value, &[0]),
+                    _ => return Err(mismatch(self, &values)),
+                };
+                Ok(self.convert(value, *scalar))
+            }
+            Type::Vector(scalar, width) => {
+                if let [value] = values.as_slice() {
+                    match value.ty {
+                        Type::Scalar(_) => {
+                            let value = self.convert(value.clone(), *scalar);
+                            return Ok(self.splat(value, *width));
+                        }
+                        Type::Vector(_, count) if count == *width => return Ok(self.convert(value.clone(), *scalar)),
+                        Type::Vector(_, count) if count > *width && self.language == Language::Glsl => {
+                            let components: Vec<u32> = (0..*width).collect();
+                            let value = self.shuffle(value.clone(), &components);
+                            return Ok(self.convert(value, *scalar));
+                        }
+                        _ => return Err(mismatch(self, &values)),
+                    }
+                }
+                let mut parts = Vec::new();
+                let mut count = 0;
+                for value in &values {
+                    match (&value.ty, value.ty.width()) {
+                        (Type::Scalar(_) | Type::Vector(..), Some(width)) => {
+                            count += width;
+                            parts.push(self.convert(value.clone(), *scalar).id);
+                        }
+                        _ => return Err(mismatch(self, &values)),
+                    }
+                }
+                if count != *width {
+                    return Err(mismatch(self, &values));
+                }
+                Ok(self.op(op::COMPOSITE_CONSTRUCT, ty, &parts))
+            }
+            Type::Matrix { columns, rows } => self.construct_matrix(*columns, *rows, values, position),
+            Type::Array(element, length) => {
+                if values.len() != *length as usize {
+                    let message =
+                        format!("`{}` expects {} elements, found {}", self.type_name(ty), length, values.len());
+                    return Err(self.error(position, message));
+                }
+                let mut elements = Vec::new();
+                for value in values {
+                    elements.push(self.coerce(value, element, position)?.id);
+                }
+                Ok(self.op(op::COMPOSITE_CONSTRUCT, ty, &elements))
+            }
+            Type::Struct(index) => {
+                let members = self.struct_members(*index);
+                if values.len() != members.len() {
+                    let message =
+                        format!("`{}` expects {} members, found {}", self.type_name(ty), members.len(), values.len());
+                    return Err(self.error(position, message));
+                }
+                let mut parts = Vec::new();
+                for (value, (_, member)) in values.drain(..).zip(&members) {
+                    parts.push(self.coerce(value, member, position)?.id);
+                }
+                Ok(self.op(op::COMPOSITE_CONSTRUCT, ty, &parts))
+            }
+            // GLSL'de ayrı doku ve sampler'dan birleşik sampler: `sampler2D(texture, sampler)`.
+            Type::SampledTexture(texture) => match values.as_slice() {
+                [Value { ty: Type::Texture(image), .. }, Value { ty: Type::Sampler { .. }, .. }]
+                    if (image.dimension, image.arrayed, image.kind)
+                        == (texture.dimension, texture.arrayed, texture.kind) =>
+                {
+                    Ok(self.op(op::SAMPLED_IMAGE, ty, &[values[0].id, values[1].id]))
+                }
+                _ => Err(mismatch(self, &values)),
+            },
+            _ => Err(mismatch(self, &values)),
+        }
+    }
+
+    // Matris kurucusu: sütunlar, bileşenler, (GLSL'de) köşegen için tek skaler veya başka boyuttaki bir matris.
+    fn construct_matrix(&mut self, columns: u32, rows: u32, values: Vec<Value>, position: Position) -> Lowered<Value> {
+        let ty = Type::Matrix { columns, rows };
+        let column_ty = Type::Vector(Scalar::Float, rows);
+        let float = Type::Scalar(Scalar::Float);
+        let glsl = self.language == Language::Glsl;
+        let component = |lowerer: &mut Self, source: Option<u32>, column: u32, row: u32| match source {
+            Some(id) => lowerer.op(op::COMPOSITE_EXTRACT, &float, &[id, column, row]).id,
+            None => lowerer.constant_float(if column == row { 1.0 } else { 0.0 }),
+        };
+        let mut scalars = Vec::new();
+        match values.as_slice() {
+            [Value { ty: Type::Matrix { columns: c, rows: r }, id, .. }] if glsl || (*c == columns && *r == rows) => {
+                if *c == columns && *r == rows {
+                    return Ok(values[0].clone());
+                }
+                let (c, r, id) = (*c, *r, *id);
+                for column in 0..columns {
+                    for row in 0..rows {
+                        let source = Some(id).filter(|_| column < c && row < r);
+                        scalars.push(component(self, source, column, row));
+                    }
+                }
+            }
+            [value @ Value { ty: Type::Scalar(_), .. }] if glsl => {
+                let diagonal = self.convert(value.clone(), Scalar::Float);
+                let zero = self.constant_float(0.0);
+                for column in 0..columns {
+                    for row in 0..rows {
+                        scalars.push(if column == row { diagonal.id } else { zero });
+                    }
+                }
+            }
+            _ => {
+                for value in &values {
+                    let value = self.convert(value.clone(), Scalar::Float);
+                    match value.ty {
+                        Type::Scalar(_) => scalars.push(value.id),
+                        Type::Vector(_, width) => {
+                            for index in 0..width {
+                                scalars.push(self.op(op::COMPOSITE_EXTRACT, &float, &[value.id, index]).id);
+                            }
+                        }
+                        _ => scalars.clear(),
+                    }
+                }
+                if scalars.len() != (columns * rows) as usize {
+                    let message = format!("`{}` expects {} components", self.type_name(&ty), columns * rows);
+                    return Err(self.error(position, message));
+                }
+            }
+        }
+        let mut column_ids = Vec::new();
+        for column in scalars.chunks(rows as usize) {
+            column_ids.push(self.op(op::COMPOSITE_CONSTRUCT, &column_ty, column).id);
+        }
+        Ok(self.op(op::COMPOSITE_CONSTRUCT, &ty, &column_ids))
+    }
+}
+
+// Sabit değişmezler üzerindeki işlem; tamsayı taşmasında ve sıfıra bölmede None.
+fn fold(op: BinaryOp, left: Literal, right: Literal) -> Option<Literal> {
+    match (left, right) {
+        (Literal::Int(a), Literal::Int(b)) => Some(Literal::Int(match op {
+            BinaryOp::Add => a.checked_add(b)?,
+            BinaryOp::Sub => a.checked_sub(b)?,
+            BinaryOp::Mul => a.checked_mul(b)?,
+            BinaryOp::Div => a.checked_div(b)?,
+            BinaryOp::Rem => a.checked_rem(b)?,
+            BinaryOp::Shl => a.checked_shl(u32::try_from(b).ok().filter(|b| *b < 32)?)?,
+            BinaryOp::Shr => a.checked_shr(u32::try_from(b).ok().filter(|b| *b < 32)?)?,
+            BinaryOp::BitAnd => a & b,
+            BinaryOp::BitOr => a | b,
+            BinaryOp::BitXor => a ^ b,
+            _ => return None,
+        })),
+        (Literal::Float(_), Literal::Int(b)) => fold(op, left, Literal::Float(b as f64)),
+        (Literal::Int(a), Literal::Float(_)) => fold(op, Literal::Float(a as f64), right),
+        (Literal::Float(a), Literal::Float(b)) => Some(Literal::Float(match op {
+            BinaryOp::Add => a + b,
+            BinaryOp::Sub => a - b,
+            BinaryOp::Mul => a * b,
+            BinaryOp::Div => a / b,
+            _ => return None,
+        })),
+        _ => None,
+    }
+}
+
+// Çıkarımlı kurucuların bileşen türü: ilk değişmez olmayan argümanınki; hepsi değişmezse ve biri kayan noktalıysa
+// float, değilse ilk değişmezin türü.
+fn infer_scalar(values: &[Value]) -> Scalar {
+    if let Some(scalar) = values.iter().find(|value| value.literal.is_none()).and_then(|value| value.ty.scalar()) {
+        return scalar;
+    }
+    if values.iter().any(|value| matches!(value.literal, Some(Literal::Float(_)))) {
+        return Scalar::Float;
+    }
+    values.first().and_then(|value| value.ty.scalar()).unwrap_or(Scalar::Float)
+}
+
+fn implicitly_converts(from: &Type, to: &Type) -> bool {
+    from.width().is_some()
+        && from.width() == to.width()
+        && matches!(
+            (from.scalar(), to.scalar()),
+            (Some(Scalar::Int), Some(Scalar::Uint)) | (Some(Scalar::Int | Scalar::Uint), Some(Scalar::Float))
+        )
+}
+
+fn slot(global: &Global, ty: &Type) -> Option<(Slot, Option<u32>)> {
+    match &global.kind {
+        GlobalKind::Input(IoAttribute { binding: IoBinding::Location { location, .. }, .. }) => {
+            Some((Slot::Input, *location))
+        }
+        GlobalKind::Output(IoAttribute { binding: IoBinding::Location { location, index }, .. }) => {
+            Some((Slot::Output(*index), *location))
+        }
+        GlobalKind::Uniform { .. } if ty.is_opaque() => Some((Slot::Binding(0), None)),
+        GlobalKind::Uniform { location } => Some((Slot::Uniform, *location)),
+        GlobalKind::Resource { group, binding } | GlobalKind::UniformBlock { group, binding } => {
+            Some((Slot::Binding(*group), *binding))
+        }
+        _ => None,
+    }
+}
+
+fn io_location(io: &IoAttribute) -> Option<u32> {
+    match io.binding {
+        IoBinding::Location { location, .. } => location,
+        IoBinding::BuiltIn(_) => None,
+    }
+}
+
+fn execution_model(stage: ShaderStage) -> u32 {
+    match stage {
+        ShaderStage::Vertex => 0,
+        ShaderStage::Fragment => 4,
+        ShaderStage::Compute => 5,
+    }
+}
+
+// WGSL yerleşik değerleri: (aşama, giriş mi) için SPIR-V BuiltIn numarası ve tipi.
+fn wgsl_builtin(name: &str, stage: ShaderStage, input: bool) -> Option<(u32, Type)> {
+    let vec4 = Type::Vector(Scalar::Float, 4);
+    let (word, ty) = match (name, stage, input) {
+        ("position", ShaderStage::Vertex, false) => (built_in::POSITION, vec4),
+        ("position", ShaderStage::Fragment, true) => (built_in::FRAG_COORD, vec4),
+        ("vertex_index", ShaderStage::Vertex, true) => (built_in::VERTEX_INDEX, Type::Scalar(Scalar::Uint)),
+        ("instance_index", ShaderStage::Vertex, true) => (built_in::INSTANCE_INDEX, Type::Scalar(Scalar::Uint)),
+        ("front_facing", ShaderStage::Fragment, true) => (built_in::FRONT_FACING, Type::Scalar(Scalar::Bool)),
+        ("frag_depth", ShaderStage::Fragment, false) => (built_in::FRAG_DEPTH, Type::Scalar(Scalar::Float)),
+        _ => return None,
+    };
+    Some((word, ty))
+}
\ No newline at end of file
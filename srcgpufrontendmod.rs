@@ -0,0 +1,45 @@
+// WGSL ve GLSL kaynaklarını SPIR-V'ye derleyen ön uçlar (front-end).
+// İki dil de ortak sözdizimi ağacına (ast) ayrıştırılır; tip denetimi ve SPIR-V üretimi ortaktır (lower, emit).
+// Üretilen modül elle yazılmış SPIR-V gibi ayrıştırılıp yansıtılır ve yazılım arka ucunda yorumlanır.
+//
+// Desteklenen alt küme:
+// - Tipler: bool, 32 bitlik int/uint/float skalerler ve vektörler, float matrisler, sabit boyutlu diziler, yapılar;
+//   2B, 2B dizi, 3B ve küp dokular (derinlik dokuları dahil) ve sampler'lar.
+// - Vertex ve fragment giriş noktaları; konumlu girişler/çıkışlar, yerleşik değişkenler, OpenGL tarzı konumlu
+//   uniform'lar (GLSL), uniform blokları, dokular ve sampler'lar.
+// - Fonksiyonlar, yerel değişkenler, if, for, while, do-while, loop/continuing, switch, break, continue, return ve
+//   discard; yaygın yerleşik matematik, türev ve doku fonksiyonları.
+// - GLSL önişlemcisinden #version, #extension, parametresiz #define, #undef, #ifdef, #ifndef, #else ve #endif.
+// Compute shader'ları, işaretçiler, depolama arabellekleri ve dokuları ve çalışma zamanı boyutlu diziler
+// desteklenmez; bunlar konumlu tanılarla reddedilir.
+pub mod ast;
+pub mod lexer;
+pub mod wgsl;
+pub mod glsl;
+pub mod emit;
+pub mod lower;
+pub mod builtin;
+
+use std::fmt;
+
+use crate::gpu::frontend::ast::Position;
+
+// Derleme hatasının kaynaktaki yeri ve açıklaması. Satır ve sütun 1'den başlar; sütun karakter cinsindendir.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: u32,
+    pub column: u32,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(position: Position, message: impl Into<String>) -> Self {
+        Diagnostic { line: position.line, column: position.column, message: message.into() }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
\ No newline at end of file
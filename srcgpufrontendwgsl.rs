@@ -0,0 +1,808 @@
+use std::collections::HashMap;
+
+use crate::gpu::error::GraphisError;
+use crate::gpu::frontend::ast::{
+    BinaryOp, Case, Expr, ExprKind, Function, Global, GlobalKind, Interpolation, IoAttribute, IoBinding, Member,
+    Module, Param, Position, Scalar, Stmt, StmtKind, StructDecl, Texture, TextureDimension, Type, TypeExpr, UnaryOp,
+};
+use crate::gpu::frontend::lexer::{tokenize, Cursor, Token};
+use crate::gpu::frontend::lower::{lower, Language};
+use crate::gpu::frontend::Diagnostic;
+use crate::gpu::shader::ShaderStage;
+
+type Parsed<T> = Result<T, Diagnostic>;
+
+// WGSL kaynağını SPIR-V kelimelerine derler. Her @vertex ve @fragment fonksiyonu kendi adıyla bir giriş noktasıdır.
+pub fn compile(source: &str) -> Result<Vec<u32>, GraphisError> {
+    let module = parse(source).map_err(|diagnostic| GraphisError::CompilationFailed(vec![diagnostic]))?;
+    lower(&module, Language::Wgsl, None).map_err(GraphisError::CompilationFailed)
+}
+
+// Ayrıştırma ilk hatada durur; tip hataları çevirme sırasında birlikte bildirilir.
+pub fn parse(source: &str) -> Parsed<Module> {
+    let mut parser = Parser { cursor: Cursor::new(tokenize(source, false)?), aliases: HashMap::new() };
+    let mut module = Module::default();
+    while *parser.cursor.peek() != Token::End {
+        parser.global(&mut module)?;
+    }
+    Ok(module)
+}
+
+// Bir bildirimin öznitelikleri (`@location(0)` vb.).
+#[derive(Debug, Default)]
+struct Attributes {
+    names: Vec<(String, Position)>,
+    location: Option<u32>,
+    builtin: Option<String>,
+    interpolation: Option<Interpolation>,
+    blend_src: Option<u32>,
+    group: Option<u32>,
+    binding: Option<u32>,
+    stage: Option<ShaderStage>,
+}
+
+impl Attributes {
+    // Bu bildirimde geçerli olmayan ilk öznitelik hatadır.
+    fn only(&self, allowed: &[&str]) -> Parsed<()> {
+        match self.names.iter().find(|(name, _)| !allowed.contains(&name.as_str())) {
+            Some((name, position)) => error(*position, format!("attribute `@{}` is not allowed here", name)),
+            None => Ok(()),
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.names.first().map(|(_, position)| *position).unwrap_or_default()
+    }
+
+    fn io(&self) -> Parsed<Option<IoAttribute>> {
+        self.only(&["location", "builtin", "interpolate", "blend_src", "invariant"])?;
+        let position = self.position();
+        let binding = match (&self.location, &self.builtin) {
+            (Some(_), Some(_)) => {
+                return Err(Diagnostic::new(position, "@location and @builtin cannot be used together"));
+            }
+            (Some(location), None) => {
+                IoBinding::Location { location: Some(*location), index: self.blend_src.unwrap_or(0) }
+            }
+            (None, Some(builtin)) => IoBinding::BuiltIn(builtin.clone()),
+            (None, None) if self.names.is_empty() => return Ok(None),
+            (None, None) => return Err(Diagnostic::new(position, "@interpolate and @blend_src need @location")),
+        };
+        Ok(Some(IoAttribute { binding, interpolation: self.interpolation, position }))
+    }
+}
+
+#[derive(Debug)]
+struct Parser {
+    cursor: Cursor,
+    aliases: HashMap<String, TypeExpr>,
+}
+
+fn error<T>(position: Position, message: impl Into<String>) -> Parsed<T> {
+    Err(Diagnostic::new(position, message))
+}
+
+fn scalar(name: &str, position: Position) -> Parsed<Option<Scalar>> {
+    match name {
+        "bool" => Ok(Some(Scalar::Bool)),
+        "i32" => Ok(Some(Scalar::Int)),
+        "u32" => Ok(Some(Scalar::Uint)),
+        "f32" => Ok(Some(Scalar::Float)),
+        "f16" => error(position, "`f16` is not supported"),
+        _ => Ok(None),
+    }
+}
+
+// Önceden tanımlı kısaltmalar: vec4f, vec2i, mat4x4f vb.
+fn predeclared(name: &str, position: Position) -> Parsed<Option<Type>> {
+    let bytes = name.as_bytes();
+    let suffix = |c: u8| match c {
+        b'f' => Ok(Some(Scalar::Float)),
+        b'i' => Ok(Some(Scalar::Int)),
+        b'u' => Ok(Some(Scalar::Uint)),
+        b'h' => error(position, format!("`{}` uses `f16`, which is not supported", name)),
+        _ => Ok(None),
+    };
+    match bytes {
+        [b'v', b'e', b'c', n @ b'2'..=b'4', c] => {
+            Ok(suffix(*c)?.map(|scalar| Type::Vector(scalar, u32::from(n - b'0'))))
+        }
+        [b'm', b'a', b't', c @ b'2'..=b'4', b'x', r @ b'2'..=b'4', s] => match suffix(*s)? {
+            Some(Scalar::Float) => Ok(Some(Type::Matrix { columns: u32::from(c - b'0'), rows: u32::from(r - b'0') })),
+            _ => Ok(None),
+        },
+        _ => Ok(None),
+    }
+}
+
+// Şablon parametresi alan tip adları; ifadelerde ardından gelen `<` karşılaştırma değil şablon listesidir.
+fn templated(name: &str) -> bool {
+    let matrix = matches!(name.as_bytes(), [b'm', b'a', b't', b'2'..=b'4', b'x', b'2'..=b'4']);
+    matrix
+        || matches!(name, "vec2" | "vec3" | "vec4" | "array" | "bitcast" | "ptr" | "atomic")
+        || name.starts_with("texture_")
+}
+
+impl Parser {
+    fn position(&self) -> Position {
+        self.cursor.position()
+    }
+
+    fn attributes(&mut self) -> Parsed<Attributes> {
+        let mut attributes = Attributes::default();
+        while self.cursor.is("@") {
+            let position = self.position();
+            self.cursor.advance();
+            let (name, _) = self.cursor.expect_identifier("an attribute name")?;
+            match name.as_str() {
+                "location" => attributes.location = Some(self.integer_argument()?),
+                "blend_src" => attributes.blend_src = Some(self.integer_argument()?),
+                "group" => attributes.group = Some(self.integer_argument()?),
+                "binding" => attributes.binding = Some(self.integer_argument()?),
+                "builtin" => {
+                    self.cursor.expect("(")?;
+                    attributes.builtin = Some(self.cursor.expect_identifier("a built-in value name")?.0);
+                    self.cursor.eat(",");
+                    self.cursor.expect(")")?;
+                }
+                "interpolate" => {
+                    self.cursor.expect("(")?;
+                    let (kind, kind_position) = self.cursor.expect_identifier("an interpolation type")?;
+                    attributes.interpolation = Some(match kind.as_str() {
+                        "perspective" => Interpolation::Perspective,
+                        "linear" => Interpolation::Linear,
+                        "flat" => Interpolation::Flat,
+                        _ => return error(kind_position, format!("unknown interpolation type `{}`", kind)),
+                    });
+                    // Örnekleme konumu (center, centroid, sample) yazılım arka ucunda etkisizdir.
+                    if self.cursor.eat(",") && !self.cursor.is(")") {
+                        self.cursor.expect_identifier("an interpolation sampling")?;
+                        self.cursor.eat(",");
+                    }
+                    self.cursor.expect(")")?;
+                }
+                "vertex" => attributes.stage = Some(ShaderStage::Vertex),
+                "fragment" => attributes.stage = Some(ShaderStage::Fragment),
+                "invariant" | "must_use" => {}
+                "compute" | "workgroup_size" => return error(position, "compute shaders are not supported"),
+                "id" => return error(position, "pipeline-overridable constants are not supported"),
+                _ => return error(position, format!("unknown attribute `@{}`", name)),
+            }
+            attributes.names.push((name, position));
+        }
+        Ok(attributes)
+    }
+
+    fn integer_argument(&mut self) -> Parsed<u32> {
+        self.cursor.expect("(")?;
+        let position = self.position();
+        let value = match self.cursor.advance().token {
+            Token::Int(value, _) => u32::try_from(value).ok(),
+            _ => None,
+        };
+        let Some(value) = value else { return error(position, "expected a non-negative integer literal") };
+        self.cursor.eat(",");
+        self.cursor.expect(")")?;
+        Ok(value)
+    }
+
+    fn global(&mut self, module: &mut Module) -> Parsed<()> {
+        if self.cursor.eat(";") {
+            return Ok(());
+        }
+        let attributes = self.attributes()?;
+        let position = self.position();
+        let (keyword, _) = self.cursor.expect_identifier("a declaration")?;
+        match keyword.as_str() {
+            "struct" => {
+                attributes.only(&[])?;
+                let declaration = self.structure(position)?;
+                module.structs.push(declaration);
+            }
+            "fn" => {
+                attributes.only(&["vertex", "fragment"])?;
+                let function = self.function(attributes.stage, position)?;
+                module.functions.push(function);
+            }
+            "var" => {
+                let global = self.global_variable(&attributes, position)?;
+                module.globals.push(global);
+            }
+            "const" => {
+                attributes.only(&[])?;
+                let (name, _) = self.cursor.expect_identifier("a constant name")?;
+                let ty = if self.cursor.eat(":") { Some(self.ty()?) } else { None };
+                self.cursor.expect("=")?;
+                let value = self.expr()?;
+                self.cursor.expect(";")?;
+                let kind = GlobalKind::Constant(value);
+                module.globals.push(Global { name, ty, kind, position, anonymous: false });
+            }
+            "alias" => {
+                attributes.only(&[])?;
+                let (name, _) = self.cursor.expect_identifier("an alias name")?;
+                self.cursor.expect("=")?;
+                let ty = self.ty()?;
+                self.cursor.expect(";")?;
+                self.aliases.insert(name, ty);
+            }
+            "override" => return error(position, "pipeline-overridable constants are not supported"),
+            "let" => return error(position, "`let` is not allowed at module scope; use `const`"),
+            "enable" | "requires" | "diagnostic" => {
+                return error(position, format!("`{}` directives are not supported", keyword));
+            }
+            "const_assert" => return error(position, "`const_assert` is not supported"),
+            _ => return error(position, format!("expected a declaration, found `{}`", keyword)),
+        }
+        Ok(())
+    }
+
+    fn structure(&mut self, position: Position) -> Parsed<StructDecl> {
+        let (name, _) = self.cursor.expect_identifier("a struct name")?;
+        self.cursor.expect("{")?;
+        let mut members = Vec::new();
+        while !self.cursor.eat("}") {
+            let attributes = self.attributes()?;
+            let (member, member_position) = self.cursor.expect_identifier("a member name")?;
+            self.cursor.expect(":")?;
+            let ty = self.ty()?;
+            members.push(Member { name: member, ty, io: attributes.io()?, position: member_position });
+            if !self.cursor.eat(",") {
+                self.cursor.expect("}")?;
+                break;
+            }
+        }
+        self.cursor.eat(";");
+        Ok(StructDecl { name, members, position })
+    }
+
+    fn global_variable(&mut self, attributes: &Attributes, position: Position) -> Parsed<Global> {
+        let mut space = None;
+        if self.cursor.eat("<") {
+            let (name, space_position) = self.cursor.expect_identifier("an address space")?;
+            if self.cursor.eat(",") {
+                self.cursor.expect_identifier("an access mode")?;
+            }
+            self.cursor.expect_closing_angle()?;
+            space = Some((name, space_position));
+        }
+        let (name, _) = self.cursor.expect_identifier("a variable name")?;
+        let ty = if self.cursor.eat(":") { Some(self.ty()?) } else { None };
+        let value = if self.cursor.eat("=") { Some(self.expr()?) } else { None };
+        self.cursor.expect(";")?;
+        let resource = |attributes: &Attributes| -> Parsed<(u32, Option<u32>)> {
+            attributes.only(&["group", "binding"])?;
+            match (attributes.group, attributes.binding) {
+                (Some(group), Some(binding)) => Ok((group, Some(binding))),
+                _ => error(position, format!("`{}` needs @group and @binding attributes", name)),
+            }
+        };
+        let kind = match space.as_ref().map(|(space, position)| (space.as_str(), *position)) {
+            Some(("private", _)) => {
+                attributes.only(&[])?;
+                GlobalKind::Private(value)
+            }
+            Some(("uniform", _)) => {
+                let (group, binding) = resource(attributes)?;
+                GlobalKind::UniformBlock { group, binding }
+            }
+            Some((space @ ("storage" | "workgroup" | "push_constant"), space_position)) => {
+                return error(space_position, format!("`{}` variables are not supported", space));
+            }
+            Some((space, space_position)) => {
+                return error(space_position, format!("`{}` is not a module-scope address space", space));
+            }
+            None => {
+                let (group, binding) = resource(attributes)?;
+                GlobalKind::Resource { group, binding }
+            }
+        };
+        Ok(Global { name, ty, kind, position, anonymous: false })
+    }
+
+    fn function(&mut self, stage: Option<ShaderStage>, position: Position) -> Parsed<Function> {
+        let (name, _) = self.cursor.expect_identifier("a function name")?;
+        self.cursor.expect("(")?;
+        let mut params = Vec::new();
+        while !self.cursor.eat(")") {
+            let attributes = self.attributes()?;
+            let (param, param_position) = self.cursor.expect_identifier("a parameter name")?;
+            self.cursor.expect(":")?;
+            let ty = self.ty()?;
+            params.push(Param { name: param, ty, io: attributes.io()?, position: param_position });
+            if !self.cursor.eat(",") {
+                self.cursor.expect(")")?;
+                break;
+            }
+        }
+        let (result, result_io) = match self.cursor.eat("->") {
+            true => {
+                let attributes = self.attributes()?;
+                (Some(self.ty()?), attributes.io()?)
+            }
+            false => (None, None),
+        };
+        let body = self.block()?;
+        Ok(Function { name, params, result, result_io, stage, body, position })
+    }
+
+    // Tipler
+
+    fn ty(&mut self) -> Parsed<TypeExpr> {
+        let (name, position) = self.cursor.expect_identifier("a type")?;
+        match self.type_named(&name, position)? {
+            Some(ty) => Ok(ty),
+            None => Ok(TypeExpr::Named(name, position)),
+        }
+    }
+
+    fn scalar_parameter(&mut self) -> Parsed<Scalar> {
+        self.cursor.expect("<")?;
+        let (name, position) = self.cursor.expect_identifier("a scalar type")?;
+        let Some(scalar) = scalar(&name, position)? else {
+            return error(position, format!("expected a scalar type, found `{}`", name));
+        };
+        self.cursor.expect_closing_angle()?;
+        Ok(scalar)
+    }
+
+    // Yerleşik tip veya takma ad; yapı adlarında None.
+    fn type_named(&mut self, name: &str, position: Position) -> Parsed<Option<TypeExpr>> {
+        if let Some(ty) = self.aliases.get(name) {
+            return Ok(Some(ty.clone()));
+        }
+        if let Some(scalar) = scalar(name, position)? {
+            return Ok(Some(TypeExpr::Builtin(Type::Scalar(scalar))));
+        }
+        if let Some(ty) = predeclared(name, position)? {
+            return Ok(Some(TypeExpr::Builtin(ty)));
+        }
+        let texture = |dimension, arrayed, depth, kind| Type::Texture(Texture { dimension, arrayed, depth, kind });
+        let ty = match name.as_bytes() {
+            [b'v', b'e', b'c', n @ b'2'..=b'4'] => {
+                let count = u32::from(n - b'0');
+                Type::Vector(self.scalar_parameter()?, count)
+            }
+            [b'm', b'a', b't', c @ b'2'..=b'4', b'x', r @ b'2'..=b'4'] => {
+                let (columns, rows) = (u32::from(c - b'0'), u32::from(r - b'0'));
+                if self.scalar_parameter()? != Scalar::Float {
+                    return error(position, "matrices must have `f32` components");
+                }
+                Type::Matrix { columns, rows }
+            }
+            b"array" => {
+                self.cursor.expect("<")?;
+                let element = self.ty()?;
+                // Uzunluk ifadesi `>` karşılaştırmasını içermemelidir.
+                let length = if self.cursor.eat(",") { Some(Box::new(self.expr_at(9)?)) } else { None };
+                self.cursor.eat(",");
+                self.cursor.expect_closing_angle()?;
+                return Ok(Some(TypeExpr::Array(Box::new(element), length, position)));
+            }
+            b"texture_2d" => texture(TextureDimension::D2, false, false, self.scalar_parameter()?),
+            b"texture_2d_array" => texture(TextureDimension::D2, true, false, self.scalar_parameter()?),
+            b"texture_3d" => texture(TextureDimension::D3, false, false, self.scalar_parameter()?),
+            b"texture_cube" => texture(TextureDimension::Cube, false, false, self.scalar_parameter()?),
+            b"texture_cube_array" => texture(TextureDimension::Cube, true, false, self.scalar_parameter()?),
+            b"texture_depth_2d" => texture(TextureDimension::D2, false, true, Scalar::Float),
+            b"texture_depth_2d_array" => texture(TextureDimension::D2, true, true, Scalar::Float),
+            b"texture_depth_cube" => texture(TextureDimension::Cube, false, true, Scalar::Float),
+            b"texture_depth_cube_array" => texture(TextureDimension::Cube, true, true, Scalar::Float),
+            b"sampler" => Type::Sampler { comparison: false },
+            b"sampler_comparison" => Type::Sampler { comparison: true },
+            b"ptr" => return error(position, "pointers are not supported"),
+            b"atomic" => return error(position, "atomic types are not supported"),
+            _ if name.starts_with("texture_") => return error(position, format!("`{}` is not supported", name)),
+            _ => return Ok(None),
+        };
+        if let Type::Texture(Texture { kind: Scalar::Bool, .. }) = ty {
+            return error(position, "texture sample types must be `f32`, `i32` or `u32`");
+        }
+        Ok(Some(TypeExpr::Builtin(ty)))
+    }
+
+    // Deyimler
+
+    fn block(&mut self) -> Parsed<Vec<Stmt>> {
+        self.cursor.expect("{")?;
+        let mut statements = Vec::new();
+        while !self.cursor.eat("}") {
+            if let Some(statement) = self.statement()? {
+                statements.push(statement);
+            }
+        }
+        Ok(statements)
+    }
+
+    fn statement(&mut self) -> Parsed<Option<Stmt>> {
+        let position = self.position();
+        let statement = |kind| Ok(Some(Stmt { kind, position }));
+        if self.cursor.eat(";") {
+            return Ok(None);
+        }
+        if self.cursor.is("{") {
+            return statement(StmtKind::Block(self.block()?));
+        }
+        if self.cursor.is("@") {
+            // Deyim öznitelikleri (ör. @diagnostic) desteklenmez.
+            return error(position, "statement attributes are not supported");
+        }
+        let keyword = match self.cursor.peek() {
+            Token::Identifier(name) => name.clone(),
+            _ => String::new(),
+        };
+        let kind = match keyword.as_str() {
+            "var" | "let" | "const" => {
+                let kind = self.declaration()?;
+                self.cursor.expect(";")?;
+                kind
+            }
+            "return" => {
+                self.cursor.advance();
+                let value = if self.cursor.is(";") { None } else { Some(self.expr()?) };
+                self.cursor.expect(";")?;
+                StmtKind::Return(value)
+            }
+            "if" => self.if_statement()?,
+            "switch" => self.switch_statement()?,
+            "loop" => {
+                self.cursor.advance();
+                self.loop_statement()?
+            }
+            "for" => self.for_statement()?,
+            "while" => {
+                self.cursor.advance();
+                let condition = self.expr()?;
+                let body = self.block()?;
+                StmtKind::Loop { condition: Some(condition), body, continuing: Vec::new() }
+            }
+            "break" => {
+                self.cursor.advance();
+                let kind = if self.cursor.eat_word("if") { StmtKind::BreakIf(self.expr()?) } else { StmtKind::Break };
+                self.cursor.expect(";")?;
+                kind
+            }
+            "continue" | "discard" => {
+                self.cursor.advance();
+                self.cursor.expect(";")?;
+                if keyword == "continue" {
+                    StmtKind::Continue
+                } else {
+                    StmtKind::Discard
+                }
+            }
+            "continuing" => return error(position, "`continuing` must be the last statement of a `loop`"),
+            "const_assert" => return error(position, "`const_assert` is not supported"),
+            _ => {
+                let kind = self.simple_statement()?;
+                self.cursor.expect(";")?;
+                kind
+            }
+        };
+        statement(kind)
+    }
+
+    fn declaration(&mut self) -> Parsed<StmtKind> {
+        let (keyword, _) = self.cursor.expect_identifier("a declaration")?;
+        let mutable = keyword == "var";
+        if mutable && self.cursor.eat("<") {
+            let (space, position) = self.cursor.expect_identifier("an address space")?;
+            if space != "function" {
+                return error(position, format!("`{}` variables cannot be declared in a function", space));
+            }
+            self.cursor.expect_closing_angle()?;
+        }
+        let (name, _) = self.cursor.expect_identifier("a variable name")?;
+        let ty = if self.cursor.eat(":") { Some(self.ty()?) } else { None };
+        let value = if self.cursor.eat("=") { Some(self.expr()?) } else { None };
+        Ok(StmtKind::Declare { name, ty, value, mutable })
+    }
+
+    // Atama, artırma/azaltma veya fonksiyon çağrısı; `for` başlığında da kullanılır.
+    fn simple_statement(&mut self) -> Parsed<StmtKind> {
+        if matches!(self.cursor.peek(), Token::Identifier(name) if name == "var" || name == "let" || name == "const") {
+            return self.declaration();
+        }
+        let target = self.expr()?;
+        let position = self.position();
+        if self.cursor.eat("=") {
+            return Ok(StmtKind::Assign { target, op: None, value: self.expr()? });
+        }
+        if let Token::Punct(punct) = *self.cursor.peek() {
+            if let Some(op) = BinaryOp::from_assignment(punct) {
+                self.cursor.advance();
+                return Ok(StmtKind::Assign { target, op: Some(op), value: self.expr()? });
+            }
+            if punct == "++" || punct == "--" {
+                self.cursor.advance();
+                let op = if punct == "++" { BinaryOp::Add } else { BinaryOp::Sub };
+                let value = Expr::new(ExprKind::Int(1, None), position);
+                return Ok(StmtKind::Assign { target, op: Some(op), value });
+            }
+        }
+        match target.kind {
+            ExprKind::Call(..) | ExprKind::Construct(..) | ExprKind::Bitcast(..) => Ok(StmtKind::Expr(target)),
+            _ => error(target.position, "expected an assignment or a function call"),
+        }
+    }
+
+    fn if_statement(&mut self) -> Parsed<StmtKind> {
+        self.cursor.advance();
+        let condition = self.expr()?;
+        let accept = self.block()?;
+        let mut reject = Vec::new();
+        if self.cursor.eat_word("else") {
+            let position = self.position();
+            match self.cursor.is_word("if") {
+                true => reject.push(Stmt { kind: self.if_statement()?, position }),
+                false => reject = self.block()?,
+            }
+        }
+        Ok(StmtKind::If { condition, accept, reject })
+    }
+
+    fn switch_statement(&mut self) -> Parsed<StmtKind> {
+        self.cursor.advance();
+        let selector = self.expr()?;
+        self.cursor.expect("{")?;
+        let mut cases = Vec::new();
+        while !self.cursor.eat("}") {
+            let position = self.position();
+            let mut selectors = Vec::new();
+            if self.cursor.eat_word("default") {
+                selectors.push(None);
+            } else if self.cursor.eat_word("case") {
+                loop {
+                    match self.cursor.eat_word("default") {
+                        true => selectors.push(None),
+                        false => selectors.push(Some(self.expr()?)),
+                    }
+                    if !self.cursor.eat(",") || self.cursor.is(":") || self.cursor.is("{") {
+                        break;
+                    }
+                }
+            } else {
+                return Err(self.cursor.unexpected("`case` or `default`"));
+            }
+            self.cursor.eat(":");
+            let body = self.block()?;
+            cases.push(Case { selectors, body, fallthrough: false, position });
+        }
+        Ok(StmtKind::Switch { selector, cases })
+    }
+
+    fn loop_statement(&mut self) -> Parsed<StmtKind> {
+        self.cursor.expect("{")?;
+        let mut body = Vec::new();
+        let mut continuing = Vec::new();
+        while !self.cursor.eat("}") {
+            if self.cursor.eat_word("continuing") {
+                continuing = self.block()?;
+                self.cursor.expect("}")?;
+                break;
+            }
+            if let Some(statement) = self.statement()? {
+                body.push(statement);
+            }
+        }
+        Ok(StmtKind::Loop { condition: None, body, continuing })
+    }
+
+    // `for (init; condition; update) { body }`, başlangıç deyimini içeren bir bloğa ve döngüye dönüşür.
+    fn for_statement(&mut self) -> Parsed<StmtKind> {
+        let position = self.position();
+        self.cursor.advance();
+        self.cursor.expect("(")?;
+        let init = match self.cursor.is(";") {
+            true => None,
+            false => Some(Stmt { position: self.position(), kind: self.simple_statement()? }),
+        };
+        self.cursor.expect(";")?;
+        let condition = if self.cursor.is(";") { None } else { Some(self.expr()?) };
+        self.cursor.expect(";")?;
+        let update = match self.cursor.is(")") {
+            true => None,
+            false => Some(Stmt { position: self.position(), kind: self.simple_statement()? }),
+        };
+        self.cursor.expect(")")?;
+        let body = self.block()?;
+        let continuing = update.into_iter().collect();
+        let looped = Stmt { kind: StmtKind::Loop { condition, body, continuing }, position };
+        Ok(StmtKind::Block(init.into_iter().chain([looped]).collect()))
+    }
+
+    // İfadeler
+
+    fn expr(&mut self) -> Parsed<Expr> {
+        self.expr_at(1)
+    }
+
+    // Önceliği en az `min` olan işleçlerden oluşan ifade.
+    fn expr_at(&mut self, min: u32) -> Parsed<Expr> {
+        let mut left = self.unary()?;
+        while let Token::Punct(punct) = *self.cursor.peek() {
+            let Some((op, precedence)) = BinaryOp::from_punct(punct) else { break };
+            if precedence < min {
+                break;
+            }
+            let position = self.position();
+            self.cursor.advance();
+            let right = self.expr_at(precedence + 1)?;
+            left = Expr::new(ExprKind::Binary(op, Box::new(left), Box::new(right)), position);
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Parsed<Expr> {
+        let position = self.position();
+        let op = match self.cursor.peek() {
+            Token::Punct("-") => UnaryOp::Negate,
+            Token::Punct("!") => UnaryOp::Not,
+            Token::Punct("~") => UnaryOp::BitNot,
+            Token::Punct("&" | "*") => return error(position, "pointers are not supported"),
+            _ => return self.postfix(),
+        };
+        self.cursor.advance();
+        let operand = self.unary()?;
+        Ok(Expr::new(ExprKind::Unary(op, Box::new(operand)), position))
+    }
+
+    fn postfix(&mut self) -> Parsed<Expr> {
+        let mut expr = self.primary()?;
+        loop {
+            let position = self.position();
+            if self.cursor.eat(".") {
+                let (member, _) = self.cursor.expect_identifier("a member name")?;
+                expr = Expr::new(ExprKind::Member(Box::new(expr), member), position);
+            } else if self.cursor.eat("[") {
+                let index = self.expr()?;
+                self.cursor.expect("]")?;
+                expr = Expr::new(ExprKind::Index(Box::new(expr), Box::new(index)), position);
+            } else {
+                return Ok(expr);
+            }
+        }
+    }
+
+    fn arguments(&mut self) -> Parsed<Vec<Expr>> {
+        self.cursor.expect("(")?;
+        let mut arguments = Vec::new();
+        while !self.cursor.eat(")") {
+            arguments.push(self.expr()?);
+            if !self.cursor.eat(",") {
+                self.cursor.expect(")")?;
+                break;
+            }
+        }
+        Ok(arguments)
+    }
+
+    fn primary(&mut self) -> Parsed<Expr> {
+        let position = self.position();
+        if !matches!(self.cursor.peek(), Token::Int(..) | Token::Float(_) | Token::Identifier(_) | Token::Punct("(")) {
+            return Err(self.cursor.unexpected("an expression"));
+        }
+        let lexeme = self.cursor.advance();
+        let kind = match lexeme.token {
+            Token::Int(value, suffix) => ExprKind::Int(value, suffix),
+            Token::Float(value) => ExprKind::Float(value),
+            Token::Punct("(") => {
+                let expr = self.expr()?;
+                self.cursor.expect(")")?;
+                return Ok(expr);
+            }
+            Token::Identifier(name) => match name.as_str() {
+                "true" => ExprKind::Bool(true),
+                "false" => ExprKind::Bool(false),
+                "bitcast" => {
+                    self.cursor.expect("<")?;
+                    let ty = self.ty()?;
+                    self.cursor.expect_closing_angle()?;
+                    let mut arguments = self.arguments()?;
+                    if arguments.len() != 1 {
+                        return error(position, "`bitcast` expects 1 argument");
+                    }
+                    ExprKind::Bitcast(ty, Box::new(arguments.remove(0)))
+                }
+                // Bileşen tipi verilmemiş kurucular çağrı olarak kalır.
+                _ if templated(&name) && !self.cursor.is("<") && self.cursor.is("(") => {
+                    ExprKind::Call(name, self.arguments()?)
+                }
+                _ if templated(&name) && self.cursor.is("<") => {
+                    let Some(ty) = self.type_named(&name, position)? else {
+                        return error(position, format!("unknown type `{}`", name));
+                    };
+                    ExprKind::Construct(ty, self.arguments()?)
+                }
+                _ if self.cursor.is("(") => match self.type_named(&name, position)? {
+                    Some(ty) => ExprKind::Construct(ty, self.arguments()?),
+                    None => ExprKind::Call(name, self.arguments()?),
+                },
+                _ => ExprKind::Name(name),
+            },
+            _ => unreachable!(),
+        };
+        Ok(Expr::new(kind, position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gpu::spirv::{BindingKind, SpirvModule, SpirvType};
+
+    const SOURCE: &str = r#"
+struct VertexOutput {
+    @builtin(position) position: vec4f,
+    @location(0) uv: vec2f,
+    @location(1) @interpolate(flat) layer: u32,
+}
+
+@group(0) @binding(0) var image: texture_2d<f32>;
+@group(0) @binding(1) var linear: sampler;
+
+@vertex
+fn vs_main(@location(0) position: vec2f, @location(1) layer: u32) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4f(position, 0.0, 1.0);
+    out.uv = position * 0.5 + 0.5;
+    out.layer = layer;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4f {
+    return textureSample(image, linear, in.uv) * f32(in.layer);
+}
+"#;
+
+    fn diagnostics(source: &str) -> Vec<(u32, u32, String)> {
+        match compile(source) {
+            Err(GraphisError::CompilationFailed(diagnostics)) => diagnostics
+                .into_iter()
+                .map(|diagnostic| (diagnostic.line, diagnostic.column, diagnostic.message))
+                .collect(),
+            other => panic!("expected a compilation failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn compiled_module_parses_and_reflects() {
+        let module = SpirvModule::from_words(&compile(SOURCE).unwrap()).unwrap();
+        let float = || Box::new(SpirvType::Float { width: 32 });
+        let uint = SpirvType::Int { width: 32, signed: false };
+
+        let vertex = module.entry_point("vs_main", ShaderStage::Vertex).unwrap();
+        let inputs: Vec<_> = vertex.inputs.iter().map(|input| (input.location, input.ty.clone())).collect();
+        assert_eq!(inputs, vec![(0, SpirvType::Vector { component: float(), count: 2 }), (1, uint.clone())]);
+        let outputs: Vec<_> = vertex.outputs.iter().map(|output| (output.location, output.interpolation)).collect();
+        assert_eq!(outputs, vec![(0, Interpolation::Perspective), (1, Interpolation::Flat)]);
+
+        let fragment = module.entry_point("fs_main", ShaderStage::Fragment).unwrap();
+        let inputs: Vec<_> = fragment.inputs.iter().map(|input| (input.location, input.interpolation)).collect();
+        assert_eq!(inputs, vec![(0, Interpolation::Perspective), (1, Interpolation::Flat)]);
+        assert_eq!(fragment.outputs.len(), 1);
+        assert_eq!(fragment.outputs[0].ty, SpirvType::Vector { component: float(), count: 4 });
+
+        let bindings: Vec<_> =
+            module.bindings().iter().map(|binding| (binding.set, binding.binding, binding.kind)).collect();
+        assert_eq!(bindings, vec![(0, 0, BindingKind::SampledTexture), (0, 1, BindingKind::Sampler)]);
+    }
+
+    #[test]
+    fn syntax_errors_point_at_the_token() {
+        let source = "@fragment\nfn f() -> @location(0) vec4f {\n    return vec4f(1.0) +;\n}\n";
+        assert_eq!(diagnostics(source), vec![(3, 24, "expected an expression, found `;`".to_string())]);
+        let (line, column, _) = diagnostics("fn f( {}").remove(0);
+        assert_eq!((line, column), (1, 7));
+    }
+
+    #[test]
+    fn type_errors_are_reported_together() {
+        let source = "@fragment\nfn f() -> @location(0) vec4f {\n    let x: f32 = true;\n    return vec4f(y);\n}\n";
+        assert_eq!(
+            diagnostics(source),
+            vec![(3, 18, "expected `f32`, found `bool`".to_string()), (4, 18, "unknown identifier `y`".to_string())]
+        );
+    }
+}
\ No newline at end of file
@@ -0,0 +1,93 @@
+use crate::core::math::{Mat4, Quaternion, Vec2, Vec3};
+use crate::gpu::error::GraphisError;
+
+// CPU tarafından erişilebilen (host-visible) bellek bloğu.
+// Tipli görünümler (&[f32], &[Vec3] vb.) için 8 byte hizalı olarak u64 kelimeleri üzerinde tutulur.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostMemory {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl HostMemory {
+    // Sıfırlarla doldurulmuş `len` byte'lık bellek ayırır.
+    pub fn new(len: usize) -> Result<Self, GraphisError> {
+        let mut words = Vec::new();
+        words
+            .try_reserve_exact(len.div_ceil(8))
+            .map_err(|_| GraphisError::OutOfMemory { requested: len })?;
+        words.resize(len.div_ceil(8), 0);
+        Ok(HostMemory { words, len })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        // Güvenli: u64 dizisi, u8 olarak okunabilir ve len <= words.len() * 8.
+        unsafe { std::slice::from_raw_parts(self.words.as_ptr() as *const u8, self.len) }
+    }
+
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.words.as_mut_ptr() as *mut u8, self.len) }
+    }
+
+    // `offset` byte'ından başlayan `count` adet T değerini yeniden yorumlayarak döndürür.
+    pub fn view<T: Pod>(&self, offset: usize, count: usize) -> Result<&[T], GraphisError> {
+        let byte_len = typed_range::<T>(self.len, offset, count)?;
+        let bytes = &self.as_bytes()[offset..offset + byte_len];
+        // Güvenli: aralık ve hizalama typed_range içinde doğrulandı, T: Pod.
+        Ok(unsafe { std::slice::from_raw_parts(bytes.as_ptr() as *const T, count) })
+    }
+
+    pub fn view_mut<T: Pod>(&mut self, offset: usize, count: usize) -> Result<&mut [T], GraphisError> {
+        let byte_len = typed_range::<T>(self.len, offset, count)?;
+        let bytes = &mut self.as_bytes_mut()[offset..offset + byte_len];
+        Ok(unsafe { std::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, count) })
+    }
+}
+
+fn typed_range<T: Pod>(len: usize, offset: usize, count: usize) -> Result<usize, GraphisError> {
+    let byte_len = std::mem::size_of::<T>()
+        .checked_mul(count)
+        .ok_or(GraphisError::OutOfBounds { offset, len: usize::MAX, size: len })?;
+    GraphisError::check_range(offset, byte_len, len)?;
+    // Bellek başlangıcı 8 byte hizalı olduğundan ofsetin hizalı olması yeterlidir.
+    if !offset.is_multiple_of(std::mem::align_of::<T>()) {
+        return Err(GraphisError::ValidationFailed(format!(
+            "offset {} is not aligned to {} byte(s)",
+            offset,
+            std::mem::align_of::<T>()
+        )));
+    }
+    Ok(byte_len)
+}
+
+/// Byte dizisi olarak güvenle yeniden yorumlanabilen tipler ("plain old data").
+///
+/// # Safety
+///
+/// Uygulayan tip dolgu (padding) byte'ı içermemeli, her bit deseni geçerli olmalı
+/// ve hizalaması 8 byte'ı aşmamalıdır.
+pub unsafe trait Pod: Copy + 'static {}
+
+unsafe impl Pod for u8 {}
+unsafe impl Pod for i8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for i16 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for i32 {}
+unsafe impl Pod for u64 {}
+unsafe impl Pod for i64 {}
+unsafe impl Pod for f32 {}
+unsafe impl Pod for f64 {}
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+unsafe impl Pod for Vec2 {}
+unsafe impl Pod for Vec3 {}
+unsafe impl Pod for Mat4 {}
+unsafe impl Pod for Quaternion {}
\ No newline at end of file
@@ -1,9 +1,18 @@
-pub mod commands;
-pub mod buffer;
-pub mod device;
-pub mod queue;
-pub mod texture;
-pub mod shader;
-pub mod render_pass;
-pub mod framebuffer;
-pub mod command_buffer; 
\ No newline at end of file
+pub mod commands;
+pub mod buffer;
+pub mod device;
+pub mod queue;
+pub mod texture;
+pub mod sampler;
+pub mod shader;
+pub mod spirv;
+pub mod frontend;
+pub mod render_pass;
+pub mod framebuffer;
+pub mod command_buffer; 
+pub mod software;
+pub mod backend;
+pub mod null;
+pub mod memory;
+pub mod resource;
+pub mod error;
\ No newline at end of file
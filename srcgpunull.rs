@@ -0,0 +1,172 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::gpu::backend::{Backend, BackendKind};
+use crate::gpu::buffer::{BufferAccess, BufferUsage};
+use crate::gpu::command_buffer::CommandBuffer;
+use crate::gpu::commands::RenderCommand;
+use crate::gpu::error::GraphisError;
+use crate::gpu::framebuffer::FramebufferDescriptor;
+use crate::gpu::memory::HostMemory;
+use crate::gpu::render_pass::RenderPassDescriptor;
+use crate::gpu::resource::ResourceRegistry;
+use crate::gpu::sampler::SamplerDescriptor;
+use crate::gpu::texture::{TextureDataLayout, TextureDescriptor, TextureRegion};
+use crate::rendering::pipeline::TextureFormat;
+
+// Hiçbir şey çalıştırmayan arka uç. Gönderilen komutları yalnızca kaydeder;
+// komut üretimini test etmek veya GPU'suz ortamlarda kütüphaneyi bağlamak için kullanılır.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullBackend;
+
+#[derive(Debug)]
+pub struct NullQueue {
+    submitted: RefCell<Vec<Vec<RenderCommand>>>,
+}
+
+impl NullQueue {
+    // Her gönderilen komut arabelleği için kaydedilen komutların bir kopyası.
+    pub fn submitted(&self) -> Vec<Vec<RenderCommand>> {
+        self.submitted.borrow().clone()
+    }
+}
+
+impl Backend for NullBackend {
+    type Device = ();
+    type Queue = NullQueue;
+    type Buffer = Rc<RefCell<HostMemory>>; // Geri okuma çalışsın diye tamponlar yine de bellek tutar
+    type Texture = TextureFormat; // Geri okunan satırların boyutu için yalnızca format tutulur
+    type Sampler = ();
+    type RenderPass = ();
+    type Framebuffer = ();
+
+    fn kind() -> BackendKind {
+        BackendKind::Null
+    }
+
+    fn create_device() -> Result<(), GraphisError> {
+        Ok(())
+    }
+
+    fn device_name(_device: &()) -> String {
+        "Null Device".to_string()
+    }
+
+    fn create_queue(_device: &()) -> Result<NullQueue, GraphisError> {
+        Ok(NullQueue { submitted: RefCell::new(Vec::new()) })
+    }
+
+    fn create_buffer(
+        _device: &(),
+        size: usize,
+        _usage: BufferUsage,
+        _access: BufferAccess,
+    ) -> Result<Rc<RefCell<HostMemory>>, GraphisError> {
+        Ok(Rc::new(RefCell::new(HostMemory::new(size)?)))
+    }
+
+    fn destroy_buffer(_device: &(), _buffer: Rc<RefCell<HostMemory>>) {}
+
+    fn buffer_memory(buffer: &Rc<RefCell<HostMemory>>) -> &RefCell<HostMemory> {
+        buffer
+    }
+
+    fn create_texture(_device: &(), descriptor: &TextureDescriptor) -> Result<TextureFormat, GraphisError> {
+        Ok(descriptor.format)
+    }
+
+    fn destroy_texture(_device: &(), _texture: TextureFormat) {}
+
+    // Doku içeriği tutulmaz: yazılanlar atılır, geri okunan satırlar sıfırdır.
+    fn write_texture(
+        _texture: &TextureFormat,
+        _region: &TextureRegion,
+        _data: &[u8],
+        _layout: &TextureDataLayout,
+    ) -> Result<(), GraphisError> {
+        Ok(())
+    }
+
+    fn read_texture(
+        format: &TextureFormat,
+        region: &TextureRegion,
+        data: &mut [u8],
+        layout: &TextureDataLayout,
+    ) -> Result<(), GraphisError> {
+        // Yalnızca texel byte'ları sıfırlanır; satırlar arasındaki dolgu olduğu gibi kalır.
+        let row_size = region.extent.width as usize * format.block_size();
+        for z in 0..region.extent.depth_or_array_layers {
+            for y in 0..region.extent.height {
+                let offset = layout.row_offset(y, z);
+                data[offset..offset + row_size].fill(0);
+            }
+        }
+        Ok(())
+    }
+
+    fn create_sampler(_device: &(), _descriptor: &SamplerDescriptor) -> Result<(), GraphisError> {
+        Ok(())
+    }
+
+    fn destroy_sampler(_device: &(), _sampler: ()) {}
+
+    fn create_render_pass(_descriptor: &RenderPassDescriptor) -> Result<(), GraphisError> {
+        Ok(())
+    }
+
+    fn create_framebuffer(_descriptor: &FramebufferDescriptor<Self>) -> Result<(), GraphisError> {
+        Ok(())
+    }
+
+    fn submit(
+        queue: &NullQueue,
+        resources: &ResourceRegistry<Self>,
+        command_buffers: &[CommandBuffer],
+    ) -> Result<(), GraphisError> {
+        let mut submitted = queue.submitted.borrow_mut();
+        for command_buffer in command_buffers {
+            if command_buffer.is_recording() {
+                return Err(GraphisError::InvalidState(
+                    "cannot submit a command buffer that is still recording".to_string(),
+                ));
+            }
+            // Komutlar çalıştırılmasa da geçersiz tanımlayıcılar diğer arka uçlarla aynı şekilde reddedilir.
+            for command in command_buffer.get_commands() {
+                resources.validate_command(command)?;
+            }
+            submitted.push(command_buffer.get_commands().to_vec());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gpu::device::Device;
+    use crate::gpu::texture::{Extent3d, Origin3d, TextureDimension, TextureUsage};
+
+    #[test]
+    fn read_texture_zeroes_texels_and_keeps_row_padding() {
+        let device = Device::<NullBackend>::with_backend().unwrap();
+        let texture = device
+            .create_texture(&TextureDescriptor {
+                format: TextureFormat::Rgba8Unorm,
+                dimension: TextureDimension::D2,
+                width: 2,
+                height: 2,
+                depth_or_array_layers: 1,
+                mip_level_count: 1,
+                sample_count: 1,
+                usage: TextureUsage::Sampled,
+            })
+            .unwrap();
+        let extent = Extent3d { width: 2, height: 2, depth_or_array_layers: 1 };
+        let layout = TextureDataLayout { offset: 0, bytes_per_row: 12, rows_per_image: 2 };
+        let mut data = [0xAA; 20];
+        texture.read_texture(0, Origin3d::default(), extent, &mut data, layout).unwrap();
+        assert_eq!(data[..8], [0; 8]);
+        assert_eq!(data[8..12], [0xAA; 4]);
+        assert_eq!(data[12..], [0; 8]);
+    }
+}
\ No newline at end of file
@@ -1,22 +1,36 @@
-use crate::gpu::command_buffer::CommandBuffer; // Assuming we will create this file
-
-#[derive(Debug)]
-pub struct Queue {
-    // Platform-specific queue handle (e.g., VkQueue for Vulkan)
-    // This would likely be a void pointer or an opaque type
-    // In a real implementation, this would hold the native GPU queue object.
-    pub(crate) native_queue: (), // Placeholder for now
-}
-
-impl Queue {
-    pub fn submit(&self, command_buffers: &[CommandBuffer]) -> Result<(), &'static str> {
-        // Platform-specific command buffer submission logic here.
-        // This would involve taking the list of command buffers and submitting them
-        // to the GPU queue for execution.
-
-        println!("Submitting {} command buffer(s) to the queue (platform-specific implementation needed).", command_buffers.len());
-        Ok(())
-    }
-
-    // Potentially add methods for waiting for the queue to become idle, etc.
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::gpu::backend::{Backend, DefaultBackend};
+use crate::gpu::command_buffer::CommandBuffer; // Assuming we will create this file
+use crate::gpu::error::GraphisError;
+use crate::gpu::resource::ResourceRegistry;
+
+#[derive(Debug)]
+pub struct Queue<B: Backend = DefaultBackend> {
+    // Platform-specific queue handle (e.g., VkQueue for Vulkan)
+    // The concrete type is chosen by the backend (see gpu::backend).
+    pub(crate) native_queue: B::Queue,
+    // The registry of the device this queue was obtained from; used to resolve resource handles.
+    pub(crate) resources: Rc<RefCell<ResourceRegistry<B>>>,
+}
+
+impl<B: Backend> Queue<B> {
+    pub fn submit(&self, command_buffers: &[CommandBuffer]) -> Result<(), GraphisError> {
+        // The backend takes the list of command buffers and executes them
+        // (software backend) or forwards them to the GPU queue.
+        log::trace!(count = command_buffers.len(), backend:? = B::kind(); "Submitting command buffers");
+        let resources = self
+            .resources
+            .try_borrow()
+            .map_err(|_| GraphisError::InvalidState("the device's resources are being modified".to_string()))?;
+        B::submit(&self.native_queue, &resources, command_buffers)
+    }
+
+    // Access to the backend-specific queue object.
+    pub fn native(&self) -> &B::Queue {
+        &self.native_queue
+    }
+
+    // Potentially add methods for waiting for the queue to become idle, etc.
 }
\ No newline at end of file
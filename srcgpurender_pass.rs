@@ -1,59 +1,228 @@
-use crate::rendering::pipeline::TextureFormat; // Assuming TextureFormat is defined here
-
-#[derive(Debug, Clone)]
-pub struct RenderPassDescriptor<'a> {
-    pub color_attachments: &'a [RenderPassColorAttachmentDescriptor<'a>],
-    pub depth_stencil_attachment: Option<RenderPassDepthStencilAttachmentDescriptor<'a>>,
-}
-
-#[derive(Debug, Clone)]
-pub struct RenderPassColorAttachmentDescriptor<'a> {
-    pub attachment: &'a crate::gpu::texture::Texture, // Reference to the texture
-    pub load_op: LoadOp,
-    pub store_op: StoreOp,
-    // Resolve attachment for multisampling (if needed)
-    pub resolve_target: Option<&'a crate::gpu::texture::Texture>,
-    pub clear_value: Option<crate::core::color::Color>, // Assuming a Color struct exists
-}
-
-#[derive(Debug, Clone)]
-pub struct RenderPassDepthStencilAttachmentDescriptor<'a> {
-    pub attachment: &'a crate::gpu::texture::Texture, // Reference to the texture
-    pub depth_load_op: LoadOp,
-    pub depth_store_op: StoreOp,
-    pub stencil_load_op: LoadOp,
-    pub stencil_store_op: StoreOp,
-    pub clear_value: Option<DepthStencilClearValue>, // Define this struct
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum LoadOp {
-    Load,
-    Clear,
-    DontCare,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum StoreOp {
-    Store,
-    DontCare,
-}
-
-#[derive(Debug, Clone, Copy)]
-pub struct DepthStencilClearValue {
-    pub depth: f32,
-    pub stencil: u32,
-}
-
-#[derive(Debug)]
-pub struct RenderPass {
-    pub(crate) native_render_pass: (), // Placeholder for now
-}
-
-impl RenderPass {
-    pub fn new(descriptor: &RenderPassDescriptor) -> Result<Self, &'static str> {
-        println!("Oluşturulan render geçişi: {:?}", descriptor);
-        // Platforma özel render geçişi oluşturma mantığı burada yer alacak.
-        Ok(RenderPass { native_render_pass: () })
-    }
+use crate::core::color::Color;
+use crate::gpu::backend::{Backend, DefaultBackend};
+use crate::gpu::commands::{RenderCommand, RenderTargetBinding};
+use crate::gpu::error::GraphisError;
+use crate::gpu::texture::TextureView;
+use crate::rendering::pipeline::TextureFormat; // Assuming TextureFormat is defined here
+
+// Eksiz bir geçiş (Default), geçiş dışında SetRenderTarget/SetDepthStencilTarget ile bağlanmış hedeflere
+// (varsayılan olarak framebuffer'a ve onun derinlik tamponuna) çizer.
+#[derive(Debug, Clone, Default)]
+pub struct RenderPassDescriptor<'a> {
+    pub color_attachments: &'a [RenderPassColorAttachmentDescriptor<'a>],
+    pub depth_stencil_attachment: Option<RenderPassDepthStencilAttachmentDescriptor<'a>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RenderPassColorAttachmentDescriptor<'a> {
+    pub attachment: &'a TextureView, // A single mip level of a single layer
+    pub load_op: LoadOp,
+    pub store_op: StoreOp,
+    // Resolve attachment for multisampling (if needed)
+    pub resolve_target: Option<&'a TextureView>,
+    pub clear_value: Option<Color>, // None: black
+}
+
+#[derive(Debug, Clone)]
+pub struct RenderPassDepthStencilAttachmentDescriptor<'a> {
+    pub attachment: &'a TextureView, // A single mip level of a single layer
+    pub depth_load_op: LoadOp,
+    pub depth_store_op: StoreOp,
+    pub stencil_load_op: LoadOp,
+    pub stencil_store_op: StoreOp,
+    pub clear_value: Option<DepthStencilClearValue>, // Define this struct
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadOp {
+    Load,
+    Clear,
+    DontCare,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoreOp {
+    Store,
+    DontCare,
+}
+
+// Varsayılan değerler en uzak derinlik (1.0) ve 0 stencil değeridir.
+#[derive(Debug, Clone, Copy)]
+pub struct DepthStencilClearValue {
+    pub depth: f32,
+    pub stencil: u32,
+}
+
+impl Default for DepthStencilClearValue {
+    fn default() -> Self {
+        DepthStencilClearValue { depth: 1.0, stencil: 0 }
+    }
+}
+
+#[derive(Debug)]
+pub struct RenderPass<B: Backend = DefaultBackend> {
+    pub(crate) native_render_pass: B::RenderPass, // Arka uca özgü render geçişi nesnesi
+}
+
+impl<B: Backend> RenderPass<B> {
+    pub fn new(descriptor: &RenderPassDescriptor) -> Result<Self, GraphisError> {
+        log::debug!(
+            color_attachments = descriptor.color_attachments.len(),
+            has_depth_stencil = descriptor.depth_stencil_attachment.is_some();
+            "Render geçişi oluşturuldu"
+        );
+        // Platforma özel render geçişi oluşturma mantığı arka uç tarafından sağlanır.
+        Ok(RenderPass { native_render_pass: B::create_render_pass(descriptor)? })
+    }
+}
+
+impl RenderPassDescriptor<'_> {
+    // Ekleri doğrular ve geçiş başında kaydedilecek komutları üretir: hedefleri bağlar, görüntü alanını ve
+    // makas dikdörtgenini ekin tamamına ayarlar, ardından LoadOp işlemlerini uygular.
+    // Eksiz geçiş hiçbir durumu değiştirmez.
+    pub(crate) fn begin_commands(&self) -> Result<Vec<RenderCommand>, GraphisError> {
+        self.validate()?;
+        let color = self.color_attachments.first();
+        let depth_stencil = self.depth_stencil_attachment.as_ref();
+        if color.is_none() && depth_stencil.is_none() {
+            return Ok(Vec::new());
+        }
+        let mut commands = vec![
+            RenderCommand::SetRenderTarget {
+                render_target: color
+                    .map_or(RenderTargetBinding::Disabled, |color| RenderTargetBinding::Texture(*color.attachment)),
+            },
+            RenderCommand::SetDepthStencilTarget {
+                depth_stencil_target: depth_stencil.map(|depth_stencil| *depth_stencil.attachment),
+            },
+        ];
+        let size = color
+            .map(|color| color.attachment.size())
+            .or(depth_stencil.map(|depth_stencil| depth_stencil.attachment.size()));
+        if let Some((width, height)) = size {
+            commands.push(RenderCommand::SetViewport { x: 0, y: 0, width, height });
+            commands.push(RenderCommand::SetScissorRect { x: 0, y: 0, width, height });
+        }
+        let mut discard = Discard::default();
+        if let Some(color) = color {
+            match color.load_op {
+                LoadOp::Load => {}
+                LoadOp::Clear => {
+                    let clear_value = color.clear_value.unwrap_or(Color::rgb(0, 0, 0));
+                    commands.push(RenderCommand::Clear8Bit { color: clear_value })
+                }
+                LoadOp::DontCare => discard.color = true,
+            }
+        }
+        if let Some(depth_stencil) = depth_stencil {
+            let clear_value = depth_stencil.clear_value.unwrap_or_default();
+            if depth_stencil.attachment.format().is_depth() {
+                match depth_stencil.depth_load_op {
+                    LoadOp::Load => {}
+                    LoadOp::Clear => commands.push(RenderCommand::ClearDepth { depth: clear_value.depth }),
+                    LoadOp::DontCare => discard.depth = true,
+                }
+            }
+            if depth_stencil.attachment.format().has_stencil() {
+                match depth_stencil.stencil_load_op {
+                    LoadOp::Load => {}
+                    LoadOp::Clear => commands.push(RenderCommand::ClearStencil { stencil: clear_value.stencil }),
+                    LoadOp::DontCare => discard.stencil = true,
+                }
+            }
+        }
+        commands.extend(discard.command());
+        Ok(commands)
+    }
+
+    // Geçiş sonunda kaydedilecek komutlar: çok örnekli ekler çözümlenir, ardından StoreOp::DontCare ile
+    // işaretlenen içerikler atılır. Çözümleme atmadan önce yapılır; böylece ara ek güvenle atılabilir.
+    pub(crate) fn end_commands(&self) -> Vec<RenderCommand> {
+        let mut commands = Vec::new();
+        let mut discard = Discard::default();
+        if let Some(color) = self.color_attachments.first() {
+            if let Some(resolve_target) = color.resolve_target {
+                commands.push(RenderCommand::ResolveTexture { source: *color.attachment, destination: *resolve_target });
+            }
+            discard.color = color.store_op == StoreOp::DontCare;
+        }
+        if let Some(depth_stencil) = &self.depth_stencil_attachment {
+            discard.depth =
+                depth_stencil.attachment.format().is_depth() && depth_stencil.depth_store_op == StoreOp::DontCare;
+            discard.stencil = depth_stencil.attachment.format().has_stencil()
+                && depth_stencil.stencil_store_op == StoreOp::DontCare;
+        }
+        commands.extend(discard.command());
+        commands
+    }
+
+    fn validate(&self) -> Result<(), GraphisError> {
+        if self.color_attachments.len() > 1 {
+            return Err(GraphisError::Unsupported(format!(
+                "render passes support a single color attachment, got {}",
+                self.color_attachments.len()
+            )));
+        }
+        let color = self.color_attachments.first();
+        if let Some(color) = color {
+            let view = color.attachment;
+            view.validate_attachment()?;
+            if view.format().is_depth_stencil() {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "{:?} cannot be used as a color attachment",
+                    view.format()
+                )));
+            }
+            if let Some(resolve) = color.resolve_target {
+                resolve.validate_attachment()?;
+                if view.sample_count() <= 1 || resolve.sample_count() != 1 {
+                    return Err(GraphisError::ValidationFailed(
+                        "a resolve target requires a multisampled attachment and a single-sample target".to_string(),
+                    ));
+                }
+                if (resolve.size(), resolve.format()) != (view.size(), view.format()) {
+                    return Err(GraphisError::ValidationFailed(
+                        "the resolve target must match the attachment's size and format".to_string(),
+                    ));
+                }
+            }
+        }
+        if let Some(depth_stencil) = &self.depth_stencil_attachment {
+            let view = depth_stencil.attachment;
+            view.validate_attachment()?;
+            if !view.format().is_depth_stencil() {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "{:?} cannot be used as a depth-stencil attachment",
+                    view.format()
+                )));
+            }
+            if let Some(color) = color {
+                let color = color.attachment;
+                if (color.size(), color.sample_count()) != (view.size(), view.sample_count()) {
+                    return Err(GraphisError::ValidationFailed(
+                        "color and depth-stencil attachments must have the same size and sample count".to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// İçeriği tanımsız kalacak ek bölümleri.
+#[derive(Default)]
+struct Discard {
+    color: bool,
+    depth: bool,
+    stencil: bool,
+}
+
+impl Discard {
+    fn command(&self) -> Option<RenderCommand> {
+        (self.color || self.depth || self.stencil).then_some(RenderCommand::DiscardAttachments {
+            color: self.color,
+            depth: self.depth,
+            stencil: self.stencil,
+        })
+    }
 }
\ No newline at end of file
@@ -0,0 +1,308 @@
+use crate::gpu::backend::Backend;
+use crate::gpu::buffer::{BufferAccess, BufferUsage};
+use crate::gpu::commands::{RenderCommand, RenderTargetBinding};
+use crate::gpu::error::GraphisError;
+use crate::gpu::sampler::SamplerDescriptor;
+use crate::gpu::texture::TextureDescriptor;
+use crate::rendering::pipeline::Pipeline;
+
+// Nesil (generation) bilgisi taşıyan ham kaynak tanımlayıcısı.
+// Bir yuva (slot) yeniden kullanıldığında nesli artar; böylece silinmiş bir kaynağa ait eski
+// tanımlayıcılar yeni kaynakla karıştırılmaz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawHandle {
+    pub index: u32,
+    pub generation: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferHandle(pub(crate) RawHandle);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextureHandle(pub(crate) RawHandle);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SamplerHandle(pub(crate) RawHandle);
+
+// Komutlarda "shader programı" olarak bağlanan, doğrulanmış pipeline nesnesi.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PipelineHandle(pub(crate) RawHandle);
+
+#[derive(Debug)]
+struct Slot<T> {
+    generation: u32,
+    value: Option<T>,
+}
+
+// Nesil kontrollü yuva haritası (generational slot map).
+#[derive(Debug)]
+pub struct SlotMap<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<u32>,
+}
+
+impl<T> Default for SlotMap<T> {
+    fn default() -> Self {
+        SlotMap { slots: Vec::new(), free: Vec::new() }
+    }
+}
+
+impl<T> SlotMap<T> {
+    pub fn insert(&mut self, value: T) -> RawHandle {
+        self.insert_with(|_| value)
+    }
+
+    // Değeri, kendisine verilecek tanımlayıcıyı bilerek oluşturur (örneğin tanımlayıcısını saklayan nesneler için).
+    pub fn insert_with<F: FnOnce(RawHandle) -> T>(&mut self, make: F) -> RawHandle {
+        match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                let handle = RawHandle { index, generation: slot.generation };
+                slot.value = Some(make(handle));
+                handle
+            }
+            None => {
+                let handle = RawHandle { index: self.slots.len() as u32, generation: 0 };
+                self.slots.push(Slot { generation: 0, value: Some(make(handle)) });
+                handle
+            }
+        }
+    }
+
+    pub fn get(&self, handle: RawHandle) -> Option<&T> {
+        self.slots
+            .get(handle.index as usize)
+            .filter(|slot| slot.generation == handle.generation)
+            .and_then(|slot| slot.value.as_ref())
+    }
+
+    // Kaynağı çıkarır ve yuvanın neslini artırır; eski tanımlayıcılar artık çözümlenmez.
+    pub fn remove(&mut self, handle: RawHandle) -> Option<T> {
+        let slot = self.slots.get_mut(handle.index as usize)?;
+        if slot.generation != handle.generation {
+            return None;
+        }
+        let value = slot.value.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(handle.index);
+        Some(value)
+    }
+
+    pub fn contains(&self, handle: RawHandle) -> bool {
+        self.get(handle).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// Kayıt defterinde tutulan tampon bilgisi. Yerel nesne, arka ucun paylaşımlı tanımlayıcısının bir kopyasıdır.
+#[derive(Debug)]
+pub struct RegisteredBuffer<B: Backend> {
+    pub native: B::Buffer,
+    pub size: usize,
+    pub usage: BufferUsage,
+    pub access: BufferAccess,
+}
+
+#[derive(Debug)]
+pub struct RegisteredTexture<B: Backend> {
+    pub native: B::Texture,
+    pub descriptor: TextureDescriptor,
+}
+
+#[derive(Debug)]
+pub struct RegisteredSampler<B: Backend> {
+    pub native: B::Sampler,
+    pub descriptor: SamplerDescriptor,
+}
+
+// Cihazın sahip olduğu kaynak kayıt defteri. Kuyruklar, komutlardaki tanımlayıcıları
+// çalıştırma sırasında bu kayıt defteri üzerinden çözümler.
+#[derive(Debug)]
+pub struct ResourceRegistry<B: Backend> {
+    buffers: SlotMap<RegisteredBuffer<B>>,
+    textures: SlotMap<RegisteredTexture<B>>,
+    samplers: SlotMap<RegisteredSampler<B>>,
+    pipelines: SlotMap<Pipeline<B>>,
+}
+
+impl<B: Backend> Default for ResourceRegistry<B> {
+    fn default() -> Self {
+        ResourceRegistry {
+            buffers: SlotMap::default(),
+            textures: SlotMap::default(),
+            samplers: SlotMap::default(),
+            pipelines: SlotMap::default(),
+        }
+    }
+}
+
+impl<B: Backend> ResourceRegistry<B> {
+    pub fn insert_buffer(&mut self, buffer: RegisteredBuffer<B>) -> BufferHandle {
+        BufferHandle(self.buffers.insert(buffer))
+    }
+
+    pub fn remove_buffer(&mut self, handle: BufferHandle) -> Option<RegisteredBuffer<B>> {
+        self.buffers.remove(handle.0)
+    }
+
+    pub fn buffer(&self, handle: BufferHandle) -> Result<&RegisteredBuffer<B>, GraphisError> {
+        self.buffers.get(handle.0).ok_or(GraphisError::StaleHandle {
+            resource: "buffer",
+            index: handle.0.index,
+            generation: handle.0.generation,
+        })
+    }
+
+    pub fn insert_texture(&mut self, texture: RegisteredTexture<B>) -> TextureHandle {
+        TextureHandle(self.textures.insert(texture))
+    }
+
+    pub fn remove_texture(&mut self, handle: TextureHandle) -> Option<RegisteredTexture<B>> {
+        self.textures.remove(handle.0)
+    }
+
+    pub fn texture(&self, handle: TextureHandle) -> Result<&RegisteredTexture<B>, GraphisError> {
+        self.textures.get(handle.0).ok_or(GraphisError::StaleHandle {
+            resource: "texture",
+            index: handle.0.index,
+            generation: handle.0.generation,
+        })
+    }
+
+    pub fn insert_sampler(&mut self, sampler: RegisteredSampler<B>) -> SamplerHandle {
+        SamplerHandle(self.samplers.insert(sampler))
+    }
+
+    pub fn remove_sampler(&mut self, handle: SamplerHandle) -> Option<RegisteredSampler<B>> {
+        self.samplers.remove(handle.0)
+    }
+
+    pub fn sampler(&self, handle: SamplerHandle) -> Result<&RegisteredSampler<B>, GraphisError> {
+        self.samplers.get(handle.0).ok_or(GraphisError::StaleHandle {
+            resource: "sampler",
+            index: handle.0.index,
+            generation: handle.0.generation,
+        })
+    }
+
+    pub fn insert_pipeline<F: FnOnce(PipelineHandle) -> Pipeline<B>>(&mut self, make: F) -> PipelineHandle {
+        PipelineHandle(self.pipelines.insert_with(|handle| make(PipelineHandle(handle))))
+    }
+
+    pub fn remove_pipeline(&mut self, handle: PipelineHandle) -> Option<Pipeline<B>> {
+        self.pipelines.remove(handle.0)
+    }
+
+    pub fn pipeline(&self, handle: PipelineHandle) -> Result<&Pipeline<B>, GraphisError> {
+        self.pipelines.get(handle.0).ok_or(GraphisError::StaleHandle {
+            resource: "pipeline",
+            index: handle.0.index,
+            generation: handle.0.generation,
+        })
+    }
+
+    // Komutun başvurduğu tüm kaynakların hâlâ yaşadığını doğrular.
+    pub fn validate_command(&self, command: &RenderCommand) -> Result<(), GraphisError> {
+        match *command {
+            RenderCommand::BindVertexBuffer { buffer, .. } | RenderCommand::BindIndexBuffer { buffer, .. } => {
+                self.buffer(buffer).map(|_| ())
+            }
+            RenderCommand::BindShaderProgram { program } => self.pipeline(program).map(|_| ()),
+            RenderCommand::BindTexture { texture: view, .. }
+            | RenderCommand::GenerateMipmaps { texture: view }
+            | RenderCommand::SetRenderTarget { render_target: RenderTargetBinding::Texture(view) }
+            | RenderCommand::SetDepthStencilTarget { depth_stencil_target: Some(view) } => {
+                self.texture(view.texture()).map(|_| ())
+            }
+            RenderCommand::BindSampler { sampler, .. } => self.sampler(sampler).map(|_| ()),
+            RenderCommand::ResolveTexture { source, destination } => {
+                self.texture(source.texture())?;
+                self.texture(destination.texture()).map(|_| ())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gpu::command_buffer::CommandBuffer;
+    use crate::gpu::device::Device;
+
+    #[test]
+    fn removed_handles_no_longer_resolve() {
+        let mut map = SlotMap::default();
+        let handle = map.insert("first");
+        assert_eq!(map.get(handle), Some(&"first"));
+        assert_eq!(map.remove(handle), Some("first"));
+        assert_eq!(map.get(handle), None);
+        assert_eq!(map.remove(handle), None);
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn reused_slots_get_a_new_generation() {
+        let mut map = SlotMap::default();
+        let old = map.insert(1);
+        map.remove(old);
+        let new = map.insert(2);
+        assert_eq!(new.index, old.index);
+        assert_eq!(new.generation, old.generation + 1);
+        assert!(!map.contains(old));
+        assert_eq!(map.get(new), Some(&2));
+        assert_eq!(map.remove(old), None);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn destroyed_resources_report_stale_handles() {
+        let device = Device::new().unwrap();
+        let buffer = device.create_buffer(16, BufferUsage::Vertex, BufferAccess::ReadWrite).unwrap();
+        let handle = buffer.handle();
+        device.destroy_buffer(buffer);
+        assert_eq!(
+            device.resources().buffer(handle).unwrap_err(),
+            GraphisError::StaleHandle { resource: "buffer", index: handle.0.index, generation: handle.0.generation }
+        );
+
+        // Yuvayı yeniden kullanan tampon eski tanımlayıcıyla çözümlenmez.
+        let reused = device.create_buffer(16, BufferUsage::Vertex, BufferAccess::ReadWrite).unwrap();
+        assert_eq!(reused.handle().0.index, handle.0.index);
+        assert!(device.resources().buffer(reused.handle()).is_ok());
+        assert!(device.resources().buffer(handle).is_err());
+
+        let sampler = device.create_sampler(&SamplerDescriptor::default()).unwrap();
+        let sampler_handle = sampler.handle();
+        device.destroy_sampler(sampler);
+        let command = RenderCommand::BindSampler { sampler: sampler_handle, unit: 0 };
+        assert!(matches!(
+            device.resources().validate_command(&command),
+            Err(GraphisError::StaleHandle { resource: "sampler", .. })
+        ));
+    }
+
+    #[test]
+    fn submitting_commands_with_stale_handles_fails() {
+        let device = Device::new().unwrap();
+        let queue = device.get_queue().unwrap();
+        let buffer = device.create_buffer(16, BufferUsage::Vertex, BufferAccess::ReadWrite).unwrap();
+        let handle = buffer.handle();
+
+        let mut command_buffer = CommandBuffer::new();
+        command_buffer.begin_recording().unwrap();
+        command_buffer.submit_command(RenderCommand::BindVertexBuffer { buffer: handle, slot: 0, offset: 0 }).unwrap();
+        command_buffer.end_recording().unwrap();
+        queue.submit(std::slice::from_ref(&command_buffer)).unwrap();
+
+        device.destroy_buffer(buffer);
+        assert!(matches!(queue.submit(&[command_buffer]), Err(GraphisError::StaleHandle { resource: "buffer", .. })));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,172 @@
+use crate::gpu::backend::{Backend, DefaultBackend};
+use crate::gpu::commands::CompareFunction;
+use crate::gpu::error::GraphisError;
+use crate::gpu::resource::SamplerHandle;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FilterMode {
+    #[default]
+    Nearest,
+    Linear,
+}
+
+// Doku koordinatları [0, 1) aralığının dışına taştığında ne okunacağı.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AddressMode {
+    Repeat,
+    MirrorRepeat,
+    #[default]
+    ClampToEdge,
+    ClampToBorder, // Dışarıdaki texel'ler yerine BorderColor okunur
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BorderColor {
+    #[default]
+    TransparentBlack,
+    OpaqueBlack,
+    OpaqueWhite,
+}
+
+impl BorderColor {
+    pub fn rgba(&self) -> [f32; 4] {
+        match self {
+            BorderColor::TransparentBlack => [0.0, 0.0, 0.0, 0.0],
+            BorderColor::OpaqueBlack => [0.0, 0.0, 0.0, 1.0],
+            BorderColor::OpaqueWhite => [1.0, 1.0, 1.0, 1.0],
+        }
+    }
+}
+
+// Bir dokunun nasıl örnekleneceği. LOD değerleri görünümün taban mip seviyesine göredir.
+// `compare` verildiğinde sampler bir karşılaştırma (gölge) sampler'ıdır: derinlik dokusundan okunan değer
+// referans değerle karşılaştırılır ve filtreleme karşılaştırma sonuçları (0 veya 1) üzerinde yapılır.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SamplerDescriptor {
+    pub address_mode_u: AddressMode,
+    pub address_mode_v: AddressMode,
+    pub address_mode_w: AddressMode,
+    pub mag_filter: FilterMode,
+    pub min_filter: FilterMode,
+    pub mipmap_filter: FilterMode,
+    pub lod_min_clamp: f32,
+    pub lod_max_clamp: f32,
+    pub compare: Option<CompareFunction>,
+    pub max_anisotropy: u16, // 1: anizotropik filtreleme kapalı
+    pub border_color: BorderColor, // Yalnızca AddressMode::ClampToBorder ile kullanılır
+}
+
+impl Default for SamplerDescriptor {
+    fn default() -> Self {
+        SamplerDescriptor {
+            address_mode_u: AddressMode::default(),
+            address_mode_v: AddressMode::default(),
+            address_mode_w: AddressMode::default(),
+            mag_filter: FilterMode::default(),
+            min_filter: FilterMode::default(),
+            mipmap_filter: FilterMode::default(),
+            lod_min_clamp: 0.0,
+            lod_max_clamp: 32.0,
+            compare: None,
+            max_anisotropy: 1,
+            border_color: BorderColor::default(),
+        }
+    }
+}
+
+impl SamplerDescriptor {
+    pub const MAX_ANISOTROPY: u16 = 16;
+
+    pub fn validate(&self) -> Result<(), GraphisError> {
+        let invalid = |message: String| Err(GraphisError::ValidationFailed(format!("invalid sampler: {}", message)));
+        if !(self.lod_min_clamp >= 0.0 && self.lod_max_clamp >= self.lod_min_clamp) {
+            return invalid(format!(
+                "the LOD clamp {}..{} must be a non-negative, non-empty range",
+                self.lod_min_clamp, self.lod_max_clamp
+            ));
+        }
+        if !(1..=Self::MAX_ANISOTROPY).contains(&self.max_anisotropy) {
+            return invalid(format!(
+                "max anisotropy must be between 1 and {}, got {}",
+                Self::MAX_ANISOTROPY,
+                self.max_anisotropy
+            ));
+        }
+        // Anizotropik filtreleme yalnızca tamamen doğrusal filtrelemeyle birlikte anlamlıdır.
+        let linear = [self.mag_filter, self.min_filter, self.mipmap_filter] == [FilterMode::Linear; 3];
+        if self.max_anisotropy > 1 && !linear {
+            return invalid("anisotropic filtering requires linear mag, min and mipmap filters".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Sampler<B: Backend = DefaultBackend> {
+    handle: SamplerHandle, // Komutlarda sampler'a başvurmak için kullanılan tanımlayıcı
+    pub(crate) native_sampler: B::Sampler, // Arka uca özgü sampler nesnesi
+    pub descriptor: SamplerDescriptor,
+}
+
+impl<B: Backend> Sampler<B> {
+    // Sampler'lar, yerel nesneyi sağlayan Device::create_sampler ile oluşturulur.
+    pub(crate) fn new(handle: SamplerHandle, native_sampler: B::Sampler, descriptor: &SamplerDescriptor) -> Self {
+        Sampler { handle, native_sampler, descriptor: *descriptor }
+    }
+
+    // BindSampler komutları için tanımlayıcı.
+    pub fn handle(&self) -> SamplerHandle {
+        self.handle
+    }
+
+    pub fn native(&self) -> &B::Sampler {
+        &self.native_sampler
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gpu::device::Device;
+
+    #[test]
+    fn lod_clamps_must_form_a_non_negative_range() {
+        let clamp =
+            |lod_min_clamp, lod_max_clamp| SamplerDescriptor { lod_min_clamp, lod_max_clamp, ..Default::default() };
+        assert!(clamp(0.0, 0.0).validate().is_ok());
+        assert!(clamp(2.0, 4.0).validate().is_ok());
+        assert!(clamp(-1.0, 4.0).validate().is_err());
+        assert!(clamp(4.0, 2.0).validate().is_err());
+        assert!(clamp(f32::NAN, 4.0).validate().is_err());
+    }
+
+    #[test]
+    fn anisotropy_needs_linear_filters_and_a_supported_count() {
+        let linear = SamplerDescriptor {
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            mipmap_filter: FilterMode::Linear,
+            ..Default::default()
+        };
+        let anisotropy = |max_anisotropy| SamplerDescriptor { max_anisotropy, ..linear };
+        assert!(anisotropy(SamplerDescriptor::MAX_ANISOTROPY).validate().is_ok());
+        assert!(anisotropy(0).validate().is_err());
+        assert!(anisotropy(SamplerDescriptor::MAX_ANISOTROPY + 1).validate().is_err());
+        let nearest_mips = SamplerDescriptor { mipmap_filter: FilterMode::Nearest, ..anisotropy(4) };
+        assert!(nearest_mips.validate().is_err());
+    }
+
+    #[test]
+    fn devices_reject_invalid_samplers() {
+        let device = Device::new().unwrap();
+        let sampler = SamplerDescriptor {
+            address_mode_u: AddressMode::ClampToBorder,
+            compare: Some(CompareFunction::LessOrEqual),
+            border_color: BorderColor::OpaqueWhite,
+            ..Default::default()
+        };
+        assert_eq!(device.create_sampler(&sampler).unwrap().descriptor, sampler);
+        let invalid = SamplerDescriptor { max_anisotropy: 2, ..sampler };
+        assert!(matches!(device.create_sampler(&invalid), Err(GraphisError::ValidationFailed(_))));
+    }
+}
\ No newline at end of file
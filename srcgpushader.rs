@@ -1,37 +1,158 @@
-#[derive(Debug)]
-pub struct ShaderDescriptor {
-    pub label: Option<String>,
-    pub source: ShaderSource,
-    pub stage: ShaderStage,
-}
-
-#[derive(Debug)]
-pub enum ShaderSource {
-    SpirV(Vec<u8>),
-    // İleride diğer kaynak türleri (örneğin, Glsl veya Hlsl) eklenebilir,
-    // potansiyel olarak derleme gerektirebilir.
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ShaderStage {
-    Vertex,
-    Fragment,
-    Compute,
-    // İhtiyaca göre diğer aşamalar eklenebilir (örneğin, Tessellation Control, Tessellation Evaluation, Geometry).
-}
-
-#[derive(Debug)]
-pub struct Shader {
-    pub(crate) native_shader: (), // Şimdilik yer tutucu
-    pub descriptor: ShaderDescriptor,
-}
-
-impl Shader {
-    pub fn new(descriptor: ShaderDescriptor) -> Self {
-        println!("Oluşturulan shader: {:?}", descriptor);
-        Shader {
-            native_shader: (),
-            descriptor,
-        }
-    }
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::gpu::backend::{Backend, DefaultBackend};
+use crate::gpu::error::GraphisError;
+use crate::gpu::frontend;
+use crate::gpu::software::shading::{FragmentShader, VertexShader};
+use crate::gpu::spirv::SpirvModule;
+
+#[derive(Debug, Clone)]
+pub struct ShaderDescriptor {
+    pub label: Option<String>,
+    pub source: ShaderSource,
+    pub stage: ShaderStage,
+}
+
+#[derive(Debug, Clone)]
+pub enum ShaderSource {
+    SpirV(Vec<u8>),
+    // Yazılım arka ucunun doğrudan çağırdığı Rust kodu; yansıtma bilgisi ve giriş noktası yoktur.
+    Native(NativeShader),
+    // WGSL kaynağı; oluşturulurken SPIR-V'ye derlenir, giriş noktaları @vertex ve @fragment fonksiyonlarıdır.
+    Wgsl(String),
+    // Tek aşamalı GLSL kaynağı (`main` giriş noktası); oluşturulurken SPIR-V'ye derlenir.
+    Glsl { source: String, stage: ShaderStage },
+}
+
+// Rust ile yazılmış bir shader aşaması. Kapanışlar (closure) da doğrudan kullanılabilir:
+// `NativeShader::vertex(|input: &VertexInput<'_>| ShadedVertex { .. })`.
+#[derive(Clone)]
+pub enum NativeShader {
+    Vertex(Rc<dyn VertexShader>),
+    Fragment(Rc<dyn FragmentShader>),
+}
+
+impl NativeShader {
+    pub fn vertex(shader: impl VertexShader + 'static) -> Self {
+        NativeShader::Vertex(Rc::new(shader))
+    }
+
+    pub fn fragment(shader: impl FragmentShader + 'static) -> Self {
+        NativeShader::Fragment(Rc::new(shader))
+    }
+
+    pub fn stage(&self) -> ShaderStage {
+        match self {
+            NativeShader::Vertex(_) => ShaderStage::Vertex,
+            NativeShader::Fragment(_) => ShaderStage::Fragment,
+        }
+    }
+}
+
+impl fmt::Debug for NativeShader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NativeShader::{:?}", self.stage())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShaderStage {
+    Vertex,
+    Fragment,
+    Compute,
+    // İhtiyaca göre diğer aşamalar eklenebilir (örneğin, Tessellation Control, Tessellation Evaluation, Geometry).
+}
+
+#[derive(Debug, Clone)]
+pub struct Shader<B: Backend = DefaultBackend> {
+    backend: PhantomData<B>, // Shader'lar arka uca özgü bir nesne tutmaz; tip yalnızca boru hattıyla eşleşir
+    pub descriptor: ShaderDescriptor,
+    module: Option<SpirvModule>, // Kaynaktan ayrıştırılmış ve yansıtılmış modül; yerel shader'larda None
+}
+
+impl<B: Backend> Shader<B> {
+    // Kaynağı ayrıştırır; bozuk modülleri ve tanımlayıcıdaki aşamaya ait giriş noktası olmayan modülleri reddeder.
+    pub fn new(descriptor: ShaderDescriptor) -> Result<Self, GraphisError> {
+        let module = match &descriptor.source {
+            ShaderSource::SpirV(bytes) => SpirvModule::parse(bytes)?,
+            ShaderSource::Wgsl(source) => SpirvModule::from_words(&frontend::wgsl::compile(source)?)?,
+            ShaderSource::Glsl { source, stage } => {
+                if *stage != descriptor.stage {
+                    return Err(GraphisError::ValidationFailed(format!(
+                        "shader {:?} is declared as a {:?} shader but its GLSL source is a {:?} shader",
+                        descriptor.label, descriptor.stage, stage
+                    )));
+                }
+                SpirvModule::from_words(&frontend::glsl::compile(source, *stage)?)?
+            }
+            ShaderSource::Native(native) => {
+                if native.stage() != descriptor.stage {
+                    return Err(GraphisError::ValidationFailed(format!(
+                        "shader {:?} is declared as a {:?} shader but its native source is a {:?} shader",
+                        descriptor.label,
+                        descriptor.stage,
+                        native.stage()
+                    )));
+                }
+                log::debug!(label:? = descriptor.label, stage:? = descriptor.stage; "Yerel shader oluşturuldu");
+                return Ok(Shader { backend: PhantomData, descriptor, module: None });
+            }
+        };
+        let models: Vec<_> = module.entry_points().iter().map(|entry_point| entry_point.execution_model).collect();
+        if !models.iter().any(|model| model.stage() == Some(descriptor.stage)) {
+            return Err(GraphisError::ValidationFailed(format!(
+                "shader {:?} is declared as a {:?} shader but its module only has {:?} entry points",
+                descriptor.label, descriptor.stage, models
+            )));
+        }
+        log::debug!(
+            label:? = descriptor.label,
+            stage:? = descriptor.stage,
+            entry_points = module.entry_points().len();
+            "Shader oluşturuldu"
+        );
+        Ok(Shader { backend: PhantomData, descriptor, module: Some(module) })
+    }
+
+    // Giriş noktaları, arayüz değişkenleri ve kaynak bağlamaları için yansıtma bilgisi. Yerel shader'larda None.
+    pub fn module(&self) -> Option<&SpirvModule> {
+        self.module.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gpu::software::shading::{FragmentInput, ShadedVertex, VertexInput};
+
+    fn native_vertex() -> NativeShader {
+        NativeShader::vertex(|_: &VertexInput<'_>| ShadedVertex {
+            position: [0.0, 0.0, 0.0, 1.0],
+            point_size: 1.0,
+            varyings: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn native_shaders_must_match_the_declared_stage() {
+        let native = |source, stage| -> Result<Shader, GraphisError> {
+            let label = Some("native".to_string());
+            Shader::new(ShaderDescriptor { label, source: ShaderSource::Native(source), stage })
+        };
+        let fragment = NativeShader::fragment(|_: &FragmentInput<'_>| None);
+        assert_eq!(fragment.stage(), ShaderStage::Fragment);
+        assert!(native(fragment.clone(), ShaderStage::Fragment).is_ok());
+        assert!(matches!(native(fragment, ShaderStage::Vertex), Err(GraphisError::ValidationFailed(_))));
+        assert!(matches!(native(native_vertex(), ShaderStage::Compute), Err(GraphisError::ValidationFailed(_))));
+    }
+
+    #[test]
+    fn native_shaders_have_no_reflected_module() {
+        let source = ShaderSource::Native(native_vertex());
+        let shader: Shader = Shader::new(ShaderDescriptor { label: None, source, stage: ShaderStage::Vertex }).unwrap();
+        assert!(shader.module().is_none());
+        assert_eq!(format!("{:?}", shader.descriptor.source), "Native(NativeShader::Vertex)");
+    }
 }
\ No newline at end of file
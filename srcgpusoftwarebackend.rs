@@ -0,0 +1,161 @@
+use std::cell::{Ref, RefCell};
+use std::rc::Rc;
+
+use crate::gpu::backend::{Backend, BackendKind};
+use crate::gpu::buffer::{BufferAccess, BufferUsage};
+use crate::gpu::command_buffer::CommandBuffer;
+use crate::gpu::error::GraphisError;
+use crate::gpu::framebuffer::FramebufferDescriptor;
+use crate::gpu::memory::HostMemory;
+use crate::gpu::render_pass::RenderPassDescriptor;
+use crate::gpu::resource::ResourceRegistry;
+use crate::gpu::sampler::SamplerDescriptor;
+use crate::gpu::software::executor::SoftwareExecutor;
+use crate::gpu::software::texture::TextureStorage;
+use crate::gpu::texture::{TextureDataLayout, TextureDescriptor, TextureRegion};
+
+// Komut arabelleklerini CPU üzerinde SoftwareExecutor ile çalıştıran arka uç.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SoftwareBackend;
+
+// Cihaz ve ondan alınan kuyruklar aynı yürütücüyü paylaşır.
+#[derive(Debug)]
+pub struct SoftwareDevice {
+    executor: Rc<RefCell<SoftwareExecutor>>,
+}
+
+impl SoftwareDevice {
+    // Varsayılan framebuffer'ı boyutlandırmak veya çizim sonuçlarını okumak için yürütücüye erişim.
+    pub fn executor(&self) -> Rc<RefCell<SoftwareExecutor>> {
+        Rc::clone(&self.executor)
+    }
+}
+
+#[derive(Debug)]
+pub struct SoftwareQueue {
+    executor: Rc<RefCell<SoftwareExecutor>>,
+}
+
+// Tampon belleği paylaşımlıdır; yürütücü çizim sırasında aynı belleği okuyabilir.
+#[derive(Debug, Clone)]
+pub struct SoftwareBuffer {
+    pub(crate) memory: Rc<RefCell<HostMemory>>,
+}
+
+// Doku içeriği de paylaşımlıdır; yürütücü dokuya render edebilir veya dokudan okuyabilir.
+#[derive(Debug, Clone)]
+pub struct SoftwareTexture {
+    pub(crate) storage: Rc<RefCell<TextureStorage>>,
+}
+
+impl SoftwareTexture {
+    pub fn storage(&self) -> Ref<'_, TextureStorage> {
+        self.storage.borrow()
+    }
+}
+
+impl Backend for SoftwareBackend {
+    type Device = SoftwareDevice;
+    type Queue = SoftwareQueue;
+    type Buffer = SoftwareBuffer;
+    type Texture = SoftwareTexture;
+    type Sampler = ();
+    type RenderPass = ();
+    type Framebuffer = ();
+
+    fn kind() -> BackendKind {
+        BackendKind::Software
+    }
+
+    fn create_device() -> Result<SoftwareDevice, GraphisError> {
+        // Varsayılan framebuffer başlangıçta boştur; boyutu yürütücü üzerinden ayarlanır.
+        Ok(SoftwareDevice {
+            executor: Rc::new(RefCell::new(SoftwareExecutor::new(0, 0))),
+        })
+    }
+
+    fn device_name(_device: &SoftwareDevice) -> String {
+        "Sahne Software Rasterizer".to_string()
+    }
+
+    fn create_queue(device: &SoftwareDevice) -> Result<SoftwareQueue, GraphisError> {
+        Ok(SoftwareQueue { executor: device.executor() })
+    }
+
+    fn create_buffer(
+        _device: &SoftwareDevice,
+        size: usize,
+        _usage: BufferUsage,
+        _access: BufferAccess,
+    ) -> Result<SoftwareBuffer, GraphisError> {
+        Ok(SoftwareBuffer {
+            memory: Rc::new(RefCell::new(HostMemory::new(size)?)),
+        })
+    }
+
+    fn destroy_buffer(_device: &SoftwareDevice, _buffer: SoftwareBuffer) {}
+
+    fn buffer_memory(buffer: &SoftwareBuffer) -> &RefCell<HostMemory> {
+        &buffer.memory
+    }
+
+    fn create_texture(_device: &SoftwareDevice, descriptor: &TextureDescriptor) -> Result<SoftwareTexture, GraphisError> {
+        Ok(SoftwareTexture {
+            storage: Rc::new(RefCell::new(TextureStorage::new(descriptor)?)),
+        })
+    }
+
+    fn destroy_texture(_device: &SoftwareDevice, _texture: SoftwareTexture) {}
+
+    fn write_texture(
+        texture: &SoftwareTexture,
+        region: &TextureRegion,
+        data: &[u8],
+        layout: &TextureDataLayout,
+    ) -> Result<(), GraphisError> {
+        let mut storage = texture
+            .storage
+            .try_borrow_mut()
+            .map_err(|_| GraphisError::InvalidState("texture is in use".to_string()))?;
+        storage.write_region(region, data, layout);
+        Ok(())
+    }
+
+    fn read_texture(
+        texture: &SoftwareTexture,
+        region: &TextureRegion,
+        data: &mut [u8],
+        layout: &TextureDataLayout,
+    ) -> Result<(), GraphisError> {
+        let storage = texture
+            .storage
+            .try_borrow()
+            .map_err(|_| GraphisError::InvalidState("texture is in use".to_string()))?;
+        storage.read_region(region, data, layout);
+        Ok(())
+    }
+
+    // Örnekleme, kayıt defterindeki tanımlayıcıyla çizim sırasında yapılır; ayrı bir yerel nesne gerekmez.
+    fn create_sampler(_device: &SoftwareDevice, _descriptor: &SamplerDescriptor) -> Result<(), GraphisError> {
+        Ok(())
+    }
+
+    fn destroy_sampler(_device: &SoftwareDevice, _sampler: ()) {}
+
+
+    fn create_render_pass(_descriptor: &RenderPassDescriptor) -> Result<(), GraphisError> {
+        Ok(())
+    }
+
+    fn create_framebuffer(_descriptor: &FramebufferDescriptor<Self>) -> Result<(), GraphisError> {
+        Ok(())
+    }
+
+    fn submit(
+        queue: &SoftwareQueue,
+        resources: &ResourceRegistry<Self>,
+        command_buffers: &[CommandBuffer],
+    ) -> Result<(), GraphisError> {
+        queue.executor.borrow_mut().submit(resources, command_buffers)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,171 @@
+use crate::gpu::commands::{BlendFactor, BlendOperation};
+use crate::gpu::software::executor::{BlendFunction, ColorTargetBlend};
+use crate::rendering::pipeline::ColorWrite;
+
+// Karıştırma denkleminin girdileri; hepsi [r, g, b, a] biçimindedir.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlendInputs {
+    pub source: [f32; 4],
+    pub second_source: [f32; 4], // Çift kaynaklı karıştırmada Src1 faktörlerinin okuduğu renk
+    pub destination: [f32; 4],
+    pub constant: [f32; 4],
+}
+
+// Hedefin karıştırma durumuna göre yazılacak rengi hesaplar:
+// sonuç = işlem(kaynak * kaynak faktörü, hedef * hedef faktörü). Renk ve alfa kanalları
+// ayrı denklemlerle hesaplanır. Min ve Max işlemleri faktörleri yok sayar.
+pub fn blend(state: &ColorTargetBlend, inputs: &BlendInputs) -> [f32; 4] {
+    if !state.enabled {
+        return inputs.source;
+    }
+    [0, 1, 2, 3].map(|channel| {
+        let function = if channel == 3 { state.alpha } else { state.color };
+        apply(function, channel, inputs)
+    })
+}
+
+// Maskede olmayan kanallar hedefteki değerini korur.
+pub fn write_masked(mask: ColorWrite, value: [f32; 4], destination: [f32; 4]) -> [f32; 4] {
+    let channels = [ColorWrite::RED, ColorWrite::GREEN, ColorWrite::BLUE, ColorWrite::ALPHA];
+    [0, 1, 2, 3].map(|i| if mask.contains(channels[i]) { value[i] } else { destination[i] })
+}
+
+fn apply(function: BlendFunction, channel: usize, inputs: &BlendInputs) -> f32 {
+    let source = inputs.source[channel];
+    let destination = inputs.destination[channel];
+    let weighted_source = source * factor(function.src_factor, channel, inputs);
+    let weighted_destination = destination * factor(function.dst_factor, channel, inputs);
+    match function.operation {
+        BlendOperation::Add => weighted_source + weighted_destination,
+        BlendOperation::Subtract => weighted_source - weighted_destination,
+        BlendOperation::ReverseSubtract => weighted_destination - weighted_source,
+        BlendOperation::Min => source.min(destination),
+        BlendOperation::Max => source.max(destination),
+    }
+}
+
+// Faktörün verilen kanaldaki değeri; alfa kanalı 3 numaralı kanaldır.
+fn factor(factor: BlendFactor, channel: usize, inputs: &BlendInputs) -> f32 {
+    let BlendInputs { source, second_source, destination, constant } = inputs;
+    match factor {
+        BlendFactor::Zero => 0.0,
+        BlendFactor::One => 1.0,
+        BlendFactor::SrcColor => source[channel],
+        BlendFactor::OneMinusSrcColor => 1.0 - source[channel],
+        BlendFactor::DstColor => destination[channel],
+        BlendFactor::OneMinusDstColor => 1.0 - destination[channel],
+        BlendFactor::SrcAlpha => source[3],
+        BlendFactor::OneMinusSrcAlpha => 1.0 - source[3],
+        BlendFactor::DstAlpha => destination[3],
+        BlendFactor::OneMinusDstAlpha => 1.0 - destination[3],
+        BlendFactor::ConstantColor => constant[channel],
+        BlendFactor::OneMinusConstantColor => 1.0 - constant[channel],
+        BlendFactor::ConstantAlpha => constant[3],
+        BlendFactor::OneMinusConstantAlpha => 1.0 - constant[3],
+        // Alfa kanalında 1, renk kanallarında min(kaynak alfası, 1 - hedef alfası).
+        BlendFactor::SrcAlphaSaturate if channel == 3 => 1.0,
+        BlendFactor::SrcAlphaSaturate => source[3].min(1.0 - destination[3]),
+        BlendFactor::Src1Color => second_source[channel],
+        BlendFactor::OneMinusSrc1Color => 1.0 - second_source[channel],
+        BlendFactor::Src1Alpha => second_source[3],
+        BlendFactor::OneMinusSrc1Alpha => 1.0 - second_source[3],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sonuçların tam karşılaştırılabilmesi için tüm değerler ikinin kuvvetleriyle ifade edilebilir.
+    const INPUTS: BlendInputs = BlendInputs {
+        source: [0.5, 0.25, 0.75, 0.5],
+        second_source: [0.25, 0.5, 0.125, 0.75],
+        destination: [0.25, 0.5, 1.0, 0.25],
+        constant: [0.125, 0.25, 0.5, 0.75],
+    };
+
+    fn enabled(color: BlendFunction, alpha: BlendFunction) -> ColorTargetBlend {
+        ColorTargetBlend { enabled: true, color, alpha, ..Default::default() }
+    }
+
+    fn function(src_factor: BlendFactor, dst_factor: BlendFactor, operation: BlendOperation) -> BlendFunction {
+        BlendFunction { src_factor, dst_factor, operation }
+    }
+
+    #[test]
+    fn blend_factors() {
+        // Her faktörün INPUTS için kanal başına değeri.
+        let cases = [
+            (BlendFactor::Zero, [0.0; 4]),
+            (BlendFactor::One, [1.0; 4]),
+            (BlendFactor::SrcColor, [0.5, 0.25, 0.75, 0.5]),
+            (BlendFactor::OneMinusSrcColor, [0.5, 0.75, 0.25, 0.5]),
+            (BlendFactor::DstColor, [0.25, 0.5, 1.0, 0.25]),
+            (BlendFactor::OneMinusDstColor, [0.75, 0.5, 0.0, 0.75]),
+            (BlendFactor::SrcAlpha, [0.5; 4]),
+            (BlendFactor::OneMinusSrcAlpha, [0.5; 4]),
+            (BlendFactor::DstAlpha, [0.25; 4]),
+            (BlendFactor::OneMinusDstAlpha, [0.75; 4]),
+            (BlendFactor::ConstantColor, [0.125, 0.25, 0.5, 0.75]),
+            (BlendFactor::OneMinusConstantColor, [0.875, 0.75, 0.5, 0.25]),
+            (BlendFactor::ConstantAlpha, [0.75; 4]),
+            (BlendFactor::OneMinusConstantAlpha, [0.25; 4]),
+            (BlendFactor::SrcAlphaSaturate, [0.5, 0.5, 0.5, 1.0]),
+            (BlendFactor::Src1Color, [0.25, 0.5, 0.125, 0.75]),
+            (BlendFactor::OneMinusSrc1Color, [0.75, 0.5, 0.875, 0.25]),
+            (BlendFactor::Src1Alpha, [0.75; 4]),
+            (BlendFactor::OneMinusSrc1Alpha, [0.25; 4]),
+        ];
+        for (factor, expected) in cases {
+            let as_source = function(factor, BlendFactor::Zero, BlendOperation::Add);
+            let result = blend(&enabled(as_source, as_source), &INPUTS);
+            assert_eq!(result, [0, 1, 2, 3].map(|i| INPUTS.source[i] * expected[i]), "source {:?}", factor);
+
+            let as_destination = function(BlendFactor::Zero, factor, BlendOperation::Add);
+            let result = blend(&enabled(as_destination, as_destination), &INPUTS);
+            assert_eq!(result, [0, 1, 2, 3].map(|i| INPUTS.destination[i] * expected[i]), "destination {:?}", factor);
+        }
+    }
+
+    #[test]
+    fn blend_operations() {
+        // Kaynak SrcAlpha (0.5), hedef DstAlpha (0.25) ile ağırlıklandırılır; Min ve Max faktörleri yok sayar.
+        let cases = [
+            (BlendOperation::Add, [0.3125, 0.25, 0.625, 0.3125]),
+            (BlendOperation::Subtract, [0.1875, 0.0, 0.125, 0.1875]),
+            (BlendOperation::ReverseSubtract, [-0.1875, 0.0, -0.125, -0.1875]),
+            (BlendOperation::Min, [0.25, 0.25, 0.75, 0.25]),
+            (BlendOperation::Max, [0.5, 0.5, 1.0, 0.5]),
+        ];
+        for (operation, expected) in cases {
+            let weighted = function(BlendFactor::SrcAlpha, BlendFactor::DstAlpha, operation);
+            assert_eq!(blend(&enabled(weighted, weighted), &INPUTS), expected, "{:?}", operation);
+        }
+    }
+
+    #[test]
+    fn color_and_alpha_use_separate_functions() {
+        let color = function(BlendFactor::One, BlendFactor::Zero, BlendOperation::Add);
+        let alpha = function(BlendFactor::Zero, BlendFactor::One, BlendOperation::Add);
+        assert_eq!(blend(&enabled(color, alpha), &INPUTS), [0.5, 0.25, 0.75, 0.25]);
+    }
+
+    #[test]
+    fn disabled_blend_writes_the_source() {
+        let state = ColorTargetBlend {
+            enabled: false,
+            color: function(BlendFactor::Zero, BlendFactor::One, BlendOperation::Add),
+            ..Default::default()
+        };
+        assert_eq!(blend(&state, &INPUTS), INPUTS.source);
+    }
+
+    #[test]
+    fn write_mask_keeps_masked_channels() {
+        let value = [1.0, 1.0, 1.0, 1.0];
+        let destination = [0.0, 0.25, 0.5, 0.75];
+        assert_eq!(write_masked(ColorWrite::ALL, value, destination), value);
+        assert_eq!(write_masked(ColorWrite::empty(), value, destination), destination);
+        assert_eq!(write_masked(ColorWrite::RED | ColorWrite::ALPHA, value, destination), [1.0, 0.25, 0.5, 1.0]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,725 @@
+use std::collections::HashMap;
+
+use crate::core::color::ColorHDRA;
+use crate::gpu::commands::{IndexType, PrimitiveTopology};
+use crate::gpu::error::GraphisError;
+use crate::gpu::resource::ResourceRegistry;
+use crate::gpu::software::backend::SoftwareBackend;
+use crate::gpu::software::blend::{self, BlendInputs};
+use crate::gpu::software::executor::{DrawKind, Rect, RenderState};
+use crate::gpu::software::rasterizer::{self, RasterFragment, WindowPoint};
+use crate::gpu::software::render_target::{ColorTarget, DepthStencilTarget};
+use crate::gpu::software::sampler::TextureBindings;
+use crate::gpu::software::shading::{FragmentInput, ShadedVertex, SoftwareProgram, VertexInput};
+use crate::gpu::software::texel;
+use crate::gpu::spirv::Interpolation;
+use crate::rendering::pipeline::{
+    CullMode, FrontFace, Pipeline, PolygonMode, RasterizationStateDescriptor, StencilOperation, TextureFormat,
+    VertexFormat,
+};
+
+// Kırpma uzayındaki koruma bandı (guard band): x ve y, NDC'de bu değerin dışına taşarsa kırpılır.
+// Görünüm alanı dışındaki pikseller zaten rasterleştirme sınırlarıyla elenir; bant yalnızca
+// sabit noktalı hesaplamaların taşmamasını sağlar.
+const GUARD_BAND: f32 = 16.0;
+const MIN_CLIP_W: f32 = 1e-6;
+
+// Bir çizim çağrısını çalıştırmak için gereken her şey.
+pub(crate) struct DrawContext<'a> {
+    pub resources: &'a ResourceRegistry<SoftwareBackend>,
+    pub state: &'a RenderState,
+    pub pipeline: &'a Pipeline<SoftwareBackend>,
+    pub program: &'a dyn SoftwareProgram,
+    pub textures: &'a TextureBindings<'a>,
+}
+
+// Çizimin yazdığı hedefler. Derinlik-stencil hedefi yoksa derinlik ve stencil testleri her zaman geçer.
+// Hedeflerin örnek sayıları birbirine eşittir.
+pub(crate) struct Attachments<'a> {
+    pub color: &'a mut dyn ColorTarget,
+    pub depth_stencil: Option<&'a mut dyn DepthStencilTarget>,
+}
+
+impl Attachments<'_> {
+    fn sample_positions(&self) -> &'static [[f32; 2]] {
+        rasterizer::sample_positions(self.color.sample_count())
+    }
+}
+
+// Bir primitifin tüm fragment'larında ortak olan bilgiler.
+#[derive(Debug, Clone, Copy)]
+struct PrimitiveState {
+    front_facing: bool,
+    depth_bias: f32,
+    depth_slope: [f32; 2], // Ekran uzayında (dz/dx, dz/dy); örnek konumlarındaki derinlik için
+}
+
+impl PrimitiveState {
+    const FRONT: PrimitiveState = PrimitiveState { front_facing: true, depth_bias: 0.0, depth_slope: [0.0, 0.0] };
+}
+
+// Vertex çekme (fetch) -> vertex aşaması -> primitif birleştirme -> kırpma -> görünüm dönüşümü
+// -> rasterleştirme -> fragment aşaması -> stencil ve derinlik testleri -> karıştırma -> hedefe yazma.
+pub(crate) fn draw(context: &DrawContext<'_>, kind: DrawKind, target: &mut Attachments<'_>) -> Result<(), GraphisError> {
+    let indices = vertex_indices(context, kind)?;
+    let mut shaded: HashMap<u32, ShadedVertex> = HashMap::new();
+    for index in indices.iter().flatten() {
+        if !shaded.contains_key(index) {
+            let vertex = shade_vertex(context, *index)?;
+            shaded.insert(*index, vertex);
+        }
+    }
+
+    if context.pipeline.multisample.count != target.color.sample_count() {
+        return Err(GraphisError::ValidationFailed(format!(
+            "pipeline renders {} samples per pixel but the render target has {}",
+            context.pipeline.multisample.count,
+            target.color.sample_count()
+        )));
+    }
+    let (width, height) = target.color.size();
+    let Some(bounds) = raster_bounds(context.state, (width, height)) else { return Ok(()) };
+    let viewport = context.state.viewport.unwrap_or(Rect { x: 0, y: 0, width, height });
+    let planes = clip_planes(context);
+
+    let topology = context.state.topology;
+    match topology {
+        PrimitiveTopology::TriangleList | PrimitiveTopology::TriangleStrip => {
+            for triangle in assemble_triangles(topology, &indices) {
+                let mut vertices = triangle.map(|index| shaded[&index].clone());
+                provoke(context.program, &mut vertices);
+                draw_polygon(context, vertices.to_vec(), viewport, bounds, target);
+            }
+        }
+        PrimitiveTopology::LineList | PrimitiveTopology::LineStrip => {
+            for [start, end] in assemble_lines(topology, &indices) {
+                let mut line = [shaded[&start].clone(), shaded[&end].clone()];
+                provoke(context.program, &mut line);
+                if let Some((start, end)) = clip_segment(planes, &line[0], &line[1]) {
+                    let (start, end) = (to_window(&start, viewport), to_window(&end, viewport));
+                    draw_line(context, [&start, &end], PrimitiveState::FRONT, bounds, target);
+                }
+            }
+        }
+        PrimitiveTopology::PointList => {
+            for index in indices.iter().flatten() {
+                let vertex = &shaded[index];
+                if planes.iter().all(|plane| plane_distance(plane, vertex) >= 0.0) {
+                    let window = to_window(vertex, viewport);
+                    draw_point(context, &window, vertex.point_size, PrimitiveState::FRONT, bounds, target);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// Çizilecek vertex indeksleri. Şerit (strip) topolojilerinde indeks tamponundaki en büyük değer
+// primitifi yeniden başlatır (primitive restart) ve None olarak işaretlenir.
+fn vertex_indices(context: &DrawContext<'_>, kind: DrawKind) -> Result<Vec<Option<u32>>, GraphisError> {
+    match kind {
+        DrawKind::NonIndexed { vertex_count } => Ok((0..vertex_count).map(Some).collect()),
+        DrawKind::Indexed { index_count } => {
+            let binding = context
+                .state
+                .index_buffer
+                .ok_or_else(|| GraphisError::InvalidState("indexed draw without a bound index buffer".to_string()))?;
+            let buffer = context.resources.buffer(binding.buffer)?;
+            let memory = buffer
+                .native
+                .memory
+                .try_borrow()
+                .map_err(|_| GraphisError::InvalidState("index buffer is mapped for writing".to_string()))?;
+            let (index_size, restart) = match binding.index_type {
+                IndexType::Uint16 => (2, u16::MAX as u32),
+                IndexType::Uint32 => (4, u32::MAX),
+            };
+            let offset = binding.offset as usize;
+            GraphisError::check_range(offset, index_count as usize * index_size, memory.len())?;
+            let bytes = &memory.as_bytes()[offset..offset + index_count as usize * index_size];
+            let strip = matches!(
+                context.state.topology,
+                PrimitiveTopology::LineStrip | PrimitiveTopology::TriangleStrip
+            );
+            Ok(bytes
+                .chunks_exact(index_size)
+                .map(|chunk| {
+                    let index = match binding.index_type {
+                        IndexType::Uint16 => u16::from_le_bytes([chunk[0], chunk[1]]) as u32,
+                        IndexType::Uint32 => u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]),
+                    };
+                    if strip && index == restart {
+                        None
+                    } else {
+                        Some(index)
+                    }
+                })
+                .collect())
+        }
+    }
+}
+
+// Pipeline'daki vertex düzenine göre attribute'ları okuyup vertex aşamasını çalıştırır.
+// Düzendeki i. tampon, i. slot'a bağlı vertex tamponundan okunur.
+fn shade_vertex(context: &DrawContext<'_>, vertex_index: u32) -> Result<ShadedVertex, GraphisError> {
+    let mut attributes = Vec::new();
+    for (slot, layout) in context.pipeline.vertex.buffers.iter().enumerate() {
+        if layout.attributes.is_empty() {
+            continue;
+        }
+        let binding = context.state.vertex_buffers.get(&(slot as u32)).ok_or_else(|| {
+            GraphisError::InvalidState(format!("no vertex buffer is bound to slot {}", slot))
+        })?;
+        let buffer = context.resources.buffer(binding.buffer)?;
+        let memory = buffer
+            .native
+            .memory
+            .try_borrow()
+            .map_err(|_| GraphisError::InvalidState("vertex buffer is mapped for writing".to_string()))?;
+        // Örnek başına düzenler Pipeline doğrulamasında reddedilir.
+        let base = binding.offset as usize + vertex_index as usize * layout.stride as usize;
+        for attribute in &layout.attributes {
+            let offset = base + attribute.offset as usize;
+            let size = attribute.format.size() as usize;
+            GraphisError::check_range(offset, size, memory.len())?;
+            let value = decode_attribute(attribute.format, &memory.as_bytes()[offset..offset + size]);
+            attributes.push((attribute.location, value));
+        }
+    }
+    attributes.sort_by_key(|(location, _)| *location);
+    Ok(context.program.vertex(&VertexInput {
+        attributes: &attributes,
+        vertex_index,
+        instance_index: 0,
+        uniforms: &context.state.uniforms,
+        textures: context.textures,
+    }))
+}
+
+// Attribute değerini dört bileşene genişletir; eksik bileşenler (0, 0, 0, 1) ile doldurulur. Tamsayı formatları
+// bit deseni olarak taşınır (bkz. VertexInput::attribute).
+fn decode_attribute(format: VertexFormat, bytes: &[u8]) -> [f32; 4] {
+    let word = |i: usize| [bytes[i * 4], bytes[i * 4 + 1], bytes[i * 4 + 2], bytes[i * 4 + 3]];
+    let mut value = [0.0, 0.0, 0.0, 1.0];
+    match format {
+        VertexFormat::Float32x2 | VertexFormat::Float32x3 | VertexFormat::Float32x4 => {
+            for (i, component) in value.iter_mut().enumerate().take(format.size() as usize / 4) {
+                *component = f32::from_le_bytes(word(i));
+            }
+        }
+        VertexFormat::Uint32 | VertexFormat::Sint32 => {
+            value = [u32::from_le_bytes(word(0)), 0, 0, 1].map(f32::from_bits);
+        }
+    }
+    value
+}
+
+// İndeks listesini üçgenlere böler. Şeritlerde tek numaralı üçgenlerin sarım yönü korunur.
+fn assemble_triangles(topology: PrimitiveTopology, indices: &[Option<u32>]) -> Vec<[u32; 3]> {
+    let mut triangles = Vec::new();
+    for run in indices.split(|index| index.is_none()) {
+        let run: Vec<u32> = run.iter().flatten().copied().collect();
+        match topology {
+            PrimitiveTopology::TriangleStrip => {
+                for i in 0..run.len().saturating_sub(2) {
+                    if i % 2 == 0 {
+                        triangles.push([run[i], run[i + 1], run[i + 2]]);
+                    } else {
+                        triangles.push([run[i + 1], run[i], run[i + 2]]);
+                    }
+                }
+            }
+            _ => {
+                for triangle in run.chunks_exact(3) {
+                    triangles.push([triangle[0], triangle[1], triangle[2]]);
+                }
+            }
+        }
+    }
+    triangles
+}
+
+// İndeks listesini doğru parçalarına böler.
+fn assemble_lines(topology: PrimitiveTopology, indices: &[Option<u32>]) -> Vec<[u32; 2]> {
+    let mut lines = Vec::new();
+    for run in indices.split(|index| index.is_none()) {
+        let run: Vec<u32> = run.iter().flatten().copied().collect();
+        match topology {
+            PrimitiveTopology::LineStrip => lines.extend(run.windows(2).map(|line| [line[0], line[1]])),
+            _ => lines.extend(run.chunks_exact(2).map(|line| [line[0], line[1]])),
+        }
+    }
+    lines
+}
+
+// Hedef, görünüm alanı (viewport) ve makas dikdörtgeninin kesişimi.
+fn raster_bounds(state: &RenderState, (width, height): (u32, u32)) -> Option<Rect> {
+    let mut bounds = Rect { x: 0, y: 0, width, height };
+    for rect in [state.viewport, state.scissor].into_iter().flatten() {
+        bounds = intersect(bounds, rect)?;
+    }
+    Some(bounds)
+}
+
+fn intersect(a: Rect, b: Rect) -> Option<Rect> {
+    let x0 = a.x.max(b.x);
+    let y0 = a.y.max(b.y);
+    let x1 = a.x.saturating_add(a.width).min(b.x.saturating_add(b.width));
+    let y1 = a.y.saturating_add(a.height).min(b.y.saturating_add(b.height));
+    if x0 >= x1 || y0 >= y1 {
+        None
+    } else {
+        Some(Rect { x: x0, y: y0, width: x1 - x0, height: y1 - y0 })
+    }
+}
+
+// Görünüm dönüşümünden geçmiş vertex.
+struct WindowVertex<'a> {
+    point: WindowPoint,
+    depth: f32,
+    inv_w: f32,
+    varyings: &'a [[f32; 4]],
+}
+
+// Kırpılmış üçgeni, pipeline'ın çokgen kipine (dolu, tel kafes veya nokta) göre çizer.
+fn draw_polygon(
+    context: &DrawContext<'_>,
+    vertices: Vec<ShadedVertex>,
+    viewport: Rect,
+    bounds: Rect,
+    target: &mut Attachments<'_>,
+) {
+    let polygon = clip_polygon(clip_planes(context), vertices);
+    if polygon.len() < 3 {
+        return;
+    }
+    let window: Vec<WindowVertex<'_>> = polygon.iter().map(|vertex| to_window(vertex, viewport)).collect();
+    let points: Vec<WindowPoint> = window.iter().map(|vertex| vertex.point).collect();
+    let area = rasterizer::signed_area(&points);
+    if area == 0.0 {
+        return;
+    }
+    let rasterization = &context.pipeline.rasterization;
+    let counter_clockwise = area < 0.0;
+    let front_facing = counter_clockwise == (rasterization.front_face == FrontFace::CounterClockwise);
+    let culled = match rasterization.cull_mode {
+        CullMode::None => false,
+        CullMode::Front => front_facing,
+        CullMode::Back => !front_facing,
+    };
+    if culled {
+        return;
+    }
+    let format = target.depth_stencil.as_ref().map(|depth_stencil| depth_stencil.format());
+    let depth_slope = depth_slope(&window);
+    let depth_bias = depth_bias(rasterization, &window, depth_slope, format);
+    let primitive = PrimitiveState { front_facing, depth_bias, depth_slope };
+    match rasterization.polygon_mode {
+        PolygonMode::Fill => {
+            let samples = target.sample_positions();
+            for i in 1..window.len() - 1 {
+                let triangle = [&window[0], &window[i], &window[i + 1]];
+                let points = [triangle[0].point, triangle[1].point, triangle[2].point];
+                rasterizer::rasterize_triangle(&points, bounds, samples, |fragment| {
+                    shade_fragment(context, &triangle, fragment, primitive, [0.0, 0.0], target);
+                });
+            }
+        }
+        // Kırpma sonucu oluşan kenarlar da çizilir.
+        PolygonMode::Line => {
+            for i in 0..window.len() {
+                let edge = [&window[i], &window[(i + 1) % window.len()]];
+                draw_line(context, edge, primitive, bounds, target);
+            }
+        }
+        PolygonMode::Point => {
+            for (vertex, shaded) in window.iter().zip(&polygon) {
+                draw_point(context, vertex, shaded.point_size, primitive, bounds, target);
+            }
+        }
+    }
+}
+
+fn draw_line(
+    context: &DrawContext<'_>,
+    line: [&WindowVertex<'_>; 2],
+    primitive: PrimitiveState,
+    bounds: Rect,
+    target: &mut Attachments<'_>,
+) {
+    rasterizer::rasterize_line(line[0].point, line[1].point, bounds, |fragment| {
+        shade_fragment(context, &line, fragment, primitive, [0.0, 0.0], target);
+    });
+}
+
+fn draw_point(
+    context: &DrawContext<'_>,
+    vertex: &WindowVertex<'_>,
+    size: f32,
+    primitive: PrimitiveState,
+    bounds: Rect,
+    target: &mut Attachments<'_>,
+) {
+    let samples = target.sample_positions();
+    rasterizer::rasterize_point(vertex.point, size, bounds, samples, |fragment, point_coord| {
+        shade_fragment(context, &[vertex], fragment, primitive, point_coord, target);
+    });
+}
+
+fn to_window(vertex: &ShadedVertex, viewport: Rect) -> WindowVertex<'_> {
+    let [x, y, z, w] = vertex.position;
+    let inv_w = 1.0 / w;
+    WindowVertex {
+        point: WindowPoint {
+            x: viewport.x as f32 + (x * inv_w + 1.0) * 0.5 * viewport.width as f32,
+            y: viewport.y as f32 + (1.0 - y * inv_w) * 0.5 * viewport.height as f32,
+        },
+        depth: z * inv_w,
+        inv_w,
+        varyings: &vertex.varyings,
+    }
+}
+
+// Üçgen derinlik sapması (depth bias): sabit * r + eğim çarpanı * en büyük derinlik eğimi.
+// r, derinlik formatında ayırt edilebilen en küçük farktır; kayan noktalı formatta primitifin
+// en büyük derinliğinin üssüne göre hesaplanır. Sıfırdan farklı kırpma değeri sonucu sınırlar.
+fn depth_bias(
+    rasterization: &RasterizationStateDescriptor,
+    window: &[WindowVertex<'_>],
+    depth_slope: [f32; 2],
+    format: Option<TextureFormat>,
+) -> f32 {
+    if rasterization.depth_bias == 0.0 && rasterization.depth_bias_slope_factor == 0.0 {
+        return 0.0;
+    }
+    let resolution = match format {
+        Some(TextureFormat::Depth32Float | TextureFormat::Depth32FloatStencil8) => {
+            let max_depth = window.iter().map(|vertex| vertex.depth.abs()).fold(0.0f32, f32::max);
+            let exponent = ((max_depth.to_bits() >> 23) & 0xFF) as i32 - 127;
+            2f32.powi(exponent.max(-126) - 23)
+        }
+        Some(TextureFormat::Depth16Unorm) => 1.0 / 65_536.0,
+        _ => 1.0 / 16_777_216.0,
+    };
+    let slope = depth_slope[0].abs().max(depth_slope[1].abs());
+    let bias = rasterization.depth_bias * resolution + rasterization.depth_bias_slope_factor * slope;
+    let clamp = rasterization.depth_bias_clamp;
+    if clamp > 0.0 {
+        bias.min(clamp)
+    } else if clamp < 0.0 {
+        bias.max(clamp)
+    } else {
+        bias
+    }
+}
+
+// Derinliğin ekran uzayındaki eğimi (dz/dx, dz/dy). Çokgen düzlemsel olduğundan eğim, alanı sıfır
+// olmayan ilk yelpaze üçgeninden hesaplanır.
+fn depth_slope(window: &[WindowVertex<'_>]) -> [f32; 2] {
+    (1..window.len() - 1)
+        .find_map(|i| {
+            let (a, b, c) = (&window[0], &window[i], &window[i + 1]);
+            let (abx, aby) = (b.point.x - a.point.x, b.point.y - a.point.y);
+            let (acx, acy) = (c.point.x - a.point.x, c.point.y - a.point.y);
+            let area = abx * acy - acx * aby;
+            if area == 0.0 {
+                return None;
+            }
+            let (dab, dac) = (b.depth - a.depth, c.depth - a.depth);
+            Some([(dab * acy - dac * aby) / area, (dac * abx - dab * acx) / area])
+        })
+        .unwrap_or([0.0, 0.0])
+}
+
+// `fragment.barycentric` ağırlıkları `vertices` sırasına göredir (doğrularda 2, noktalarda 1 vertex).
+// Fragment aşaması piksel başına bir kez çalışır; derinlik, stencil ve karıştırma ise kapsanan her örnek
+// için ayrı yapılır. Kapsama; rasterleştirici, pipeline'ın örnek maskesi ve isteğe bağlı olarak
+// alfa-kapsama (alpha-to-coverage) maskesinin kesişimidir. Atılan (discard) fragment'lar hiçbir şey yazmaz.
+fn shade_fragment(
+    context: &DrawContext<'_>,
+    vertices: &[&WindowVertex<'_>],
+    fragment: RasterFragment,
+    primitive: PrimitiveState,
+    point_coord: [f32; 2],
+    target: &mut Attachments<'_>,
+) {
+    let sample_count = target.color.sample_count();
+    let multisample = &context.pipeline.multisample;
+    let mut coverage = fragment.coverage & rasterizer::full_coverage(sample_count) & multisample.mask;
+    if coverage == 0 {
+        return;
+    }
+    let b = &fragment.barycentric[..vertices.len()];
+    let inv_w: f32 = vertices.iter().zip(b).map(|(vertex, b)| b * vertex.inv_w).sum();
+    // z / w ekran uzayında doğrusaldır. Sonuç, kırpılmamış derinlikte de görünüm alanının
+    // derinlik aralığına sıkıştırılır.
+    let center_depth: f32 = vertices.iter().zip(b).map(|(vertex, b)| b * vertex.depth).sum();
+    let depth_at = |[sx, sy]: [f32; 2]| {
+        let [dz_dx, dz_dy] = primitive.depth_slope;
+        (center_depth + dz_dx * (sx - 0.5) + dz_dy * (sy - 0.5) + primitive.depth_bias).clamp(0.0, 1.0)
+    };
+    let program = context.program;
+    let varyings = interpolate_varyings(program, vertices, b);
+    let derivatives = if context.textures.is_empty() && !program.uses_derivatives() {
+        Vec::new()
+    } else {
+        varying_derivatives(program, vertices, [fragment.x as f32 + 0.5, fragment.y as f32 + 0.5], &varyings)
+    };
+    let input = FragmentInput {
+        frag_coord: [fragment.x as f32 + 0.5, fragment.y as f32 + 0.5, depth_at([0.5, 0.5]), inv_w],
+        front_facing: primitive.front_facing,
+        point_coord,
+        varyings: &varyings,
+        derivatives: &derivatives,
+        uniforms: &context.state.uniforms,
+        textures: context.textures,
+    };
+    let Some(output) = program.fragment(&input) else { return };
+    // Pipeline'lar tek renk hedefine yazar (bkz. PipelineDescriptor::validate).
+    let source = output.colors.first().copied();
+    // Programın yazdığı derinlik tüm örneklerde aynıdır; derinlik sapması uygulanmaz.
+    let fragment_depth = output.depth.map(|depth| depth.clamp(0.0, 1.0));
+    if multisample.alpha_to_coverage_enabled {
+        coverage &= alpha_coverage(source.map_or(1.0, |color| color[3]), sample_count);
+    }
+
+    let positions = rasterizer::sample_positions(sample_count);
+    for (sample, position) in positions.iter().enumerate() {
+        if coverage & (1 << sample) == 0 {
+            continue;
+        }
+        let sample = sample as u32;
+        if let Some(depth_stencil) = target.depth_stencil.as_deref_mut() {
+            let coordinates = (fragment.x, fragment.y, sample);
+            let depth = fragment_depth.unwrap_or_else(|| depth_at(*position));
+            if !depth_stencil_test(context, primitive, coordinates, depth, depth_stencil) {
+                continue;
+            }
+        }
+        if let Some(source) = source {
+            let state = context.state;
+            let blend_state = state.blend.first().copied().unwrap_or_default();
+            let destination = target.color.read(fragment.x, fragment.y, sample);
+            let destination = [destination.r, destination.g, destination.b, destination.a];
+            let constant = state.blend_constant;
+            let inputs = BlendInputs {
+                source,
+                second_source: output.second_color.unwrap_or([0.0; 4]),
+                destination,
+                constant: [constant.r, constant.g, constant.b, constant.a],
+            };
+            let color = blend::blend(&blend_state, &inputs);
+            let [r, g, b, a] = blend::write_masked(blend_state.write_mask, color, destination);
+            target.color.write(fragment.x, fragment.y, sample, ColorHDRA::rgba(r, g, b, a));
+        }
+    }
+}
+
+// Perspektif doğru enterpolasyonda değer / w doğrusal enterpole edilip 1 / w'ye bölünür; doğrusal (NoPerspective)
+// varying'ler ekran uzayında enterpole edilir, düz (flat) varying'ler ilk vertex'in değerini alır.
+fn interpolate_varyings(
+    program: &dyn SoftwareProgram,
+    vertices: &[&WindowVertex<'_>],
+    barycentric: &[f32],
+) -> Vec<[f32; 4]> {
+    let inv_w: f32 = vertices.iter().zip(barycentric).map(|(vertex, b)| b * vertex.inv_w).sum();
+    let weights: Vec<f32> = vertices.iter().zip(barycentric).map(|(vertex, b)| b * vertex.inv_w / inv_w).collect();
+    (0..vertices[0].varyings.len())
+        .map(|k| {
+            let weights = match program.interpolation(k) {
+                Interpolation::Flat => return vertices[0].varyings[k],
+                Interpolation::Linear => barycentric,
+                Interpolation::Perspective => &weights[..],
+            };
+            let mut value = [0.0; 4];
+            for (vertex, weight) in vertices.iter().zip(weights) {
+                for (component, source) in value.iter_mut().zip(vertex.varyings[k]) {
+                    *component += weight * source;
+                }
+            }
+            value
+        })
+        .collect()
+}
+
+// Üçgenlerde varying'lerin bir sağdaki ve bir alttaki piksel merkezindeki değerlerle farkı. Komşu pikseller
+// üçgenin dışında kalsa da düzlem denklemi geçerli olduğundan ağırlıklar üçgenin dışına uzatılır.
+fn varying_derivatives(
+    program: &dyn SoftwareProgram,
+    vertices: &[&WindowVertex<'_>],
+    [x, y]: [f32; 2],
+    varyings: &[[f32; 4]],
+) -> Vec<([f32; 4], [f32; 4])> {
+    let &[a, b, c] = vertices else {
+        return vec![([0.0; 4], [0.0; 4]); varyings.len()];
+    };
+    let area = rasterizer::signed_area(&[a.point, b.point, c.point]);
+    let at = |px: f32, py: f32| {
+        let point = WindowPoint { x: px, y: py };
+        let weights = [
+            rasterizer::signed_area(&[point, b.point, c.point]) / area,
+            rasterizer::signed_area(&[a.point, point, c.point]) / area,
+            rasterizer::signed_area(&[a.point, b.point, point]) / area,
+        ];
+        interpolate_varyings(program, vertices, &weights)
+    };
+    let (right, below) = (at(x + 1.0, y), at(x, y + 1.0));
+    let difference = |from: &[f32; 4], to: &[f32; 4]| [0, 1, 2, 3].map(|i| to[i] - from[i]);
+    varyings
+        .iter()
+        .zip(right.iter().zip(&below))
+        .map(|(value, (right, below))| (difference(value, right), difference(value, below)))
+        .collect()
+}
+
+// Düz (flat) varying'ler primitifin ilk (provoking) vertex'inden alınır. Kırpmanın oluşturduğu vertex'ler de
+// aynı değeri taşısın diye değer kırpmadan önce diğer vertex'lere kopyalanır.
+fn provoke(program: &dyn SoftwareProgram, vertices: &mut [ShadedVertex]) {
+    let Some((first, rest)) = vertices.split_first_mut() else { return };
+    for (k, value) in first.varyings.iter().enumerate() {
+        if program.interpolation(k) == Interpolation::Flat {
+            for varying in rest.iter_mut().filter_map(|vertex| vertex.varyings.get_mut(k)) {
+                *varying = *value;
+            }
+        }
+    }
+}
+
+// Alfa değeriyle orantılı sayıda örneği kapsayan maske (ilk örneklerden başlayarak).
+fn alpha_coverage(alpha: f32, sample_count: u32) -> u32 {
+    let covered = (alpha.clamp(0.0, 1.0) * sample_count as f32).round() as u32;
+    rasterizer::full_coverage(covered)
+}
+
+// Önce stencil, ardından derinlik testi yapılır; stencil değeri sonuca göre fail_op, depth_fail_op
+// veya pass_op ile güncellenir. Stencil testi pipeline'da derinlik-stencil durumu varsa ve hedef
+// formatı stencil içeriyorsa uygulanır. Örneğin çizilmeye devam edip etmeyeceğini döndürür.
+fn depth_stencil_test(
+    context: &DrawContext<'_>,
+    primitive: PrimitiveState,
+    (x, y, sample): (u32, u32, u32),
+    depth: f32,
+    target: &mut dyn DepthStencilTarget,
+) -> bool {
+    let state = context.state;
+    let stencil = context
+        .pipeline
+        .depth_stencil
+        .as_ref()
+        .filter(|_| target.format().has_stencil())
+        .map(|depth_stencil| {
+            let face = if primitive.front_facing { depth_stencil.stencil_front } else { depth_stencil.stencil_back };
+            (depth_stencil, face)
+        });
+    let stored = target.read_stencil(x, y, sample);
+    let reference = state.stencil_reference as u8;
+    let update_stencil = |target: &mut dyn DepthStencilTarget, operation: StencilOperation| {
+        if let Some((depth_stencil, _)) = stencil {
+            let mask = depth_stencil.stencil_write_mask as u8;
+            let value = operation.apply(stored, reference);
+            target.write_stencil(x, y, sample, (stored & !mask) | (value & mask));
+        }
+    };
+
+    if let Some((depth_stencil, face)) = stencil {
+        let mask = depth_stencil.stencil_read_mask as u8;
+        if !face.compare.passes(reference & mask, stored & mask) {
+            update_stencil(target, face.fail_op);
+            return false;
+        }
+    }
+    // Derinlik bileşeni olmayan hedeflerde (Stencil8) derinlik testi her zaman geçer.
+    let depth_test = state.depth_test_enabled && target.format().is_depth();
+    let depth = texel::quantize_depth(target.format(), depth);
+    let depth_passed = !depth_test || state.depth_compare.passes(depth, target.read_depth(x, y, sample));
+    if let Some((_, face)) = stencil {
+        update_stencil(target, if depth_passed { face.pass_op } else { face.depth_fail_op });
+    }
+    if depth_passed && depth_test && state.depth_write_enabled {
+        target.write_depth(x, y, sample, depth);
+    }
+    depth_passed
+}
+
+// Kırpma uzayındaki düzlemler; dot(düzlem, konum) + sapma >= 0 içeride demektir.
+// İlk iki düzlem yakın/uzak düzlemlerdir ve `unclipped_depth` açıkken atlanır.
+const CLIP_PLANES: [ClipPlane; 7] = [
+    ([0.0, 0.0, 1.0, 0.0], 0.0),          // z >= 0
+    ([0.0, 0.0, -1.0, 1.0], 0.0),         // z <= w
+    ([0.0, 0.0, 0.0, 1.0], -MIN_CLIP_W),  // w >= MIN_CLIP_W
+    ([1.0, 0.0, 0.0, GUARD_BAND], 0.0),   // x >= -bant * w
+    ([-1.0, 0.0, 0.0, GUARD_BAND], 0.0),  // x <= bant * w
+    ([0.0, 1.0, 0.0, GUARD_BAND], 0.0),   // y >= -bant * w
+    ([0.0, -1.0, 0.0, GUARD_BAND], 0.0),  // y <= bant * w
+];
+
+type ClipPlane = ([f32; 4], f32);
+
+fn clip_planes(context: &DrawContext<'_>) -> &'static [ClipPlane] {
+    if context.pipeline.primitive.unclipped_depth {
+        &CLIP_PLANES[2..]
+    } else {
+        &CLIP_PLANES
+    }
+}
+
+fn plane_distance(plane: &ClipPlane, vertex: &ShadedVertex) -> f32 {
+    let (normal, offset) = plane;
+    normal.iter().zip(vertex.position).map(|(n, c)| n * c).sum::<f32>() + offset
+}
+
+// Çokgeni kırpma uzayında yakın/uzak düzlemlere ve koruma bandına göre kırpar (Sutherland-Hodgman).
+fn clip_polygon(planes: &[ClipPlane], mut polygon: Vec<ShadedVertex>) -> Vec<ShadedVertex> {
+    for plane in planes {
+        if polygon.is_empty() {
+            break;
+        }
+        let mut clipped = Vec::with_capacity(polygon.len() + 1);
+        for j in 0..polygon.len() {
+            let current = &polygon[j];
+            let next = &polygon[(j + 1) % polygon.len()];
+            let (d_current, d_next) = (plane_distance(plane, current), plane_distance(plane, next));
+            if d_current >= 0.0 {
+                clipped.push(current.clone());
+            }
+            if (d_current >= 0.0) != (d_next >= 0.0) {
+                clipped.push(lerp_vertex(current, next, d_current / (d_current - d_next)));
+            }
+        }
+        polygon = clipped;
+    }
+    polygon
+}
+
+// Doğru parçasını aynı düzlemlere göre kırpar; tamamen dışarıdaysa None döner.
+fn clip_segment(
+    planes: &[ClipPlane],
+    start: &ShadedVertex,
+    end: &ShadedVertex,
+) -> Option<(ShadedVertex, ShadedVertex)> {
+    let (mut t_start, mut t_end) = (0.0f32, 1.0f32);
+    for plane in planes {
+        let (d_start, d_end) = (plane_distance(plane, start), plane_distance(plane, end));
+        match (d_start >= 0.0, d_end >= 0.0) {
+            (false, false) => return None,
+            (true, false) => t_end = t_end.min(d_start / (d_start - d_end)),
+            (false, true) => t_start = t_start.max(d_start / (d_start - d_end)),
+            (true, true) => {}
+        }
+    }
+    if t_start > t_end {
+        return None;
+    }
+    Some((lerp_vertex(start, end, t_start), lerp_vertex(start, end, t_end)))
+}
+
+fn lerp_vertex(a: &ShadedVertex, b: &ShadedVertex, t: f32) -> ShadedVertex {
+    // Eşit bileşenler olduğu gibi kopyalanır; düz (flat) tamsayı varying'lerinin bit deseni NaN olabilir.
+    let lerp = |x: f32, y: f32| if x.to_bits() == y.to_bits() { x } else { x + (y - x) * t };
+    let lerp4 = |x: [f32; 4], y: [f32; 4]| [0, 1, 2, 3].map(|i| lerp(x[i], y[i]));
+    ShadedVertex {
+        position: lerp4(a.position, b.position),
+        point_size: a.point_size + (b.point_size - a.point_size) * t,
+        varyings: a.varyings.iter().zip(&b.varyings).map(|(x, y)| lerp4(*x, *y)).collect(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,1299 @@
+use std::cell::RefMut;
+use std::collections::HashMap;
+
+use crate::core::color::ColorHDRA;
+use crate::core::math::{Mat4, Vec2, Vec3};
+use crate::gpu::command_buffer::CommandBuffer;
+use crate::gpu::commands::{
+    BlendFactor, BlendOperation, CompareFunction, IndexType, PrimitiveTopology, RenderCommand, RenderTargetBinding,
+};
+use crate::gpu::error::GraphisError;
+use crate::gpu::resource::{BufferHandle, PipelineHandle, ResourceRegistry, SamplerHandle, TextureHandle};
+use crate::gpu::sampler::SamplerDescriptor;
+use crate::gpu::software::backend::SoftwareBackend;
+use crate::gpu::software::draw::{self, Attachments, DrawContext};
+use crate::gpu::software::render_target::{
+    ColorTarget, DepthStencilBuffer, DepthStencilTarget, NullColorTarget, RenderTarget,
+};
+use crate::gpu::software::sampler::{SampledTexture, TextureBindings};
+use crate::gpu::software::shading;
+use crate::gpu::software::texture::{TextureStorage, TextureTarget};
+use crate::gpu::texture::TextureView;
+use crate::rendering::pipeline::{self, ColorTargetStateDescriptor, ColorWrite};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UniformValue {
+    Int(i32),
+    Float(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    Vec4((f32, f32, f32, f32)),
+    Mat4(Mat4),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexBufferBinding {
+    pub buffer: BufferHandle,
+    pub offset: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexBufferBinding {
+    pub buffer: BufferHandle,
+    pub index_type: IndexType,
+    pub offset: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlendFunction {
+    pub src_factor: BlendFactor,
+    pub dst_factor: BlendFactor,
+    pub operation: BlendOperation,
+}
+
+impl BlendFunction {
+    // Kaynağı olduğu gibi yazan denklem (One, Zero, Add).
+    pub const REPLACE: BlendFunction =
+        BlendFunction { src_factor: BlendFactor::One, dst_factor: BlendFactor::Zero, operation: BlendOperation::Add };
+}
+
+// Tek bir renk hedefinin karıştırma durumu ve yazma maskesi.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorTargetBlend {
+    pub enabled: bool,
+    pub color: BlendFunction,
+    pub alpha: BlendFunction,
+    pub write_mask: ColorWrite,
+}
+
+impl Default for ColorTargetBlend {
+    fn default() -> Self {
+        ColorTargetBlend {
+            enabled: false,
+            color: BlendFunction::REPLACE,
+            alpha: BlendFunction::REPLACE,
+            write_mask: ColorWrite::ALL,
+        }
+    }
+}
+
+impl From<&ColorTargetStateDescriptor> for ColorTargetBlend {
+    fn from(target: &ColorTargetStateDescriptor) -> Self {
+        let function = |component: &pipeline::BlendComponent| BlendFunction {
+            src_factor: component.src_factor.into(),
+            dst_factor: component.dst_factor.into(),
+            operation: component.operation.into(),
+        };
+        match &target.blend {
+            Some(blend) => ColorTargetBlend {
+                enabled: true,
+                color: function(&blend.color),
+                alpha: function(&blend.alpha),
+                write_mask: target.write_mask,
+            },
+            None => ColorTargetBlend { write_mask: target.write_mask, ..Default::default() },
+        }
+    }
+}
+
+// Hata ayıklama derlemelerinde atılan (DontCare) eklere yazılan değerler.
+const DISCARDED_COLOR: ColorHDRA = ColorHDRA { r: 1.0, g: 0.0, b: 1.0, a: 1.0 };
+const DISCARDED_DEPTH: f32 = 0.5;
+const DISCARDED_STENCIL: u8 = 0xA5;
+
+// Komutlar tarafından değiştirilen ve çizimler sırasında okunan sabit fonksiyon durumu.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderState {
+    pub render_target: RenderTargetBinding,
+    pub depth_stencil_target: Option<TextureView>, // None: varsayılan derinlik tamponu
+    pub viewport: Option<Rect>,   // None: hedefin tamamı
+    pub scissor: Option<Rect>,    // None: makas testi kapalı
+    pub vertex_buffers: HashMap<u32, VertexBufferBinding>, // slot -> bağlama
+    pub index_buffer: Option<IndexBufferBinding>,
+    pub program: Option<PipelineHandle>,
+    pub uniforms: HashMap<u32, UniformValue>, // konum -> değer
+    pub textures: HashMap<u32, TextureView>, // texture unit -> doku görünümü
+    pub samplers: HashMap<u32, SamplerHandle>, // texture unit -> sampler
+    pub depth_test_enabled: bool,
+    pub depth_write_enabled: bool,
+    pub depth_compare: CompareFunction,
+    pub stencil_reference: u32,
+    pub blend: Vec<ColorTargetBlend>, // Renk hedefi konumuna göre
+    pub blend_constant: ColorHDRA,
+    pub topology: PrimitiveTopology,
+}
+
+impl Default for RenderState {
+    fn default() -> Self {
+        RenderState {
+            render_target: RenderTargetBinding::Framebuffer,
+            depth_stencil_target: None,
+            viewport: None,
+            scissor: None,
+            vertex_buffers: HashMap::new(),
+            index_buffer: None,
+            program: None,
+            uniforms: HashMap::new(),
+            textures: HashMap::new(),
+            samplers: HashMap::new(),
+            depth_test_enabled: false,
+            depth_write_enabled: true,
+            depth_compare: CompareFunction::Less,
+            stencil_reference: 0,
+            blend: vec![ColorTargetBlend::default()],
+            blend_constant: ColorHDRA::rgba(0.0, 0.0, 0.0, 0.0),
+            topology: PrimitiveTopology::TriangleList,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawKind {
+    NonIndexed { vertex_count: u32 },
+    Indexed { index_count: u32 },
+}
+
+// Kayıt açıkken (set_record_draws) çalıştırılan her çizim çağrısı, o anki durumun bir kopyasıyla birlikte kaydedilir.
+// Böylece testler hangi durumla çizim yapıldığını doğrulayabilir.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrawCall {
+    pub kind: DrawKind,
+    pub state: RenderState,
+}
+
+// RenderCommand akışını CPU üzerindeki render hedeflerine karşı çalıştıran referans yürütücü.
+// Komutlardaki tanımlayıcılar, cihazın kaynak kayıt defteri üzerinden çözümlenir.
+#[derive(Debug)]
+pub struct SoftwareExecutor {
+    framebuffer: RenderTarget, // Varsayılan framebuffer
+    depth_buffer: DepthStencilBuffer, // Varsayılan framebuffer'ın derinlik tamponu
+    state: RenderState,
+    record_draws: bool, // Varsayılan olarak kapalıdır; açıkken draw_calls sınırsız büyür
+    draw_calls: Vec<DrawCall>,
+}
+
+impl SoftwareExecutor {
+    pub fn new(width: u32, height: u32) -> Self {
+        SoftwareExecutor {
+            framebuffer: RenderTarget::new(width, height),
+            depth_buffer: DepthStencilBuffer::new(width, height),
+            state: RenderState::default(),
+            record_draws: false,
+            draw_calls: Vec::new(),
+        }
+    }
+
+    // Varsayılan framebuffer'ı ve derinlik tamponunu yeniden boyutlandırır; içerikleri sıfırlanır.
+    pub fn resize_framebuffer(&mut self, width: u32, height: u32) {
+        self.framebuffer = RenderTarget::new(width, height);
+        self.depth_buffer = DepthStencilBuffer::new(width, height);
+    }
+
+    pub fn framebuffer(&self) -> &RenderTarget {
+        &self.framebuffer
+    }
+
+    pub fn depth_buffer(&self) -> &DepthStencilBuffer {
+        &self.depth_buffer
+    }
+
+    pub fn state(&self) -> &RenderState {
+        &self.state
+    }
+
+    // Çizim çağrılarının kaydedilip kaydedilmeyeceğini belirler; kapatmak mevcut kayıtları silmez.
+    pub fn set_record_draws(&mut self, record: bool) {
+        self.record_draws = record;
+    }
+
+    pub fn draw_calls(&self) -> &[DrawCall] {
+        &self.draw_calls
+    }
+
+    // Kaydedilen çizim çağrılarını döndürür ve listeyi boşaltır.
+    pub fn take_draw_calls(&mut self) -> Vec<DrawCall> {
+        std::mem::take(&mut self.draw_calls)
+    }
+
+    // Gönderilen komut arabelleklerini sırayla çalıştırır.
+    // Durum, arabellekler arasında korunur (tek bir kuyruk gibi davranır).
+    pub fn submit(
+        &mut self,
+        resources: &ResourceRegistry<SoftwareBackend>,
+        command_buffers: &[CommandBuffer],
+    ) -> Result<(), GraphisError> {
+        for command_buffer in command_buffers {
+            if command_buffer.is_recording() {
+                return Err(GraphisError::InvalidState(
+                    "cannot execute a command buffer that is still recording".to_string(),
+                ));
+            }
+            self.execute(resources, command_buffer.get_commands())?;
+        }
+        Ok(())
+    }
+
+    pub fn execute(
+        &mut self,
+        resources: &ResourceRegistry<SoftwareBackend>,
+        commands: &[RenderCommand],
+    ) -> Result<(), GraphisError> {
+        for command in commands {
+            self.execute_command(resources, command)?;
+        }
+        Ok(())
+    }
+
+    fn execute_command(
+        &mut self,
+        resources: &ResourceRegistry<SoftwareBackend>,
+        command: &RenderCommand,
+    ) -> Result<(), GraphisError> {
+        resources.validate_command(command)?;
+        match *command {
+            RenderCommand::Clear8Bit { color } => {
+                let (r, g, b) = color.as_f32();
+                self.clear(resources, ColorHDRA::rgba(r, g, b, 1.0))
+            }
+            RenderCommand::Clear10Bit { color } => {
+                let (r, g, b) = color.as_f32();
+                self.clear(resources, ColorHDRA::rgba(r, g, b, 1.0))
+            }
+            RenderCommand::Clear12Bit { color } => {
+                let (r, g, b) = color.as_f32();
+                self.clear(resources, ColorHDRA::rgba(r, g, b, 1.0))
+            }
+            RenderCommand::ClearHDR { color } => self.clear(resources, ColorHDRA::rgba(color.r, color.g, color.b, 1.0)),
+            RenderCommand::ClearDepth { depth } => self.clear_depth(resources, depth),
+            RenderCommand::ClearStencil { stencil } => self.clear_stencil(resources, stencil as u8),
+            RenderCommand::Draw { vertex_count } => self.draw(resources, DrawKind::NonIndexed { vertex_count }),
+            RenderCommand::DrawIndexed { index_count } => {
+                if self.state.index_buffer.is_none() {
+                    return Err(GraphisError::InvalidState("indexed draw without a bound index buffer".to_string()));
+                }
+                self.draw(resources, DrawKind::Indexed { index_count })
+            }
+            RenderCommand::SetViewport { x, y, width, height } => {
+                self.state.viewport = Some(Rect { x, y, width, height });
+                Ok(())
+            }
+            RenderCommand::SetScissorRect { x, y, width, height } => {
+                self.state.scissor = Some(Rect { x, y, width, height });
+                Ok(())
+            }
+            RenderCommand::BindVertexBuffer { buffer, slot, offset } => {
+                self.state.vertex_buffers.insert(slot, VertexBufferBinding { buffer, offset });
+                Ok(())
+            }
+            RenderCommand::BindIndexBuffer { buffer, index_type, offset } => {
+                self.state.index_buffer = Some(IndexBufferBinding { buffer, index_type, offset });
+                Ok(())
+            }
+            RenderCommand::BindShaderProgram { program } => {
+                // Pipeline'ın sabit durumu dinamik duruma yüklenir; sonraki Set* komutları bunu geçersiz kılabilir.
+                let pipeline = resources.pipeline(program)?;
+                self.state.topology = match pipeline.primitive.topology {
+                    pipeline::PrimitiveTopology::PointList => PrimitiveTopology::PointList,
+                    pipeline::PrimitiveTopology::LineList => PrimitiveTopology::LineList,
+                    pipeline::PrimitiveTopology::LineStrip => PrimitiveTopology::LineStrip,
+                    pipeline::PrimitiveTopology::TriangleList => PrimitiveTopology::TriangleList,
+                    pipeline::PrimitiveTopology::TriangleStrip => PrimitiveTopology::TriangleStrip,
+                };
+                match &pipeline.depth_stencil {
+                    Some(depth_stencil) => {
+                        self.state.depth_test_enabled = true;
+                        self.state.depth_write_enabled = depth_stencil.depth_write_enabled;
+                        self.state.depth_compare = depth_stencil.depth_compare;
+                    }
+                    None => self.state.depth_test_enabled = false,
+                }
+                // Renk hedefi tanımlamayan pipeline'lar varsayılan framebuffer'a karıştırmasız yazar.
+                self.state.blend = match pipeline.color_targets() {
+                    [] => vec![ColorTargetBlend::default()],
+                    targets => targets.iter().map(ColorTargetBlend::from).collect(),
+                };
+                self.state.program = Some(program);
+                Ok(())
+            }
+            RenderCommand::SetUniformInt { location, value } => self.set_uniform(location, UniformValue::Int(value)),
+            RenderCommand::SetUniformFloat { location, value } => self.set_uniform(location, UniformValue::Float(value)),
+            RenderCommand::SetUniformVec2 { location, value } => self.set_uniform(location, UniformValue::Vec2(value)),
+            RenderCommand::SetUniformVec3 { location, value } => self.set_uniform(location, UniformValue::Vec3(value)),
+            RenderCommand::SetUniformVec4 { location, value } => self.set_uniform(location, UniformValue::Vec4(value)),
+            RenderCommand::SetUniformMat4 { location, value } => self.set_uniform(location, UniformValue::Mat4(value)),
+            RenderCommand::BindTexture { texture, unit } => {
+                self.state.textures.insert(unit, texture);
+                Ok(())
+            }
+            RenderCommand::BindSampler { sampler, unit } => {
+                self.state.samplers.insert(unit, sampler);
+                Ok(())
+            }
+            RenderCommand::SetRenderTarget { render_target } => {
+                if let Some(view) = render_target.view() {
+                    view.validate_attachment()?;
+                    let format = view.format();
+                    if !format.is_renderable() {
+                        return Err(GraphisError::ValidationFailed(format!(
+                            "texture format {:?} cannot be used as a render target",
+                            format
+                        )));
+                    }
+                }
+                self.state.render_target = render_target;
+                Ok(())
+            }
+            RenderCommand::SetDepthStencilTarget { depth_stencil_target } => {
+                if let Some(view) = depth_stencil_target {
+                    view.validate_attachment()?;
+                    let format = view.format();
+                    if !format.is_depth_stencil() {
+                        return Err(GraphisError::ValidationFailed(format!(
+                            "texture format {:?} cannot be used as a depth-stencil target",
+                            format
+                        )));
+                    }
+                }
+                self.state.depth_stencil_target = depth_stencil_target;
+                Ok(())
+            }
+            RenderCommand::SetDepthTestEnabled { enabled } => {
+                self.state.depth_test_enabled = enabled;
+                Ok(())
+            }
+            RenderCommand::SetDepthMaskEnabled { enabled } => {
+                self.state.depth_write_enabled = enabled;
+                Ok(())
+            }
+            RenderCommand::SetDepthCompareFunction { function } => {
+                self.state.depth_compare = function;
+                Ok(())
+            }
+            RenderCommand::SetStencilReference { reference } => {
+                self.state.stencil_reference = reference;
+                Ok(())
+            }
+            // Dinamik karıştırma komutları tüm renk hedeflerine uygulanır (OpenGL davranışı).
+            RenderCommand::SetBlendEnabled { enabled } => {
+                self.state.blend.iter_mut().for_each(|target| target.enabled = enabled);
+                Ok(())
+            }
+            RenderCommand::SetBlendFunction { src_factor, dst_factor, operation } => {
+                let function = BlendFunction { src_factor, dst_factor, operation };
+                self.state.blend.iter_mut().for_each(|target| {
+                    target.color = function;
+                    target.alpha = function;
+                });
+                Ok(())
+            }
+            RenderCommand::SetBlendConstant { color } => {
+                self.state.blend_constant = color;
+                Ok(())
+            }
+            RenderCommand::SetPrimitiveTopology { topology } => {
+                self.state.topology = topology;
+                Ok(())
+            }
+            RenderCommand::ResolveTexture { source, destination } => Self::resolve(resources, source, destination),
+            RenderCommand::GenerateMipmaps { texture } => Self::generate_mipmaps(resources, texture),
+            RenderCommand::DiscardAttachments { color, depth, stencil } => {
+                self.discard(resources, color, depth, stencil)
+            }
+        }
+    }
+
+    // Temizleme işlemi, açıksa makas dikdörtgenine uyar (OpenGL davranışı).
+    fn clear(&mut self, resources: &ResourceRegistry<SoftwareBackend>, color: ColorHDRA) -> Result<(), GraphisError> {
+        let scissor = self.state.scissor;
+        self.with_attachments(resources, |attachments| {
+            let (width, height) = attachments.color.size();
+            let rect = scissor.unwrap_or(Rect { x: 0, y: 0, width, height });
+            attachments.color.fill_rect(rect.x, rect.y, rect.width, rect.height, color);
+        })
+    }
+
+    // Derinlik-stencil hedefi bağlı değilse etkisizdir.
+    fn clear_depth(&mut self, resources: &ResourceRegistry<SoftwareBackend>, depth: f32) -> Result<(), GraphisError> {
+        let scissor = self.state.scissor;
+        self.with_attachments(resources, |attachments| {
+            if let Some(depth_stencil) = attachments.depth_stencil.as_deref_mut() {
+                let (width, height) = DepthStencilTarget::size(depth_stencil);
+                let rect = scissor.unwrap_or(Rect { x: 0, y: 0, width, height });
+                depth_stencil.fill_depth(rect.x, rect.y, rect.width, rect.height, depth.clamp(0.0, 1.0));
+            }
+        })
+    }
+
+    fn clear_stencil(&mut self, resources: &ResourceRegistry<SoftwareBackend>, stencil: u8) -> Result<(), GraphisError> {
+        let scissor = self.state.scissor;
+        self.with_attachments(resources, |attachments| {
+            if let Some(depth_stencil) = attachments.depth_stencil.as_deref_mut() {
+                let (width, height) = DepthStencilTarget::size(depth_stencil);
+                let rect = scissor.unwrap_or(Rect { x: 0, y: 0, width, height });
+                depth_stencil.fill_stencil(rect.x, rect.y, rect.width, rect.height, stencil);
+            }
+        })
+    }
+
+    // Atılan içerik tanımsızdır; hata ayıklama derlemelerinde ona güvenen kod kolayca fark edilsin diye
+    // hedefin tamamı (makastan bağımsız olarak) göze batan değerlerle doldurulur.
+    fn discard(
+        &mut self,
+        resources: &ResourceRegistry<SoftwareBackend>,
+        color: bool,
+        depth: bool,
+        stencil: bool,
+    ) -> Result<(), GraphisError> {
+        if !cfg!(debug_assertions) {
+            return Ok(());
+        }
+        self.with_attachments(resources, |attachments| {
+            let (width, height) = attachments.color.size();
+            if color {
+                attachments.color.fill_rect(0, 0, width, height, DISCARDED_COLOR);
+            }
+            if let Some(depth_stencil) = attachments.depth_stencil.as_deref_mut() {
+                if depth {
+                    depth_stencil.fill_depth(0, 0, width, height, DISCARDED_DEPTH);
+                }
+                if stencil {
+                    depth_stencil.fill_stencil(0, 0, width, height, DISCARDED_STENCIL);
+                }
+            }
+        })
+    }
+
+    fn draw(&mut self, resources: &ResourceRegistry<SoftwareBackend>, kind: DrawKind) -> Result<(), GraphisError> {
+        let program = self
+            .state
+            .program
+            .ok_or_else(|| GraphisError::InvalidState("draw without a bound shader program".to_string()))?;
+        // Bağlandıktan sonra yok edilen kaynaklar çizim anında yakalanır.
+        let pipeline = resources.pipeline(program)?;
+        for binding in self.state.vertex_buffers.values() {
+            resources.buffer(binding.buffer)?;
+        }
+        if let Some(binding) = self.state.index_buffer {
+            resources.buffer(binding.buffer)?;
+        }
+        let textures = Self::bind_textures(resources, &self.state)?;
+        if let (Some(depth_stencil), Some(view)) = (&pipeline.depth_stencil, self.state.depth_stencil_target) {
+            let format = view.format();
+            if format != depth_stencil.format.texture_format() {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "pipeline expects a {:?} depth-stencil target but {:?} is bound",
+                    depth_stencil.format, format
+                )));
+            }
+        }
+        log::trace!(kind:?, render_target:? = self.state.render_target; "Çizim çalıştırılıyor");
+        let program = shading::program_for(pipeline)?;
+        let context =
+            DrawContext { resources, state: &self.state, pipeline, program: program.as_ref(), textures: &textures };
+        Self::attach(&mut self.framebuffer, &mut self.depth_buffer, &self.state, resources, |attachments| {
+            draw::draw(&context, kind, attachments)
+        })??;
+        if self.record_draws {
+            self.draw_calls.push(DrawCall { kind, state: self.state.clone() });
+        }
+        Ok(())
+    }
+
+    // Bağlı dokuları örneklemeye hazırlar; sampler bağlanmamış birimler varsayılan sampler'ı kullanır.
+    // Çizimin yazdığı bir doku aynı çizimde örneklenemez (geri besleme döngüsü).
+    fn bind_textures<'r>(
+        resources: &'r ResourceRegistry<SoftwareBackend>,
+        state: &RenderState,
+    ) -> Result<TextureBindings<'r>, GraphisError> {
+        for &sampler in state.samplers.values() {
+            resources.sampler(sampler)?;
+        }
+        let attachments = [state.render_target.view(), state.depth_stencil_target];
+        let mut bindings = TextureBindings::default();
+        for (&unit, view) in &state.textures {
+            let texture = resources.texture(view.texture())?;
+            if attachments.iter().flatten().any(|attachment| attachment.texture() == view.texture()) {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "the texture bound to unit {} is also an attachment of the draw",
+                    unit
+                )));
+            }
+            if view.sample_count() > 1 {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "the multisampled texture bound to unit {} cannot be sampled",
+                    unit
+                )));
+            }
+            let sampler = match state.samplers.get(&unit) {
+                Some(&sampler) => resources.sampler(sampler)?.descriptor,
+                None => SamplerDescriptor::default(),
+            };
+            if sampler.compare.is_some() && !view.format().is_depth() {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "the comparison sampler at unit {} requires a depth texture, got {:?}",
+                    unit,
+                    view.format()
+                )));
+            }
+            let storage = texture
+                .native
+                .storage
+                .try_borrow()
+                .map_err(|_| GraphisError::InvalidState(format!("the texture bound to unit {} is in use", unit)))?;
+            bindings.insert(unit, SampledTexture::new(storage, *view, sampler));
+        }
+        Ok(bindings)
+    }
+
+    fn with_attachments<R, F>(&mut self, resources: &ResourceRegistry<SoftwareBackend>, f: F) -> Result<R, GraphisError>
+    where
+        F: FnOnce(&mut Attachments<'_>) -> R,
+    {
+        Self::attach(&mut self.framebuffer, &mut self.depth_buffer, &self.state, resources, f)
+    }
+
+    // Etkin renk ve derinlik hedeflerini çözümleyip `f`'ye verir. Varsayılan derinlik tamponu yalnızca
+    // varsayılan framebuffer'a çizilirken kullanılır; dokuya çizerken derinlik dokusu ayrıca bağlanmalıdır.
+    fn attach<R, F>(
+        framebuffer: &mut RenderTarget,
+        depth_buffer: &mut DepthStencilBuffer,
+        state: &RenderState,
+        resources: &ResourceRegistry<SoftwareBackend>,
+        f: F,
+    ) -> Result<R, GraphisError>
+    where
+        F: FnOnce(&mut Attachments<'_>) -> R,
+    {
+        let mut color_storage = state
+            .render_target
+            .view()
+            .map(|view| Self::borrow_storage(resources, view.texture(), "render target"))
+            .transpose()?;
+        let mut depth_storage = state
+            .depth_stencil_target
+            .map(|view| Self::borrow_storage(resources, view.texture(), "depth-stencil target"))
+            .transpose()?;
+        let mut depth_texture;
+        let depth_stencil: Option<&mut dyn DepthStencilTarget> =
+            match (depth_storage.as_deref_mut(), &state.depth_stencil_target) {
+                (Some(storage), Some(view)) => {
+                    depth_texture = TextureTarget::new(storage, view);
+                    Some(&mut depth_texture)
+                }
+                _ if state.render_target == RenderTargetBinding::Framebuffer => Some(depth_buffer),
+                _ => None,
+            };
+        let mut color_texture;
+        let mut no_color;
+        let color: &mut dyn ColorTarget = match (color_storage.as_deref_mut(), &state.render_target) {
+            (Some(storage), RenderTargetBinding::Texture(view)) => {
+                color_texture = TextureTarget::new(storage, view);
+                &mut color_texture
+            }
+            // Renk eki olmayan geçişler boyutu ve örnek sayısını derinlik-stencil dokusundan alır.
+            (_, RenderTargetBinding::Disabled) => {
+                let depth_stencil = depth_stencil.as_deref().ok_or_else(|| {
+                    GraphisError::ValidationFailed(
+                        "a pass without a color target needs a depth-stencil texture target".to_string(),
+                    )
+                })?;
+                let (width, height) = DepthStencilTarget::size(depth_stencil);
+                no_color = NullColorTarget::new(width, height, DepthStencilTarget::sample_count(depth_stencil));
+                &mut no_color
+            }
+            _ => framebuffer,
+        };
+        if let Some(depth_stencil) = &depth_stencil {
+            if DepthStencilTarget::size(&**depth_stencil) != color.size() {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "depth-stencil target size {:?} does not match render target size {:?}",
+                    DepthStencilTarget::size(&**depth_stencil),
+                    color.size()
+                )));
+            }
+            if DepthStencilTarget::sample_count(&**depth_stencil) != color.sample_count() {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "depth-stencil target has {} samples but the render target has {}",
+                    DepthStencilTarget::sample_count(&**depth_stencil),
+                    color.sample_count()
+                )));
+            }
+        }
+        Ok(f(&mut Attachments { color, depth_stencil }))
+    }
+
+    // Çok örnekli görünümün her texel'ini örneklerinin ortalamasıyla tek örnekli görünüme yazar.
+    // Örnekler görünüm formatıyla çözülür; sRGB görünümlerde ortalama doğrusal uzayda alınır.
+    fn resolve(
+        resources: &ResourceRegistry<SoftwareBackend>,
+        source: TextureView,
+        destination: TextureView,
+    ) -> Result<(), GraphisError> {
+        source.validate_attachment()?;
+        destination.validate_attachment()?;
+        let format = source.format();
+        let problem = if source.sample_count() <= 1 {
+            Some("the source texture is not multisampled".to_string())
+        } else if destination.sample_count() != 1 {
+            Some("the destination texture is multisampled".to_string())
+        } else if destination.format() != format {
+            Some(format!("formats differ ({:?} and {:?})", format, destination.format()))
+        } else if source.size() != destination.size() {
+            Some("sizes differ".to_string())
+        } else if format.is_integer() || format.is_depth_stencil() {
+            Some(format!("the format {:?} cannot be resolved", format))
+        } else {
+            None
+        };
+        if let Some(problem) = problem {
+            return Err(GraphisError::ValidationFailed(format!("cannot resolve texture: {}", problem)));
+        }
+
+        let mut source_storage = Self::borrow_storage(resources, source.texture(), "resolve source")?;
+        let source_target = TextureTarget::new(&mut source_storage, &source);
+        let mut destination_storage = Self::borrow_storage(resources, destination.texture(), "resolve destination")?;
+        let mut destination_target = TextureTarget::new(&mut destination_storage, &destination);
+        let samples = source.sample_count();
+        let (width, height) = source.size();
+        for y in 0..height {
+            for x in 0..width {
+                let mut sum = ColorHDRA::rgba(0.0, 0.0, 0.0, 0.0);
+                for sample in 0..samples {
+                    let color = source_target.read(x, y, sample);
+                    sum = ColorHDRA::rgba(sum.r + color.r, sum.g + color.g, sum.b + color.b, sum.a + color.a);
+                }
+                let scale = 1.0 / samples as f32;
+                let average = ColorHDRA::rgba(sum.r * scale, sum.g * scale, sum.b * scale, sum.a * scale);
+                destination_target.write(x, y, 0, average);
+            }
+        }
+        Ok(())
+    }
+
+    fn generate_mipmaps(resources: &ResourceRegistry<SoftwareBackend>, view: TextureView) -> Result<(), GraphisError> {
+        let format = view.format();
+        let problem = if view.sample_count() != 1 {
+            Some("the texture is multisampled".to_string())
+        } else if !format.is_filterable() {
+            Some(format!("the format {:?} cannot be filtered", format))
+        } else {
+            None
+        };
+        if let Some(problem) = problem {
+            return Err(GraphisError::ValidationFailed(format!("cannot generate mipmaps: {}", problem)));
+        }
+        let mut storage = Self::borrow_storage(resources, view.texture(), "mipmap")?;
+        storage.generate_mipmaps(&view);
+        Ok(())
+    }
+
+    fn borrow_storage<'r>(
+        resources: &'r ResourceRegistry<SoftwareBackend>,
+        handle: TextureHandle,
+        role: &str,
+    ) -> Result<RefMut<'r, TextureStorage>, GraphisError> {
+        resources
+            .texture(handle)?
+            .native
+            .storage
+            .try_borrow_mut()
+            .map_err(|_| GraphisError::InvalidState(format!("{} texture is in use", role)))
+    }
+
+    fn set_uniform(&mut self, location: u32, value: UniformValue) -> Result<(), GraphisError> {
+        self.state.uniforms.insert(location, value);
+        Ok(())
+    }
+}
+
+// Testler yazılım arka ucunu varsayılan cihaz üzerinden sürer.
+#[cfg(all(test, not(feature = "null-backend")))]
+mod tests {
+    use super::*;
+    use crate::core::color::Color8Bit;
+    use crate::gpu::device::Device;
+    use crate::gpu::render_pass::{LoadOp, RenderPassDepthStencilAttachmentDescriptor, RenderPassDescriptor, StoreOp};
+    use crate::gpu::shader::{NativeShader, Shader, ShaderDescriptor, ShaderSource};
+    use crate::gpu::software::shading::{FragmentInput, FragmentOutput, ShadedVertex, VertexInput};
+    use crate::gpu::software::texel;
+    use crate::gpu::texture::{Texture, TextureDescriptor, TextureDimension, TextureUsage};
+    use crate::rendering::pipeline::{
+        DepthStencilFormat, DepthStencilStateDescriptor, FragmentStateDescriptor, MultisampleStateDescriptor, Pipeline,
+        PipelineDescriptor, PolygonMode, PrimitiveTopology, RasterizationStateDescriptor, StencilFaceState,
+        StencilOperation, TextureFormat,
+    };
+
+    const RED: (f32, f32, f32, f32) = (1.0, 0.0, 0.0, 1.0);
+
+    // Konumları vertex indisine göre `positions` dizisinden alan ve 0 konumundaki Vec4 uniform'unu renk olarak
+    // yazan pipeline tanımı; testler geri kalan durumu tanım üzerinde değiştirir.
+    fn descriptor(positions: &[[f32; 4]]) -> PipelineDescriptor {
+        let positions = positions.to_vec();
+        let vertex = NativeShader::vertex(move |input: &VertexInput<'_>| ShadedVertex {
+            position: positions[input.vertex_index as usize],
+            point_size: 1.0,
+            varyings: Vec::new(),
+        });
+        let fragment = NativeShader::fragment(|input: &FragmentInput<'_>| match input.uniforms.get(&0) {
+            Some(&UniformValue::Vec4((r, g, b, a))) => {
+                Some(FragmentOutput { colors: vec![[r, g, b, a]], ..Default::default() })
+            }
+            _ => None,
+        });
+        let shader = |shader: NativeShader| {
+            let stage = shader.stage();
+            Shader::new(ShaderDescriptor { label: None, source: ShaderSource::Native(shader), stage }).unwrap()
+        };
+        let mut descriptor = PipelineDescriptor::default();
+        descriptor.vertex.module = Some(shader(vertex));
+        descriptor.fragment = Some(FragmentStateDescriptor { module: Some(shader(fragment)), ..Default::default() });
+        descriptor
+    }
+
+    // Hedefin tamamını kaplayan, sabit derinlikli üçgen.
+    fn full_screen(depth: f32) -> [[f32; 4]; 3] {
+        [[-1.0, 1.0, depth, 1.0], [3.0, 1.0, depth, 1.0], [-1.0, -3.0, depth, 1.0]]
+    }
+
+    fn draw(pipeline: &Pipeline, color: (f32, f32, f32, f32)) -> [RenderCommand; 3] {
+        [
+            RenderCommand::BindShaderProgram { program: pipeline.handle() },
+            RenderCommand::SetUniformVec4 { location: 0, value: color },
+            RenderCommand::Draw { vertex_count: 3 },
+        ]
+    }
+
+    fn pixel(executor: &SoftwareExecutor, x: u32, y: u32) -> [f32; 4] {
+        let color = executor.framebuffer().pixel(x, y).unwrap();
+        [color.r, color.g, color.b, color.a]
+    }
+
+    #[test]
+    fn clear_then_draw_one_triangle() {
+        let device = Device::new().unwrap();
+        // Sol üst köşegen yarıyı kaplayan üçgen: pencere uzayında (0, 0), (4, 0), (0, 4).
+        let positions = [[-1.0, 1.0, 0.5, 1.0], [1.0, 1.0, 0.5, 1.0], [-1.0, -1.0, 0.5, 1.0]];
+        let pipeline = device.create_pipeline(&descriptor(&positions)).unwrap();
+        let mut command_buffer = CommandBuffer::new();
+        command_buffer.begin_recording().unwrap();
+        command_buffer.clear_color(Color8Bit::rgb(0, 0, 255)).unwrap();
+        {
+            let mut pass = command_buffer.begin_render_pass(&RenderPassDescriptor::default()).unwrap();
+            for command in draw(&pipeline, RED) {
+                pass.submit_command(command).unwrap();
+            }
+        }
+        command_buffer.end_recording().unwrap();
+
+        let mut executor = SoftwareExecutor::new(4, 4);
+        executor.submit(&device.resources(), &[command_buffer]).unwrap();
+        let red = [1.0, 0.0, 0.0, 1.0];
+        let blue = [0.0, 0.0, 1.0, 1.0];
+        for (x, y) in [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (0, 2)] {
+            assert_eq!(pixel(&executor, x, y), red, "pixel ({}, {})", x, y);
+        }
+        for (x, y) in [(3, 1), (2, 2), (1, 3), (3, 3), (2, 3), (3, 2)] {
+            assert_eq!(pixel(&executor, x, y), blue, "pixel ({}, {})", x, y);
+        }
+    }
+
+    #[test]
+    fn viewport_and_scissor_limit_the_draw() {
+        let device = Device::new().unwrap();
+        let pipeline = device.create_pipeline(&descriptor(&full_screen(0.5))).unwrap();
+        let mut commands = vec![
+            RenderCommand::SetViewport { x: 0, y: 0, width: 2, height: 4 },
+            RenderCommand::SetScissorRect { x: 0, y: 1, width: 4, height: 2 },
+        ];
+        commands.extend(draw(&pipeline, RED));
+        let mut executor = SoftwareExecutor::new(4, 4);
+        executor.execute(&device.resources(), &commands).unwrap();
+        for y in 0..4 {
+            for x in 0..4 {
+                let expected = if x < 2 && (1..3).contains(&y) { [1.0, 0.0, 0.0, 1.0] } else { [0.0; 4] };
+                assert_eq!(pixel(&executor, x, y), expected, "pixel ({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn draw_calls_are_recorded_only_on_request() {
+        let device = Device::new().unwrap();
+        let pipeline = device.create_pipeline(&descriptor(&full_screen(0.5))).unwrap();
+        let mut executor = SoftwareExecutor::new(2, 2);
+        executor.execute(&device.resources(), &draw(&pipeline, RED)).unwrap();
+        assert!(executor.draw_calls().is_empty());
+
+        executor.set_record_draws(true);
+        executor.execute(&device.resources(), &draw(&pipeline, RED)).unwrap();
+        let calls = executor.take_draw_calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].kind, DrawKind::NonIndexed { vertex_count: 3 });
+        assert_eq!(calls[0].state.program, Some(pipeline.handle()));
+        assert!(executor.draw_calls().is_empty());
+    }
+
+    #[test]
+    fn submit_rejects_a_recording_command_buffer() {
+        let device = Device::new().unwrap();
+        let mut command_buffer = CommandBuffer::new();
+        command_buffer.begin_recording().unwrap();
+        command_buffer.clear_color(Color8Bit::rgb(255, 0, 0)).unwrap();
+        let mut executor = SoftwareExecutor::new(2, 2);
+        let result = executor.submit(&device.resources(), &[command_buffer]);
+        assert!(matches!(result, Err(GraphisError::InvalidState(_))));
+        assert_eq!(pixel(&executor, 0, 0), [0.0; 4]);
+    }
+
+    // Varsayılan derinlik tamponunun saklama hassasiyetine yuvarlanmış derinlik.
+    fn stored_depth(depth: f32) -> f32 {
+        texel::quantize_depth(DepthStencilBuffer::FORMAT, depth)
+    }
+
+    fn depth_state(depth_write_enabled: bool, depth_compare: CompareFunction) -> DepthStencilStateDescriptor {
+        DepthStencilStateDescriptor {
+            format: DepthStencilFormat::Depth24PlusStencil8,
+            depth_write_enabled,
+            depth_compare,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn depth_compare_functions() {
+        let device = Device::new().unwrap();
+        // Saklı derinlik 0.5 iken sırasıyla daha yakın, eşit ve daha uzak üçgenlerin testi geçip geçmediği.
+        let cases = [
+            (CompareFunction::Never, [false, false, false]),
+            (CompareFunction::Less, [true, false, false]),
+            (CompareFunction::Equal, [false, true, false]),
+            (CompareFunction::LessOrEqual, [true, true, false]),
+            (CompareFunction::Greater, [false, false, true]),
+            (CompareFunction::NotEqual, [true, false, true]),
+            (CompareFunction::GreaterOrEqual, [false, true, true]),
+            (CompareFunction::Always, [true, true, true]),
+        ];
+        for (function, expected) in cases {
+            for (depth, passes) in [0.25, 0.5, 0.75].into_iter().zip(expected) {
+                let mut descriptor = descriptor(&full_screen(depth));
+                descriptor.depth_stencil = Some(depth_state(true, function));
+                let pipeline = device.create_pipeline(&descriptor).unwrap();
+                let mut commands = vec![RenderCommand::ClearDepth { depth: 0.5 }];
+                commands.extend(draw(&pipeline, RED));
+                let mut executor = SoftwareExecutor::new(2, 2);
+                executor.execute(&device.resources(), &commands).unwrap();
+                let (color, stored) = if passes { ([1.0, 0.0, 0.0, 1.0], depth) } else { ([0.0; 4], 0.5) };
+                assert_eq!(pixel(&executor, 1, 1), color, "{:?} at depth {}", function, depth);
+                assert_eq!(executor.depth_buffer().depth(1, 1), Some(stored_depth(stored)));
+            }
+        }
+    }
+
+    #[test]
+    fn depth_write_mask_and_dynamic_compare_function() {
+        let device = Device::new().unwrap();
+        let mut near = descriptor(&full_screen(0.25));
+        near.depth_stencil = Some(depth_state(false, CompareFunction::Less));
+        let near = device.create_pipeline(&near).unwrap();
+        let mut far = descriptor(&full_screen(0.75));
+        far.depth_stencil = Some(depth_state(true, CompareFunction::Less));
+        let far = device.create_pipeline(&far).unwrap();
+
+        let mut executor = SoftwareExecutor::new(2, 2);
+        let mut commands = vec![RenderCommand::ClearDepth { depth: 0.5 }];
+        commands.extend(draw(&near, RED));
+        executor.execute(&device.resources(), &commands).unwrap();
+        // Yakın üçgen testi geçer ama derinlik yazması kapalı olduğundan tampon değişmez.
+        assert_eq!(pixel(&executor, 0, 0), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(executor.depth_buffer().depth(0, 0), Some(stored_depth(0.5)));
+
+        // Pipeline'ın Less karşılaştırması bağlandıktan sonra Greater ile değiştirilir.
+        let commands = [
+            RenderCommand::BindShaderProgram { program: far.handle() },
+            RenderCommand::SetDepthCompareFunction { function: CompareFunction::Greater },
+            RenderCommand::SetUniformVec4 { location: 0, value: (0.0, 1.0, 0.0, 1.0) },
+            RenderCommand::Draw { vertex_count: 3 },
+        ];
+        executor.execute(&device.resources(), &commands).unwrap();
+        assert_eq!(pixel(&executor, 0, 0), [0.0, 1.0, 0.0, 1.0]);
+        assert_eq!(executor.depth_buffer().depth(0, 0), Some(stored_depth(0.75)));
+    }
+
+    // Derinlik testi her zaman geçen ve derinlik yazan, verilen sapma ayarlarıyla çizilmiş üçgenin (1, 0)
+    // pikselindeki derinlik.
+    fn biased_depth(positions: &[[f32; 4]], rasterization: RasterizationStateDescriptor) -> f32 {
+        let device = Device::new().unwrap();
+        let mut descriptor = descriptor(positions);
+        descriptor.depth_stencil = Some(depth_state(true, CompareFunction::Always));
+        descriptor.rasterization = Some(rasterization);
+        let pipeline = device.create_pipeline(&descriptor).unwrap();
+        let mut executor = SoftwareExecutor::new(4, 4);
+        executor.execute(&device.resources(), &draw(&pipeline, RED)).unwrap();
+        executor.depth_buffer().depth(1, 0).unwrap()
+    }
+
+    #[test]
+    fn constant_depth_bias_and_clamp() {
+        // Depth24PlusStencil8 tamponunda sabit sapmanın birimi 2^-24'tür.
+        let rasterization = RasterizationStateDescriptor { depth_bias: 1000.0, ..Default::default() };
+        let depth = biased_depth(&full_screen(0.5), rasterization);
+        assert_eq!(depth, stored_depth(0.5 + 1000.0 / 16_777_216.0));
+
+        let rasterization =
+            RasterizationStateDescriptor { depth_bias: 1.0e6, depth_bias_clamp: 0.01, ..Default::default() };
+        assert_eq!(biased_depth(&full_screen(0.5), rasterization), stored_depth(0.51));
+    }
+
+    #[test]
+    fn slope_scaled_depth_bias() {
+        // 4x4 hedefte derinlik pencere x'i boyunca piksel başına 0.1 artar (dz/dx = 0.1, dz/dy = 0);
+        // (1, 0) pikselinin merkezinde derinlik 0.15, sapma 2 * 0.1'dir.
+        let positions = [[-1.0, 1.0, 0.0, 1.0], [3.0, 1.0, 0.8, 1.0], [-1.0, -3.0, 0.0, 1.0]];
+        let rasterization = RasterizationStateDescriptor { depth_bias_slope_factor: 2.0, ..Default::default() };
+        let depth = biased_depth(&positions, rasterization);
+        assert!((depth - stored_depth(0.35)).abs() < 1.0e-6, "depth {}", depth);
+        assert!((biased_depth(&positions, Default::default()) - stored_depth(0.15)).abs() < 1.0e-6);
+    }
+
+    fn stencil_state(face: StencilFaceState) -> DepthStencilStateDescriptor {
+        DepthStencilStateDescriptor {
+            format: DepthStencilFormat::Depth24PlusStencil8,
+            stencil_front: face,
+            stencil_back: face,
+            ..Default::default()
+        }
+    }
+
+    // `setup` komutlarından sonra `state` ile üçgeni çizer; (0, 0) pikselinin rengini ve stencil değerini döndürür.
+    fn draw_with_stencil(
+        positions: &[[f32; 4]],
+        state: DepthStencilStateDescriptor,
+        setup: &[RenderCommand],
+    ) -> ([f32; 4], u8) {
+        let device = Device::new().unwrap();
+        let mut descriptor = descriptor(positions);
+        descriptor.depth_stencil = Some(state);
+        let pipeline = device.create_pipeline(&descriptor).unwrap();
+        let mut executor = SoftwareExecutor::new(2, 2);
+        executor.execute(&device.resources(), setup).unwrap();
+        executor.execute(&device.resources(), &draw(&pipeline, RED)).unwrap();
+        (pixel(&executor, 0, 0), executor.depth_buffer().stencil(0, 0).unwrap())
+    }
+
+    #[test]
+    fn stencil_operations() {
+        // (işlem, saklı değer, referans, beklenen değer)
+        let cases = [
+            (StencilOperation::Keep, 5, 9, 5),
+            (StencilOperation::Zero, 5, 9, 0),
+            (StencilOperation::Replace, 5, 9, 9),
+            (StencilOperation::IncrementClamp, 5, 0, 6),
+            (StencilOperation::IncrementClamp, 255, 0, 255),
+            (StencilOperation::DecrementClamp, 5, 0, 4),
+            (StencilOperation::DecrementClamp, 0, 0, 0),
+            (StencilOperation::Invert, 0x0F, 0, 0xF0),
+            (StencilOperation::IncrementWrap, 255, 0, 0),
+            (StencilOperation::DecrementWrap, 0, 0, 255),
+        ];
+        for (operation, stored, reference, expected) in cases {
+            let face = StencilFaceState { pass_op: operation, ..Default::default() };
+            let setup =
+                [RenderCommand::ClearStencil { stencil: stored }, RenderCommand::SetStencilReference { reference }];
+            let (color, stencil) = draw_with_stencil(&full_screen(0.5), stencil_state(face), &setup);
+            assert_eq!(color, [1.0, 0.0, 0.0, 1.0], "{:?}", operation);
+            assert_eq!(stencil, expected, "{:?} on {}", operation, stored);
+        }
+    }
+
+    #[test]
+    fn stencil_fail_depth_fail_and_pass_ops() {
+        let face = StencilFaceState {
+            compare: CompareFunction::Equal,
+            fail_op: StencilOperation::IncrementWrap,
+            depth_fail_op: StencilOperation::DecrementWrap,
+            pass_op: StencilOperation::Invert,
+        };
+        let state = DepthStencilStateDescriptor { depth_compare: CompareFunction::Less, ..stencil_state(face) };
+        let setup = |stencil: u32, depth: f32| {
+            [
+                RenderCommand::ClearStencil { stencil },
+                RenderCommand::ClearDepth { depth },
+                RenderCommand::SetStencilReference { reference: 7 },
+            ]
+        };
+        // Stencil testi başarısız: fail_op uygulanır, renk yazılmaz.
+        let (color, stencil) = draw_with_stencil(&full_screen(0.5), state.clone(), &setup(3, 1.0));
+        assert_eq!((color, stencil), ([0.0; 4], 4));
+        // Stencil testi geçer, derinlik testi başarısız: depth_fail_op uygulanır.
+        let (color, stencil) = draw_with_stencil(&full_screen(0.5), state.clone(), &setup(7, 0.25));
+        assert_eq!((color, stencil), ([0.0; 4], 6));
+        // İki test de geçer: pass_op uygulanır ve renk yazılır.
+        let (color, stencil) = draw_with_stencil(&full_screen(0.5), state, &setup(7, 1.0));
+        assert_eq!((color, stencil), ([1.0, 0.0, 0.0, 1.0], !7));
+    }
+
+    #[test]
+    fn stencil_read_and_write_masks() {
+        let face = StencilFaceState {
+            compare: CompareFunction::Equal,
+            pass_op: StencilOperation::Replace,
+            ..Default::default()
+        };
+        let setup =
+            [RenderCommand::ClearStencil { stencil: 0xA5 }, RenderCommand::SetStencilReference { reference: 0x05 }];
+        // Okuma maskesi yalnızca alt dört biti karşılaştırır; yazma maskesi yalnızca üst dört bite yazar.
+        let state =
+            DepthStencilStateDescriptor { stencil_read_mask: 0x0F, stencil_write_mask: 0xF0, ..stencil_state(face) };
+        let (color, stencil) = draw_with_stencil(&full_screen(0.5), state, &setup);
+        assert_eq!((color, stencil), ([1.0, 0.0, 0.0, 1.0], 0x05));
+        // Maskesiz karşılaştırmada 0x05 != 0xA5 olduğundan test başarısız olur ve değer korunur.
+        let (color, stencil) = draw_with_stencil(&full_screen(0.5), stencil_state(face), &setup);
+        assert_eq!((color, stencil), ([0.0; 4], 0xA5));
+    }
+
+    #[test]
+    fn stencil_state_follows_the_facing() {
+        let state = DepthStencilStateDescriptor {
+            stencil_front: StencilFaceState { pass_op: StencilOperation::Replace, ..Default::default() },
+            stencil_back: StencilFaceState { pass_op: StencilOperation::Invert, ..Default::default() },
+            ..stencil_state(StencilFaceState::default())
+        };
+        let setup = [RenderCommand::ClearStencil { stencil: 1 }, RenderCommand::SetStencilReference { reference: 9 }];
+        // full_screen ekranda saat yönünde sarılır; varsayılan ön yüz saat yönünün tersidir.
+        let [a, b, c] = full_screen(0.5);
+        assert_eq!(draw_with_stencil(&[a, b, c], state.clone(), &setup).1, !1);
+        assert_eq!(draw_with_stencil(&[a, c, b], state, &setup).1, 9);
+    }
+
+    #[test]
+    fn dynamic_blend_state_blends_into_the_framebuffer() {
+        let device = Device::new().unwrap();
+        let pipeline = device.create_pipeline(&descriptor(&full_screen(0.5))).unwrap();
+        let mut commands = draw(&pipeline, (0.25, 0.5, 1.0, 0.25)).to_vec();
+        commands.extend([
+            RenderCommand::SetBlendEnabled { enabled: true },
+            RenderCommand::SetBlendFunction {
+                src_factor: BlendFactor::SrcAlpha,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            RenderCommand::SetUniformVec4 { location: 0, value: (0.5, 0.25, 0.75, 0.5) },
+            RenderCommand::Draw { vertex_count: 3 },
+        ]);
+        let mut executor = SoftwareExecutor::new(2, 2);
+        executor.execute(&device.resources(), &commands).unwrap();
+        assert_eq!(pixel(&executor, 1, 1), [0.375, 0.375, 0.875, 0.375]);
+
+        // Dinamik durum sonraki çizimlerde de geçerlidir; pipeline'ı yeniden bağlamak karıştırmayı pipeline'ın
+        // durumuna (kapalı) döndürür.
+        let commands = [
+            RenderCommand::SetBlendFunction {
+                src_factor: BlendFactor::ConstantColor,
+                dst_factor: BlendFactor::Zero,
+                operation: BlendOperation::Add,
+            },
+            RenderCommand::SetBlendConstant { color: ColorHDRA::rgba(0.5, 0.5, 0.5, 0.25) },
+            RenderCommand::Draw { vertex_count: 3 },
+        ];
+        executor.execute(&device.resources(), &commands).unwrap();
+        assert_eq!(pixel(&executor, 1, 1), [0.25, 0.125, 0.375, 0.125]);
+        executor.execute(&device.resources(), &draw(&pipeline, RED)).unwrap();
+        assert_eq!(pixel(&executor, 1, 1), [1.0, 0.0, 0.0, 1.0]);
+    }
+
+    // 2x2 hedefte sol kenarı (0, 0) ve (0, 1) piksellerinin ortasından dikey geçen, diğer pikselleri tamamen
+    // kaplayan üçgen. 4 örnekli desende bu piksellerin yalnızca 1 ve 3 numaralı örnekleri kapsanır.
+    const HALF_PIXEL_EDGE: [[f32; 4]; 3] = [[-0.5, 9.0, 0.5, 1.0], [15.0, -3.0, 0.5, 1.0], [-0.5, -15.0, 0.5, 1.0]];
+
+    fn render_texture(device: &Device, sample_count: u32) -> Texture {
+        let descriptor = TextureDescriptor {
+            format: TextureFormat::Rgba32Float,
+            dimension: TextureDimension::D2,
+            width: 2,
+            height: 2,
+            depth_or_array_layers: 1,
+            mip_level_count: 1,
+            sample_count,
+            usage: TextureUsage::RenderAttachment,
+        };
+        device.create_texture(&descriptor).unwrap()
+    }
+
+    // 4 örnekli çizimin (0, 0) pikselindeki örnekleri ve tek örnekli dokuya çözümlenen (0, 0), (1, 0) texel'leri.
+    struct Multisampled {
+        samples: [[f32; 4]; 4],
+        resolved: [[f32; 4]; 2],
+    }
+
+    fn draw_multisampled(
+        multisample: MultisampleStateDescriptor,
+        color: (f32, f32, f32, f32),
+    ) -> Result<Multisampled, GraphisError> {
+        let device = Device::new().unwrap();
+        let mut descriptor = descriptor(&HALF_PIXEL_EDGE);
+        descriptor.multisample = Some(multisample);
+        let pipeline = device.create_pipeline(&descriptor).unwrap();
+        let (multisampled, resolved) = (render_texture(&device, 4), render_texture(&device, 1));
+        let mut commands =
+            vec![RenderCommand::SetRenderTarget { render_target: RenderTargetBinding::Texture(multisampled.view()) }];
+        commands.extend(draw(&pipeline, color));
+        commands.push(RenderCommand::ResolveTexture { source: multisampled.view(), destination: resolved.view() });
+        SoftwareExecutor::new(2, 2).execute(&device.resources(), &commands)?;
+
+        let samples = multisampled.native().storage.borrow();
+        let resolved = resolved.native().storage.borrow();
+        Ok(Multisampled {
+            samples: [0, 1, 2, 3].map(|sample| samples.read_texel(0, 0, 0, 0, sample).unwrap()),
+            resolved: [0, 1].map(|x| resolved.read_texel(0, 0, x, 0, 0).unwrap()),
+        })
+    }
+
+    fn multisample(count: u32, mask: u32, alpha_to_coverage_enabled: bool) -> MultisampleStateDescriptor {
+        MultisampleStateDescriptor { count, mask, alpha_to_coverage_enabled }
+    }
+
+    #[test]
+    fn multisampled_coverage_is_resolved_to_the_average() {
+        let Multisampled { samples, resolved } = draw_multisampled(multisample(4, u32::MAX, false), RED).unwrap();
+        let red = [1.0, 0.0, 0.0, 1.0];
+        assert_eq!(samples, [[0.0; 4], red, [0.0; 4], red]);
+        assert_eq!(resolved, [[0.5, 0.0, 0.0, 0.5], red]);
+    }
+
+    #[test]
+    fn sample_mask_limits_coverage() {
+        // Maske 0 ve 1 numaralı örnekleri bırakır; (0, 0) pikselinde rasterleştiricinin kapsamıyla kesişimi
+        // yalnızca 1 numaralı örnektir.
+        let Multisampled { samples, resolved } = draw_multisampled(multisample(4, 0b0011, false), RED).unwrap();
+        assert_eq!(samples, [[0.0; 4], [1.0, 0.0, 0.0, 1.0], [0.0; 4], [0.0; 4]]);
+        assert_eq!(resolved, [[0.25, 0.0, 0.0, 0.25], [0.5, 0.0, 0.0, 0.5]]);
+    }
+
+    #[test]
+    fn alpha_to_coverage_covers_samples_in_proportion_to_alpha() {
+        // Alfa 0.5 iken dört örneğin ilk ikisi kapsanır.
+        let half_red = (1.0, 0.0, 0.0, 0.5);
+        let Multisampled { samples, resolved } = draw_multisampled(multisample(4, u32::MAX, true), half_red).unwrap();
+        assert_eq!(samples, [[0.0; 4], [1.0, 0.0, 0.0, 0.5], [0.0; 4], [0.0; 4]]);
+        assert_eq!(resolved, [[0.25, 0.0, 0.0, 0.125], [0.5, 0.0, 0.0, 0.25]]);
+    }
+
+    #[test]
+    fn pipeline_sample_count_must_match_the_target() {
+        let result = draw_multisampled(MultisampleStateDescriptor::default(), RED);
+        assert!(matches!(result, Err(GraphisError::ValidationFailed(_))));
+    }
+
+    #[test]
+    fn depth_only_pass_renders_into_a_depth_texture_of_its_own_size() {
+        let device = Device::new().unwrap();
+        let depth = device
+            .create_texture(&TextureDescriptor {
+                format: TextureFormat::Depth32Float,
+                dimension: TextureDimension::D2,
+                width: 8,
+                height: 2,
+                depth_or_array_layers: 1,
+                mip_level_count: 1,
+                sample_count: 1,
+                usage: TextureUsage::RenderAttachment,
+            })
+            .unwrap();
+        let mut descriptor = descriptor(&full_screen(0.25));
+        descriptor.depth_stencil = Some(DepthStencilStateDescriptor {
+            format: DepthStencilFormat::Depth32Float,
+            ..depth_state(true, CompareFunction::Less)
+        });
+        let pipeline = device.create_pipeline(&descriptor).unwrap();
+        let view = depth.view();
+        let pass = RenderPassDescriptor {
+            color_attachments: &[],
+            depth_stencil_attachment: Some(RenderPassDepthStencilAttachmentDescriptor {
+                attachment: &view,
+                depth_load_op: LoadOp::Clear,
+                depth_store_op: StoreOp::Store,
+                stencil_load_op: LoadOp::Load,
+                stencil_store_op: StoreOp::Store,
+                clear_value: None,
+            }),
+        };
+        let mut command_buffer = CommandBuffer::new();
+        command_buffer.begin_recording().unwrap();
+        {
+            let mut pass = command_buffer.begin_render_pass(&pass).unwrap();
+            for command in draw(&pipeline, RED) {
+                pass.submit_command(command).unwrap();
+            }
+        }
+        command_buffer.end_recording().unwrap();
+
+        // Framebuffer 4x4 olsa da geçiş 8x2'lik derinlik dokusuna çizer ve framebuffer'a dokunmaz.
+        let mut executor = SoftwareExecutor::new(4, 4);
+        executor.submit(&device.resources(), &[command_buffer]).unwrap();
+        let storage = depth.native().storage.borrow();
+        for (x, y) in [(0, 0), (7, 0), (7, 1)] {
+            assert_eq!(storage.read_texel(0, 0, x, y, 0).unwrap()[0], 0.25, "texel ({}, {})", x, y);
+        }
+        assert!(executor.framebuffer().pixels().iter().all(|pixel| *pixel == ColorHDRA::rgba(0.0, 0.0, 0.0, 0.0)));
+        assert_eq!(executor.depth_buffer().depth(0, 0), Some(1.0));
+    }
+
+    #[test]
+    fn disabled_color_target_requires_a_depth_texture() {
+        let device = Device::new().unwrap();
+        let pipeline = device.create_pipeline(&descriptor(&full_screen(0.5))).unwrap();
+        let mut commands = vec![RenderCommand::SetRenderTarget { render_target: RenderTargetBinding::Disabled }];
+        commands.extend(draw(&pipeline, RED));
+        let result = SoftwareExecutor::new(2, 2).execute(&device.resources(), &commands);
+        assert!(matches!(result, Err(GraphisError::ValidationFailed(_))));
+    }
+
+    // 8x8'lik hedefte pencere koordinatı (x, y) olan vertex konumu.
+    fn window_position(x: f32, y: f32) -> [f32; 4] {
+        [x / 4.0 - 1.0, 1.0 - y / 4.0, 0.5, 1.0]
+    }
+
+    // Tek bir çizimi 8x8'lik framebuffer'a işler ve kırmızıya boyanan pikselleri döndürür.
+    fn lit_pixels(descriptor: &PipelineDescriptor, vertex_count: u32) -> Vec<(u32, u32)> {
+        let device = Device::new().unwrap();
+        let pipeline = device.create_pipeline(descriptor).unwrap();
+        let mut commands = draw(&pipeline, RED);
+        commands[2] = RenderCommand::Draw { vertex_count };
+        let mut executor = SoftwareExecutor::new(8, 8);
+        executor.execute(&device.resources(), &commands).unwrap();
+        (0..8).flat_map(|y| (0..8).map(move |x| (x, y))).filter(|&(x, y)| pixel(&executor, x, y)[0] == 1.0).collect()
+    }
+
+    #[test]
+    fn line_list_draws_diamond_exit_pixels() {
+        let mut descriptor = descriptor(&[window_position(0.5, 0.5), window_position(6.5, 0.5)]);
+        descriptor.primitive.topology = PrimitiveTopology::LineList;
+        assert_eq!(lit_pixels(&descriptor, 2), (0..6).map(|x| (x, 0)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn polygon_modes_draw_edges_and_vertices() {
+        let triangle = [window_position(0.5, 0.5), window_position(6.5, 0.5), window_position(0.5, 6.5)];
+        let polygon_mode = |polygon_mode: PolygonMode| {
+            let mut descriptor = descriptor(&triangle);
+            descriptor.rasterization = Some(RasterizationStateDescriptor { polygon_mode, ..Default::default() });
+            lit_pixels(&descriptor, 3)
+        };
+        // Kenarlar: üst satır, sol sütun ve köşegen; her vertex pikseli yalnızca bir kenar tarafından çizilir.
+        let edges: Vec<_> = (0..8)
+            .flat_map(|y| (0..8).map(move |x| (x, y)))
+            .filter(|&(x, y)| x + y <= 6 && (x == 0 || y == 0 || x + y == 6))
+            .collect();
+        assert_eq!(polygon_mode(PolygonMode::Line), edges);
+        assert_eq!(polygon_mode(PolygonMode::Point), [(0, 0), (6, 0), (0, 6)]);
+        assert_eq!(polygon_mode(PolygonMode::Fill).len(), 6 + 5 + 4 + 3 + 2 + 1);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,2246 @@
+use std::collections::{HashMap, HashSet};
+use std::f32::consts::{LN_2, PI};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::gpu::error::GraphisError;
+use crate::gpu::shader::ShaderStage;
+use crate::gpu::software::executor::UniformValue;
+use crate::gpu::software::sampler::{Lod, SampledTexture, TextureBindings};
+use crate::gpu::software::shading::{
+    FixedFunctionProgram, FragmentInput, FragmentOutput, ShadedVertex, SoftwareProgram, VertexInput,
+};
+use crate::gpu::software::texel;
+use crate::gpu::spirv::parser::Instruction;
+use crate::gpu::spirv::{
+    built_in, decoration, glsl, op, EntryPoint, ExecutionModel, ImageDimension, Interpolation, ScalarKind, SpirvModule,
+    SpirvType, StorageClass, StructMember,
+};
+use crate::gpu::texture::TextureViewDimension;
+
+// Bir shader çağrısının çalıştırabileceği en fazla komut sayısı. Sınırı aşan çağrı sonlandırılır: vertex'in
+// konumu (0, 0, 0, 0) olur ve primitif kırpılır, fragment ise atılır.
+const MAX_STEPS: usize = 1 << 20;
+
+// Görüntü işlenenleri (Image Operands) maskesinin bitleri.
+const BIAS: u32 = 0x1;
+const LOD: u32 = 0x2;
+const GRAD: u32 = 0x4;
+// NonPrivateTexel, VolatileTexel, SignExtend, ZeroExtend ve Nontemporal örneklemenin sonucunu değiştirmez.
+const IGNORED_IMAGE_OPERANDS: u32 = 0x400 | 0x800 | 0x1000 | 0x2000 | 0x4000;
+
+// IMix, çift duyarlıklı paketleme ve Interpolate* komutları dışındaki GLSL.std.450 komutları.
+fn glsl_supported(number: u32) -> bool {
+    matches!(
+        number,
+        glsl::ROUND..=glsl::F_MIX
+            | glsl::STEP..=glsl::PACK_HALF_2X16
+            | glsl::UNPACK_SNORM_2X16..=glsl::UNPACK_UNORM_4X8
+            | glsl::LENGTH..=glsl::FIND_U_MSB
+            | glsl::N_MIN..=glsl::N_CLAMP
+    )
+}
+
+fn unsupported<T>(message: String) -> Result<T, GraphisError> {
+    Err(GraphisError::Unsupported(format!("the software backend cannot run this shader: {}", message)))
+}
+
+fn invalid<T>(message: String) -> Result<T, GraphisError> {
+    Err(GraphisError::ValidationFailed(format!("invalid SPIR-V module: {}", message)))
+}
+
+// SPIR-V modüllerini CPU üzerinde yorumlayarak çalıştıran program. Shader yeteneğinin grafik alt kümesini
+// destekler: 32 bitlik skalerler, vektörler, matrisler, diziler ve yapılar; aritmetik, bit, dönüşüm ve
+// karşılaştırma komutları; dallanma, döngü ve fonksiyon çağrıları; GLSL.std.450 komutları; 2B, 2B dizi, 3B ve
+// küp dokuların örneklenmesi.
+//
+// Bağlamalar yürütücünün durumuna şöyle eşlenir:
+// - Vertex girişleri aynı konumdaki attribute'tan, fragment girişleri aynı konumdaki varying'den okunur.
+//   Vertex çıkışları konumlarına göre varying olur; fragment çıkışları konumlarına göre renk, Index 1 ile
+//   dekore edilmiş çıkış ise çift kaynaklı karıştırmanın ikinci rengidir.
+// - Location ile dekore edilmiş, opak olmayan UniformConstant değişkenleri (OpenGL tarzı uniform'lar) o konumdaki
+//   uniform değerini okur. Tipler farklıysa bileşenler sırayla dönüştürülür, eksik bileşenler sıfır olur.
+// - 0 numaralı tanımlayıcı kümesindeki dokular, Binding numarasıyla aynı doku birimine bağlanır; dizilerin
+//   elemanları ardışık birimlere düşer. Doku birimleri sampler'larıyla birlikte bağlandığından ayrı sampler
+//   değişkenleri birim seçmez: bir görüntü her zaman kendi biriminin sampler'ı ile örneklenir.
+//
+// Fragment'lar tek tek çalıştırıldığından ekran uzayı türevleri, girişlerin komşu piksellere göre farklarından
+// ileri yönlü otomatik türevle hesaplanır (bkz. Dual).
+// Uniform ve depolama arabellekleri, push constant'lar, 32 bit dışındaki sayısal tipler ve desteklenmeyen
+// komutlar program oluşturulurken Unsupported hatasıyla reddedilir.
+#[derive(Debug)]
+pub struct SpirvProgram<'m> {
+    vertex: Stage<'m>,
+    fragment: Option<Stage<'m>>,
+    interpolation: Vec<Interpolation>, // Fragment girişlerinin konuma göre ara değerleme biçimi
+}
+
+impl<'m> SpirvProgram<'m> {
+    // Aşamalar (modül, giriş noktasının adı) olarak verilir. Fragment aşaması yoksa fragment'lar sabit fonksiyonlu
+    // programla renklendirilir.
+    pub fn new(
+        vertex: (&'m SpirvModule, &str),
+        fragment: Option<(&'m SpirvModule, &str)>,
+    ) -> Result<Self, GraphisError> {
+        let vertex = Stage::new(vertex.0, vertex.1, ShaderStage::Vertex)?;
+        let fragment = fragment.map(|(module, name)| Stage::new(module, name, ShaderStage::Fragment)).transpose()?;
+        let mut interpolation = Vec::new();
+        for input in fragment.iter().flat_map(|stage| &stage.entry_point.inputs) {
+            for location in input.location..input.location + input.ty.location_count() {
+                let location = location as usize;
+                if interpolation.len() <= location {
+                    interpolation.resize(location + 1, Interpolation::default());
+                }
+                interpolation[location] = input.interpolation;
+            }
+        }
+        log::debug!(
+            vertex_functions = vertex.functions.len(),
+            fragment_functions = fragment.as_ref().map_or(0, |stage| stage.functions.len());
+            "SPIR-V programı hazırlandı"
+        );
+        Ok(SpirvProgram { vertex, fragment, interpolation })
+    }
+}
+
+impl SoftwareProgram for SpirvProgram<'_> {
+    fn vertex(&self, input: &VertexInput<'_>) -> ShadedVertex {
+        let locations = |location| input.attribute(location).unwrap_or([0.0, 0.0, 0.0, 1.0]).map(Dual::constant);
+        let built_in = |kind| match kind {
+            built_in::VERTEX_INDEX | built_in::VERTEX_ID => Value::Int(input.vertex_index),
+            built_in::INSTANCE_INDEX | built_in::INSTANCE_ID => Value::Int(input.instance_index),
+            _ => Value::Undefined,
+        };
+        let inputs = Inputs { locations: &locations, built_in: &built_in, uniforms: input.uniforms };
+        let mut vertex = ShadedVertex { position: [0.0; 4], point_size: 1.0, varyings: Vec::new() };
+        match self.vertex.run(&inputs, input.textures) {
+            Ok((memory, _)) => self.vertex.outputs(&memory, &mut |output| match output {
+                Output::BuiltIn(built_in::POSITION, value) => {
+                    vertex.position = [0, 1, 2, 3].map(|i| value.item(i).float().value);
+                }
+                Output::BuiltIn(built_in::POINT_SIZE, value) => vertex.point_size = value.float().value,
+                Output::BuiltIn(..) => {}
+                Output::Scalar { location, component, value, .. } => {
+                    set_component(&mut vertex.varyings, location, component, value, [0.0; 4]);
+                }
+            }),
+            Err(halt) => log::warn!(halt:?, vertex_index = input.vertex_index; "Vertex shader çağrısı sonlandırıldı"),
+        }
+        vertex
+    }
+
+    fn fragment(&self, input: &FragmentInput<'_>) -> Option<FragmentOutput> {
+        let Some(stage) = &self.fragment else { return FixedFunctionProgram.fragment(input) };
+        let locations = |location: u32| {
+            let value = input.varyings.get(location as usize).copied().unwrap_or_default();
+            let (ddx, ddy) = input.derivatives.get(location as usize).copied().unwrap_or_default();
+            [0, 1, 2, 3].map(|i| Dual { value: value[i], ddx: ddx[i], ddy: ddy[i] })
+        };
+        let built_in = |kind| match kind {
+            built_in::FRAG_COORD => {
+                let [x, y, z, w] = input.frag_coord;
+                let x = Value::Float(Dual { value: x, ddx: 1.0, ddy: 0.0 });
+                let y = Value::Float(Dual { value: y, ddx: 0.0, ddy: 1.0 });
+                Value::Composite(vec![x, y, float(z), float(w)])
+            }
+            built_in::FRONT_FACING => Value::Bool(input.front_facing),
+            built_in::POINT_COORD => Value::Composite(input.point_coord.iter().map(|&value| float(value)).collect()),
+            built_in::HELPER_INVOCATION => Value::Bool(false),
+            _ => Value::Undefined,
+        };
+        let inputs = Inputs { locations: &locations, built_in: &built_in, uniforms: input.uniforms };
+        let (memory, demoted) = match stage.run(&inputs, input.textures) {
+            Ok(result) => result,
+            Err(Halt::Kill) => return None,
+            Err(halt) => {
+                log::warn!(halt:?, frag_coord:? = input.frag_coord; "Fragment shader çağrısı sonlandırıldı");
+                return None;
+            }
+        };
+        if demoted {
+            return None;
+        }
+        let mut output = FragmentOutput::default();
+        stage.outputs(&memory, &mut |written| match written {
+            Output::BuiltIn(built_in::FRAG_DEPTH, value) => output.depth = Some(value.float().value),
+            Output::BuiltIn(..) => {}
+            Output::Scalar { location: 0, index: 1, component, value } => {
+                let color = output.second_color.get_or_insert([0.0, 0.0, 0.0, 1.0]);
+                if let Some(channel) = color.get_mut(component) {
+                    *channel = value;
+                }
+            }
+            Output::Scalar { location, component, value, .. } => {
+                set_component(&mut output.colors, location, component, value, [0.0, 0.0, 0.0, 1.0]);
+            }
+        });
+        Some(output)
+    }
+
+    fn interpolation(&self, varying: usize) -> Interpolation {
+        self.interpolation.get(varying).copied().unwrap_or_default()
+    }
+
+    fn uses_derivatives(&self) -> bool {
+        self.fragment.as_ref().is_some_and(|stage| stage.derivatives)
+    }
+}
+
+// `values[location][component]` değerini yazar; liste gerekirse `fill` ile büyütülür.
+fn set_component(values: &mut Vec<[f32; 4]>, location: u32, component: usize, value: f32, fill: [f32; 4]) {
+    let location = location as usize;
+    if component >= 4 {
+        return;
+    }
+    if values.len() <= location {
+        values.resize(location + 1, fill);
+    }
+    values[location][component] = value;
+}
+
+// Bir giriş noktasının çalıştırılmaya hazırlanmış hali.
+#[derive(Debug)]
+struct Stage<'m> {
+    module: &'m SpirvModule,
+    entry_point: &'m EntryPoint,
+    functions: HashMap<u32, Function>,
+    // Kimliğe göre sabitlerin değerleri ve global değişkenlerin işaretçileri; diğer kimlikler için Undefined.
+    constants: Vec<Value>,
+    globals: Vec<Global<'m>>, // Bellekteki sırasıyla
+    extended: HashMap<u32, ExtendedSet>, // OpExtInstImport kimliği -> komut kümesi
+    derivatives: bool, // Türev komutları veya örtük LOD ile örnekleme kullanılıyor
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExtendedSet {
+    Glsl,
+    NonSemantic, // Hata ayıklama bilgisi gibi anlamı olmayan komutlar; yok sayılır
+}
+
+#[derive(Debug, Default)]
+struct Function {
+    parameters: Vec<u32>,
+    blocks: Vec<Block>, // İlki giriş bloğu
+    labels: HashMap<u32, usize>, // Etiket kimliği -> blok sırası
+}
+
+// `start..end`, modülün komut listesinde bloğun OpLabel'dan sonraki komutlarıdır; sonuncusu bloğu sonlandırır.
+#[derive(Debug, Clone, Copy)]
+struct Block {
+    label: u32,
+    start: usize,
+    end: usize,
+}
+
+#[derive(Debug)]
+struct Global<'m> {
+    id: u32,
+    storage_class: StorageClass,
+    ty: &'m SpirvType, // İşaret edilen tip
+    initializer: Option<u32>,
+    binding: Option<Binding>,
+    used: bool, // Giriş noktasından erişilebilen kod tarafından kullanılıyor
+}
+
+// Bir arayüz değişkeninin bağlandığı yerleşik değişken veya konum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Binding {
+    BuiltIn(u32),
+    Location { location: u32, component: u32, index: u32 },
+}
+
+// Bir çağrının girişleri.
+struct Inputs<'a> {
+    locations: &'a dyn Fn(u32) -> [Dual; 4],
+    built_in: &'a dyn Fn(u32) -> Value,
+    uniforms: &'a HashMap<u32, UniformValue>,
+}
+
+// Çağrı sonunda okunan bir çıkış: yerleşik değişkenin değeri veya bir konumun tek bileşeni.
+enum Output<'v> {
+    BuiltIn(u32, &'v Value),
+    Scalar { location: u32, index: u32, component: usize, value: f32 },
+}
+
+impl<'m> Stage<'m> {
+    fn new(module: &'m SpirvModule, name: &str, stage: ShaderStage) -> Result<Self, GraphisError> {
+        let entry_point = module.entry_point(name, stage).ok_or_else(|| {
+            GraphisError::ValidationFailed(format!("module has no {:?} entry point named {:?}", stage, name))
+        })?;
+        for ty in module.types.values() {
+            check_type(ty)?;
+        }
+        let mut compiled = Stage {
+            module,
+            entry_point,
+            functions: HashMap::new(),
+            constants: vec![Value::Undefined; module.bound() as usize],
+            globals: Vec::new(),
+            extended: HashMap::new(),
+            derivatives: false,
+        };
+        let initializers = compiled.read_module()?;
+        for (&id, variable) in &module.variables {
+            compiled.constants[id as usize] =
+                Value::Pointer(Pointer { slot: compiled.globals.len(), path: Vec::new() });
+            compiled.globals.push(Global {
+                id,
+                storage_class: variable.storage_class,
+                ty: compiled.type_of(variable.pointee),
+                initializer: initializers.get(&id).copied(),
+                binding: binding(module, id),
+                used: false,
+            });
+        }
+        let mut used = HashSet::new();
+        let mut derivatives = false;
+        compiled.check_function(
+            entry_point.function,
+            &mut Vec::new(),
+            &mut HashSet::new(),
+            &mut used,
+            &mut derivatives,
+        )?;
+        compiled.derivatives = derivatives;
+        for global in &mut compiled.globals {
+            global.used = used.contains(&global.id);
+        }
+        for global in compiled.globals.iter().filter(|global| global.used) {
+            compiled.check_global(global)?;
+        }
+        Ok(compiled)
+    }
+
+    // Fonksiyonları bloklarına ayırır, sabitleri değerlendirir ve genişletilmiş komut kümelerini tanır.
+    // Global değişkenlerin ilk değerlerinin kimliklerini döndürür.
+    fn read_module(&mut self) -> Result<HashMap<u32, u32>, GraphisError> {
+        let module = self.module;
+        let mut initializers = HashMap::new();
+        let mut function: Option<(u32, Function)> = None;
+        let mut block: Option<(u32, usize)> = None; // (etiket, ilk komut)
+        for (index, instruction) in module.instructions.iter().enumerate() {
+            match (instruction.opcode, function.as_mut()) {
+                (op::FUNCTION, None) => function = Some((instruction.operand(1)?, Function::default())),
+                (op::FUNCTION_END, Some(_)) => {
+                    if let Some((label, _)) = block {
+                        return invalid(format!("block %{} has no terminator", label));
+                    }
+                    if let Some((id, finished)) = function.take() {
+                        self.functions.insert(id, finished);
+                    }
+                }
+                (op::FUNCTION_PARAMETER, Some((_, current))) => current.parameters.push(instruction.operand(1)?),
+                (op::LABEL, Some(_)) => {
+                    if let Some((label, _)) = block {
+                        return invalid(format!("block %{} has no terminator", label));
+                    }
+                    block = Some((instruction.operand(0)?, index + 1));
+                }
+                (opcode, Some((_, current))) if is_terminator(opcode) => {
+                    let Some((label, start)) = block.take() else {
+                        return invalid(format!("instruction at word {} is outside of a block", instruction.offset));
+                    };
+                    current.labels.insert(label, current.blocks.len());
+                    current.blocks.push(Block { label, start, end: index + 1 });
+                }
+                (_, Some(_)) => {
+                    if block.is_none() {
+                        return invalid(format!("instruction at word {} is outside of a block", instruction.offset));
+                    }
+                }
+                (_, None) => self.read_global(instruction, &mut initializers)?,
+            }
+        }
+        Ok(initializers)
+    }
+
+    fn read_global(
+        &mut self,
+        instruction: &Instruction,
+        initializers: &mut HashMap<u32, u32>,
+    ) -> Result<(), GraphisError> {
+        let value = match instruction.opcode {
+            op::EXT_INST_IMPORT => {
+                let (name, _) = instruction.string(1)?;
+                let set = if name == "GLSL.std.450" {
+                    ExtendedSet::Glsl
+                } else if name.starts_with("NonSemantic.") {
+                    ExtendedSet::NonSemantic
+                } else {
+                    return unsupported(format!("extended instruction set {:?} is not supported", name));
+                };
+                self.extended.insert(instruction.operand(0)?, set);
+                return Ok(());
+            }
+            op::VARIABLE => {
+                if let Some(&initializer) = instruction.operands.get(3) {
+                    initializers.insert(instruction.operand(1)?, initializer);
+                }
+                return Ok(());
+            }
+            op::CONSTANT | op::SPEC_CONSTANT => match self.type_of(instruction.operand(0)?) {
+                SpirvType::Float { .. } => float(f32::from_bits(instruction.operand(2)?)),
+                _ => Value::Int(instruction.operand(2)?),
+            },
+            op::CONSTANT_TRUE | op::SPEC_CONSTANT_TRUE => Value::Bool(true),
+            op::CONSTANT_FALSE | op::SPEC_CONSTANT_FALSE => Value::Bool(false),
+            op::CONSTANT_COMPOSITE | op::SPEC_CONSTANT_COMPOSITE => {
+                let components = instruction.operands.get(2..).unwrap_or(&[]);
+                Value::Composite(components.iter().map(|&id| self.constant(id)).collect())
+            }
+            op::CONSTANT_NULL | op::UNDEF => zero(self.type_of(instruction.operand(0)?)),
+            op::SPEC_CONSTANT_OP => {
+                return unsupported(format!("OpSpecConstantOp at word {} is not supported", instruction.offset));
+            }
+            _ => return Ok(()),
+        };
+        let id = instruction.operand(1)?;
+        match self.constants.get_mut(id as usize) {
+            Some(slot) => *slot = value,
+            None => return invalid(format!("id %{} is out of bounds", id)),
+        }
+        Ok(())
+    }
+
+    // `id` fonksiyonunu ve çağırdığı fonksiyonları denetler. `path`, özyinelemeyi yakalamak için çağrı zinciridir.
+    // Kullanılan global değişkenler `used`'a eklenir.
+    fn check_function(
+        &self,
+        id: u32,
+        path: &mut Vec<u32>,
+        visited: &mut HashSet<u32>,
+        used: &mut HashSet<u32>,
+        derivatives: &mut bool,
+    ) -> Result<(), GraphisError> {
+        if path.contains(&id) {
+            return invalid(format!("function %{} is called recursively", id));
+        }
+        if !visited.insert(id) {
+            return Ok(());
+        }
+        let function = match self.functions.get(&id) {
+            Some(function) if !function.blocks.is_empty() => function,
+            _ => return unsupported(format!("function %{} has no body", id)),
+        };
+        path.push(id);
+        for block in &function.blocks {
+            for instruction in &self.module.instructions[block.start..block.end] {
+                self.check_instruction(instruction, function, used, derivatives)?;
+                if instruction.opcode == op::FUNCTION_CALL {
+                    self.check_function(instruction.operand(2)?, path, visited, used, derivatives)?;
+                }
+            }
+        }
+        path.pop();
+        Ok(())
+    }
+
+    fn check_instruction(
+        &self,
+        instruction: &Instruction,
+        function: &Function,
+        used: &mut HashSet<u32>,
+        derivatives: &mut bool,
+    ) -> Result<(), GraphisError> {
+        let opcode = instruction.opcode;
+        let operands = &instruction.operands;
+        if !supported(opcode) {
+            return unsupported(format!("opcode {} at word {} is not supported", opcode, instruction.offset));
+        }
+        // İşaretçi alan işlenenler; global değişkenlerin kullanımı bunlardan bulunur.
+        let pointers = match opcode {
+            op::LOAD | op::ACCESS_CHAIN | op::IN_BOUNDS_ACCESS_CHAIN | op::COPY_OBJECT => operands.get(2..3),
+            op::STORE => operands.get(..1),
+            op::COPY_MEMORY => operands.get(..2),
+            op::FUNCTION_CALL => operands.get(3..),
+            op::EXT_INST => operands.get(4..),
+            _ => None,
+        };
+        used.extend(pointers.unwrap_or(&[]).iter().filter(|id| self.module.variables.contains_key(id)));
+        match opcode {
+            op::BRANCH | op::BRANCH_CONDITIONAL | op::SWITCH => {
+                let targets: Vec<u32> = match opcode {
+                    op::BRANCH => operands.iter().take(1).copied().collect(),
+                    op::BRANCH_CONDITIONAL => operands.iter().skip(1).take(2).copied().collect(),
+                    _ => operands.iter().skip(1).take(1).chain(operands.iter().skip(3).step_by(2)).copied().collect(),
+                };
+                if let Some(target) = targets.iter().find(|target| !function.labels.contains_key(target)) {
+                    return invalid(format!(
+                        "branch at word {} targets %{}, which is not a block of the same function",
+                        instruction.offset, target
+                    ));
+                }
+            }
+            op::EXT_INST => match self.extended.get(&instruction.operand(2)?) {
+                Some(ExtendedSet::Glsl) => {
+                    let number = instruction.operand(3)?;
+                    if !glsl_supported(number) {
+                        return unsupported(format!(
+                            "GLSL.std.450 instruction {} at word {} is not supported",
+                            number, instruction.offset
+                        ));
+                    }
+                }
+                Some(ExtendedSet::NonSemantic) => {}
+                None => {
+                    return invalid(format!("OpExtInst at word {} uses an unknown instruction set", instruction.offset))
+                }
+            },
+            op::IMAGE_SAMPLE_IMPLICIT_LOD..=op::IMAGE_FETCH => {
+                let mask = operands.get(if is_dref(opcode) { 5 } else { 4 }).copied().unwrap_or(0);
+                if mask & !(BIAS | LOD | GRAD | IGNORED_IMAGE_OPERANDS) != 0 {
+                    return unsupported(format!(
+                        "image operands {:#x} at word {} are not supported",
+                        mask, instruction.offset
+                    ));
+                }
+                *derivatives |= matches!(
+                    opcode,
+                    op::IMAGE_SAMPLE_IMPLICIT_LOD
+                        | op::IMAGE_SAMPLE_DREF_IMPLICIT_LOD
+                        | op::IMAGE_SAMPLE_PROJ_IMPLICIT_LOD
+                        | op::IMAGE_SAMPLE_PROJ_DREF_IMPLICIT_LOD
+                );
+            }
+            op::DPDX..=op::FWIDTH_COARSE => *derivatives = true,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn check_global(&self, global: &Global<'_>) -> Result<(), GraphisError> {
+        match global.storage_class {
+            StorageClass::Input | StorageClass::Output => {
+                let mut built_ins = Vec::new();
+                if let Some(Binding::BuiltIn(kind)) = global.binding {
+                    built_ins.push(kind);
+                }
+                if let SpirvType::Struct { members, .. } = global.ty {
+                    built_ins.extend(members.iter().filter_map(|member| member.built_in));
+                }
+                let model = self.entry_point.execution_model;
+                if let Some(kind) =
+                    built_ins.iter().find(|&&kind| !supported_built_in(model, global.storage_class, kind))
+                {
+                    return unsupported(format!(
+                        "built-in {} of {:?} variable %{} is not supported in {:?} shaders",
+                        kind, global.storage_class, global.id, model
+                    ));
+                }
+            }
+            StorageClass::UniformConstant if is_texture(global.ty) => {
+                let set = self.decoration(global.id, decoration::DESCRIPTOR_SET).unwrap_or(0);
+                if set != 0 {
+                    return unsupported(format!(
+                        "texture variable %{} is in descriptor set {}; only set 0 maps to texture units",
+                        global.id, set
+                    ));
+                }
+            }
+            StorageClass::UniformConstant | StorageClass::Private | StorageClass::Function => {}
+            other => {
+                return unsupported(format!(
+                    "variable %{} is in the {:?} storage class; uniform and storage buffers and push constants are not \
+                     supported",
+                    global.id, other
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    fn type_of(&self, id: u32) -> &'m SpirvType {
+        self.module.types.get(&id).unwrap_or(&SpirvType::Void)
+    }
+
+    // İşaretçi tipinin işaret ettiği tip.
+    fn pointee(&self, pointer_type: u32) -> &'m SpirvType {
+        self.module.inner_types.get(&pointer_type).map_or(&SpirvType::Void, |&id| self.type_of(id))
+    }
+
+    fn constant(&self, id: u32) -> Value {
+        self.constants.get(id as usize).cloned().unwrap_or(Value::Undefined)
+    }
+
+    fn decoration(&self, id: u32, kind: u32) -> Option<u32> {
+        self.module.decoration(id, kind).and_then(|operands| operands.first().copied())
+    }
+
+    // Giriş noktasını çalıştırır. Çağrının belleğini ve fragment'ın yardımcı çağrıya dönüştürülüp
+    // dönüştürülmediğini (OpDemoteToHelperInvocation) döndürür.
+    fn run(&self, inputs: &Inputs<'_>, textures: &TextureBindings<'_>) -> Result<(Vec<Value>, bool), Halt> {
+        let memory = self.globals.iter().map(|global| self.initial_value(global, inputs)).collect();
+        let mut machine = Machine { stage: self, textures, memory, steps: 0, demoted: false };
+        machine.call(self.entry_point.function, Vec::new())?;
+        Ok((machine.memory, machine.demoted))
+    }
+
+    fn initial_value(&self, global: &Global<'_>, inputs: &Inputs<'_>) -> Value {
+        if !global.used {
+            return Value::Undefined;
+        }
+        match global.storage_class {
+            StorageClass::Input => read_interface(global, inputs),
+            StorageClass::UniformConstant if is_texture(global.ty) => {
+                texture_units(global.ty, self.decoration(global.id, decoration::BINDING).unwrap_or(0))
+            }
+            StorageClass::UniformConstant => match global.binding {
+                Some(Binding::Location { location, .. }) => uniform(global.ty, location, inputs.uniforms),
+                _ => zero(global.ty),
+            },
+            _ => match global.initializer {
+                Some(id) => self.constant(id),
+                None => zero(global.ty),
+            },
+        }
+    }
+
+    // Kullanılan çıkış değişkenlerini yerleşik değişkenler ve konum başına bileşenler olarak `sink`'e verir.
+    fn outputs(&self, memory: &[Value], sink: &mut dyn FnMut(Output<'_>)) {
+        for (global, value) in self.globals.iter().zip(memory) {
+            if global.used && global.storage_class == StorageClass::Output {
+                write_interface(global, value, sink);
+            }
+        }
+    }
+}
+
+fn is_terminator(opcode: u16) -> bool {
+    matches!(opcode, op::BRANCH..=op::UNREACHABLE | op::TERMINATE_INVOCATION)
+}
+
+fn is_dref(opcode: u16) -> bool {
+    matches!(
+        opcode,
+        op::IMAGE_SAMPLE_DREF_IMPLICIT_LOD
+            | op::IMAGE_SAMPLE_DREF_EXPLICIT_LOD
+            | op::IMAGE_SAMPLE_PROJ_DREF_IMPLICIT_LOD
+            | op::IMAGE_SAMPLE_PROJ_DREF_EXPLICIT_LOD
+    )
+}
+
+// Fonksiyon gövdelerinde yorumlanabilen komutlar.
+fn supported(opcode: u16) -> bool {
+    matches!(
+        opcode,
+        op::NOP
+            | op::UNDEF
+            | op::LINE
+            | op::NO_LINE
+            | op::EXT_INST
+            | op::FUNCTION_CALL
+            | op::VARIABLE
+            | op::LOAD
+            | op::STORE
+            | op::COPY_MEMORY
+            | op::ACCESS_CHAIN
+            | op::IN_BOUNDS_ACCESS_CHAIN
+            | op::VECTOR_EXTRACT_DYNAMIC..=op::TRANSPOSE
+            | op::SAMPLED_IMAGE..=op::IMAGE_FETCH
+            | op::IMAGE
+            | op::IMAGE_QUERY_SIZE_LOD
+            | op::IMAGE_QUERY_SIZE
+            | op::IMAGE_QUERY_LEVELS
+            | op::CONVERT_F_TO_U..=op::QUANTIZE_TO_F16
+            | op::BITCAST
+            | op::S_NEGATE..=op::S_MUL_EXTENDED
+            | op::ANY..=op::IS_INF
+            | op::LOGICAL_EQUAL..=op::F_UNORD_GREATER_THAN_EQUAL
+            | op::SHIFT_RIGHT_LOGICAL..=op::BIT_COUNT
+            | op::DPDX..=op::FWIDTH_COARSE
+            | op::PHI..=op::UNREACHABLE
+            | op::COPY_LOGICAL
+            | op::TERMINATE_INVOCATION
+            | op::DEMOTE_TO_HELPER_INVOCATION
+    )
+}
+
+fn supported_built_in(model: ExecutionModel, storage_class: StorageClass, kind: u32) -> bool {
+    match (model, storage_class) {
+        (ExecutionModel::Vertex, StorageClass::Input) => matches!(
+            kind,
+            built_in::VERTEX_INDEX | built_in::INSTANCE_INDEX | built_in::VERTEX_ID | built_in::INSTANCE_ID
+        ),
+        // Kırpma ve eleme mesafeleri yazılabilir, ancak kırpmada kullanılmaz.
+        (ExecutionModel::Vertex, StorageClass::Output) => matches!(
+            kind,
+            built_in::POSITION | built_in::POINT_SIZE | built_in::CLIP_DISTANCE | built_in::CULL_DISTANCE
+        ),
+        (ExecutionModel::Fragment, StorageClass::Input) => matches!(
+            kind,
+            built_in::FRAG_COORD | built_in::FRONT_FACING | built_in::POINT_COORD | built_in::HELPER_INVOCATION
+        ),
+        (ExecutionModel::Fragment, StorageClass::Output) => kind == built_in::FRAG_DEPTH,
+        _ => false,
+    }
+}
+
+fn check_type(ty: &SpirvType) -> Result<(), GraphisError> {
+    match ty {
+        SpirvType::Int { width, .. } | SpirvType::Float { width } if *width != 32 => {
+            unsupported(format!("{}-bit numeric types are not supported", width))
+        }
+        SpirvType::Image { multisampled: true, .. } | SpirvType::Image { storage: true, .. } => {
+            unsupported("multisampled and storage images are not supported".to_string())
+        }
+        SpirvType::Image { dimension, arrayed, .. } => match (dimension, arrayed) {
+            (ImageDimension::D2, _) | (ImageDimension::D3 | ImageDimension::Cube, false) => Ok(()),
+            _ => unsupported(format!(
+                "{:?} images{} are not supported",
+                dimension,
+                if *arrayed { " with array layers" } else { "" }
+            )),
+        },
+        _ => Ok(()),
+    }
+}
+
+fn is_texture(ty: &SpirvType) -> bool {
+    match ty {
+        SpirvType::Image { .. } | SpirvType::Sampler | SpirvType::SampledImage { .. } => true,
+        SpirvType::Array { element, .. } => is_texture(element),
+        _ => false,
+    }
+}
+
+fn binding(module: &SpirvModule, id: u32) -> Option<Binding> {
+    let first = |kind| module.decoration(id, kind).and_then(|operands| operands.first().copied());
+    if let Some(kind) = first(decoration::BUILT_IN) {
+        return Some(Binding::BuiltIn(kind));
+    }
+    Some(Binding::Location {
+        location: first(decoration::LOCATION)?,
+        component: first(decoration::COMPONENT).unwrap_or(0),
+        index: first(decoration::INDEX).unwrap_or(0),
+    })
+}
+
+// Bir çağrının erken sonlanma nedeni.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Halt {
+    Kill, // OpKill, OpTerminateInvocation veya OpUnreachable
+    StepLimit,
+}
+
+// Bir çağrının değerlerini ve belleğini tutan yorumlayıcı.
+struct Machine<'s, 'm> {
+    stage: &'s Stage<'m>,
+    textures: &'s TextureBindings<'s>,
+    // Global değişkenler ve çağrı yığınındaki fonksiyonların değişkenleri; işaretçiler buradaki sıraya işaret eder.
+    memory: Vec<Value>,
+    steps: usize,
+    demoted: bool,
+}
+
+impl<'m> Stage<'m> {
+    // Fonksiyonun yerel değeri, yoksa sabitin değeri.
+    fn value<'v>(&'v self, frame: &'v [Value], id: u32) -> &'v Value {
+        match frame.get(id as usize) {
+            Some(Value::Undefined) | None => self.constants.get(id as usize).unwrap_or(&UNDEFINED),
+            Some(value) => value,
+        }
+    }
+}
+
+impl Machine<'_, '_> {
+    fn call(&mut self, id: u32, arguments: Vec<Value>) -> Result<Value, Halt> {
+        let stage = self.stage;
+        let Some(function) = stage.functions.get(&id) else { return Ok(Value::Undefined) };
+        let mut frame = vec![Value::Undefined; stage.constants.len()];
+        for (&parameter, argument) in function.parameters.iter().zip(arguments) {
+            set(&mut frame, parameter, argument);
+        }
+        let base = self.memory.len();
+        let result = self.run(function, &mut frame);
+        self.memory.truncate(base);
+        result
+    }
+
+    fn run(&mut self, function: &Function, frame: &mut [Value]) -> Result<Value, Halt> {
+        let stage = self.stage;
+        let mut block = function.blocks[0];
+        let mut previous = None;
+        loop {
+            // Bloğun başındaki OpPhi'ler, hepsi önceki bloktan gelinen değerlerle okunduktan sonra atanır.
+            let mut phis = Vec::new();
+            let mut next = None;
+            for instruction in &stage.module.instructions[block.start..block.end] {
+                self.steps += 1;
+                if self.steps > MAX_STEPS {
+                    return Err(Halt::StepLimit);
+                }
+                let operands = &instruction.operands;
+                let word = |index: usize| operands.get(index).copied().unwrap_or(0);
+                if instruction.opcode == op::PHI {
+                    let incoming = operands
+                        .get(2..)
+                        .unwrap_or(&[])
+                        .chunks_exact(2)
+                        .find(|pair| Some(pair[1]) == previous)
+                        .map_or(Value::Undefined, |pair| stage.value(frame, pair[0]).clone());
+                    phis.push((word(1), incoming));
+                    continue;
+                }
+                for (id, value) in phis.drain(..) {
+                    set(frame, id, value);
+                }
+                match instruction.opcode {
+                    op::BRANCH => next = Some(word(0)),
+                    op::BRANCH_CONDITIONAL => {
+                        next = Some(if stage.value(frame, word(0)).bool() { word(1) } else { word(2) });
+                    }
+                    op::SWITCH => {
+                        let selector = stage.value(frame, word(0)).int();
+                        let targets = operands.get(2..).unwrap_or(&[]).chunks_exact(2);
+                        next =
+                            Some(targets.into_iter().find(|pair| pair[0] == selector).map_or(word(1), |pair| pair[1]));
+                    }
+                    op::RETURN => return Ok(Value::Undefined),
+                    op::RETURN_VALUE => return Ok(stage.value(frame, word(0)).clone()),
+                    op::KILL | op::TERMINATE_INVOCATION | op::UNREACHABLE => return Err(Halt::Kill),
+                    _ => self.execute(instruction, frame)?,
+                }
+            }
+            previous = Some(block.label);
+            block = match next.and_then(|label| function.labels.get(&label)) {
+                Some(&index) => function.blocks[index],
+                None => return Ok(Value::Undefined),
+            };
+        }
+    }
+
+    // Dallanma dışındaki bir komutu çalıştırır.
+    fn execute(&mut self, instruction: &Instruction, frame: &mut [Value]) -> Result<(), Halt> {
+        let stage = self.stage;
+        let operands = &instruction.operands;
+        let word = |index: usize| operands.get(index).copied().unwrap_or(0);
+        let result = match instruction.opcode {
+            op::NOP | op::LINE | op::NO_LINE | op::SELECTION_MERGE | op::LOOP_MERGE => return Ok(()),
+            op::DEMOTE_TO_HELPER_INVOCATION => {
+                self.demoted = true;
+                return Ok(());
+            }
+            op::STORE => {
+                let value = stage.value(frame, word(1)).clone();
+                self.store(stage.value(frame, word(0)), value);
+                return Ok(());
+            }
+            op::COPY_MEMORY => {
+                let value = self.load(stage.value(frame, word(1)));
+                self.store(stage.value(frame, word(0)), value);
+                return Ok(());
+            }
+            op::FUNCTION_CALL => {
+                let arguments = operands.iter().skip(3).map(|&id| stage.value(frame, id).clone()).collect();
+                self.call(word(2), arguments)?
+            }
+            op::VARIABLE => {
+                let initial = match operands.get(3) {
+                    Some(&id) => stage.value(frame, id).clone(),
+                    None => zero(stage.pointee(word(0))),
+                };
+                self.memory.push(initial);
+                Value::Pointer(Pointer { slot: self.memory.len() - 1, path: Vec::new() })
+            }
+            op::LOAD => self.load(stage.value(frame, word(2))),
+            op::EXT_INST => {
+                if stage.extended.get(&word(2)) != Some(&ExtendedSet::Glsl) {
+                    return Ok(());
+                }
+                let arguments: Vec<&Value> = operands.iter().skip(4).map(|&id| stage.value(frame, id)).collect();
+                match word(3) {
+                    // Tam kısım ve üs, ikinci argümanın işaret ettiği değişkene yazılır.
+                    number @ (glsl::MODF | glsl::FREXP) => {
+                        let structure = if number == glsl::MODF { glsl::MODF_STRUCT } else { glsl::FREXP_STRUCT };
+                        let Value::Composite(mut parts) = extended(structure, &arguments) else { return Ok(()) };
+                        let second = parts.pop().unwrap_or_default();
+                        if let Some(pointer) = arguments.get(1) {
+                            self.store(pointer, second);
+                        }
+                        parts.pop().unwrap_or_default()
+                    }
+                    number => extended(number, &arguments),
+                }
+            }
+            _ => self.evaluate(instruction, frame),
+        };
+        set(frame, word(1), result);
+        Ok(())
+    }
+
+    fn load(&self, pointer: &Value) -> Value {
+        match pointer {
+            Value::Pointer(pointer) => {
+                self.memory.get(pointer.slot).map_or(Value::Undefined, |value| value.at(&pointer.path).clone())
+            }
+            _ => Value::Undefined,
+        }
+    }
+
+    fn store(&mut self, pointer: &Value, value: Value) {
+        if let Value::Pointer(pointer) = pointer {
+            if let Some(target) = self.memory.get_mut(pointer.slot).and_then(|target| target.at_mut(&pointer.path)) {
+                *target = value;
+            }
+        }
+    }
+
+    // Belleğe dokunmayan, sonucu yalnızca işlenenlerine bağlı komutlar.
+    fn evaluate(&self, instruction: &Instruction, frame: &[Value]) -> Value {
+        let stage = self.stage;
+        let operands = &instruction.operands;
+        let word = |index: usize| operands.get(index).copied().unwrap_or(0);
+        let arg = |index: usize| stage.value(frame, word(index));
+        let ty = stage.type_of(word(0));
+        let (a, b) = (arg(2), arg(3));
+        match instruction.opcode {
+            op::UNDEF => zero(ty),
+            op::ACCESS_CHAIN | op::IN_BOUNDS_ACCESS_CHAIN => match a {
+                Value::Pointer(pointer) => {
+                    let mut path = pointer.path.clone();
+                    path.extend(operands.iter().skip(3).map(|&id| stage.value(frame, id).int()));
+                    Value::Pointer(Pointer { slot: pointer.slot, path })
+                }
+                _ => Value::Undefined,
+            },
+
+            // Bileşik değerler
+            op::VECTOR_EXTRACT_DYNAMIC => a.at(&[b.int()]).clone(),
+            op::VECTOR_INSERT_DYNAMIC => {
+                let mut vector = a.clone();
+                if let Some(component) = vector.at_mut(&[arg(4).int()]) {
+                    *component = b.clone();
+                }
+                vector
+            }
+            op::VECTOR_SHUFFLE => {
+                let components: Vec<&Value> = a.items().iter().chain(b.items()).collect();
+                let fill = match ty {
+                    SpirvType::Vector { component, .. } => zero(component),
+                    _ => Value::Undefined,
+                };
+                let selected = operands.iter().skip(4).map(|&index| match components.get(index as usize) {
+                    Some(&component) => component.clone(),
+                    None => fill.clone(), // 0xFFFFFFFF: tanımsız bileşen
+                });
+                Value::Composite(selected.collect())
+            }
+            op::COMPOSITE_CONSTRUCT => {
+                let constituents = operands.iter().skip(2).map(|&id| stage.value(frame, id));
+                match ty {
+                    // Vektörler daha küçük vektörlerden de oluşturulabilir.
+                    SpirvType::Vector { .. } => {
+                        Value::Composite(constituents.flat_map(Value::items).cloned().collect())
+                    }
+                    _ => Value::Composite(constituents.cloned().collect()),
+                }
+            }
+            op::COMPOSITE_EXTRACT => a.at(operands.get(3..).unwrap_or(&[])).clone(),
+            op::COMPOSITE_INSERT => {
+                let mut composite = b.clone();
+                if let Some(target) = composite.at_mut(operands.get(4..).unwrap_or(&[])) {
+                    *target = a.clone();
+                }
+                composite
+            }
+            op::COPY_OBJECT | op::COPY_LOGICAL | op::SAMPLED_IMAGE | op::IMAGE => a.clone(),
+            op::TRANSPOSE => transpose(a),
+
+            // Dokular
+            op::IMAGE_SAMPLE_IMPLICIT_LOD..=op::IMAGE_SAMPLE_PROJ_DREF_EXPLICIT_LOD => {
+                self.sample(instruction, frame, ty)
+            }
+            op::IMAGE_FETCH => self.fetch(instruction, frame, ty),
+            op::IMAGE_QUERY_SIZE_LOD | op::IMAGE_QUERY_SIZE => {
+                let level = if instruction.opcode == op::IMAGE_QUERY_SIZE_LOD { b.int() } else { 0 };
+                let size = self.texture(a).map_or([0; 3], |texture| texture.size(level));
+                match ty {
+                    SpirvType::Vector { count, .. } => {
+                        Value::Composite(size.iter().take(*count as usize).map(|&value| Value::Int(value)).collect())
+                    }
+                    _ => Value::Int(size[0]),
+                }
+            }
+            op::IMAGE_QUERY_LEVELS => Value::Int(self.texture(a).map_or(0, |texture| texture.view().mip_level_count())),
+
+            // Dönüşümler
+            op::CONVERT_F_TO_U => map(a, &|x| Value::Int(x.float().value as u32)),
+            op::CONVERT_F_TO_S => map(a, &|x| Value::Int(x.float().value as i32 as u32)),
+            op::CONVERT_S_TO_F => map(a, &|x| float(x.int() as i32 as f32)),
+            op::CONVERT_U_TO_F => map(a, &|x| float(x.int() as f32)),
+            op::U_CONVERT | op::S_CONVERT | op::F_CONVERT => a.clone(),
+            op::QUANTIZE_TO_F16 => floats(a, &|x| Dual { value: texel::f16_to_f32(texel::f32_to_f16(x.value)), ..x }),
+            op::BITCAST => {
+                let kind = ty.scalar_kind();
+                map(a, &|x| match kind {
+                    Some(ScalarKind::Float) => float(f32::from_bits(bits(x))),
+                    _ => Value::Int(bits(x)),
+                })
+            }
+
+            // Aritmetik
+            op::S_NEGATE => ints(a, &u32::wrapping_neg),
+            op::F_NEGATE => floats(a, &|x| -x),
+            op::I_ADD => ints2(a, b, &u32::wrapping_add),
+            op::F_ADD => floats2(a, b, &|x, y| x + y),
+            op::I_SUB => ints2(a, b, &u32::wrapping_sub),
+            op::F_SUB => floats2(a, b, &|x, y| x - y),
+            op::I_MUL => ints2(a, b, &u32::wrapping_mul),
+            op::F_MUL | op::VECTOR_TIMES_SCALAR | op::MATRIX_TIMES_SCALAR => floats2(a, b, &|x, y| x * y),
+            // Sıfıra bölme ve taşan bölme tanımsızdır; sonuç 0 olur.
+            op::U_DIV => ints2(a, b, &|x, y| x.checked_div(y).unwrap_or(0)),
+            op::S_DIV => ints2(a, b, &|x, y| (x as i32).checked_div(y as i32).unwrap_or(0) as u32),
+            op::F_DIV => floats2(a, b, &|x, y| x / y),
+            op::U_MOD => ints2(a, b, &|x, y| x.checked_rem(y).unwrap_or(0)),
+            op::S_REM => ints2(a, b, &|x, y| (x as i32).checked_rem(y as i32).unwrap_or(0) as u32),
+            op::S_MOD => ints2(a, b, &|x, y| {
+                let (x, y) = (x as i32, y as i32);
+                match x.checked_rem(y) {
+                    Some(remainder) if remainder != 0 && (remainder < 0) != (y < 0) => remainder.wrapping_add(y) as u32,
+                    remainder => remainder.unwrap_or(0) as u32,
+                }
+            }),
+            // Kalanın işareti F_REM'de bölünenin, F_MOD'da bölenin işaretidir.
+            op::F_REM => floats2(a, b, &|x, y| Dual {
+                value: x.value % y.value,
+                ..x - y * Dual::constant((x.value / y.value).trunc())
+            }),
+            op::F_MOD => floats2(a, b, &|x, y| x - y * Dual::constant((x.value / y.value).floor())),
+            op::VECTOR_TIMES_MATRIX => {
+                Value::Composite(b.items().iter().map(|column| Value::Float(dot(a, column))).collect())
+            }
+            op::MATRIX_TIMES_VECTOR => matrix_times_vector(a, b),
+            op::MATRIX_TIMES_MATRIX => {
+                Value::Composite(b.items().iter().map(|column| matrix_times_vector(a, column)).collect())
+            }
+            op::OUTER_PRODUCT => Value::Composite(b.items().iter().map(|y| floats(a, &|x| x * y.float())).collect()),
+            op::DOT => Value::Float(dot(a, b)),
+            op::I_ADD_CARRY => Value::Composite(vec![
+                ints2(a, b, &u32::wrapping_add),
+                ints2(a, b, &|x, y| x.overflowing_add(y).1 as u32),
+            ]),
+            op::I_SUB_BORROW => {
+                Value::Composite(vec![ints2(a, b, &u32::wrapping_sub), ints2(a, b, &|x, y| (x < y) as u32)])
+            }
+            op::U_MUL_EXTENDED => Value::Composite(vec![
+                ints2(a, b, &u32::wrapping_mul),
+                ints2(a, b, &|x, y| ((x as u64 * y as u64) >> 32) as u32),
+            ]),
+            op::S_MUL_EXTENDED => Value::Composite(vec![
+                ints2(a, b, &u32::wrapping_mul),
+                ints2(a, b, &|x, y| ((x as i32 as i64 * y as i32 as i64) >> 32) as u32),
+            ]),
+
+            // Karşılaştırma ve mantık
+            op::ANY => Value::Bool(a.items().iter().any(Value::bool)),
+            op::ALL => Value::Bool(a.items().iter().all(Value::bool)),
+            op::IS_NAN => map(a, &|x| Value::Bool(x.float().value.is_nan())),
+            op::IS_INF => map(a, &|x| Value::Bool(x.float().value.is_infinite())),
+            op::LOGICAL_EQUAL => compare(a, b, &|x, y| x.bool() == y.bool()),
+            op::LOGICAL_NOT_EQUAL => compare(a, b, &|x, y| x.bool() != y.bool()),
+            op::LOGICAL_OR => compare(a, b, &|x, y| x.bool() || y.bool()),
+            op::LOGICAL_AND => compare(a, b, &|x, y| x.bool() && y.bool()),
+            op::LOGICAL_NOT => map(a, &|x| Value::Bool(!x.bool())),
+            // Koşul skalerse bileşik değerin tamamı seçilir.
+            op::SELECT => match a {
+                Value::Composite(_) => {
+                    zip3(a, b, arg(4), &|condition, x, y| if condition.bool() { x } else { y }.clone())
+                }
+                condition => if condition.bool() { b } else { arg(4) }.clone(),
+            },
+            op::I_EQUAL => compare(a, b, &|x, y| x.int() == y.int()),
+            op::I_NOT_EQUAL => compare(a, b, &|x, y| x.int() != y.int()),
+            op::U_GREATER_THAN => compare(a, b, &|x, y| x.int() > y.int()),
+            op::S_GREATER_THAN => compare(a, b, &|x, y| x.int() as i32 > y.int() as i32),
+            op::U_GREATER_THAN_EQUAL => compare(a, b, &|x, y| x.int() >= y.int()),
+            op::S_GREATER_THAN_EQUAL => compare(a, b, &|x, y| x.int() as i32 >= y.int() as i32),
+            op::U_LESS_THAN => compare(a, b, &|x, y| x.int() < y.int()),
+            op::S_LESS_THAN => compare(a, b, &|x, y| (x.int() as i32) < y.int() as i32),
+            op::U_LESS_THAN_EQUAL => compare(a, b, &|x, y| x.int() <= y.int()),
+            op::S_LESS_THAN_EQUAL => compare(a, b, &|x, y| x.int() as i32 <= y.int() as i32),
+            // Sıralı (ordered) karşılaştırmalar NaN ile false, sırasızlar true verir.
+            opcode @ op::F_ORD_EQUAL..=op::F_UNORD_GREATER_THAN_EQUAL => {
+                let (ordered, test): (bool, fn(f32, f32) -> bool) = match opcode {
+                    op::F_ORD_EQUAL => (true, |x, y| x == y),
+                    op::F_UNORD_EQUAL => (false, |x, y| x == y),
+                    op::F_ORD_NOT_EQUAL => (true, |x, y| x != y),
+                    op::F_UNORD_NOT_EQUAL => (false, |x, y| x != y),
+                    op::F_ORD_LESS_THAN => (true, |x, y| x < y),
+                    op::F_UNORD_LESS_THAN => (false, |x, y| x < y),
+                    op::F_ORD_GREATER_THAN => (true, |x, y| x > y),
+                    op::F_UNORD_GREATER_THAN => (false, |x, y| x > y),
+                    op::F_ORD_LESS_THAN_EQUAL => (true, |x, y| x <= y),
+                    op::F_UNORD_LESS_THAN_EQUAL => (false, |x, y| x <= y),
+                    op::F_ORD_GREATER_THAN_EQUAL => (true, |x, y| x >= y),
+                    _ => (false, |x, y| x >= y), // F_UNORD_GREATER_THAN_EQUAL
+                };
+                compare(a, b, &|x, y| {
+                    let (x, y) = (x.float().value, y.float().value);
+                    if x.is_nan() || y.is_nan() {
+                        !ordered
+                    } else {
+                        test(x, y)
+                    }
+                })
+            }
+
+            // Bit işlemleri; kaydırma miktarı 32'ye göre alınır.
+            op::SHIFT_RIGHT_LOGICAL => ints2(a, b, &u32::wrapping_shr),
+            op::SHIFT_RIGHT_ARITHMETIC => ints2(a, b, &|x, y| (x as i32).wrapping_shr(y) as u32),
+            op::SHIFT_LEFT_LOGICAL => ints2(a, b, &u32::wrapping_shl),
+            op::BITWISE_OR => ints2(a, b, &|x, y| x | y),
+            op::BITWISE_XOR => ints2(a, b, &|x, y| x ^ y),
+            op::BITWISE_AND => ints2(a, b, &|x, y| x & y),
+            op::NOT => ints(a, &|x| !x),
+            op::BIT_FIELD_INSERT => {
+                let mask = field_mask(arg(4).int(), arg(5).int());
+                ints2(a, b, &|base, insert| (base & !mask) | (insert.wrapping_shl(arg(4).int()) & mask))
+            }
+            op::BIT_FIELD_S_EXTRACT | op::BIT_FIELD_U_EXTRACT => {
+                let (offset, count) = (b.int(), arg(4).int());
+                let signed = instruction.opcode == op::BIT_FIELD_S_EXTRACT;
+                ints(a, &|base| {
+                    if count == 0 {
+                        return 0;
+                    }
+                    let field = (base & field_mask(offset, count)).wrapping_shr(offset);
+                    let shift = 32u32.saturating_sub(count);
+                    match signed {
+                        true => ((field << shift) as i32 >> shift) as u32,
+                        false => field,
+                    }
+                })
+            }
+            op::BIT_REVERSE => ints(a, &u32::reverse_bits),
+            op::BIT_COUNT => ints(a, &u32::count_ones),
+
+            // Türevler; ikinci türevler sıfırdır.
+            op::DPDX | op::DPDX_FINE | op::DPDX_COARSE => floats(a, &|x| Dual::constant(x.ddx)),
+            op::DPDY | op::DPDY_FINE | op::DPDY_COARSE => floats(a, &|x| Dual::constant(x.ddy)),
+            op::FWIDTH | op::FWIDTH_FINE | op::FWIDTH_COARSE => {
+                floats(a, &|x| Dual::constant(x.ddx.abs() + x.ddy.abs()))
+            }
+            _ => Value::Undefined,
+        }
+    }
+
+    fn texture(&self, value: &Value) -> Option<&SampledTexture<'_>> {
+        match value {
+            Value::Texture(unit) => self.textures.get(*unit),
+            _ => None,
+        }
+    }
+
+    fn sample(&self, instruction: &Instruction, frame: &[Value], ty: &SpirvType) -> Value {
+        let stage = self.stage;
+        let opcode = instruction.opcode;
+        let operands = &instruction.operands;
+        let word = |index: usize| operands.get(index).copied().unwrap_or(0);
+        let dref = is_dref(opcode);
+        let projective = matches!(
+            opcode,
+            op::IMAGE_SAMPLE_PROJ_IMPLICIT_LOD
+                | op::IMAGE_SAMPLE_PROJ_EXPLICIT_LOD
+                | op::IMAGE_SAMPLE_PROJ_DREF_IMPLICIT_LOD
+                | op::IMAGE_SAMPLE_PROJ_DREF_EXPLICIT_LOD
+        );
+        let Some(texture) = self.texture(stage.value(frame, word(2))) else {
+            return if dref { float(0.0) } else { texel_value(ty, [0.0, 0.0, 0.0, 1.0]) };
+        };
+        let coordinate = stage.value(frame, word(3));
+        let mut reference = if dref { stage.value(frame, word(4)).float() } else { Dual::default() };
+        let mask_index = if dref { 5 } else { 4 };
+        let mask = word(mask_index);
+        let mut extra = operands.iter().skip(mask_index + 1).map(|&id| stage.value(frame, id));
+        let mut next = |bit: u32| if mask & bit != 0 { extra.next() } else { None };
+        let bias = next(BIAS).map_or(0.0, |bias| bias.float().value);
+        let level = next(LOD).map(|level| level.float().value);
+        let gradient = next(GRAD).zip(next(GRAD));
+
+        // Projektif örneklemede koordinatlar ve karşılaştırma değeri koordinatın son bileşenine bölünür.
+        let dimension = texture.view().dimension();
+        let count = if dimension == TextureViewDimension::D2 { 2 } else { 3 };
+        let mut coords = [0, 1, 2].map(|i| coordinate.item(i).float());
+        if let Value::Float(x) = coordinate {
+            coords[0] = *x;
+        }
+        if projective {
+            let q = coordinate.item(count).float();
+            for component in coords.iter_mut().take(count) {
+                *component = *component / q;
+            }
+            reference = reference / q;
+        }
+        let planar = |value: &Value| [value.item(0).float().value, value.item(1).float().value];
+        // Küp dokularda yön vektörünün türevleri yüz koordinatlarına dönüştürülmez; örtük LOD sapmadan ibarettir.
+        let lod = match (level, gradient) {
+            (Some(level), _) => Lod::Level(level + bias),
+            (None, Some((ddx, ddy))) => Lod::Gradient { ddx: planar(ddx), ddy: planar(ddy) },
+            (None, None) if dimension == TextureViewDimension::Cube => Lod::Level(bias),
+            (None, None) => {
+                let scale = bias.exp2();
+                Lod::Gradient {
+                    ddx: [coords[0].ddx * scale, coords[1].ddx * scale],
+                    ddy: [coords[0].ddy * scale, coords[1].ddy * scale],
+                }
+            }
+        };
+        let coords = coords.map(|component| component.value);
+        match dref {
+            true => float(texture.sample_compare(coords, reference.value, lod)),
+            false => texel_value(ty, texture.sample(coords, lod)),
+        }
+    }
+
+    fn fetch(&self, instruction: &Instruction, frame: &[Value], ty: &SpirvType) -> Value {
+        let stage = self.stage;
+        let word = |index: usize| instruction.operands.get(index).copied().unwrap_or(0);
+        let coordinate = stage.value(frame, word(3));
+        let level = match word(4) & LOD {
+            0 => 0,
+            _ => stage.value(frame, word(5)).int() as i32,
+        };
+        let texel = self.texture(stage.value(frame, word(2))).and_then(|texture| {
+            let [x, y, z] = [0, 1, 2].map(|i| coordinate.item(i).int() as i32);
+            let layer = match texture.view().dimension() {
+                TextureViewDimension::D2Array | TextureViewDimension::D3 => z,
+                _ => 0,
+            };
+            match [x, y, layer, level].iter().all(|&value| value >= 0) {
+                true => texture.fetch(x as u32, y as u32, layer as u32, level as u32),
+                false => None,
+            }
+        });
+        texel_value(ty, texel.unwrap_or([0.0; 4]))
+    }
+}
+
+// GLSL.std.450 komutları. Modf ve Frexp, çağıran tarafından yapı döndüren sürümleriyle hesaplanır.
+fn extended(number: u32, arguments: &[&Value]) -> Value {
+    let arg = |index: usize| arguments.get(index).copied().unwrap_or(&UNDEFINED);
+    let (x, y, z) = (arg(0), arg(1), arg(2));
+    match number {
+        glsl::ROUND => floats(x, &|x| Dual::constant(x.value.round())),
+        glsl::ROUND_EVEN => floats(x, &|x| Dual::constant(x.value.round_ties_even())),
+        glsl::TRUNC => floats(x, &|x| Dual::constant(x.value.trunc())),
+        glsl::F_ABS => floats(x, &|x| x.chain(x.value.abs(), sign(x.value))),
+        glsl::S_ABS => ints(x, &|x| (x as i32).wrapping_abs() as u32),
+        glsl::F_SIGN => floats(x, &|x| Dual::constant(sign(x.value))),
+        glsl::S_SIGN => ints(x, &|x| (x as i32).signum() as u32),
+        glsl::FLOOR => floats(x, &|x| Dual::constant(x.value.floor())),
+        glsl::CEIL => floats(x, &|x| Dual::constant(x.value.ceil())),
+        glsl::FRACT => floats(x, &|x| x - Dual::constant(x.value.floor())),
+        glsl::RADIANS => floats(x, &|x| x * Dual::constant(PI / 180.0)),
+        glsl::DEGREES => floats(x, &|x| x * Dual::constant(180.0 / PI)),
+        glsl::SIN => floats(x, &|x| x.chain(x.value.sin(), x.value.cos())),
+        glsl::COS => floats(x, &|x| x.chain(x.value.cos(), -x.value.sin())),
+        glsl::TAN => floats(x, &|x| x.chain(x.value.tan(), 1.0 / (x.value.cos() * x.value.cos()))),
+        glsl::ASIN => floats(x, &|x| x.chain(x.value.asin(), 1.0 / (1.0 - x.value * x.value).sqrt())),
+        glsl::ACOS => floats(x, &|x| x.chain(x.value.acos(), -1.0 / (1.0 - x.value * x.value).sqrt())),
+        glsl::ATAN => floats(x, &|x| x.chain(x.value.atan(), 1.0 / (1.0 + x.value * x.value))),
+        glsl::SINH => floats(x, &|x| x.chain(x.value.sinh(), x.value.cosh())),
+        glsl::COSH => floats(x, &|x| x.chain(x.value.cosh(), x.value.sinh())),
+        glsl::TANH => floats(x, &|x| x.chain(x.value.tanh(), 1.0 - x.value.tanh() * x.value.tanh())),
+        glsl::ASINH => floats(x, &|x| x.chain(x.value.asinh(), 1.0 / (x.value * x.value + 1.0).sqrt())),
+        glsl::ACOSH => floats(x, &|x| x.chain(x.value.acosh(), 1.0 / (x.value * x.value - 1.0).sqrt())),
+        glsl::ATANH => floats(x, &|x| x.chain(x.value.atanh(), 1.0 / (1.0 - x.value * x.value))),
+        glsl::ATAN2 => floats2(x, y, &|y, x| {
+            let length = x.value * x.value + y.value * y.value;
+            let derivative = |dy: f32, dx: f32| (product(x.value, dy) - product(y.value, dx)) / length;
+            Dual { value: y.value.atan2(x.value), ddx: derivative(y.ddx, x.ddx), ddy: derivative(y.ddy, x.ddy) }
+        }),
+        glsl::POW => floats2(x, y, &|x, y| {
+            let value = x.value.powf(y.value);
+            let base = y.value * x.value.powf(y.value - 1.0);
+            let exponent = value * x.value.ln();
+            let derivative = |dx: f32, dy: f32| product(base, dx) + product(exponent, dy);
+            Dual { value, ddx: derivative(x.ddx, y.ddx), ddy: derivative(x.ddy, y.ddy) }
+        }),
+        glsl::EXP => floats(x, &|x| x.chain(x.value.exp(), x.value.exp())),
+        glsl::LOG => floats(x, &|x| x.chain(x.value.ln(), 1.0 / x.value)),
+        glsl::EXP2 => floats(x, &|x| x.chain(x.value.exp2(), x.value.exp2() * LN_2)),
+        glsl::LOG2 => floats(x, &|x| x.chain(x.value.log2(), 1.0 / (x.value * LN_2))),
+        glsl::SQRT => floats(x, &|x| x.chain(x.value.sqrt(), 0.5 / x.value.sqrt())),
+        glsl::INVERSE_SQRT => floats(x, &|x| x.chain(1.0 / x.value.sqrt(), -0.5 / (x.value * x.value.sqrt()))),
+        glsl::DETERMINANT => Value::Float(determinant(&rows(x))),
+        glsl::MATRIX_INVERSE => inverse(x),
+        glsl::MODF | glsl::MODF_STRUCT => Value::Composite(vec![
+            floats(x, &|x| x - Dual::constant(x.value.trunc())),
+            floats(x, &|x| Dual::constant(x.value.trunc())),
+        ]),
+        glsl::F_MIN => floats2(x, y, &|x, y| if y.value < x.value { y } else { x }),
+        glsl::U_MIN => ints2(x, y, &u32::min),
+        glsl::S_MIN => ints2(x, y, &|x, y| (x as i32).min(y as i32) as u32),
+        glsl::F_MAX => floats2(x, y, &|x, y| if y.value > x.value { y } else { x }),
+        glsl::U_MAX => ints2(x, y, &u32::max),
+        glsl::S_MAX => ints2(x, y, &|x, y| (x as i32).max(y as i32) as u32),
+        glsl::F_CLAMP => floats3(x, y, z, &|x, low, high| min(max(x, low), high)),
+        glsl::U_CLAMP => ints3(x, y, z, &|x, low, high| x.max(low).min(high)),
+        glsl::S_CLAMP => ints3(x, y, z, &|x, low, high| (x as i32).max(low as i32).min(high as i32) as u32),
+        glsl::F_MIX => floats3(x, y, z, &|x, y, a| x + (y - x) * a),
+        glsl::STEP => floats2(x, y, &|edge, x| Dual::constant(if x.value < edge.value { 0.0 } else { 1.0 })),
+        glsl::SMOOTH_STEP => floats3(x, y, z, &|edge0, edge1, x| {
+            let t = min(max((x - edge0) / (edge1 - edge0), Dual::constant(0.0)), Dual::constant(1.0));
+            t * t * (Dual::constant(3.0) - Dual::constant(2.0) * t)
+        }),
+        glsl::FMA => floats3(x, y, z, &|a, b, c| a * b + c),
+        glsl::FREXP | glsl::FREXP_STRUCT => {
+            let scale = |x: Dual| {
+                let (mantissa, exponent) = frexp(x.value);
+                Dual { value: mantissa, ..x * Dual::constant((-exponent as f32).exp2()) }
+            };
+            Value::Composite(vec![floats(x, &scale), map(x, &|x| Value::Int(frexp(x.float().value).1 as u32))])
+        }
+        glsl::LDEXP => zip(x, y, &|x, exponent| {
+            let exponent = (exponent.int() as i32).clamp(-300, 300);
+            let x = x.float();
+            Value::Float(
+                x * Dual::constant(2.0f32.powi(exponent / 2)) * Dual::constant(2.0f32.powi(exponent - exponent / 2)),
+            )
+        }),
+        glsl::PACK_SNORM_4X8 => pack(x, 8, &|c| (c.clamp(-1.0, 1.0) * 127.0).round() as i8 as u8 as u32),
+        glsl::PACK_UNORM_4X8 => pack(x, 8, &|c| (c.clamp(0.0, 1.0) * 255.0).round() as u32),
+        glsl::PACK_SNORM_2X16 => pack(x, 16, &|c| (c.clamp(-1.0, 1.0) * 32767.0).round() as i16 as u16 as u32),
+        glsl::PACK_UNORM_2X16 => pack(x, 16, &|c| (c.clamp(0.0, 1.0) * 65535.0).round() as u32),
+        glsl::PACK_HALF_2X16 => pack(x, 16, &|c| texel::f32_to_f16(c) as u32),
+        glsl::UNPACK_SNORM_2X16 => unpack(x, 2, 16, &|bits| (bits as u16 as i16 as f32 / 32767.0).clamp(-1.0, 1.0)),
+        glsl::UNPACK_UNORM_2X16 => unpack(x, 2, 16, &|bits| bits as f32 / 65535.0),
+        glsl::UNPACK_HALF_2X16 => unpack(x, 2, 16, &|bits| texel::f16_to_f32(bits as u16)),
+        glsl::UNPACK_SNORM_4X8 => unpack(x, 4, 8, &|bits| (bits as u8 as i8 as f32 / 127.0).clamp(-1.0, 1.0)),
+        glsl::UNPACK_UNORM_4X8 => unpack(x, 4, 8, &|bits| bits as f32 / 255.0),
+        glsl::LENGTH => Value::Float(length(x)),
+        glsl::DISTANCE => Value::Float(length(&floats2(x, y, &|x, y| x - y))),
+        glsl::CROSS => {
+            let [a, b] = [x, y].map(|vector| [0, 1, 2].map(|i| vector.item(i).float()));
+            let cross = [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+            Value::Composite(cross.map(Value::Float).to_vec())
+        }
+        glsl::NORMALIZE => {
+            let length = length(x);
+            floats(x, &|x| x / length)
+        }
+        glsl::FACE_FORWARD => match dot(z, y).value < 0.0 {
+            true => x.clone(),
+            false => floats(x, &|x| -x),
+        },
+        glsl::REFLECT => {
+            let scale = Dual::constant(2.0) * dot(y, x);
+            floats2(x, y, &|i, n| i - scale * n)
+        }
+        glsl::REFRACT => {
+            let (cosine, eta) = (dot(y, x), z.float());
+            let k = Dual::constant(1.0) - eta * eta * (Dual::constant(1.0) - cosine * cosine);
+            if k.value < 0.0 {
+                return floats(x, &|_| Dual::default());
+            }
+            let k = k.chain(k.value.sqrt(), 0.5 / k.value.sqrt());
+            floats2(x, y, &|i, n| eta * i - (eta * cosine + k) * n)
+        }
+        glsl::FIND_I_LSB => ints(x, &|x| if x == 0 { u32::MAX } else { x.trailing_zeros() }),
+        glsl::FIND_S_MSB => ints(x, &|x| {
+            let x = if (x as i32) < 0 { !x } else { x };
+            if x == 0 {
+                u32::MAX
+            } else {
+                31 - x.leading_zeros()
+            }
+        }),
+        glsl::FIND_U_MSB => ints(x, &|x| if x == 0 { u32::MAX } else { 31 - x.leading_zeros() }),
+        glsl::N_MIN => floats2(x, y, &|x, y| if x.value.is_nan() || y.value < x.value { y } else { x }),
+        glsl::N_MAX => floats2(x, y, &|x, y| if x.value.is_nan() || y.value > x.value { y } else { x }),
+        glsl::N_CLAMP => floats3(x, y, z, &|x, low, high| {
+            let x = if x.value.is_nan() || low.value > x.value { low } else { x };
+            if x.value.is_nan() || high.value < x.value {
+                high
+            } else {
+                x
+            }
+        }),
+        _ => Value::Undefined,
+    }
+}
+
+fn sign(value: f32) -> f32 {
+    if value > 0.0 {
+        1.0
+    } else if value < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
+
+fn min(x: Dual, y: Dual) -> Dual {
+    if y.value < x.value {
+        y
+    } else {
+        x
+    }
+}
+
+fn max(x: Dual, y: Dual) -> Dual {
+    if y.value > x.value {
+        y
+    } else {
+        x
+    }
+}
+
+// `offset` bitinden başlayan `count` bitlik alan.
+fn field_mask(offset: u32, count: u32) -> u32 {
+    let bits = if count >= 32 { u32::MAX } else { (1 << count) - 1 };
+    bits.checked_shl(offset).unwrap_or(0)
+}
+
+// Normalize mantis (0.5 <= |m| < 1) ve üs; sıfır, sonsuz ve NaN değişmeden, üs 0 ile döner.
+fn frexp(value: f32) -> (f32, i32) {
+    if value == 0.0 || !value.is_finite() {
+        return (value, 0);
+    }
+    if value.abs() < f32::MIN_POSITIVE {
+        let (mantissa, exponent) = frexp(value * 4294967296.0);
+        return (mantissa, exponent - 32);
+    }
+    let bits = value.to_bits();
+    let exponent = ((bits >> 23) & 0xff) as i32 - 126;
+    (f32::from_bits((bits & 0x807f_ffff) | (126 << 23)), exponent)
+}
+
+fn pack(vector: &Value, bits: u32, encode: &dyn Fn(f32) -> u32) -> Value {
+    let mask = (1u32 << bits) - 1;
+    let packed = vector
+        .items()
+        .iter()
+        .enumerate()
+        .fold(0, |packed, (i, component)| packed | (encode(component.float().value) & mask) << (i as u32 * bits));
+    Value::Int(packed)
+}
+
+fn unpack(word: &Value, count: u32, bits: u32, decode: &dyn Fn(u32) -> f32) -> Value {
+    let (word, mask) = (word.int(), (1u32 << bits) - 1);
+    Value::Composite((0..count).map(|i| float(decode((word >> (i * bits)) & mask))).collect())
+}
+
+fn dot(a: &Value, b: &Value) -> Dual {
+    a.items().iter().zip(b.items()).fold(Dual::default(), |sum, (x, y)| sum + x.float() * y.float())
+}
+
+fn length(vector: &Value) -> Dual {
+    let squared = dot(vector, vector);
+    let length = squared.value.sqrt();
+    squared.chain(length, 0.5 / length)
+}
+
+// Matrisler sütunlarının listesidir.
+fn matrix_times_vector(matrix: &Value, vector: &Value) -> Value {
+    let rows = matrix.item(0).items().len();
+    let row = |r: usize| {
+        let products = matrix.items().iter().zip(vector.items()).map(|(column, x)| column.item(r).float() * x.float());
+        Value::Float(products.fold(Dual::default(), |sum, product| sum + product))
+    };
+    Value::Composite((0..rows).map(row).collect())
+}
+
+fn transpose(matrix: &Value) -> Value {
+    let rows = matrix.item(0).items().len();
+    let row = |r: usize| Value::Composite(matrix.items().iter().map(|column| column.item(r).clone()).collect());
+    Value::Composite((0..rows).map(row).collect())
+}
+
+// Kare matrisin satırları.
+fn rows(matrix: &Value) -> Vec<Vec<Dual>> {
+    let columns = matrix.items();
+    (0..columns.len()).map(|r| columns.iter().map(|column| column.item(r).float()).collect()).collect()
+}
+
+fn minor(rows: &[Vec<Dual>], row: usize, column: usize) -> Vec<Vec<Dual>> {
+    let keep =
+        |values: &Vec<Dual>| values.iter().enumerate().filter(|(c, _)| *c != column).map(|(_, &value)| value).collect();
+    rows.iter().enumerate().filter(|(r, _)| *r != row).map(|(_, values)| keep(values)).collect()
+}
+
+// Kofaktör açılımı; yalnızca 4x4'e kadar matrisler için kullanıldığından yeterince hızlıdır.
+fn determinant(rows: &[Vec<Dual>]) -> Dual {
+    match rows.len() {
+        0 => Dual::constant(1.0),
+        1 => rows[0][0],
+        n => (0..n).fold(Dual::default(), |sum, c| {
+            let term = rows[0][c] * determinant(&minor(rows, 0, c));
+            if c.is_multiple_of(2) {
+                sum + term
+            } else {
+                sum - term
+            }
+        }),
+    }
+}
+
+fn inverse(matrix: &Value) -> Value {
+    let rows = rows(matrix);
+    let determinant = determinant(&rows);
+    // Tersin (r, c) elemanı, (c, r) kofaktörünün determinanta bölümüdür.
+    let element = |r: usize, c: usize| {
+        let cofactor = self::determinant(&minor(&rows, c, r));
+        Value::Float(if (r + c).is_multiple_of(2) { cofactor } else { -cofactor } / determinant)
+    };
+    let n = rows.len();
+    Value::Composite((0..n).map(|c| Value::Composite((0..n).map(|r| element(r, c)).collect())).collect())
+}
+
+// Örneklenen texel'i sonuç tipinin skaler türüne dönüştürür.
+fn texel_value(ty: &SpirvType, texel: [f32; 4]) -> Value {
+    let kind = ty.scalar_kind();
+    Value::Composite(
+        texel
+            .iter()
+            .map(|&value| match kind {
+                Some(ScalarKind::Sint) => Value::Int(value as i32 as u32),
+                Some(ScalarKind::Uint) => Value::Int(value as u32),
+                _ => float(value),
+            })
+            .collect(),
+    )
+}
+
+fn zero(ty: &SpirvType) -> Value {
+    match ty {
+        SpirvType::Bool => Value::Bool(false),
+        SpirvType::Int { .. } => Value::Int(0),
+        SpirvType::Float { .. } => float(0.0),
+        SpirvType::Vector { component: inner, count }
+        | SpirvType::Matrix { column: inner, count }
+        | SpirvType::Array { element: inner, length: Some(count), .. } => {
+            Value::Composite((0..*count).map(|_| zero(inner)).collect())
+        }
+        SpirvType::Array { length: None, .. } => Value::Composite(Vec::new()),
+        SpirvType::Struct { members, .. } => Value::Composite(members.iter().map(|member| zero(&member.ty)).collect()),
+        _ => Value::Undefined,
+    }
+}
+
+// Bir skaleri tipin skaler türüne dönüştürür. Tamsayılar kayan noktaya işaretli olarak çevrilir.
+fn convert(ty: &SpirvType, value: &Value) -> Value {
+    match (ty, value) {
+        (SpirvType::Float { .. }, Value::Int(x)) => float(*x as i32 as f32),
+        (SpirvType::Float { .. }, Value::Float(x)) => Value::Float(*x),
+        (SpirvType::Int { signed: true, .. }, Value::Float(x)) => Value::Int(x.value as i32 as u32),
+        (SpirvType::Int { .. }, Value::Float(x)) => Value::Int(x.value as u32),
+        (SpirvType::Int { .. }, Value::Int(x)) => Value::Int(*x),
+        (SpirvType::Bool, _) => Value::Bool(value.bool() || value.float().value != 0.0),
+        _ => zero(ty),
+    }
+}
+
+// Bir doku değişkeninin değeri: doku birimi veya dizilerde ardışık birimler.
+fn texture_units(ty: &SpirvType, binding: u32) -> Value {
+    match ty {
+        SpirvType::Array { length, .. } => {
+            Value::Composite((0..length.unwrap_or(0)).map(|i| Value::Texture(binding + i)).collect())
+        }
+        _ => Value::Texture(binding),
+    }
+}
+
+// Konumdaki uniform değerini tipe sığdırır; matrisler sütun sırasıyla okunur.
+fn uniform(ty: &SpirvType, location: u32, uniforms: &HashMap<u32, UniformValue>) -> Value {
+    let scalars = match uniforms.get(&location) {
+        Some(UniformValue::Int(value)) => vec![Value::Int(*value as u32)],
+        Some(UniformValue::Float(value)) => vec![float(*value)],
+        Some(UniformValue::Vec2(value)) => vec![float(value.x), float(value.y)],
+        Some(UniformValue::Vec3(value)) => vec![float(value.x), float(value.y), float(value.z)],
+        Some(UniformValue::Vec4((x, y, z, w))) => vec![float(*x), float(*y), float(*z), float(*w)],
+        Some(UniformValue::Mat4(matrix)) => {
+            (0..4).flat_map(|c| (0..4).map(move |r| float(matrix.elements[r][c]))).collect()
+        }
+        None => return zero(ty),
+    };
+    fill(ty, &mut scalars.iter())
+}
+
+fn fill(ty: &SpirvType, scalars: &mut dyn Iterator<Item = &Value>) -> Value {
+    match ty {
+        SpirvType::Vector { component: inner, count }
+        | SpirvType::Matrix { column: inner, count }
+        | SpirvType::Array { element: inner, length: Some(count), .. } => {
+            Value::Composite((0..*count).map(|_| fill(inner, scalars)).collect())
+        }
+        SpirvType::Struct { members, .. } => {
+            Value::Composite(members.iter().map(|member| fill(&member.ty, scalars)).collect())
+        }
+        _ => convert(ty, scalars.next().unwrap_or(&UNDEFINED)),
+    }
+}
+
+// Giriş değişkeninin değeri: yerleşik değişken, konumlardan okunan değer veya yerleşik üyeli arayüz bloğu.
+fn read_interface(global: &Global<'_>, inputs: &Inputs<'_>) -> Value {
+    match (global.binding, global.ty) {
+        (Some(Binding::BuiltIn(kind)), _) => (inputs.built_in)(kind),
+        (Some(Binding::Location { location, component, .. }), ty) => {
+            read_locations(ty, &mut { location }, component, inputs.locations)
+        }
+        (None, SpirvType::Struct { members, .. }) => {
+            let member = |member: &StructMember| match (member.built_in, member.location) {
+                (Some(kind), _) => (inputs.built_in)(kind),
+                (None, Some(location)) => read_locations(&member.ty, &mut { location }, 0, inputs.locations),
+                (None, None) => zero(&member.ty),
+            };
+            Value::Composite(members.iter().map(member).collect())
+        }
+        (None, ty) => zero(ty),
+    }
+}
+
+// `location` konumundan başlayarak okur ve `location`'ı okunan son konumdan sonrasına ilerletir.
+fn read_locations(ty: &SpirvType, location: &mut u32, component: u32, read: &dyn Fn(u32) -> [Dual; 4]) -> Value {
+    let mut next = || {
+        let values = read(*location);
+        *location += 1;
+        values
+    };
+    match ty {
+        SpirvType::Vector { component: scalar, count } => {
+            let values = next();
+            let scalar = |i: u32| {
+                values.get((component + i) as usize).map_or(zero(scalar), |&value| interface_scalar(scalar, value))
+            };
+            Value::Composite((0..*count).map(scalar).collect())
+        }
+        SpirvType::Matrix { column: inner, count } | SpirvType::Array { element: inner, length: Some(count), .. } => {
+            Value::Composite((0..*count).map(|_| read_locations(inner, location, component, read)).collect())
+        }
+        SpirvType::Struct { members, .. } => {
+            let mut member = |member: &StructMember| {
+                if let Some(first) = member.location {
+                    *location = first;
+                }
+                read_locations(&member.ty, location, 0, read)
+            };
+            Value::Composite(members.iter().map(&mut member).collect())
+        }
+        scalar => next().get(component as usize).map_or(zero(scalar), |&value| interface_scalar(scalar, value)),
+    }
+}
+
+// Arayüzdeki değerler f32 olarak taşınır; tamsayılar 2^24'ten büyük değerler bozulmasın diye sayısal değer olarak
+// değil, bit deseni olarak (f32::from_bits) taşınır.
+fn interface_scalar(ty: &SpirvType, value: Dual) -> Value {
+    match ty {
+        SpirvType::Float { .. } => Value::Float(value),
+        SpirvType::Int { .. } => Value::Int(value.value.to_bits()),
+        SpirvType::Bool => Value::Bool(value.value != 0.0),
+        _ => Value::Undefined,
+    }
+}
+
+fn write_interface(global: &Global<'_>, value: &Value, sink: &mut dyn FnMut(Output<'_>)) {
+    match (global.binding, global.ty) {
+        (Some(Binding::BuiltIn(kind)), _) => sink(Output::BuiltIn(kind, value)),
+        (Some(Binding::Location { location, component, index }), ty) => {
+            write_locations(ty, value, &mut { location }, component, index, sink);
+        }
+        (None, SpirvType::Struct { members, .. }) => {
+            for (member, value) in members.iter().zip(value.items()) {
+                match (member.built_in, member.location) {
+                    (Some(kind), _) => sink(Output::BuiltIn(kind, value)),
+                    (None, Some(location)) => write_locations(&member.ty, value, &mut { location }, 0, 0, sink),
+                    (None, None) => {}
+                }
+            }
+        }
+        (None, _) => {}
+    }
+}
+
+fn write_locations(
+    ty: &SpirvType,
+    value: &Value,
+    location: &mut u32,
+    component: u32,
+    index: u32,
+    sink: &mut dyn FnMut(Output<'_>),
+) {
+    match ty {
+        SpirvType::Vector { component: scalar, .. } => {
+            for (i, item) in value.items().iter().enumerate() {
+                let component = component as usize + i;
+                sink(Output::Scalar { location: *location, index, component, value: output_scalar(scalar, item) });
+            }
+            *location += 1;
+        }
+        SpirvType::Matrix { column: inner, .. } | SpirvType::Array { element: inner, .. } => {
+            for item in value.items() {
+                write_locations(inner, item, location, component, index, sink);
+            }
+        }
+        SpirvType::Struct { members, .. } => {
+            for (member, item) in members.iter().zip(value.items()) {
+                if let Some(first) = member.location {
+                    *location = first;
+                }
+                write_locations(&member.ty, item, location, 0, index, sink);
+            }
+        }
+        scalar => {
+            let value = output_scalar(scalar, value);
+            sink(Output::Scalar { location: *location, index, component: component as usize, value });
+            *location += 1;
+        }
+    }
+}
+
+fn output_scalar(ty: &SpirvType, value: &Value) -> f32 {
+    match (ty, value) {
+        (_, Value::Float(x)) => x.value,
+        (_, Value::Int(x)) => f32::from_bits(*x),
+        (_, Value::Bool(x)) => *x as u32 as f32,
+        _ => 0.0,
+    }
+}
+
+fn set(frame: &mut [Value], id: u32, value: Value) {
+    if let Some(slot) = frame.get_mut(id as usize) {
+        *slot = value;
+    }
+}
+
+// Global veya yerel bir değişkenin içindeki bir konuma işaretçi.
+#[derive(Debug, Clone, PartialEq)]
+struct Pointer {
+    slot: usize,
+    path: Vec<u32>, // Bileşik değerin içindeki indisler
+}
+
+// Yorumlayıcının değerleri. Tamsayılar işaretten bağımsız olarak bitleriyle tutulur.
+#[derive(Debug, Clone, Default, PartialEq)]
+enum Value {
+    Bool(bool),
+    Int(u32),
+    Float(Dual),
+    Composite(Vec<Value>), // Vektör, matris (sütunlar), dizi veya yapı
+    Pointer(Pointer),
+    Texture(u32), // Doku birimi; görüntüler, sampler'lar ve örneklenen görüntüler
+    #[default]
+    Undefined,
+}
+
+static UNDEFINED: Value = Value::Undefined;
+
+impl Value {
+    fn item(&self, index: usize) -> &Value {
+        match self {
+            Value::Composite(items) => items.get(index).unwrap_or(&UNDEFINED),
+            _ => &UNDEFINED,
+        }
+    }
+
+    // Bileşik değerlerin elemanları; skalerler tek elemanlı sayılır.
+    fn items(&self) -> &[Value] {
+        match self {
+            Value::Composite(items) => items,
+            _ => std::slice::from_ref(self),
+        }
+    }
+
+    // Dinamik indisler sınırlara kırpılır.
+    fn at(&self, path: &[u32]) -> &Value {
+        path.iter().fold(self, |value, &index| match value {
+            Value::Composite(items) if !items.is_empty() => &items[(index as usize).min(items.len() - 1)],
+            _ => &UNDEFINED,
+        })
+    }
+
+    fn at_mut(&mut self, path: &[u32]) -> Option<&mut Value> {
+        path.iter().try_fold(self, |value, &index| match value {
+            Value::Composite(items) if !items.is_empty() => {
+                let last = items.len() - 1;
+                Some(&mut items[(index as usize).min(last)])
+            }
+            _ => None,
+        })
+    }
+
+    fn float(&self) -> Dual {
+        match self {
+            Value::Float(value) => *value,
+            _ => Dual::default(),
+        }
+    }
+
+    fn int(&self) -> u32 {
+        match self {
+            Value::Int(value) => *value,
+            Value::Bool(value) => *value as u32,
+            _ => 0,
+        }
+    }
+
+    fn bool(&self) -> bool {
+        match self {
+            Value::Bool(value) => *value,
+            Value::Int(value) => *value != 0,
+            _ => false,
+        }
+    }
+}
+
+fn float(value: f32) -> Value {
+    Value::Float(Dual::constant(value))
+}
+
+fn bits(value: &Value) -> u32 {
+    match value {
+        Value::Float(value) => value.value.to_bits(),
+        value => value.int(),
+    }
+}
+
+// Bileşenlerden biri bileşik olduğunda eleman eleman, skalerleri her elemana yayarak uygular.
+fn zip3(a: &Value, b: &Value, c: &Value, f: &dyn Fn(&Value, &Value, &Value) -> Value) -> Value {
+    let length = [a, b, c].iter().find_map(|value| match value {
+        Value::Composite(items) => Some(items.len()),
+        _ => None,
+    });
+    match length {
+        Some(length) => Value::Composite((0..length).map(|i| zip3(part(a, i), part(b, i), part(c, i), f)).collect()),
+        None => f(a, b, c),
+    }
+}
+
+// Bileşik değerin elemanı; skaler her elemanın yerine geçer.
+fn part(value: &Value, index: usize) -> &Value {
+    match value {
+        Value::Composite(_) => value.item(index),
+        _ => value,
+    }
+}
+
+fn zip(a: &Value, b: &Value, f: &dyn Fn(&Value, &Value) -> Value) -> Value {
+    zip3(a, b, &UNDEFINED, &|x, y, _| f(x, y))
+}
+
+fn map(a: &Value, f: &dyn Fn(&Value) -> Value) -> Value {
+    zip3(a, &UNDEFINED, &UNDEFINED, &|x, _, _| f(x))
+}
+
+fn floats(a: &Value, f: &dyn Fn(Dual) -> Dual) -> Value {
+    map(a, &|x| Value::Float(f(x.float())))
+}
+
+fn floats2(a: &Value, b: &Value, f: &dyn Fn(Dual, Dual) -> Dual) -> Value {
+    zip(a, b, &|x, y| Value::Float(f(x.float(), y.float())))
+}
+
+fn floats3(a: &Value, b: &Value, c: &Value, f: &dyn Fn(Dual, Dual, Dual) -> Dual) -> Value {
+    zip3(a, b, c, &|x, y, z| Value::Float(f(x.float(), y.float(), z.float())))
+}
+
+fn ints(a: &Value, f: &dyn Fn(u32) -> u32) -> Value {
+    map(a, &|x| Value::Int(f(x.int())))
+}
+
+fn ints2(a: &Value, b: &Value, f: &dyn Fn(u32, u32) -> u32) -> Value {
+    zip(a, b, &|x, y| Value::Int(f(x.int(), y.int())))
+}
+
+fn ints3(a: &Value, b: &Value, c: &Value, f: &dyn Fn(u32, u32, u32) -> u32) -> Value {
+    zip3(a, b, c, &|x, y, z| Value::Int(f(x.int(), y.int(), z.int())))
+}
+
+fn compare(a: &Value, b: &Value, f: &dyn Fn(&Value, &Value) -> bool) -> Value {
+    zip(a, b, &|x, y| Value::Bool(f(x, y)))
+}
+
+// Ekran uzayı türevleriyle birlikte taşınan kayan noktalı sayı (ileri yönlü otomatik türev). Türevler, değerin
+// sağdaki ve alttaki komşu piksele göre farkının doğrusal yaklaşımıdır; vertex aşamasında sıfırdır.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Dual {
+    value: f32,
+    ddx: f32,
+    ddy: f32,
+}
+
+impl Dual {
+    fn constant(value: f32) -> Self {
+        Dual { value, ddx: 0.0, ddy: 0.0 }
+    }
+
+    // f(self) sonucunu, f'nin bu noktadaki türevi `derivative` ile zincir kuralına göre döndürür.
+    fn chain(self, value: f32, derivative: f32) -> Self {
+        Dual { value, ddx: product(self.ddx, derivative), ddy: product(self.ddy, derivative) }
+    }
+}
+
+// Sıfır türevler sonsuz veya NaN katsayılarla çarpıldığında da sıfır kalır.
+fn product(a: f32, b: f32) -> f32 {
+    if a == 0.0 || b == 0.0 {
+        0.0
+    } else {
+        a * b
+    }
+}
+
+// (ab)' = a'b + ab'
+fn multiply(a: Dual, b: Dual) -> Dual {
+    let derivative = |da: f32, db: f32| product(da, b.value) + product(a.value, db);
+    Dual { value: a.value * b.value, ddx: derivative(a.ddx, b.ddx), ddy: derivative(a.ddy, b.ddy) }
+}
+
+// (a / b)' = (a' - (a / b) b') / b
+fn divide(a: Dual, b: Dual) -> Dual {
+    let value = a.value / b.value;
+    let derivative = |da: f32, db: f32| {
+        let numerator = da - product(value, db);
+        if numerator == 0.0 {
+            0.0
+        } else {
+            numerator / b.value
+        }
+    };
+    Dual { value, ddx: derivative(a.ddx, b.ddx), ddy: derivative(a.ddy, b.ddy) }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, rhs: Dual) -> Dual {
+        Dual { value: self.value + rhs.value, ddx: self.ddx + rhs.ddx, ddy: self.ddy + rhs.ddy }
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, rhs: Dual) -> Dual {
+        Dual { value: self.value - rhs.value, ddx: self.ddx - rhs.ddx, ddy: self.ddy - rhs.ddy }
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, rhs: Dual) -> Dual {
+        multiply(self, rhs)
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+
+    fn div(self, rhs: Dual) -> Dual {
+        divide(self, rhs)
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+
+    fn neg(self) -> Dual {
+        Dual { value: -self.value, ddx: -self.ddx, ddy: -self.ddy }
+    }
+}
+
+// Testler yazılım arka ucunu varsayılan cihaz üzerinden sürer.
+#[cfg(all(test, not(feature = "null-backend")))]
+mod tests {
+    use super::*;
+    use crate::gpu::device::Device;
+    use crate::gpu::sampler::SamplerDescriptor;
+    use crate::gpu::spirv::{execution_mode, MAGIC_NUMBER};
+    use crate::gpu::texture::{TextureDescriptor, TextureDimension, TextureUsage};
+    use crate::rendering::pipeline::TextureFormat;
+
+    // Her modülde bulunan kimlikler; testlerin kendi kimlikleri 20'den başlar.
+    const MAIN: u32 = 1;
+    const GLSL: u32 = 2;
+    const VOID: u32 = 3;
+    const MAIN_TYPE: u32 = 4;
+    const FLOAT: u32 = 5;
+    const UINT: u32 = 6;
+    const BOOL: u32 = 7;
+    const VEC4: u32 = 8;
+    const VEC2: u32 = 9;
+    const ENTRY: u32 = 10;
+
+    // Depolama sınıfları.
+    const UNIFORM_CONSTANT: u32 = 0;
+    const INPUT: u32 = 1;
+    const OUTPUT: u32 = 3;
+    const FUNCTION: u32 = 7;
+
+    fn instruction(opcode: u16, operands: &[u32]) -> Vec<u32> {
+        let mut words = vec![((operands.len() as u32 + 1) << 16) | opcode as u32];
+        words.extend_from_slice(operands);
+        words
+    }
+
+    fn string(value: &str) -> Vec<u32> {
+        let mut bytes = value.as_bytes().to_vec();
+        bytes.resize((bytes.len() / 4 + 1) * 4, 0);
+        bytes.chunks_exact(4).map(|word| u32::from_le_bytes([word[0], word[1], word[2], word[3]])).collect()
+    }
+
+    fn float_constant(id: u32, value: f32) -> Vec<u32> {
+        instruction(op::CONSTANT, &[FLOAT, id, value.to_bits()])
+    }
+
+    // `main` giriş noktası olan bir modül kurar. `body`, ENTRY etiketinden sonra gelir; OpReturn ve
+    // OpFunctionEnd sona eklenir.
+    fn assemble(stage: ShaderStage, interface: &[u32], globals: &[Vec<u32>], body: &[Vec<u32>]) -> SpirvModule {
+        let model = if stage == ShaderStage::Vertex { 0 } else { 4 };
+        let entry_point = [&[model, MAIN][..], &string("main"), interface].concat();
+        let mut words = vec![MAGIC_NUMBER, 0x0001_0000, 0, 100, 0];
+        words.extend(instruction(op::CAPABILITY, &[1]));
+        words.extend(instruction(op::EXT_INST_IMPORT, &[&[GLSL][..], &string("GLSL.std.450")].concat()));
+        words.extend(instruction(op::MEMORY_MODEL, &[0, 1]));
+        words.extend(instruction(op::ENTRY_POINT, &entry_point));
+        if stage == ShaderStage::Fragment {
+            words.extend(instruction(op::EXECUTION_MODE, &[MAIN, execution_mode::ORIGIN_UPPER_LEFT]));
+        }
+        words.extend(instruction(op::TYPE_VOID, &[VOID]));
+        words.extend(instruction(op::TYPE_FUNCTION, &[MAIN_TYPE, VOID]));
+        words.extend(instruction(op::TYPE_FLOAT, &[FLOAT, 32]));
+        words.extend(instruction(op::TYPE_INT, &[UINT, 32, 0]));
+        words.extend(instruction(op::TYPE_BOOL, &[BOOL]));
+        words.extend(instruction(op::TYPE_VECTOR, &[VEC4, FLOAT, 4]));
+        words.extend(instruction(op::TYPE_VECTOR, &[VEC2, FLOAT, 2]));
+        words.extend(globals.concat());
+        words.extend(instruction(op::FUNCTION, &[VOID, MAIN, 0, MAIN_TYPE]));
+        words.extend(instruction(op::LABEL, &[ENTRY]));
+        words.extend(body.concat());
+        words.extend(instruction(op::RETURN, &[]));
+        words.extend(instruction(op::FUNCTION_END, &[]));
+        SpirvModule::from_words(&words).unwrap()
+    }
+
+    fn run_vertex(module: &SpirvModule, attributes: &[(u32, [f32; 4])], vertex_index: u32) -> ShadedVertex {
+        let program = SpirvProgram::new((module, "main"), None).unwrap();
+        let uniforms = HashMap::new();
+        let textures = TextureBindings::default();
+        program.vertex(&VertexInput {
+            attributes,
+            vertex_index,
+            instance_index: 0,
+            uniforms: &uniforms,
+            textures: &textures,
+        })
+    }
+
+    fn run_fragment(
+        module: &SpirvModule,
+        frag_coord: [f32; 4],
+        varyings: &[[f32; 4]],
+        textures: &TextureBindings<'_>,
+    ) -> Option<FragmentOutput> {
+        let vertex = assemble(ShaderStage::Vertex, &[], &[], &[]);
+        let program = SpirvProgram::new((&vertex, "main"), Some((module, "main"))).unwrap();
+        let uniforms = HashMap::new();
+        program.fragment(&FragmentInput {
+            frag_coord,
+            front_facing: true,
+            point_coord: [0.0; 2],
+            varyings,
+            derivatives: &[],
+            uniforms: &uniforms,
+            textures,
+        })
+    }
+
+    #[test]
+    fn vertex_arithmetic_and_vertex_index() {
+        // Position = 3 * attribute 0; varying 0 = (i, sqrt(16), i * i, 1); varying 1 = attribute 1 + i (uint).
+        let module = assemble(
+            ShaderStage::Vertex,
+            &[21, 23, 24, 26, 27, 29],
+            &[
+                instruction(op::TYPE_POINTER, &[20, INPUT, VEC4]),
+                instruction(op::VARIABLE, &[20, 21, INPUT]),
+                instruction(op::DECORATE, &[21, decoration::LOCATION, 0]),
+                instruction(op::TYPE_POINTER, &[22, INPUT, UINT]),
+                instruction(op::VARIABLE, &[22, 23, INPUT]),
+                instruction(op::DECORATE, &[23, decoration::BUILT_IN, built_in::VERTEX_INDEX]),
+                instruction(op::VARIABLE, &[22, 24, INPUT]),
+                instruction(op::DECORATE, &[24, decoration::LOCATION, 1]),
+                instruction(op::TYPE_POINTER, &[25, OUTPUT, VEC4]),
+                instruction(op::VARIABLE, &[25, 26, OUTPUT]),
+                instruction(op::DECORATE, &[26, decoration::BUILT_IN, built_in::POSITION]),
+                instruction(op::VARIABLE, &[25, 27, OUTPUT]),
+                instruction(op::DECORATE, &[27, decoration::LOCATION, 0]),
+                instruction(op::TYPE_POINTER, &[28, OUTPUT, UINT]),
+                instruction(op::VARIABLE, &[28, 29, OUTPUT]),
+                instruction(op::DECORATE, &[29, decoration::LOCATION, 1]),
+                instruction(op::DECORATE, &[29, decoration::FLAT]),
+                float_constant(30, 2.0),
+                float_constant(31, 16.0),
+                float_constant(32, 1.0),
+            ],
+            &[
+                instruction(op::LOAD, &[VEC4, 40, 21]),
+                instruction(op::VECTOR_TIMES_SCALAR, &[VEC4, 41, 40, 30]),
+                instruction(op::F_ADD, &[VEC4, 42, 41, 40]),
+                instruction(op::STORE, &[26, 42]),
+                instruction(op::LOAD, &[UINT, 43, 23]),
+                instruction(op::CONVERT_U_TO_F, &[FLOAT, 44, 43]),
+                instruction(op::EXT_INST, &[FLOAT, 45, GLSL, glsl::SQRT, 31]),
+                instruction(op::F_MUL, &[FLOAT, 46, 44, 44]),
+                instruction(op::COMPOSITE_CONSTRUCT, &[VEC4, 47, 44, 45, 46, 32]),
+                instruction(op::STORE, &[27, 47]),
+                instruction(op::LOAD, &[UINT, 48, 24]),
+                instruction(op::I_ADD, &[UINT, 49, 48, 43]),
+                instruction(op::STORE, &[29, 49]),
+            ],
+        );
+        // Tamsayı attribute'lar bit deseni olarak taşınır; 0xffff_fff0 kayan noktalı sayıya çevrilince bozulurdu.
+        let integer = [0xffff_fff0, 0, 0, 1].map(f32::from_bits);
+        let vertex = run_vertex(&module, &[(0, [1.0, 2.0, 3.0, 4.0]), (1, integer)], 3);
+        assert_eq!(vertex.position, [3.0, 6.0, 9.0, 12.0]);
+        assert_eq!(vertex.varyings[0], [3.0, 4.0, 9.0, 1.0]);
+        assert_eq!(vertex.varyings[1][0].to_bits(), 0xffff_fff3);
+    }
+
+    #[test]
+    fn vertex_loops_and_branches() {
+        // sum = 0 + 1 + ... + (i - 1) bir phi döngüsüyle; sum > 5 ise 20, değilse 10 bir fonksiyon değişkenine yazılır.
+        let module = assemble(
+            ShaderStage::Vertex,
+            &[23, 27],
+            &[
+                instruction(op::TYPE_POINTER, &[22, INPUT, UINT]),
+                instruction(op::VARIABLE, &[22, 23, INPUT]),
+                instruction(op::DECORATE, &[23, decoration::BUILT_IN, built_in::VERTEX_INDEX]),
+                instruction(op::TYPE_POINTER, &[25, OUTPUT, VEC4]),
+                instruction(op::VARIABLE, &[25, 27, OUTPUT]),
+                instruction(op::DECORATE, &[27, decoration::LOCATION, 0]),
+                instruction(op::CONSTANT, &[UINT, 30, 0]),
+                instruction(op::CONSTANT, &[UINT, 31, 1]),
+                instruction(op::CONSTANT, &[UINT, 32, 5]),
+                float_constant(33, 10.0),
+                float_constant(34, 20.0),
+                instruction(op::TYPE_POINTER, &[35, FUNCTION, FLOAT]),
+            ],
+            &[
+                instruction(op::VARIABLE, &[35, 36, FUNCTION]),
+                instruction(op::LOAD, &[UINT, 40, 23]),
+                instruction(op::BRANCH, &[11]),
+                // Döngü başlığı
+                instruction(op::LABEL, &[11]),
+                instruction(op::PHI, &[UINT, 41, 30, ENTRY, 44, 12]),
+                instruction(op::PHI, &[UINT, 42, 30, ENTRY, 45, 12]),
+                instruction(op::U_LESS_THAN, &[BOOL, 43, 41, 40]),
+                instruction(op::LOOP_MERGE, &[13, 12, 0]),
+                instruction(op::BRANCH_CONDITIONAL, &[43, 12, 13]),
+                // Döngü gövdesi ve devam bloğu
+                instruction(op::LABEL, &[12]),
+                instruction(op::I_ADD, &[UINT, 45, 42, 41]),
+                instruction(op::I_ADD, &[UINT, 44, 41, 31]),
+                instruction(op::BRANCH, &[11]),
+                instruction(op::LABEL, &[13]),
+                instruction(op::U_GREATER_THAN, &[BOOL, 46, 42, 32]),
+                instruction(op::SELECTION_MERGE, &[16, 0]),
+                instruction(op::BRANCH_CONDITIONAL, &[46, 14, 15]),
+                instruction(op::LABEL, &[14]),
+                instruction(op::STORE, &[36, 34]),
+                instruction(op::BRANCH, &[16]),
+                instruction(op::LABEL, &[15]),
+                instruction(op::STORE, &[36, 33]),
+                instruction(op::BRANCH, &[16]),
+                instruction(op::LABEL, &[16]),
+                instruction(op::CONVERT_U_TO_F, &[FLOAT, 47, 42]),
+                instruction(op::LOAD, &[FLOAT, 48, 36]),
+                instruction(op::COMPOSITE_CONSTRUCT, &[VEC4, 49, 47, 48, 47, 48]),
+                instruction(op::STORE, &[27, 49]),
+            ],
+        );
+        assert_eq!(run_vertex(&module, &[], 0).varyings[0], [0.0, 10.0, 0.0, 10.0]);
+        assert_eq!(run_vertex(&module, &[], 3).varyings[0], [3.0, 10.0, 3.0, 10.0]);
+        assert_eq!(run_vertex(&module, &[], 4).varyings[0], [6.0, 20.0, 6.0, 20.0]);
+    }
+
+    #[test]
+    fn fragment_kill_and_frag_coord() {
+        // FragCoord.x < 1 ise fragment atılır, değilse renk FragCoord olur.
+        let module = assemble(
+            ShaderStage::Fragment,
+            &[21, 26],
+            &[
+                instruction(op::TYPE_POINTER, &[20, INPUT, VEC4]),
+                instruction(op::VARIABLE, &[20, 21, INPUT]),
+                instruction(op::DECORATE, &[21, decoration::BUILT_IN, built_in::FRAG_COORD]),
+                instruction(op::TYPE_POINTER, &[25, OUTPUT, VEC4]),
+                instruction(op::VARIABLE, &[25, 26, OUTPUT]),
+                instruction(op::DECORATE, &[26, decoration::LOCATION, 0]),
+                float_constant(30, 1.0),
+            ],
+            &[
+                instruction(op::LOAD, &[VEC4, 40, 21]),
+                instruction(op::COMPOSITE_EXTRACT, &[FLOAT, 41, 40, 0]),
+                instruction(op::F_ORD_LESS_THAN, &[BOOL, 42, 41, 30]),
+                instruction(op::SELECTION_MERGE, &[12, 0]),
+                instruction(op::BRANCH_CONDITIONAL, &[42, 11, 12]),
+                instruction(op::LABEL, &[11]),
+                instruction(op::KILL, &[]),
+                instruction(op::LABEL, &[12]),
+                instruction(op::STORE, &[26, 40]),
+            ],
+        );
+        let textures = TextureBindings::default();
+        assert_eq!(run_fragment(&module, [0.5, 3.5, 0.25, 1.0], &[], &textures), None);
+        let output = run_fragment(&module, [2.5, 3.5, 0.25, 0.5], &[], &textures).unwrap();
+        assert_eq!(output.colors, vec![[2.5, 3.5, 0.25, 0.5]]);
+        assert_eq!(output.depth, None);
+    }
+
+    #[test]
+    fn fragment_samples_a_texture() {
+        // Renk, 0 numaralı birimdeki dokunun varying 0'ın (x, y) bileşenlerinde örneklenmesidir.
+        let module = assemble(
+            ShaderStage::Fragment,
+            &[25, 27],
+            &[
+                instruction(op::TYPE_IMAGE, &[20, FLOAT, 1, 0, 0, 0, 1, 0]),
+                instruction(op::TYPE_SAMPLED_IMAGE, &[21, 20]),
+                instruction(op::TYPE_POINTER, &[22, UNIFORM_CONSTANT, 21]),
+                instruction(op::VARIABLE, &[22, 23, UNIFORM_CONSTANT]),
+                instruction(op::DECORATE, &[23, decoration::DESCRIPTOR_SET, 0]),
+                instruction(op::DECORATE, &[23, decoration::BINDING, 0]),
+                instruction(op::TYPE_POINTER, &[24, INPUT, VEC4]),
+                instruction(op::VARIABLE, &[24, 25, INPUT]),
+                instruction(op::DECORATE, &[25, decoration::LOCATION, 0]),
+                instruction(op::TYPE_POINTER, &[26, OUTPUT, VEC4]),
+                instruction(op::VARIABLE, &[26, 27, OUTPUT]),
+                instruction(op::DECORATE, &[27, decoration::LOCATION, 0]),
+            ],
+            &[
+                instruction(op::LOAD, &[21, 40, 23]),
+                instruction(op::LOAD, &[VEC4, 41, 25]),
+                instruction(op::VECTOR_SHUFFLE, &[VEC2, 42, 41, 41, 0, 1]),
+                instruction(op::IMAGE_SAMPLE_IMPLICIT_LOD, &[VEC4, 43, 40, 42]),
+                instruction(op::STORE, &[27, 43]),
+            ],
+        );
+        let device = Device::new().unwrap();
+        let texture = device
+            .create_texture(&TextureDescriptor {
+                format: TextureFormat::Rgba32Float,
+                dimension: TextureDimension::D2,
+                width: 2,
+                height: 1,
+                depth_or_array_layers: 1,
+                mip_level_count: 1,
+                sample_count: 1,
+                usage: TextureUsage::Sampled,
+            })
+            .unwrap();
+        {
+            let mut storage = texture.native().storage.borrow_mut();
+            storage.write_texel(0, 0, 0, 0, 0, [1.0, 0.0, 0.0, 1.0]);
+            storage.write_texel(0, 0, 1, 0, 0, [0.0, 0.0, 1.0, 0.5]);
+        }
+        let mut textures = TextureBindings::default();
+        let sampled = SampledTexture::new(texture.native().storage(), texture.view(), SamplerDescriptor::default());
+        textures.insert(0, sampled);
+
+        let left = run_fragment(&module, [0.5, 0.5, 0.0, 1.0], &[[0.25, 0.5, 0.0, 0.0]], &textures).unwrap();
+        assert_eq!(left.colors, vec![[1.0, 0.0, 0.0, 1.0]]);
+        let right = run_fragment(&module, [1.5, 0.5, 0.0, 1.0], &[[0.75, 0.5, 0.0, 0.0]], &textures).unwrap();
+        assert_eq!(right.colors, vec![[0.0, 0.0, 1.0, 0.5]]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,24 @@
+// CPU üzerinde çalışan referans arka uç.
+// GPU bulunmayan makinelerde (örneğin CI) komut arabelleklerini gerçekten çalıştırmak için kullanılır.
+pub mod render_target;
+pub mod texel;
+pub mod texture;
+pub mod sampler;
+pub mod shading;
+pub mod interpreter;
+pub mod rasterizer;
+pub mod blend;
+pub mod draw;
+pub mod executor;
+pub mod backend;
+
+pub use backend::{SoftwareBackend, SoftwareBuffer, SoftwareDevice, SoftwareQueue, SoftwareTexture};
+pub use executor::{ColorTargetBlend, DrawCall, DrawKind, RenderState, SoftwareExecutor, UniformValue};
+pub use shading::{
+    FixedFunctionProgram, FragmentInput, FragmentOutput, FragmentShader, NativeProgram, ShadedVertex, SoftwareProgram,
+    VertexInput, VertexShader,
+};
+pub use render_target::{ColorTarget, DepthStencilBuffer, DepthStencilTarget, NullColorTarget, RenderTarget};
+pub use texture::{TextureStorage, TextureTarget};
+pub use sampler::{Lod, SampledTexture, TextureBindings};
+pub use interpreter::SpirvProgram;
\ No newline at end of file
@@ -0,0 +1,429 @@
+use crate::gpu::software::executor::Rect;
+
+// Alt piksel (sub-pixel) hassasiyeti: koordinatlar 1/256 piksel adımlarına yuvarlanır.
+pub const SUBPIXEL_BITS: u32 = 8;
+const SUBPIXEL_ONE: f32 = (1 << SUBPIXEL_BITS) as f32;
+
+// Pencere (framebuffer) koordinatlarında bir nokta; y ekseni aşağı doğrudur.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+// Rasterleştiricinin ürettiği fragment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RasterFragment {
+    pub x: u32,
+    pub y: u32,
+    // Piksel merkezindeki ağırlık merkezi (barycentric) koordinatları, giriş vertex sırasına göre.
+    pub barycentric: [f32; 3],
+    // Primitifin kapsadığı örnekler; i. bit i. örnek konumudur.
+    pub coverage: u32,
+}
+
+// Piksel içindeki standart örnek konumları (Direct3D/Vulkan standart desenleri), piksel köşesine göre.
+const SAMPLE_POSITIONS_1: [[f32; 2]; 1] = [[0.5, 0.5]];
+const SAMPLE_POSITIONS_2: [[f32; 2]; 2] = [[0.75, 0.75], [0.25, 0.25]];
+const SAMPLE_POSITIONS_4: [[f32; 2]; 4] = [[0.375, 0.125], [0.875, 0.375], [0.125, 0.625], [0.625, 0.875]];
+const SAMPLE_POSITIONS_8: [[f32; 2]; 8] = [
+    [0.5625, 0.3125],
+    [0.4375, 0.6875],
+    [0.8125, 0.5625],
+    [0.3125, 0.1875],
+    [0.1875, 0.8125],
+    [0.0625, 0.4375],
+    [0.6875, 0.9375],
+    [0.9375, 0.0625],
+];
+
+// Örnek sayısına göre örnek konumları. Desteklenmeyen sayılarda tek örnek (piksel merkezi) kullanılır.
+pub fn sample_positions(sample_count: u32) -> &'static [[f32; 2]] {
+    match sample_count {
+        2 => &SAMPLE_POSITIONS_2,
+        4 => &SAMPLE_POSITIONS_4,
+        8 => &SAMPLE_POSITIONS_8,
+        _ => &SAMPLE_POSITIONS_1,
+    }
+}
+
+// Tüm örnekleri kapsayan maske.
+pub fn full_coverage(sample_count: u32) -> u32 {
+    match sample_count {
+        0 => 0,
+        32.. => u32::MAX,
+        count => (1 << count) - 1,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FixedPoint {
+    x: i64,
+    y: i64,
+}
+
+impl FixedPoint {
+    fn from_window(point: WindowPoint) -> Self {
+        FixedPoint {
+            x: (point.x * SUBPIXEL_ONE).round() as i64,
+            y: (point.y * SUBPIXEL_ONE).round() as i64,
+        }
+    }
+}
+
+// Kenar fonksiyonu: `p`, a -> b kenarının hangi tarafında? Pozitif alanlı üçgenlerde iç taraf pozitiftir.
+fn edge(a: FixedPoint, b: FixedPoint, p: FixedPoint) -> i64 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+// Üst-sol (top-left) kuralı: tam kenar üzerine düşen örnekler yalnızca üst veya sol kenarlara aittir.
+// Böylece ortak kenarı paylaşan iki üçgen aynı pikseli iki kez çizmez.
+fn is_top_left(a: FixedPoint, b: FixedPoint) -> bool {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    (dy == 0 && dx > 0) || dy < 0
+}
+
+// Çokgenin pencere koordinatlarındaki işaretli alanının iki katı (shoelace formülü). y aşağı doğru
+// olduğundan ekranda saat yönünün tersine (counter-clockwise) görünen çokgenlerde negatiftir.
+pub fn signed_area(points: &[WindowPoint]) -> f32 {
+    (0..points.len())
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % points.len()]);
+            a.x * b.y - b.x * a.y
+        })
+        .sum()
+}
+
+// Üçgeni kenar fonksiyonlarıyla rasterleştirir. Her pikselde `samples` konumları test edilir ve
+// en az bir örneği kapsanan pikseller üretilir; yalnızca `bounds` içindeki pikseller dikkate alınır.
+// Ağırlıklar, piksel merkezi üçgenin dışında kalsa bile merkezde hesaplanır.
+pub fn rasterize_triangle<F: FnMut(RasterFragment)>(
+    vertices: &[WindowPoint; 3],
+    bounds: Rect,
+    samples: &[[f32; 2]],
+    mut emit: F,
+) {
+    let mut v = [
+        FixedPoint::from_window(vertices[0]),
+        FixedPoint::from_window(vertices[1]),
+        FixedPoint::from_window(vertices[2]),
+    ];
+    let mut area = edge(v[0], v[1], v[2]);
+    if area == 0 {
+        return; // Dejenere üçgen
+    }
+    // Kenar fonksiyonlarının iç tarafta pozitif olması için sarım yönü normalize edilir.
+    let swapped = area < 0;
+    if swapped {
+        v.swap(1, 2);
+        area = -area;
+    }
+
+    let min_x = v.iter().map(|p| p.x).min().unwrap_or(0);
+    let max_x = v.iter().map(|p| p.x).max().unwrap_or(0);
+    let min_y = v.iter().map(|p| p.y).min().unwrap_or(0);
+    let max_y = v.iter().map(|p| p.y).max().unwrap_or(0);
+    let Some((x_start, x_end)) = pixel_span(min_x, max_x, bounds.x, bounds.width) else { return };
+    let Some((y_start, y_end)) = pixel_span(min_y, max_y, bounds.y, bounds.height) else { return };
+
+    // Üst-sol olmayan kenarlarda tam kenar üzerindeki örnekler dışarıda kalsın diye 1 birimlik sapma.
+    let bias = [
+        if is_top_left(v[1], v[2]) { 0 } else { -1 },
+        if is_top_left(v[2], v[0]) { 0 } else { -1 },
+        if is_top_left(v[0], v[1]) { 0 } else { -1 },
+    ];
+    let offsets: Vec<FixedPoint> =
+        samples.iter().map(|&[x, y]| FixedPoint::from_window(WindowPoint { x, y })).collect();
+    let half = 1i64 << (SUBPIXEL_BITS - 1);
+    for y in y_start..y_end {
+        for x in x_start..x_end {
+            let corner = FixedPoint { x: (x as i64) << SUBPIXEL_BITS, y: (y as i64) << SUBPIXEL_BITS };
+            let mut coverage = 0;
+            for (i, offset) in offsets.iter().enumerate() {
+                let p = FixedPoint { x: corner.x + offset.x, y: corner.y + offset.y };
+                if edge(v[1], v[2], p) + bias[0] >= 0
+                    && edge(v[2], v[0], p) + bias[1] >= 0
+                    && edge(v[0], v[1], p) + bias[2] >= 0
+                {
+                    coverage |= 1 << i;
+                }
+            }
+            if coverage == 0 {
+                continue;
+            }
+            let center = FixedPoint { x: corner.x + half, y: corner.y + half };
+            let area = area as f64;
+            let (w0, w1, w2) = (edge(v[1], v[2], center), edge(v[2], v[0], center), edge(v[0], v[1], center));
+            let (b0, b1, b2) = (w0 as f64 / area, w1 as f64 / area, w2 as f64 / area);
+            let barycentric = if swapped { [b0, b2, b1] } else { [b0, b1, b2] };
+            emit(RasterFragment {
+                x,
+                y,
+                barycentric: [barycentric[0] as f32, barycentric[1] as f32, barycentric[2] as f32],
+                coverage,
+            });
+        }
+    }
+}
+
+// Sabit noktalı [min, max] aralığına merkezi düşebilecek piksellerin, sınırlarla kesişen aralığı.
+fn pixel_span(min: i64, max: i64, bounds_start: u32, bounds_len: u32) -> Option<(u32, u32)> {
+    let first = (min >> SUBPIXEL_BITS).max(bounds_start as i64);
+    let last = ((max >> SUBPIXEL_BITS) + 1).min(bounds_start as i64 + bounds_len as i64);
+    if first >= last {
+        None
+    } else {
+        Some((first as u32, last as u32))
+    }
+}
+
+// Doğruyu elmas çıkış (diamond-exit) kuralıyla rasterleştirir: her piksel merkezinin çevresinde |dx| + |dy| < 1/2
+// olan açık bir elmas vardır; parça bir pikselin elmasını kesiyorsa ve bitiş noktası o elmasın içinde değilse
+// piksel üretilir. Böylece şeritteki ardışık parçalar ortak vertex'i iki kez çizmez ve ana eksendeki her sütunda
+// (satırda) en fazla bir piksel üretilir. Uç noktalar önce alt piksel ızgarasına yuvarlanır. Ağırlıklar, piksel
+// merkezinin parça üzerindeki izdüşümüyle [1 - t, t, 0] biçimindedir; üretilen pikseller tüm örnekleri kapsar.
+pub fn rasterize_line<F: FnMut(RasterFragment)>(start: WindowPoint, end: WindowPoint, bounds: Rect, mut emit: F) {
+    let snap = |point: WindowPoint| {
+        let fixed = FixedPoint::from_window(point);
+        (fixed.x as f64 / SUBPIXEL_ONE as f64, fixed.y as f64 / SUBPIXEL_ONE as f64)
+    };
+    let (start, end) = (snap(start), snap(end));
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    if dx == 0.0 && dy == 0.0 {
+        return;
+    }
+    // Elmas iki eksende de simetrik olduğundan hesaplar (ana, yan) eksen sırasındaki koordinatlarla yapılır.
+    let x_major = dx.abs() >= dy.abs();
+    let axes = |(x, y): (f64, f64)| if x_major { (x, y) } else { (y, x) };
+    let (a, b) = (axes(start), axes(end));
+    let ((major_start, major_len), (minor_start, minor_len)) = if x_major {
+        ((bounds.x, bounds.width), (bounds.y, bounds.height))
+    } else {
+        ((bounds.y, bounds.height), (bounds.x, bounds.width))
+    };
+    let (major_min, major_max) = (a.0.min(b.0), a.0.max(b.0));
+    let first = major_min.floor().max(major_start as f64);
+    let last = (major_max.ceil() - 1.0).min(major_start as f64 + major_len as f64 - 1.0);
+    if first > last {
+        return;
+    }
+    let minor_at = |major: f64| a.1 + (major.clamp(major_min, major_max) - a.0) / (b.0 - a.0) * (b.1 - a.1);
+    let length_squared = (b.0 - a.0).powi(2) + (b.1 - a.1).powi(2);
+    let (first, last) = (first as i64, last as i64);
+    for i in 0..=last - first {
+        // Fragment'lar parçanın yönünde üretilir.
+        let column = if b.0 >= a.0 { first + i } else { last - i };
+        // Parçanın bu sütuna düşen kısmı yalnızca bu sütundaki elmasları kesebilir.
+        let (from, to) = (minor_at(column as f64), minor_at(column as f64 + 1.0));
+        let row_first = from.min(to).floor().max(minor_start as f64) as i64;
+        let row_last = from.max(to).floor().min(minor_start as f64 + minor_len as f64 - 1.0) as i64;
+        for row in row_first..=row_last {
+            let center = (column as f64 + 0.5, row as f64 + 0.5);
+            if in_diamond(b, center) || !segment_hits_diamond(a, b, center) {
+                continue;
+            }
+            let projection = (center.0 - a.0) * (b.0 - a.0) + (center.1 - a.1) * (b.1 - a.1);
+            let t = (projection / length_squared).clamp(0.0, 1.0) as f32;
+            let (x, y) = if x_major { (column, row) } else { (row, column) };
+            emit(RasterFragment { x: x as u32, y: y as u32, barycentric: [1.0 - t, t, 0.0], coverage: u32::MAX });
+        }
+    }
+}
+
+fn in_diamond(point: (f64, f64), center: (f64, f64)) -> bool {
+    (point.0 - center.0).abs() + (point.1 - center.1).abs() < 0.5
+}
+
+// Parça boyunca merkeze olan |dx| + |dy| uzaklığı dışbükey ve parçalı doğrusaldır; en küçük değeri uç noktalarda
+// ya da parçanın merkezden geçen yatay veya dikey doğruyu kestiği yerde alınır.
+fn segment_hits_diamond(a: (f64, f64), b: (f64, f64), center: (f64, f64)) -> bool {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let crossing =
+        |from: f64, delta: f64, to: f64| if delta == 0.0 { 0.0 } else { ((to - from) / delta).clamp(0.0, 1.0) };
+    [0.0, 1.0, crossing(a.0, dx, center.0), crossing(a.1, dy, center.1)]
+        .into_iter()
+        .any(|t| in_diamond((a.0 + t * dx, a.1 + t * dy), center))
+}
+
+// Noktayı, merkezi `center` olan `size` x `size` piksellik bir kare (sprite) olarak rasterleştirir.
+// Örnekleri karenin içine düşen her piksel için sprite içindeki konum (0..1) da verilir.
+pub fn rasterize_point<F: FnMut(RasterFragment, [f32; 2])>(
+    center: WindowPoint,
+    size: f32,
+    bounds: Rect,
+    samples: &[[f32; 2]],
+    mut emit: F,
+) {
+    let size = size.max(1.0);
+    let (left, top) = (center.x - size * 0.5, center.y - size * 0.5);
+    let (right, bottom) = (left + size, top + size);
+    let x_first = left.floor().max(bounds.x as f32);
+    let y_first = top.floor().max(bounds.y as f32);
+    let x_last = (right.ceil() - 1.0).min(bounds.x as f32 + bounds.width as f32 - 1.0);
+    let y_last = (bottom.ceil() - 1.0).min(bounds.y as f32 + bounds.height as f32 - 1.0);
+    if x_first > x_last || y_first > y_last {
+        return;
+    }
+    for y in y_first as u32..=y_last as u32 {
+        for x in x_first as u32..=x_last as u32 {
+            let mut coverage = 0;
+            for (i, [sx, sy]) in samples.iter().enumerate() {
+                let (px, py) = (x as f32 + sx, y as f32 + sy);
+                if px >= left && px < right && py >= top && py < bottom {
+                    coverage |= 1 << i;
+                }
+            }
+            if coverage == 0 {
+                continue;
+            }
+            let point_coord = [(x as f32 + 0.5 - left) / size, (y as f32 + 0.5 - top) / size];
+            emit(RasterFragment { x, y, barycentric: [1.0, 0.0, 0.0], coverage }, point_coord);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rect = Rect { x: 0, y: 0, width: 8, height: 8 };
+
+    fn point(x: f32, y: f32) -> WindowPoint {
+        WindowPoint { x, y }
+    }
+
+    // Tek örnekle rasterleştirilen üçgenin kapsadığı pikseller, üretilme sırasıyla.
+    fn covered(vertices: [WindowPoint; 3]) -> Vec<(u32, u32)> {
+        let mut pixels = Vec::new();
+        rasterize_triangle(&vertices, BOUNDS, sample_positions(1), |fragment| pixels.push((fragment.x, fragment.y)));
+        pixels
+    }
+
+    // Rasterleştirilen doğrunun pikselleri, üretilme sırasıyla.
+    fn line(start: WindowPoint, end: WindowPoint) -> Vec<(u32, u32)> {
+        let mut pixels = Vec::new();
+        rasterize_line(start, end, BOUNDS, |fragment| pixels.push((fragment.x, fragment.y)));
+        pixels
+    }
+
+    #[test]
+    fn samples_on_top_and_left_edges_are_inside() {
+        // Üst ve sol kenar piksel merkezlerinden, hipotenüs ise x + y = 4 olan merkezlerden geçer;
+        // hipotenüs üzerindeki merkezler sağ-alt kenara ait olduğundan dışarıda kalır.
+        let pixels = covered([point(0.5, 0.5), point(4.5, 0.5), point(0.5, 4.5)]);
+        let expected: Vec<(u32, u32)> = (0..4).flat_map(|y| (0..4 - y).map(move |x| (x, y))).collect();
+        assert_eq!(pixels, expected);
+        assert_eq!(pixels.len(), 4 + 3 + 2 + 1);
+    }
+
+    #[test]
+    fn samples_on_bottom_and_right_edges_are_outside() {
+        // Aynı köşegenin diğer yarısı: alt ve sağ kenar üzerindeki merkezler dışarıda, bu kez sol-üst kenar
+        // olan hipotenüs üzerindekiler içeride kalır.
+        let pixels = covered([point(4.5, 0.5), point(4.5, 4.5), point(0.5, 4.5)]);
+        let expected: Vec<(u32, u32)> = (1..4).flat_map(|y| (4 - y..4).map(move |x| (x, y))).collect();
+        assert_eq!(pixels, expected);
+    }
+
+    #[test]
+    fn triangles_sharing_an_edge_cover_each_pixel_once() {
+        // Köşegeni piksel merkezlerinden geçen 6x6'lık bir kare, iki sarım yönünde de.
+        let [a, b, c, d] = [point(0.5, 0.5), point(6.5, 0.5), point(6.5, 6.5), point(0.5, 6.5)];
+        for triangles in [[[a, b, c], [a, c, d]], [[a, c, b], [a, d, c]]] {
+            let mut counts = [[0; 8]; 8];
+            for triangle in triangles {
+                for (x, y) in covered(triangle) {
+                    counts[y as usize][x as usize] += 1;
+                }
+            }
+            for (y, row) in counts.iter().enumerate() {
+                for (x, &count) in row.iter().enumerate() {
+                    let inside = x < 6 && y < 6;
+                    assert_eq!(count, inside as u32, "pixel ({}, {})", x, y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn winding_does_not_change_coverage() {
+        let triangle = [point(0.3, 1.2), point(7.1, 2.7), point(2.6, 6.9)];
+        let mut reversed = covered([triangle[0], triangle[2], triangle[1]]);
+        let mut pixels = covered(triangle);
+        pixels.sort();
+        reversed.sort();
+        assert!(!pixels.is_empty());
+        assert_eq!(pixels, reversed);
+    }
+
+    #[test]
+    fn multisample_coverage_bits() {
+        // Sol kenarı x = 0.5'te dikey duran büyük üçgen: (0, 0) pikselinde yalnızca x'i 0.5'ten büyük olan
+        // 1 ve 3 numaralı örnekler kapsanır, (1, 0) pikseli ise tamamen kapsanır.
+        let triangle = [point(0.5, -8.0), point(16.0, 4.0), point(0.5, 16.0)];
+        let mut fragments = Vec::new();
+        rasterize_triangle(&triangle, BOUNDS, sample_positions(4), |fragment| fragments.push(fragment));
+        assert_eq!((fragments[0].x, fragments[0].y, fragments[0].coverage), (0, 0, 0b1010));
+        assert_eq!((fragments[1].x, fragments[1].y, fragments[1].coverage), (1, 0, full_coverage(4)));
+    }
+
+    #[test]
+    fn line_leaves_out_the_pixel_whose_diamond_holds_the_end_point() {
+        // Merkezden merkeze çizilen doğruda başlangıç pikseli çizilir, bitiş pikseli ise sonraki parçaya kalır.
+        assert_eq!(line(point(0.5, 0.5), point(4.5, 0.5)), [(0, 0), (1, 0), (2, 0), (3, 0)]);
+        assert_eq!(line(point(4.5, 0.5), point(0.5, 0.5)), [(4, 0), (3, 0), (2, 0), (1, 0)]);
+        assert_eq!(line(point(1.5, 0.5), point(1.5, 3.5)), [(1, 0), (1, 1), (1, 2)]);
+        // İki elmasın arasındaki köşede biten doğru, son kestiği elmasın pikselini de çizer.
+        assert_eq!(line(point(0.5, 1.5), point(3.0, 1.5)), [(0, 1), (1, 1), (2, 1)]);
+        // Hiçbir elmasa ulaşmayan kısa parça piksel üretmez.
+        assert_eq!(line(point(1.0, 1.0), point(1.2, 1.1)), []);
+    }
+
+    #[test]
+    fn shallow_and_steep_lines_cover_one_pixel_per_major_step() {
+        let shallow = line(point(0.2, 0.3), point(7.9, 5.1));
+        assert_eq!(shallow.iter().map(|&(x, _)| x).collect::<Vec<_>>(), (0..8).collect::<Vec<_>>());
+        // Her sütunda, doğrunun piksel merkezinden geçen dikey doğruyu kestiği noktanın satırı.
+        for &(x, y) in &shallow {
+            let crossing = 0.3 + (x as f32 + 0.5 - 0.2) * 4.8 / 7.7;
+            assert_eq!(y, crossing.floor() as u32, "column {}", x);
+        }
+        let steep = line(point(0.3, 0.2), point(5.1, 7.9));
+        assert_eq!(steep, shallow.iter().map(|&(x, y)| (y, x)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn closed_line_strip_draws_every_pixel_once() {
+        let strip = [point(0.5, 0.5), point(6.5, 2.5), point(2.5, 7.5), point(0.5, 0.5)];
+        let mut counts = [[0; 8]; 8];
+        for segment in strip.windows(2) {
+            for (x, y) in line(segment[0], segment[1]) {
+                counts[y as usize][x as usize] += 1;
+            }
+        }
+        for (x, y) in [(0, 0), (6, 2), (2, 7)] {
+            assert_eq!(counts[y][x], 1, "vertex pixel ({}, {})", x, y);
+        }
+        assert!(counts.iter().flatten().all(|&count| count <= 1));
+    }
+
+    #[test]
+    fn point_covers_the_pixels_of_its_square() {
+        let rasterize = |center: WindowPoint, size: f32, sample_count: u32| {
+            let mut fragments = Vec::new();
+            rasterize_point(center, size, BOUNDS, sample_positions(sample_count), |fragment, point_coord| {
+                fragments.push((fragment.x, fragment.y, fragment.coverage, point_coord));
+            });
+            fragments
+        };
+        assert_eq!(rasterize(point(2.5, 3.5), 1.0, 1), [(2, 3, 1, [0.5, 0.5])]);
+        assert_eq!(
+            rasterize(point(2.0, 2.0), 2.0, 1),
+            [(1, 1, 1, [0.25, 0.25]), (2, 1, 1, [0.75, 0.25]), (1, 2, 1, [0.25, 0.75]), (2, 2, 1, [0.75, 0.75])]
+        );
+        // Pikseller arasındaki sınıra ortalanmış nokta: her iki pikselde de örneklerin yalnızca bir kısmı kapsanır.
+        let fragments = rasterize(point(2.0, 2.5), 1.0, 4);
+        let coverage: Vec<_> = fragments.iter().map(|&(x, y, coverage, _)| (x, y, coverage)).collect();
+        assert_eq!(coverage, [(1, 2, 0b1010), (2, 2, 0b0101)]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,248 @@
+use crate::core::color::ColorHDRA;
+use crate::gpu::software::texel;
+use crate::rendering::pipeline::TextureFormat;
+
+// CPU belleğinde tutulan basit bir render hedefi.
+// Pikseller satır satır (row-major) ve kayan noktalı RGBA olarak saklanır.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderTarget {
+    pub width: u32,
+    pub height: u32,
+    pixels: Vec<ColorHDRA>,
+}
+
+impl RenderTarget {
+    pub fn new(width: u32, height: u32) -> Self {
+        RenderTarget {
+            width,
+            height,
+            pixels: vec![ColorHDRA::rgba(0.0, 0.0, 0.0, 0.0); (width as usize) * (height as usize)],
+        }
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> Option<ColorHDRA> {
+        self.index(x, y).map(|i| self.pixels[i])
+    }
+
+    pub fn set_pixel(&mut self, x: u32, y: u32, color: ColorHDRA) {
+        if let Some(i) = self.index(x, y) {
+            self.pixels[i] = color;
+        }
+    }
+
+    // Verilen dikdörtgeni (hedef sınırlarına kırpılarak) tek bir renkle doldurur.
+    pub fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, color: ColorHDRA) {
+        let x_end = x.saturating_add(width).min(self.width);
+        let y_end = y.saturating_add(height).min(self.height);
+        for py in y.min(y_end)..y_end {
+            let row = (py as usize) * (self.width as usize);
+            for px in x.min(x_end)..x_end {
+                self.pixels[row + px as usize] = color;
+            }
+        }
+    }
+
+    pub fn clear(&mut self, color: ColorHDRA) {
+        self.pixels.fill(color);
+    }
+
+    pub fn pixels(&self) -> &[ColorHDRA] {
+        &self.pixels
+    }
+
+    fn index(&self, x: u32, y: u32) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some((y as usize) * (self.width as usize) + x as usize)
+        } else {
+            None
+        }
+    }
+}
+
+// Yürütücünün çizdiği renk hedefi: varsayılan framebuffer veya bir dokunun alt kaynağı.
+// Çok örnekli hedeflerde her piksel `sample_count` örnek içerir.
+pub trait ColorTarget {
+    fn size(&self) -> (u32, u32);
+    fn sample_count(&self) -> u32;
+    fn read(&self, x: u32, y: u32, sample: u32) -> ColorHDRA;
+    fn write(&mut self, x: u32, y: u32, sample: u32, color: ColorHDRA);
+
+    // Verilen dikdörtgenin (hedef sınırlarına kırpılarak) tüm örneklerini tek bir renkle doldurur.
+    fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, color: ColorHDRA) {
+        let (target_width, target_height) = self.size();
+        let x_end = x.saturating_add(width).min(target_width);
+        let y_end = y.saturating_add(height).min(target_height);
+        for py in y.min(y_end)..y_end {
+            for px in x.min(x_end)..x_end {
+                for sample in 0..self.sample_count() {
+                    self.write(px, py, sample, color);
+                }
+            }
+        }
+    }
+}
+
+impl ColorTarget for RenderTarget {
+    fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn sample_count(&self) -> u32 {
+        1
+    }
+
+    fn read(&self, x: u32, y: u32, _sample: u32) -> ColorHDRA {
+        self.pixel(x, y).unwrap_or(ColorHDRA::rgba(0.0, 0.0, 0.0, 0.0))
+    }
+
+    fn write(&mut self, x: u32, y: u32, _sample: u32, color: ColorHDRA) {
+        self.set_pixel(x, y, color);
+    }
+
+    fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, color: ColorHDRA) {
+        RenderTarget::fill_rect(self, x, y, width, height, color);
+    }
+}
+
+// Renk eki olmayan geçişlerin (gölge haritası, derinlik ön geçişi) renk hedefi. Yazmalar atılır,
+// okumalar saydam siyah döner; boyut ve örnek sayısı derinlik-stencil hedefininkidir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NullColorTarget {
+    pub width: u32,
+    pub height: u32,
+    pub sample_count: u32,
+}
+
+impl NullColorTarget {
+    pub fn new(width: u32, height: u32, sample_count: u32) -> Self {
+        NullColorTarget { width, height, sample_count }
+    }
+}
+
+impl ColorTarget for NullColorTarget {
+    fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    fn read(&self, _x: u32, _y: u32, _sample: u32) -> ColorHDRA {
+        ColorHDRA::rgba(0.0, 0.0, 0.0, 0.0)
+    }
+
+    fn write(&mut self, _x: u32, _y: u32, _sample: u32, _color: ColorHDRA) {}
+
+    fn fill_rect(&mut self, _x: u32, _y: u32, _width: u32, _height: u32, _color: ColorHDRA) {}
+}
+
+// Varsayılan framebuffer'a eşlik eden derinlik-stencil tamponu. Değerler Depth24PlusStencil8 hassasiyetinde
+// saklanır; başlangıçta tüm pikseller en uzak derinlikte (1.0) ve stencil değeri 0'dır.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepthStencilBuffer {
+    pub width: u32,
+    pub height: u32,
+    depth: Vec<f32>,
+    stencil: Vec<u8>,
+}
+
+impl DepthStencilBuffer {
+    pub const FORMAT: TextureFormat = TextureFormat::Depth24PlusStencil8;
+
+    pub fn new(width: u32, height: u32) -> Self {
+        let len = (width as usize) * (height as usize);
+        DepthStencilBuffer { width, height, depth: vec![1.0; len], stencil: vec![0; len] }
+    }
+
+    pub fn depth(&self, x: u32, y: u32) -> Option<f32> {
+        self.index(x, y).map(|i| self.depth[i])
+    }
+
+    pub fn stencil(&self, x: u32, y: u32) -> Option<u8> {
+        self.index(x, y).map(|i| self.stencil[i])
+    }
+
+    fn index(&self, x: u32, y: u32) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some((y as usize) * (self.width as usize) + x as usize)
+        } else {
+            None
+        }
+    }
+}
+
+// Yürütücünün derinlik ve stencil testlerinde kullandığı hedef: varsayılan tampon veya bir derinlik dokusu.
+// Stencil içermeyen formatlarda stencil okumaları 0 döner ve yazmalar yok sayılır.
+pub trait DepthStencilTarget {
+    fn size(&self) -> (u32, u32);
+    fn sample_count(&self) -> u32;
+    fn format(&self) -> TextureFormat;
+    fn read_depth(&self, x: u32, y: u32, sample: u32) -> f32;
+    // Değer formatın hassasiyetine yuvarlanarak yazılır.
+    fn write_depth(&mut self, x: u32, y: u32, sample: u32, depth: f32);
+    fn read_stencil(&self, x: u32, y: u32, sample: u32) -> u8;
+    fn write_stencil(&mut self, x: u32, y: u32, sample: u32, stencil: u8);
+
+    fn fill_depth(&mut self, x: u32, y: u32, width: u32, height: u32, depth: f32) {
+        for_each_sample(self, x, y, width, height, |target, px, py, sample| target.write_depth(px, py, sample, depth));
+    }
+
+    fn fill_stencil(&mut self, x: u32, y: u32, width: u32, height: u32, stencil: u8) {
+        for_each_sample(self, x, y, width, height, |target, px, py, sample| {
+            target.write_stencil(px, py, sample, stencil)
+        });
+    }
+}
+
+// Dikdörtgenin (hedef sınırlarına kırpılarak) her örneği için `f`'yi çağırır.
+fn for_each_sample<T, F>(target: &mut T, x: u32, y: u32, width: u32, height: u32, mut f: F)
+where
+    T: DepthStencilTarget + ?Sized,
+    F: FnMut(&mut T, u32, u32, u32),
+{
+    let (target_width, target_height) = target.size();
+    let x_end = x.saturating_add(width).min(target_width);
+    let y_end = y.saturating_add(height).min(target_height);
+    for py in y.min(y_end)..y_end {
+        for px in x.min(x_end)..x_end {
+            for sample in 0..target.sample_count() {
+                f(target, px, py, sample);
+            }
+        }
+    }
+}
+
+impl DepthStencilTarget for DepthStencilBuffer {
+    fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn sample_count(&self) -> u32 {
+        1
+    }
+
+    fn format(&self) -> TextureFormat {
+        Self::FORMAT
+    }
+
+    fn read_depth(&self, x: u32, y: u32, _sample: u32) -> f32 {
+        self.depth(x, y).unwrap_or(1.0)
+    }
+
+    fn write_depth(&mut self, x: u32, y: u32, _sample: u32, depth: f32) {
+        if let Some(i) = self.index(x, y) {
+            self.depth[i] = texel::quantize_depth(Self::FORMAT, depth);
+        }
+    }
+
+    fn read_stencil(&self, x: u32, y: u32, _sample: u32) -> u8 {
+        self.stencil(x, y).unwrap_or(0)
+    }
+
+    fn write_stencil(&mut self, x: u32, y: u32, _sample: u32, stencil: u8) {
+        if let Some(i) = self.index(x, y) {
+            self.stencil[i] = stencil;
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,388 @@
+use std::cell::Ref;
+use std::collections::HashMap;
+
+use crate::gpu::commands::CompareFunction;
+use crate::gpu::sampler::{AddressMode, FilterMode, SamplerDescriptor};
+use crate::gpu::software::texel;
+use crate::gpu::software::texture::TextureStorage;
+use crate::gpu::texture::{TextureAspect, TextureView, TextureViewDimension};
+
+// Örneklemede kullanılacak ayrıntı seviyesi (level of detail), görünümün taban mip seviyesine göre.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Lod {
+    Level(f32),
+    // Doku koordinatlarının ekran uzayındaki türevleri (normalize koordinatlarda, x ve y yönünde).
+    // LOD ve anizotropik filtrelemenin ekseni bunlardan hesaplanır.
+    Gradient { ddx: [f32; 2], ddy: [f32; 2] },
+}
+
+// Programların doku birimleri üzerinden eriştiği, örneklenmeye hazır dokular.
+#[derive(Debug, Default)]
+pub struct TextureBindings<'a> {
+    units: HashMap<u32, SampledTexture<'a>>,
+}
+
+impl<'a> TextureBindings<'a> {
+    pub fn get(&self, unit: u32) -> Option<&SampledTexture<'a>> {
+        self.units.get(&unit)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    pub(crate) fn insert(&mut self, unit: u32, texture: SampledTexture<'a>) {
+        self.units.insert(unit, texture);
+    }
+}
+
+// Bir doku birimine bağlı görünüm ve onu örnekleyen sampler. Çizim boyunca doku depolaması
+// salt okunur olarak ödünç alınır.
+//
+// Texel'ler görünüm formatıyla çözülür (sRGB görünümler doğrusal değer verir) ve filtreleme doğrusal
+// uzayda yapılır. Filtrelenemeyen formatlar (bkz. TextureFormat::is_filterable), karşılaştırma sampler'ı
+// ile örneklenmedikçe her zaman en yakın texel ile örneklenir. Derinlik görünümlerinde derinlik kırmızı
+// kanaldadır; StencilOnly görünümlerinde stencil değeri kırmızı kanala taşınır.
+// Küp görünümlerinde yüzler arasında filtreleme yapılmaz; her yüz kenarına kırpılır.
+#[derive(Debug)]
+pub struct SampledTexture<'a> {
+    storage: Ref<'a, TextureStorage>,
+    view: TextureView,
+    sampler: SamplerDescriptor,
+}
+
+impl<'a> SampledTexture<'a> {
+    pub fn new(storage: Ref<'a, TextureStorage>, view: TextureView, sampler: SamplerDescriptor) -> Self {
+        SampledTexture { storage, view, sampler }
+    }
+
+    pub fn view(&self) -> &TextureView {
+        &self.view
+    }
+
+    pub fn sampler(&self) -> &SamplerDescriptor {
+        &self.sampler
+    }
+
+    // Görünümün `level` mip seviyesinin genişliği, yüksekliği ve katman (3B görünümde derinlik) sayısı.
+    pub fn size(&self, level: u32) -> [u32; 3] {
+        let mip = self.view.base_mip_level() + level;
+        let (width, height) = self.storage.mip_extent(mip);
+        let depth = match self.view.dimension() {
+            TextureViewDimension::D3 => self.storage.descriptor().mip_depth(mip),
+            _ => self.view.array_layer_count(),
+        };
+        [width, height, depth]
+    }
+
+    // Koordinatlar görünüm boyutuna göre yorumlanır: D2 için (u, v), D2Array için (u, v, katman),
+    // D3 için (u, v, w), Cube için yön vektörü. Kullanılmayan bileşenler yok sayılır.
+    pub fn sample(&self, coords: [f32; 3], lod: Lod) -> [f32; 4] {
+        self.filter(coords, lod, |texel| texel)
+    }
+
+    // Karşılaştırmalı (gölge) örnekleme: her texel'in derinliği için sampler'ın karşılaştırma fonksiyonu
+    // `passes(reference, derinlik)` olarak uygulanır ve geçen texel'lerin filtrelenmiş oranı döner (PCF).
+    // Karşılaştırma fonksiyonu olmayan sampler'larda her texel geçer.
+    pub fn sample_compare(&self, coords: [f32; 3], reference: f32, lod: Lod) -> f32 {
+        let compare = self.sampler.compare.unwrap_or(CompareFunction::Always);
+        self.filter(coords, lod, |texel| {
+            let passed = if compare.passes(reference, texel[0]) { 1.0 } else { 0.0 };
+            [passed, passed, passed, 1.0]
+        })[0]
+    }
+
+    // Filtresiz ve adreslemesiz okuma; koordinatlar görünümün dışındaysa None döner.
+    pub fn fetch(&self, x: u32, y: u32, layer: u32, level: u32) -> Option<[f32; 4]> {
+        if level >= self.view.mip_level_count() || layer >= self.view.array_layer_count() {
+            return None;
+        }
+        self.read(level, layer, x, y)
+    }
+
+    fn filter<F: Fn([f32; 4]) -> [f32; 4]>(&self, coords: [f32; 3], lod: Lod, map: F) -> [f32; 4] {
+        let (layer, coords) = self.project(coords);
+        let (lambda, axis, taps) = self.level_of_detail(lod);
+        let sampler = &self.sampler;
+        let lambda = lambda.clamp(sampler.lod_min_clamp, sampler.lod_max_clamp);
+        let filterable = self.view.format().is_filterable() || sampler.compare.is_some();
+        let pick = |filter: FilterMode| if filterable { filter } else { FilterMode::Nearest };
+        let filter = pick(if lambda > 0.0 { sampler.min_filter } else { sampler.mag_filter });
+        // Mip seviyeleri arasında doğrusal geçiş veya en yakın seviye (yarım değerler aşağı yuvarlanır).
+        let max_level = (self.view.mip_level_count() - 1) as f32;
+        let level = lambda.clamp(0.0, max_level);
+        let levels = match pick(sampler.mipmap_filter) {
+            FilterMode::Nearest => [((level + 0.5).ceil() - 1.0).max(0.0), 0.0, 0.0],
+            FilterMode::Linear => [level.floor(), (level.floor() + 1.0).min(max_level), level.fract()],
+        };
+        let mut result = [0.0; 4];
+        for tap in 0..taps {
+            // Anizotropik örnekler, ayak izinin (footprint) uzun ekseni boyunca eşit aralıklarla alınır.
+            let offset = (tap as f32 + 0.5) / taps as f32 - 0.5;
+            let coords = [coords[0] + axis[0] * offset, coords[1] + axis[1] * offset, coords[2]];
+            let mut color = self.sample_level(levels[0] as u32, layer, coords, filter, &map);
+            if levels[2] > 0.0 {
+                let next = self.sample_level(levels[1] as u32, layer, coords, filter, &map);
+                color = lerp(color, next, levels[2]);
+            }
+            for (sum, value) in result.iter_mut().zip(color) {
+                *sum += value / taps as f32;
+            }
+        }
+        result
+    }
+
+    // (lambda, anizotropi ekseni, örnek sayısı). Eksen normalize doku koordinatlarındadır.
+    fn level_of_detail(&self, lod: Lod) -> (f32, [f32; 2], u32) {
+        let (ddx, ddy) = match lod {
+            Lod::Level(level) => return (level, [0.0, 0.0], 1),
+            Lod::Gradient { ddx, ddy } => (ddx, ddy),
+        };
+        let (width, height) = self.view.size();
+        let length = |d: [f32; 2]| (d[0] * width as f32).hypot(d[1] * height as f32);
+        let (x, y) = (length(ddx), length(ddy));
+        let (major, minor, axis) = if x >= y { (x, y, ddx) } else { (y, x, ddy) };
+        let max_anisotropy = self.sampler.max_anisotropy as f32;
+        if max_anisotropy <= 1.0 || minor <= 0.0 || major <= minor {
+            return (major.log2(), [0.0, 0.0], 1);
+        }
+        let taps = (major / minor).ceil().min(max_anisotropy);
+        ((major / taps).log2(), axis, taps as u32)
+    }
+
+    // Koordinatları örneklenecek katmana ve o katmandaki (u, v, w) değerine dönüştürür.
+    fn project(&self, coords: [f32; 3]) -> (u32, [f32; 3]) {
+        match self.view.dimension() {
+            TextureViewDimension::D2 | TextureViewDimension::D3 => (0, coords),
+            TextureViewDimension::D2Array => {
+                let last = (self.view.array_layer_count() - 1) as f32;
+                (coords[2].round().clamp(0.0, last) as u32, coords)
+            }
+            TextureViewDimension::Cube => cube_face(coords),
+        }
+    }
+
+    fn sample_level<F: Fn([f32; 4]) -> [f32; 4]>(
+        &self,
+        level: u32,
+        layer: u32,
+        [u, v, w]: [f32; 3],
+        filter: FilterMode,
+        map: &F,
+    ) -> [f32; 4] {
+        let [width, height, depth] = self.size(level);
+        let volume = self.view.dimension() == TextureViewDimension::D3;
+        let texel = |x: i64, y: i64, z: i64| map(self.addressed(level, layer, [x, y, z], volume));
+        match filter {
+            FilterMode::Nearest => {
+                let nearest = |coord: f32, size: u32| (coord * size as f32).floor() as i64;
+                let z = if volume { nearest(w, depth) } else { 0 };
+                texel(nearest(u, width), nearest(v, height), z)
+            }
+            FilterMode::Linear => {
+                // Texel merkezleri yarım texel kaydırılmıştır.
+                let split = |coord: f32, size: u32| {
+                    let position = coord * size as f32 - 0.5;
+                    (position.floor() as i64, position - position.floor())
+                };
+                let ((x, fx), (y, fy)) = (split(u, width), split(v, height));
+                let bilinear = |z: i64| {
+                    let top = lerp(texel(x, y, z), texel(x + 1, y, z), fx);
+                    let bottom = lerp(texel(x, y + 1, z), texel(x + 1, y + 1, z), fx);
+                    lerp(top, bottom, fy)
+                };
+                if volume {
+                    let (z, fz) = split(w, depth);
+                    lerp(bilinear(z), bilinear(z + 1), fz)
+                } else {
+                    bilinear(0)
+                }
+            }
+        }
+    }
+
+    // Adresleme kiplerini uygulayıp texel'i okur; ClampToBorder dışarıda kenarlık rengini verir.
+    fn addressed(&self, level: u32, layer: u32, [x, y, z]: [i64; 3], volume: bool) -> [f32; 4] {
+        let [width, height, depth] = self.size(level);
+        let sampler = &self.sampler;
+        let modes = if self.view.dimension() == TextureViewDimension::Cube {
+            [AddressMode::ClampToEdge; 3]
+        } else {
+            [sampler.address_mode_u, sampler.address_mode_v, sampler.address_mode_w]
+        };
+        let x = address(modes[0], x, width);
+        let y = address(modes[1], y, height);
+        let z = if volume { address(modes[2], z, depth) } else { Some(layer) };
+        match (x, y, z) {
+            (Some(x), Some(y), Some(z)) => self.read(level, z, x, y).unwrap_or(sampler.border_color.rgba()),
+            _ => sampler.border_color.rgba(),
+        }
+    }
+
+    fn read(&self, level: u32, layer: u32, x: u32, y: u32) -> Option<[f32; 4]> {
+        let mip = self.view.base_mip_level() + level;
+        let bytes = self.storage.texel_bytes(mip, self.view.base_array_layer() + layer, x, y, 0)?;
+        let [r, g, b, a] = texel::decode(self.view.format(), bytes);
+        Some(match self.view.aspect() {
+            TextureAspect::All => [r, g, b, a],
+            TextureAspect::DepthOnly => [r, 0.0, 0.0, 1.0],
+            TextureAspect::StencilOnly => [g, 0.0, 0.0, 1.0],
+        })
+    }
+}
+
+// Texel koordinatını adresleme kipine göre [0, size) aralığına taşır.
+fn address(mode: AddressMode, coord: i64, size: u32) -> Option<u32> {
+    let size = size as i64;
+    let coord = match mode {
+        AddressMode::Repeat => coord.rem_euclid(size),
+        AddressMode::MirrorRepeat => {
+            let period = coord.rem_euclid(2 * size);
+            if period < size {
+                period
+            } else {
+                2 * size - 1 - period
+            }
+        }
+        AddressMode::ClampToEdge => coord.clamp(0, size - 1),
+        AddressMode::ClampToBorder if (0..size).contains(&coord) => coord,
+        AddressMode::ClampToBorder => return None,
+    };
+    Some(coord as u32)
+}
+
+// Yön vektörünün en büyük bileşeni yüzü seçer (+X, -X, +Y, -Y, +Z, -Z sırasıyla katman 0..5);
+// yüz içi koordinatlar Vulkan/OpenGL küp haritası kuralına göre hesaplanır.
+fn cube_face([x, y, z]: [f32; 3]) -> (u32, [f32; 3]) {
+    let (ax, ay, az) = (x.abs(), y.abs(), z.abs());
+    let (face, s, t, major) = if ax >= ay && ax >= az {
+        if x >= 0.0 {
+            (0, -z, -y, ax)
+        } else {
+            (1, z, -y, ax)
+        }
+    } else if ay >= az {
+        if y >= 0.0 {
+            (2, x, z, ay)
+        } else {
+            (3, x, -z, ay)
+        }
+    } else if z >= 0.0 {
+        (4, x, -y, az)
+    } else {
+        (5, -x, -y, az)
+    };
+    let major = if major > 0.0 { major } else { 1.0 };
+    (face, [(s / major + 1.0) * 0.5, (t / major + 1.0) * 0.5, 0.0])
+}
+
+fn lerp(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    [0, 1, 2, 3].map(|i| a[i] + (b[i] - a[i]) * t)
+}
+
+// Testler yazılım arka ucunu varsayılan cihaz üzerinden sürer.
+#[cfg(all(test, not(feature = "null-backend")))]
+mod tests {
+    use super::*;
+    use crate::gpu::device::Device;
+    use crate::gpu::sampler::BorderColor;
+    use crate::gpu::texture::{Texture, TextureDescriptor, TextureDimension, TextureUsage};
+    use crate::rendering::pipeline::TextureFormat;
+
+    // Kırmızı kanalında (derinlik formatlarında derinliğinde) `values` taşıyan Nx1 doku.
+    fn row_texture(device: &Device, format: TextureFormat, values: &[f32]) -> Texture {
+        let texture = device
+            .create_texture(&TextureDescriptor {
+                format,
+                dimension: TextureDimension::D2,
+                width: values.len() as u32,
+                height: 1,
+                depth_or_array_layers: 1,
+                mip_level_count: 1,
+                sample_count: 1,
+                usage: TextureUsage::Sampled,
+            })
+            .unwrap();
+        let mut storage = texture.native().storage.borrow_mut();
+        for (x, &value) in values.iter().enumerate() {
+            storage.write_texel(0, 0, x as u32, 0, 0, [value, 0.0, 0.0, 1.0]);
+        }
+        drop(storage);
+        texture
+    }
+
+    fn sample(texture: &Texture, sampler: SamplerDescriptor, u: f32, lod: f32) -> [f32; 4] {
+        let sampled = SampledTexture::new(texture.native().storage.borrow(), texture.view(), sampler);
+        sampled.sample([u, 0.5, 0.0], Lod::Level(lod))
+    }
+
+    fn addressed(mode: AddressMode) -> SamplerDescriptor {
+        SamplerDescriptor { address_mode_u: mode, border_color: BorderColor::OpaqueWhite, ..Default::default() }
+    }
+
+    #[test]
+    fn address_modes_wrap_texel_coordinates() {
+        for (mode, expected) in [
+            (AddressMode::Repeat, [Some(3), Some(1)]),
+            (AddressMode::MirrorRepeat, [Some(0), Some(2)]),
+            (AddressMode::ClampToEdge, [Some(0), Some(3)]),
+            (AddressMode::ClampToBorder, [None, None]),
+        ] {
+            assert_eq!([address(mode, -1, 4), address(mode, 5, 4)], expected, "{:?}", mode);
+            assert_eq!(address(mode, 2, 4), Some(2));
+        }
+        assert_eq!(address(AddressMode::MirrorRepeat, -5, 4), Some(3));
+    }
+
+    #[test]
+    fn nearest_sampling_applies_the_address_mode() {
+        let device = Device::new().unwrap();
+        let texture = row_texture(&device, TextureFormat::R32Float, &[0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(sample(&texture, addressed(AddressMode::Repeat), 1.3, 0.0)[0], 1.0);
+        assert_eq!(sample(&texture, addressed(AddressMode::MirrorRepeat), 1.3, 0.0)[0], 2.0);
+        assert_eq!(sample(&texture, addressed(AddressMode::ClampToEdge), 1.3, 0.0)[0], 3.0);
+        assert_eq!(sample(&texture, addressed(AddressMode::ClampToBorder), 1.3, 0.0), [1.0; 4]);
+        assert_eq!(sample(&texture, addressed(AddressMode::ClampToBorder), 0.3, 0.0)[0], 1.0);
+    }
+
+    #[test]
+    fn linear_filtering_blends_neighbours_across_the_edge() {
+        let device = Device::new().unwrap();
+        let texture = row_texture(&device, TextureFormat::R32Float, &[0.0, 1.0, 2.0, 3.0]);
+        let linear = |mode| SamplerDescriptor { mag_filter: FilterMode::Linear, ..addressed(mode) };
+        assert_eq!(sample(&texture, linear(AddressMode::ClampToEdge), 0.25, 0.0)[0], 0.5);
+        assert_eq!(sample(&texture, linear(AddressMode::ClampToEdge), 0.0, 0.0)[0], 0.0);
+        assert_eq!(sample(&texture, linear(AddressMode::Repeat), 0.0, 0.0)[0], 1.5);
+        assert_eq!(sample(&texture, linear(AddressMode::ClampToBorder), 0.0, 0.0)[0], 0.5);
+    }
+
+    #[test]
+    fn magnification_and_minification_use_their_own_filters() {
+        let device = Device::new().unwrap();
+        let texture = row_texture(&device, TextureFormat::R32Float, &[0.0, 1.0, 2.0, 3.0]);
+        let sampler = SamplerDescriptor { min_filter: FilterMode::Linear, ..Default::default() };
+        assert_eq!(sample(&texture, sampler, 0.25, 0.0)[0], 1.0);
+        assert_eq!(sample(&texture, sampler, 0.25, 1.0)[0], 0.5);
+    }
+
+    #[test]
+    fn comparison_samplers_filter_the_comparison_results() {
+        let device = Device::new().unwrap();
+        let texture = row_texture(&device, TextureFormat::Depth32Float, &[0.1, 0.2, 0.3, 0.4]);
+        let compare = |compare, filter| SamplerDescriptor { compare, mag_filter: filter, ..Default::default() };
+        let sample_compare = |sampler, u, reference| {
+            let sampled = SampledTexture::new(texture.native().storage.borrow(), texture.view(), sampler);
+            sampled.sample_compare([u, 0.5, 0.0], reference, Lod::Level(0.0))
+        };
+        let less = Some(CompareFunction::Less);
+        assert_eq!(sample_compare(compare(less, FilterMode::Nearest), 0.125, 0.05), 1.0);
+        assert_eq!(sample_compare(compare(less, FilterMode::Nearest), 0.125, 0.15), 0.0);
+        assert_eq!(sample_compare(compare(less, FilterMode::Linear), 0.5, 0.25), 0.5);
+        let greater = Some(CompareFunction::Greater);
+        assert_eq!(sample_compare(compare(greater, FilterMode::Linear), 0.5, 0.25), 0.5);
+        assert_eq!(sample_compare(compare(None, FilterMode::Linear), 0.5, 0.25), 1.0);
+
+        // Karşılaştırma olmadan derinlik dokuları filtrelenmez; en yakın texel okunur.
+        assert_eq!(sample(&texture, compare(None, FilterMode::Linear), 0.5, 0.0)[0], 0.3);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+
+use crate::gpu::error::GraphisError;
+use crate::gpu::software::backend::SoftwareBackend;
+use crate::gpu::software::executor::UniformValue;
+use crate::gpu::software::interpreter::SpirvProgram;
+use crate::gpu::software::sampler::{Lod, TextureBindings};
+use crate::gpu::shader::{NativeShader, ShaderSource};
+use crate::gpu::spirv::Interpolation;
+use crate::rendering::pipeline::Pipeline;
+
+// Yazılım arka ucunda vertex ve fragment aşamalarını çalıştıran program.
+// Yürütücü, bağlı pipeline'ın shader'larından bir program seçer ve her vertex ile
+// her fragment için onu çağırır.
+pub trait SoftwareProgram: Debug {
+    fn vertex(&self, input: &VertexInput<'_>) -> ShadedVertex;
+    // None dönerse fragment atılır (discard).
+    fn fragment(&self, input: &FragmentInput<'_>) -> Option<FragmentOutput>;
+
+    // `varying` sıradaki varying'in primitif üzerinde nasıl enterpole edileceği.
+    fn interpolation(&self, _varying: usize) -> Interpolation {
+        Interpolation::Perspective
+    }
+
+    // Fragment aşaması bağlı doku olmasa da varying türevlerine ihtiyaç duyuyorsa true.
+    fn uses_derivatives(&self) -> bool {
+        false
+    }
+}
+
+// Rust ile yazılmış vertex aşaması (`ShaderSource::Native`). `Fn(&VertexInput<'_>) -> ShadedVertex`
+// imzalı kapanışlar bu trait'i kendiliğinden uygular.
+pub trait VertexShader {
+    fn vertex(&self, input: &VertexInput<'_>) -> ShadedVertex;
+}
+
+impl<F> VertexShader for F
+where
+    F: Fn(&VertexInput<'_>) -> ShadedVertex,
+{
+    fn vertex(&self, input: &VertexInput<'_>) -> ShadedVertex {
+        self(input)
+    }
+}
+
+// Rust ile yazılmış fragment aşaması. Kapanışlarda tüm varying'ler perspektif doğru enterpole edilir;
+// başka bir biçim gerekiyorsa trait bir tür üzerinde uygulanmalıdır.
+pub trait FragmentShader {
+    // None dönerse fragment atılır (discard).
+    fn fragment(&self, input: &FragmentInput<'_>) -> Option<FragmentOutput>;
+
+    fn interpolation(&self, _varying: usize) -> Interpolation {
+        Interpolation::Perspective
+    }
+
+    fn uses_derivatives(&self) -> bool {
+        false
+    }
+}
+
+impl<F> FragmentShader for F
+where
+    F: Fn(&FragmentInput<'_>) -> Option<FragmentOutput>,
+{
+    fn fragment(&self, input: &FragmentInput<'_>) -> Option<FragmentOutput> {
+        self(input)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct VertexInput<'a> {
+    pub attributes: &'a [(u32, [f32; 4])], // (konum, değer), konuma göre sıralı
+    pub vertex_index: u32,
+    pub instance_index: u32,
+    pub uniforms: &'a HashMap<u32, UniformValue>,
+    pub textures: &'a TextureBindings<'a>, // Türev olmadığından açık LOD (Lod::Level) ile örneklenir
+}
+
+impl VertexInput<'_> {
+    // Uint32 ve Sint32 attribute'ları sayısal değer olarak değil bit deseni olarak taşınır; değer
+    // `f32::to_bits` ile okunur.
+    pub fn attribute(&self, location: u32) -> Option<[f32; 4]> {
+        self.attributes
+            .iter()
+            .find(|(attribute_location, _)| *attribute_location == location)
+            .map(|(_, value)| *value)
+    }
+}
+
+// Vertex aşamasının çıktısı: kırpma uzayında (clip space) konum, nokta boyutu ve fragment'lara
+// aktarılacak ara değerler (varyings).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShadedVertex {
+    pub position: [f32; 4],
+    pub point_size: f32, // Piksel cinsinden; yalnızca noktalar çizilirken kullanılır
+    pub varyings: Vec<[f32; 4]>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FragmentInput<'a> {
+    pub frag_coord: [f32; 4], // (x, y) piksel merkezi, z derinlik, w = 1 / clip w
+    pub front_facing: bool,
+    pub point_coord: [f32; 2], // Nokta sprite'ı içindeki konum (0..1); noktalar dışında (0, 0)
+    pub varyings: &'a [[f32; 4]], // Programın ara değerleme biçimine göre enterpole edilmiş değerler
+    // Varying'lerin sağdaki ve alttaki komşu piksele göre farkları (ddx, ddy); doku örneklerken LOD seçimi için.
+    // Doğru ve noktalarda sıfırdır; bağlı doku yoksa ve program türev kullanmıyorsa boştur.
+    pub derivatives: &'a [([f32; 4], [f32; 4])],
+    pub uniforms: &'a HashMap<u32, UniformValue>,
+    pub textures: &'a TextureBindings<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FragmentOutput {
+    pub colors: Vec<[f32; 4]>, // Renk hedefi konumuna göre
+    pub second_color: Option<[f32; 4]>, // Çift kaynaklı karıştırmada Src1 faktörlerinin okuduğu renk
+    pub depth: Option<f32>, // Rasterleştirilen derinliğin yerine kullanılacak derinlik (FragDepth)
+}
+
+// Çalıştırılabilir shader kodu olmadığında kullanılan sabit fonksiyonlu program:
+// - 0 numaralı attribute kırpma uzayındaki konumdur (eksik bileşenler 0, w ise 1 kabul edilir).
+// - Diğer attribute'lar konum sırasıyla varying olarak aktarılır.
+// - Fragment rengi ilk varying'dir; varying yoksa 0 konumundaki Vec4/Vec3 uniform'u, o da yoksa beyazdır.
+// - 0 numaralı doku birimine doku bağlıysa renk, ikinci varying'in (u, v, w) bileşenlerinde örneklenen değerle çarpılır.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixedFunctionProgram;
+
+impl SoftwareProgram for FixedFunctionProgram {
+    fn vertex(&self, input: &VertexInput<'_>) -> ShadedVertex {
+        ShadedVertex {
+            position: input.attribute(0).unwrap_or([0.0, 0.0, 0.0, 1.0]),
+            point_size: 1.0,
+            varyings: input
+                .attributes
+                .iter()
+                .filter(|(location, _)| *location != 0)
+                .map(|(_, value)| *value)
+                .collect(),
+        }
+    }
+
+    fn fragment(&self, input: &FragmentInput<'_>) -> Option<FragmentOutput> {
+        let color = match (input.varyings.first(), input.uniforms.get(&0)) {
+            (Some(varying), _) => *varying,
+            (None, Some(UniformValue::Vec4((r, g, b, a)))) => [*r, *g, *b, *a],
+            (None, Some(UniformValue::Vec3(color))) => [color.x, color.y, color.z, 1.0],
+            _ => [1.0, 1.0, 1.0, 1.0],
+        };
+        let color = match (input.textures.get(0), input.varyings.get(1)) {
+            (Some(texture), Some(&[u, v, w, _])) => {
+                let (ddx, ddy) = input.derivatives.get(1).copied().unwrap_or_default();
+                let lod = Lod::Gradient { ddx: [ddx[0], ddx[1]], ddy: [ddy[0], ddy[1]] };
+                let texel = texture.sample([u, v, w], lod);
+                [0, 1, 2, 3].map(|i| color[i] * texel[i])
+            }
+            _ => color,
+        };
+        Some(FragmentOutput { colors: vec![color], second_color: None, depth: None })
+    }
+}
+
+// Yerel (Rust) shader'ları çağıran program. Fragment aşaması yoksa sabit fonksiyonlu fragment kullanılır.
+pub struct NativeProgram<'a> {
+    vertex: &'a dyn VertexShader,
+    fragment: Option<&'a dyn FragmentShader>,
+}
+
+impl<'a> NativeProgram<'a> {
+    pub fn new(vertex: &'a dyn VertexShader, fragment: Option<&'a dyn FragmentShader>) -> Self {
+        NativeProgram { vertex, fragment }
+    }
+}
+
+impl Debug for NativeProgram<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeProgram").field("fragment", &self.fragment.is_some()).finish_non_exhaustive()
+    }
+}
+
+impl SoftwareProgram for NativeProgram<'_> {
+    fn vertex(&self, input: &VertexInput<'_>) -> ShadedVertex {
+        self.vertex.vertex(input)
+    }
+
+    fn fragment(&self, input: &FragmentInput<'_>) -> Option<FragmentOutput> {
+        match self.fragment {
+            Some(fragment) => fragment.fragment(input),
+            None => FixedFunctionProgram.fragment(input),
+        }
+    }
+
+    fn interpolation(&self, varying: usize) -> Interpolation {
+        self.fragment.map_or(Interpolation::Perspective, |fragment| fragment.interpolation(varying))
+    }
+
+    fn uses_derivatives(&self) -> bool {
+        self.fragment.is_some_and(|fragment| fragment.uses_derivatives())
+    }
+}
+
+// Pipeline için çalıştırılacak programı seçer: vertex shader yerelse NativeProgram, SPIR-V ise SPIR-V
+// yorumlayıcısı, hiç yoksa sabit fonksiyonlu program. Program, pipeline'ın shader'larını ödünç alır.
+// Yerel ve SPIR-V aşamaları aynı pipeline'da birlikte kullanılamaz.
+pub(crate) fn program_for(
+    pipeline: &Pipeline<SoftwareBackend>,
+) -> Result<Box<dyn SoftwareProgram + '_>, GraphisError> {
+    let Some(vertex) = &pipeline.vertex.module else { return Ok(Box::new(FixedFunctionProgram)) };
+    let fragment = pipeline
+        .fragment
+        .as_ref()
+        .and_then(|fragment| Some((fragment.module.as_ref()?, fragment.entry_point.as_str())));
+    let mixed = || {
+        GraphisError::Unsupported(format!(
+            "pipeline with vertex shader {:?} mixes native and SPIR-V shader stages",
+            vertex.descriptor.label
+        ))
+    };
+    if let ShaderSource::Native(NativeShader::Vertex(native)) = &vertex.descriptor.source {
+        let fragment = match fragment.map(|(shader, _)| &shader.descriptor.source) {
+            None => None,
+            Some(ShaderSource::Native(NativeShader::Fragment(native))) => Some(native.as_ref()),
+            Some(_) => return Err(mixed()),
+        };
+        return Ok(Box::new(NativeProgram::new(native.as_ref(), fragment)));
+    }
+    let module = vertex.module().ok_or_else(mixed)?;
+    let fragment = match fragment {
+        Some((shader, entry_point)) => Some((shader.module().ok_or_else(mixed)?, entry_point)),
+        None => None,
+    };
+    Ok(Box::new(SpirvProgram::new((module, &pipeline.vertex.entry_point), fragment)?))
+}
+
+// Testler yazılım arka ucunu varsayılan cihaz üzerinden sürer.
+#[cfg(all(test, not(feature = "null-backend")))]
+mod tests {
+    use super::*;
+    use crate::gpu::commands::RenderCommand;
+    use crate::gpu::device::Device;
+    use crate::gpu::shader::{Shader, ShaderDescriptor, ShaderStage};
+    use crate::gpu::software::executor::SoftwareExecutor;
+    use crate::rendering::pipeline::{FragmentStateDescriptor, PipelineDescriptor};
+
+    // Vertex indisini ilk varying'in kırmızı kanalında taşıyan, hedefi kaplayan üçgen.
+    fn full_screen(input: &VertexInput<'_>) -> ShadedVertex {
+        let positions = [[-1.0, 1.0, 0.5, 1.0], [3.0, 1.0, 0.5, 1.0], [-1.0, -3.0, 0.5, 1.0]];
+        ShadedVertex {
+            position: positions[input.vertex_index as usize],
+            point_size: 1.0,
+            varyings: vec![[input.vertex_index as f32, 0.0, 0.0, 1.0]],
+        }
+    }
+
+    // Sol sütunu atan ve varying'i düz (Flat) okuyan fragment shader'ı.
+    struct FlatFragment;
+
+    impl FragmentShader for FlatFragment {
+        fn fragment(&self, input: &FragmentInput<'_>) -> Option<FragmentOutput> {
+            if input.frag_coord[0] < 1.0 {
+                return None;
+            }
+            let [index, ..] = input.varyings[0];
+            Some(FragmentOutput { colors: vec![[index, 1.0, 0.0, 1.0]], ..Default::default() })
+        }
+
+        fn interpolation(&self, _varying: usize) -> Interpolation {
+            Interpolation::Flat
+        }
+    }
+
+    fn shader(source: ShaderSource, stage: ShaderStage) -> Shader {
+        Shader::new(ShaderDescriptor { label: None, source, stage }).unwrap()
+    }
+
+    fn descriptor(fragment: Option<ShaderSource>) -> PipelineDescriptor {
+        let mut descriptor = PipelineDescriptor::default();
+        let vertex = ShaderSource::Native(NativeShader::vertex(full_screen));
+        descriptor.vertex.module = Some(shader(vertex, ShaderStage::Vertex));
+        descriptor.fragment = fragment.map(|source| FragmentStateDescriptor {
+            module: Some(shader(source, ShaderStage::Fragment)),
+            entry_point: "main".to_string(),
+            ..Default::default()
+        });
+        descriptor
+    }
+
+    #[test]
+    fn native_shaders_run_for_every_vertex_and_fragment() {
+        let device = Device::new().unwrap();
+        let fragment = ShaderSource::Native(NativeShader::fragment(FlatFragment));
+        let pipeline = device.create_pipeline(&descriptor(Some(fragment))).unwrap();
+        let mut executor = SoftwareExecutor::new(2, 2);
+        let commands =
+            [RenderCommand::BindShaderProgram { program: pipeline.handle() }, RenderCommand::Draw { vertex_count: 3 }];
+        executor.execute(&device.resources(), &commands).unwrap();
+        for (x, y) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            let color = executor.framebuffer().pixel(x, y).unwrap();
+            // Düz değerler ilk (provoking) vertex'ten gelir; x = 0 sütunu atılmıştır.
+            let expected = if x == 0 { [0.0; 4] } else { [0.0, 1.0, 0.0, 1.0] };
+            assert_eq!([color.r, color.g, color.b, color.a], expected, "pixel ({}, {})", x, y);
+        }
+    }
+
+    #[test]
+    fn native_programs_fall_back_to_the_fixed_function_fragment() {
+        let device = Device::new().unwrap();
+        let pipeline = device.create_pipeline(&descriptor(None)).unwrap();
+        let program = program_for(&pipeline).unwrap();
+        let uniforms = HashMap::new();
+        let textures = TextureBindings::default();
+        let varyings = [[0.25, 0.5, 0.75, 1.0]];
+        let input = FragmentInput {
+            frag_coord: [0.5, 0.5, 0.5, 1.0],
+            front_facing: true,
+            point_coord: [0.0, 0.0],
+            varyings: &varyings,
+            derivatives: &[],
+            uniforms: &uniforms,
+            textures: &textures,
+        };
+        assert_eq!(program.fragment(&input).unwrap().colors, vec![[0.25, 0.5, 0.75, 1.0]]);
+        assert_eq!(program.interpolation(0), Interpolation::Perspective);
+    }
+
+    #[test]
+    fn native_and_spirv_stages_cannot_be_mixed() {
+        let device = Device::new().unwrap();
+        let source = "#version 450\nlayout(location = 0) out vec4 color;\nvoid main() { color = vec4(1.0); }";
+        let fragment = ShaderSource::Glsl { source: source.to_string(), stage: ShaderStage::Fragment };
+        let pipeline = device.create_pipeline(&descriptor(Some(fragment))).unwrap();
+        assert!(matches!(program_for(&pipeline), Err(GraphisError::Unsupported(_))));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,374 @@
+use crate::rendering::pipeline::TextureFormat;
+
+// Texel'lerin formatlarına göre kodlanması ve çözülmesi.
+// Renkler her zaman [r, g, b, a] kayan noktalı dörtlüsü olarak işlenir:
+// normalize formatlar 0..1 (Snorm için -1..1) aralığına, tamsayı formatlar ise
+// tamsayı değerlerinin kendisine çözülür. Eksik kanallar (0, 0, 0, 1) ile tamamlanır.
+// BGRA formatları da RGBA sırasıyla çözülür. Derinlik formatlarında derinlik kırmızı kanala, stencil değeri
+// yeşil kanala yazılır.
+
+pub fn decode(format: TextureFormat, bytes: &[u8]) -> [f32; 4] {
+    let count = format.channel_count();
+    match format {
+        TextureFormat::R8Unorm | TextureFormat::Rg8Unorm | TextureFormat::Rgba8Unorm => {
+            fill(count, |i| bytes[i] as f32 / 255.0)
+        }
+        TextureFormat::Rgba8UnormSrgb => {
+            map4(|i| if i < 3 { srgb_to_linear(bytes[i] as f32 / 255.0) } else { bytes[i] as f32 / 255.0 })
+        }
+        TextureFormat::Bgra8Unorm => map4(|i| bytes[BGRA[i]] as f32 / 255.0),
+        TextureFormat::Bgra8UnormSrgb => map4(|i| {
+            let value = bytes[BGRA[i]] as f32 / 255.0;
+            if i < 3 {
+                srgb_to_linear(value)
+            } else {
+                value
+            }
+        }),
+        TextureFormat::Rg8Snorm | TextureFormat::Rgba8Snorm => {
+            fill(count, |i| (bytes[i] as i8 as f32 / 127.0).max(-1.0))
+        }
+        TextureFormat::Rg8Uint | TextureFormat::Rgba8Uint => fill(count, |i| bytes[i] as f32),
+        TextureFormat::Rg8Sint | TextureFormat::Rgba8Sint => fill(count, |i| bytes[i] as i8 as f32),
+        TextureFormat::Rg16Float | TextureFormat::Rgba16Float => fill(count, |i| f16_to_f32(u16_at(bytes, i))),
+        TextureFormat::Rg16Unorm | TextureFormat::Rgba16Unorm => fill(count, |i| u16_at(bytes, i) as f32 / 65535.0),
+        TextureFormat::Rg16Snorm | TextureFormat::Rgba16Snorm => {
+            fill(count, |i| (u16_at(bytes, i) as i16 as f32 / 32767.0).max(-1.0))
+        }
+        TextureFormat::Rg16Uint | TextureFormat::Rgba16Uint => fill(count, |i| u16_at(bytes, i) as f32),
+        TextureFormat::Rg16Sint | TextureFormat::Rgba16Sint => fill(count, |i| u16_at(bytes, i) as i16 as f32),
+        TextureFormat::R32Float
+        | TextureFormat::Rg32Float
+        | TextureFormat::Rgba32Float
+        | TextureFormat::Depth32Float => {
+            fill(count, |i| f32::from_bits(u32_at(bytes, i)))
+        }
+        TextureFormat::R32Uint | TextureFormat::Rg32Uint => fill(count, |i| u32_at(bytes, i) as f32),
+        TextureFormat::R32Sint | TextureFormat::Rg32Sint => fill(count, |i| u32_at(bytes, i) as i32 as f32),
+        TextureFormat::Rgb10a2Unorm => {
+            let packed = u32_at(bytes, 0);
+            map4(|i| if i < 3 { bits(packed, i * 10, 10) as f32 / 1023.0 } else { bits(packed, 30, 2) as f32 / 3.0 })
+        }
+        TextureFormat::Rg11b10Float => {
+            let packed = u32_at(bytes, 0);
+            [
+                small_float_to_f32(bits(packed, 0, 11), 6),
+                small_float_to_f32(bits(packed, 11, 11), 6),
+                small_float_to_f32(bits(packed, 22, 10), 5),
+                1.0,
+            ]
+        }
+        TextureFormat::Rgb9e5Ufloat => {
+            let packed = u32_at(bytes, 0);
+            let scale = 2f32.powi(bits(packed, 27, 5) as i32 - RGB9E5_BIAS - 9);
+            fill(3, |i| bits(packed, i * 9, 9) as f32 * scale)
+        }
+        TextureFormat::Depth16Unorm => [u16_at(bytes, 0) as f32 / 65535.0, 0.0, 0.0, 1.0],
+        TextureFormat::Depth24PlusStencil8 => {
+            let packed = u32_at(bytes, 0);
+            [(packed & 0x00FF_FFFF) as f32 / 16_777_215.0, (packed >> 24) as f32, 0.0, 1.0]
+        }
+        TextureFormat::Depth32FloatStencil8 => [f32::from_bits(u32_at(bytes, 0)), bytes[4] as f32, 0.0, 1.0],
+        TextureFormat::Stencil8 => [0.0, bytes[0] as f32, 0.0, 1.0],
+    }
+}
+
+// `bytes` en az format.block_size() uzunluğunda olmalıdır. Aralık dışı değerler kırpılır; formatta
+// bulunmayan kanallar yok sayılır.
+pub fn encode(format: TextureFormat, color: [f32; 4], bytes: &mut [u8]) {
+    let bytes = &mut bytes[..format.block_size()];
+    match format {
+        TextureFormat::R8Unorm | TextureFormat::Rg8Unorm | TextureFormat::Rgba8Unorm => {
+            put_channels(bytes, color, |v| [unorm(v, 255.0) as u8])
+        }
+        TextureFormat::Rgba8UnormSrgb => put_channels(bytes, to_srgb(color), |v| [unorm(v, 255.0) as u8]),
+        TextureFormat::Bgra8Unorm => put_channels(bytes, map4(|i| color[BGRA[i]]), |v| [unorm(v, 255.0) as u8]),
+        TextureFormat::Bgra8UnormSrgb => {
+            let srgb = to_srgb(color);
+            put_channels(bytes, map4(|i| srgb[BGRA[i]]), |v| [unorm(v, 255.0) as u8])
+        }
+        TextureFormat::Rg8Snorm | TextureFormat::Rgba8Snorm => {
+            put_channels(bytes, color, |v| [snorm(v, 127.0) as i8 as u8])
+        }
+        TextureFormat::Rg8Uint | TextureFormat::Rgba8Uint => {
+            put_channels(bytes, color, |v| [v.clamp(0.0, u8::MAX as f32) as u8])
+        }
+        TextureFormat::Rg8Sint | TextureFormat::Rgba8Sint => {
+            put_channels(bytes, color, |v| [v.clamp(i8::MIN as f32, i8::MAX as f32) as i8 as u8])
+        }
+        TextureFormat::Rg16Float | TextureFormat::Rgba16Float => {
+            put_channels(bytes, color, |v| f32_to_f16(v).to_le_bytes())
+        }
+        TextureFormat::Rg16Unorm | TextureFormat::Rgba16Unorm => {
+            put_channels(bytes, color, |v| (unorm(v, 65535.0) as u16).to_le_bytes())
+        }
+        TextureFormat::Rg16Snorm | TextureFormat::Rgba16Snorm => {
+            put_channels(bytes, color, |v| (snorm(v, 32767.0) as i16).to_le_bytes())
+        }
+        TextureFormat::Rg16Uint | TextureFormat::Rgba16Uint => {
+            put_channels(bytes, color, |v| (v.clamp(0.0, u16::MAX as f32) as u16).to_le_bytes())
+        }
+        TextureFormat::Rg16Sint | TextureFormat::Rgba16Sint => {
+            put_channels(bytes, color, |v| (v.clamp(i16::MIN as f32, i16::MAX as f32) as i16).to_le_bytes())
+        }
+        TextureFormat::R32Float
+        | TextureFormat::Rg32Float
+        | TextureFormat::Rgba32Float
+        | TextureFormat::Depth32Float => {
+            put_channels(bytes, color, |v| v.to_le_bytes())
+        }
+        TextureFormat::R32Uint | TextureFormat::Rg32Uint => {
+            put_channels(bytes, color, |v| (v.max(0.0) as u32).to_le_bytes())
+        }
+        TextureFormat::R32Sint | TextureFormat::Rg32Sint => put_channels(bytes, color, |v| (v as i32).to_le_bytes()),
+        TextureFormat::Rgb10a2Unorm => {
+            let [r, g, b, a] = color;
+            let packed = unorm(r, 1023.0) as u32
+                | (unorm(g, 1023.0) as u32) << 10
+                | (unorm(b, 1023.0) as u32) << 20
+                | (unorm(a, 3.0) as u32) << 30;
+            bytes.copy_from_slice(&packed.to_le_bytes());
+        }
+        TextureFormat::Rg11b10Float => {
+            let [r, g, b, _] = color;
+            let packed = f32_to_small_float(r, 6) | f32_to_small_float(g, 6) << 11 | f32_to_small_float(b, 5) << 22;
+            bytes.copy_from_slice(&packed.to_le_bytes());
+        }
+        TextureFormat::Rgb9e5Ufloat => bytes.copy_from_slice(&pack_rgb9e5(color).to_le_bytes()),
+        TextureFormat::Depth16Unorm => bytes.copy_from_slice(&(unorm(color[0], 65535.0) as u16).to_le_bytes()),
+        TextureFormat::Depth24PlusStencil8 => {
+            let depth = unorm(color[0], 16_777_215.0) as u32;
+            let stencil = color[1].clamp(0.0, 255.0) as u32;
+            bytes.copy_from_slice(&(depth | (stencil << 24)).to_le_bytes());
+        }
+        TextureFormat::Depth32FloatStencil8 => {
+            bytes[..4].copy_from_slice(&color[0].to_le_bytes());
+            bytes[4] = color[1].clamp(0.0, 255.0) as u8;
+            bytes[5..].fill(0);
+        }
+        TextureFormat::Stencil8 => bytes[0] = color[1].clamp(0.0, 255.0) as u8,
+    }
+}
+
+// sRGB transfer fonksiyonu (IEC 61966-2-1); alfa kanalı her zaman doğrusaldır.
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+pub fn linear_to_srgb(value: f32) -> f32 {
+    let value = value.clamp(0.0, 1.0);
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// Derinlik değerini formatın saklama hassasiyetine yuvarlar. Gelen fragment derinliği de aynı
+// hassasiyete getirilerek karşılaştırılır; böylece Equal gibi karşılaştırmalar tutarlı çalışır.
+pub fn quantize_depth(format: TextureFormat, depth: f32) -> f32 {
+    match format {
+        TextureFormat::Depth16Unorm => unorm(depth, 65535.0) / 65535.0,
+        TextureFormat::Depth24PlusStencil8 => unorm(depth, 16_777_215.0) / 16_777_215.0,
+        _ => depth,
+    }
+}
+
+// Dört kanalı art arda, her biri N byte olacak şekilde yazar.
+fn put_channels<const N: usize, F: Fn(f32) -> [u8; N]>(bytes: &mut [u8], color: [f32; 4], encode: F) {
+    for (channel, value) in bytes.chunks_exact_mut(N).zip(color) {
+        channel.copy_from_slice(&encode(value));
+    }
+}
+
+fn map4<F: Fn(usize) -> f32>(channel: F) -> [f32; 4] {
+    [channel(0), channel(1), channel(2), channel(3)]
+}
+
+// İlk `count` kanalı okur; eksik kanallar (0, 0, 0, 1) ile tamamlanır.
+fn fill<F: Fn(usize) -> f32>(count: usize, channel: F) -> [f32; 4] {
+    map4(|i| if i < count { channel(i) } else if i == 3 { 1.0 } else { 0.0 })
+}
+
+// BGRA formatlarında RGBA kanalının bellekteki konumu (ve tersi; eşleme kendi tersidir).
+const BGRA: [usize; 4] = [2, 1, 0, 3];
+
+fn to_srgb([r, g, b, a]: [f32; 4]) -> [f32; 4] {
+    [linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b), a]
+}
+
+// Paketlenmiş sözcüğün `offset` bitinden başlayan `count` bitlik alanı.
+fn bits(packed: u32, offset: usize, count: usize) -> u32 {
+    (packed >> offset) & ((1 << count) - 1)
+}
+
+fn unorm(value: f32, max: f32) -> f32 {
+    (value.clamp(0.0, 1.0) * max).round()
+}
+
+fn snorm(value: f32, max: f32) -> f32 {
+    (value.clamp(-1.0, 1.0) * max).round()
+}
+
+fn u16_at(bytes: &[u8], channel: usize) -> u16 {
+    u16::from_le_bytes([bytes[channel * 2], bytes[channel * 2 + 1]])
+}
+
+fn u32_at(bytes: &[u8], channel: usize) -> u32 {
+    let i = channel * 4;
+    u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]])
+}
+
+// IEEE 754 yarım duyarlıklı (binary16) sayıyı f32'ye çevirir.
+pub fn f16_to_f32(bits: u16) -> f32 {
+    let sign = ((bits >> 15) as u32) << 31;
+    let exponent = ((bits >> 10) & 0x1F) as u32;
+    let mantissa = (bits & 0x3FF) as u32;
+    let magnitude = match exponent {
+        0 => {
+            // Sıfır veya normalize edilmemiş (subnormal) sayı: mantissa * 2^-24
+            let value = mantissa as f32 / 16_777_216.0;
+            return if sign != 0 { -value } else { value };
+        }
+        0x1F => 0x7F80_0000 | (mantissa << 13), // Sonsuz veya NaN
+        _ => ((exponent + 127 - 15) << 23) | (mantissa << 13),
+    };
+    f32::from_bits(sign | magnitude)
+}
+
+// f32'yi en yakına yuvarlayarak yarım duyarlıklı sayıya çevirir.
+pub fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xFF) as i32;
+    let mantissa = bits & 0x007F_FFFF;
+    if exponent == 0xFF {
+        // Sonsuz veya NaN (NaN'ın sessiz kalması için mantissa'nın üst biti korunur)
+        return sign | 0x7C00 | if mantissa != 0 { 0x0200 } else { 0 };
+    }
+    let half_exponent = exponent - 127 + 15;
+    if half_exponent >= 0x1F {
+        return sign | 0x7C00; // Taşma: sonsuz
+    }
+    if half_exponent <= 0 {
+        if half_exponent < -10 {
+            return sign; // Sıfıra yuvarlanır
+        }
+        // Normalize edilmemiş sonuç
+        let full = mantissa | 0x0080_0000;
+        let shift = (14 - half_exponent) as u32;
+        let half = full >> shift;
+        let remainder = full & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        let rounded = if remainder > halfway || (remainder == halfway && half & 1 == 1) { half + 1 } else { half };
+        return sign | rounded as u16;
+    }
+    let half = ((half_exponent as u32) << 10) | (mantissa >> 13);
+    let remainder = mantissa & 0x1FFF;
+    // Yuvarlama, üsse taşarsa doğru biçimde bir sonraki üsse (veya sonsuza) geçer.
+    let rounded = if remainder > 0x1000 || (remainder == 0x1000 && half & 1 == 1) { half + 1 } else { half };
+    sign | rounded as u16
+}
+
+// Rg11b10Float kanallarındaki işaretsiz küçük kayan noktalı sayılar: 5 bit üs (binary16 ile aynı sapma)
+// ve `mantissa_bits` bit mantis. Değer binary16 üzerinden çevrilir.
+fn small_float_to_f32(value: u32, mantissa_bits: u32) -> f32 {
+    f16_to_f32((value << (10 - mantissa_bits)) as u16)
+}
+
+// Negatif değerler ve NaN sıfıra, binary16'da taşan değerler sonsuza çevrilir; mantis en yakına yuvarlanır.
+fn f32_to_small_float(value: f32, mantissa_bits: u32) -> u32 {
+    if value.is_nan() || value <= 0.0 {
+        return 0;
+    }
+    let half = f32_to_f16(value) as u32;
+    let shift = 10 - mantissa_bits;
+    // Yuvarlama taşması doğal olarak bir sonraki üsse geçer; sonsuzdan öteye taşmaz.
+    let rounded = if half >= 0x7C00 { 0x7C00 } else { (half + (1 << (shift - 1))).min(0x7C00) };
+    rounded >> shift
+}
+
+const RGB9E5_BIAS: i32 = 15;
+
+// Ortak üslü paketleme (EXT_texture_shared_exponent): üs en büyük kanala göre seçilir.
+fn pack_rgb9e5(color: [f32; 4]) -> u32 {
+    const MAX: f32 = 511.0 / 512.0 * 65536.0;
+    let channels = [0, 1, 2].map(|i| if color[i].is_nan() { 0.0 } else { color[i].clamp(0.0, MAX) });
+    let max = channels.iter().copied().fold(0.0f32, f32::max);
+    let mut exponent = (max.log2().floor() as i32).max(-RGB9E5_BIAS - 1) + 1 + RGB9E5_BIAS;
+    if (max / 2f32.powi(exponent - RGB9E5_BIAS - 9) + 0.5).floor() >= 512.0 {
+        exponent += 1;
+    }
+    let scale = 2f32.powi(exponent - RGB9E5_BIAS - 9);
+    let mantissas = channels.map(|value| ((value / scale + 0.5).floor() as u32).min(511));
+    mantissas[0] | mantissas[1] << 9 | mantissas[2] << 18 | (exponent as u32) << 27
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encoded(format: TextureFormat, color: [f32; 4]) -> Vec<u8> {
+        let mut bytes = vec![0; format.block_size()];
+        encode(format, color, &mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn rgb10a2_packs_ten_bit_channels_and_a_two_bit_alpha() {
+        let bytes = encoded(TextureFormat::Rgb10a2Unorm, [1.0, 0.5, -1.0, 1.0 / 3.0]);
+        assert_eq!(u32::from_le_bytes(bytes[..].try_into().unwrap()), 1023 | 512 << 10 | 1 << 30);
+        assert_eq!(decode(TextureFormat::Rgb10a2Unorm, &bytes), [1.0, 512.0 / 1023.0, 0.0, 1.0 / 3.0]);
+    }
+
+    #[test]
+    fn rg11b10_keeps_five_bit_exponents_and_rounds_the_mantissa() {
+        let bytes = encoded(TextureFormat::Rg11b10Float, [1.0, 0.5, 2.0, 0.0]);
+        assert_eq!(u32::from_le_bytes(bytes[..].try_into().unwrap()), 0x3C0 | 0x380 << 11 | 0x200 << 22);
+        assert_eq!(decode(TextureFormat::Rg11b10Float, &bytes), [1.0, 0.5, 2.0, 1.0]);
+
+        // Kırmızı ve yeşilde 6, mavide 5 bit mantis; negatifler sıfıra, taşanlar sonsuza gider.
+        let round_trip = |color| decode(TextureFormat::Rg11b10Float, &encoded(TextureFormat::Rg11b10Float, color));
+        assert_eq!(round_trip([1.02, 1.02, 1.02, 0.0]), [1.015625, 1.015625, 1.03125, 1.0]);
+        assert_eq!(round_trip([-3.0, 1e6, f32::NAN, 0.0]), [0.0, f32::INFINITY, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn rgb9e5_shares_the_exponent_of_the_largest_channel() {
+        let bytes = encoded(TextureFormat::Rgb9e5Ufloat, [1.0, 0.5, 0.25, 0.0]);
+        assert_eq!(u32::from_le_bytes(bytes[..].try_into().unwrap()), 256 | 128 << 9 | 64 << 18 | 16 << 27);
+        assert_eq!(decode(TextureFormat::Rgb9e5Ufloat, &bytes), [1.0, 0.5, 0.25, 1.0]);
+
+        // Yuvarlama mantisi taşırsa üs bir artar; aralık dışı değerler kırpılır.
+        let round_trip = |color| decode(TextureFormat::Rgb9e5Ufloat, &encoded(TextureFormat::Rgb9e5Ufloat, color));
+        assert_eq!(round_trip([0.99999, 0.0, 0.0, 0.0]), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(round_trip([1e9, -1.0, f32::NAN, 0.0]), [65408.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn srgb_formats_convert_color_channels_but_not_alpha() {
+        let bytes = encoded(TextureFormat::Rgba8UnormSrgb, [0.0, srgb_to_linear(128.0 / 255.0), 1.0, 0.5]);
+        assert_eq!(bytes, [0, 128, 255, 128]);
+        let [r, g, b, a] = decode(TextureFormat::Rgba8UnormSrgb, &bytes);
+        assert_eq!((r, b, a), (0.0, 1.0, 128.0 / 255.0));
+        assert!((g - 0.215_861).abs() < 1e-5);
+
+        let bgra = encoded(TextureFormat::Bgra8UnormSrgb, [1.0, srgb_to_linear(128.0 / 255.0), 0.0, 0.5]);
+        assert_eq!(bgra, [0, 128, 255, 128]);
+        assert_eq!(decode(TextureFormat::Bgra8UnormSrgb, &bgra)[0], 1.0);
+    }
+
+    #[test]
+    fn srgb_bytes_survive_a_decode_encode_round_trip() {
+        for format in [TextureFormat::Rgba8UnormSrgb, TextureFormat::Bgra8UnormSrgb] {
+            for value in 0..=255u8 {
+                let bytes = [value, value, value, value];
+                assert_eq!(encoded(format, decode(format, &bytes)), bytes, "{:?} {}", format, value);
+            }
+        }
+    }
+}
\ No newline at end of file
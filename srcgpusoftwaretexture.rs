@@ -0,0 +1,407 @@
+use crate::core::color::ColorHDRA;
+use crate::gpu::error::GraphisError;
+use crate::gpu::memory::HostMemory;
+use crate::gpu::software::render_target::{ColorTarget, DepthStencilTarget};
+use crate::gpu::software::texel;
+use crate::gpu::texture::{TextureDataLayout, TextureDescriptor, TextureRegion, TextureView, TextureViewDimension};
+use crate::rendering::pipeline::TextureFormat;
+
+// Yazılım arka ucunda bir dokunun CPU belleğindeki içeriği.
+// Her mip seviyesi ve dizi katmanı ayrı bir alt kaynaktır (subresource). Alt kaynak içinde texel'ler
+// satır satır saklanır; çok örnekli dokularda her texel'in örnekleri art arda durur.
+// Texel'ler dokunun kendi formatında (format.block_size() byte) tutulur.
+#[derive(Debug)]
+pub struct TextureStorage {
+    descriptor: TextureDescriptor,
+    subresources: Vec<HostMemory>, // indeks: mip * katman sayısı + katman
+}
+
+impl TextureStorage {
+    pub fn new(descriptor: &TextureDescriptor) -> Result<Self, GraphisError> {
+        let mut subresources = Vec::new();
+        for mip in 0..descriptor.mip_level_count {
+            let (width, height) = descriptor.mip_extent(mip);
+            let len = (width as usize)
+                .checked_mul(height as usize)
+                .and_then(|texels| texels.checked_mul(descriptor.sample_count as usize))
+                .and_then(|samples| samples.checked_mul(descriptor.format.block_size()))
+                .ok_or(GraphisError::OutOfMemory { requested: usize::MAX })?;
+            for _ in 0..descriptor.depth_or_array_layers {
+                subresources.push(HostMemory::new(len)?);
+            }
+        }
+        Ok(TextureStorage { descriptor: descriptor.clone(), subresources })
+    }
+
+    pub fn descriptor(&self) -> &TextureDescriptor {
+        &self.descriptor
+    }
+
+    // Mip seviyesinin boyutları; her seviye bir öncekinin yarısıdır (en az 1).
+    pub fn mip_extent(&self, mip: u32) -> (u32, u32) {
+        self.descriptor.mip_extent(mip)
+    }
+
+    pub fn subresource(&self, mip: u32, layer: u32) -> Option<&HostMemory> {
+        let index = self.subresource_index(mip, layer)?;
+        self.subresources.get(index)
+    }
+
+    pub fn subresource_mut(&mut self, mip: u32, layer: u32) -> Option<&mut HostMemory> {
+        let index = self.subresource_index(mip, layer)?;
+        self.subresources.get_mut(index)
+    }
+
+    // Texel'i çözülmüş RGBA olarak okur; koordinatlar alt kaynağın dışındaysa None döner.
+    pub fn read_texel(&self, mip: u32, layer: u32, x: u32, y: u32, sample: u32) -> Option<[f32; 4]> {
+        let bytes = self.texel_bytes(mip, layer, x, y, sample)?;
+        Some(texel::decode(self.descriptor.format, bytes))
+    }
+
+    pub fn write_texel(&mut self, mip: u32, layer: u32, x: u32, y: u32, sample: u32, color: [f32; 4]) {
+        let format = self.descriptor.format;
+        if let Some(bytes) = self.texel_bytes_mut(mip, layer, x, y, sample) {
+            texel::encode(format, color, bytes);
+        }
+    }
+
+    // Texel'in ham byte'ları; görünüm formatıyla yorumlamak için (bkz. TextureFormat::is_view_compatible).
+    pub fn texel_bytes(&self, mip: u32, layer: u32, x: u32, y: u32, sample: u32) -> Option<&[u8]> {
+        let offset = self.texel_offset(mip, x, y, sample)?;
+        let block_size = self.descriptor.format.block_size();
+        Some(&self.subresource(mip, layer)?.as_bytes()[offset..offset + block_size])
+    }
+
+    pub fn texel_bytes_mut(&mut self, mip: u32, layer: u32, x: u32, y: u32, sample: u32) -> Option<&mut [u8]> {
+        let offset = self.texel_offset(mip, x, y, sample)?;
+        let block_size = self.descriptor.format.block_size();
+        Some(&mut self.subresource_mut(mip, layer)?.as_bytes_mut()[offset..offset + block_size])
+    }
+
+    // Bölgenin satırlarını `layout` düzenindeki `data`'dan kopyalar. Bölge TextureDescriptor::validate_copy ile
+    // doğrulanmış olmalıdır (tek örnekli doku, sınırlar içinde).
+    pub fn write_region(&mut self, region: &TextureRegion, data: &[u8], layout: &TextureDataLayout) {
+        for (z, y, storage_offset, row_size) in self.region_rows(region) {
+            let data_offset = layout.row_offset(y, z);
+            if let Some(memory) = self.subresource_mut(region.mip_level, region.origin.z + z) {
+                memory.as_bytes_mut()[storage_offset..storage_offset + row_size]
+                    .copy_from_slice(&data[data_offset..data_offset + row_size]);
+            }
+        }
+    }
+
+    pub fn read_region(&self, region: &TextureRegion, data: &mut [u8], layout: &TextureDataLayout) {
+        for (z, y, storage_offset, row_size) in self.region_rows(region) {
+            let data_offset = layout.row_offset(y, z);
+            if let Some(memory) = self.subresource(region.mip_level, region.origin.z + z) {
+                data[data_offset..data_offset + row_size]
+                    .copy_from_slice(&memory.as_bytes()[storage_offset..storage_offset + row_size]);
+            }
+        }
+    }
+
+    // Görünümün taban seviyesinden sonraki her mip seviyesini bir önceki seviyeden kutu filtresiyle üretir.
+    // Texel'ler görünüm formatıyla çözülüp doğrusal uzayda ortalanır; böylece sRGB formatlarda sonuç gama
+    // doğrudur. İkinin kuvveti olmayan boyutlarda hedef texel, kaynakta örttüğü alanın (ör. 2,5 texel)
+    // kısmi ağırlıklı ortalamasıdır. D3 görünümlerinde dilimler de birlikte süzülür.
+    pub fn generate_mipmaps(&mut self, view: &TextureView) {
+        let format = view.format();
+        let volume = view.dimension() == TextureViewDimension::D3;
+        let first_layer = view.base_array_layer();
+        let layers = if volume { 1 } else { view.array_layer_count() };
+        let first_level = view.base_mip_level() + 1;
+        for level in first_level..view.base_mip_level() + view.mip_level_count() {
+            let (source_width, source_height) = self.mip_extent(level - 1);
+            let (width, height) = self.mip_extent(level);
+            let (source_depth, depth) =
+                if volume { (self.descriptor.mip_depth(level - 1), self.descriptor.mip_depth(level)) } else { (1, 1) };
+            let weights_x = box_weights(source_width, width);
+            let weights_y = box_weights(source_height, height);
+            let weights_z = box_weights(source_depth, depth);
+            for layer in first_layer..first_layer + layers {
+                for (z, weights_z) in weights_z.iter().enumerate() {
+                    for (y, weights_y) in weights_y.iter().enumerate() {
+                        for (x, weights_x) in weights_x.iter().enumerate() {
+                            let color = self.box_filter(format, level - 1, layer, [weights_x, weights_y, weights_z]);
+                            if let Some(bytes) = self.texel_bytes_mut(level, layer + z as u32, x as u32, y as u32, 0) {
+                                texel::encode(format, color, bytes);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Ağırlıklı kaynak texel'lerin ortalaması; z ağırlıkları `layer`'dan başlayan dilimlere uygulanır.
+    fn box_filter(&self, format: TextureFormat, mip: u32, layer: u32, [x, y, z]: [&[(u32, f32)]; 3]) -> [f32; 4] {
+        let mut sum = [0.0; 4];
+        for &(sz, wz) in z {
+            for &(sy, wy) in y {
+                for &(sx, wx) in x {
+                    if let Some(bytes) = self.texel_bytes(mip, layer + sz, sx, sy, 0) {
+                        let color = texel::decode(format, bytes);
+                        for (sum, channel) in sum.iter_mut().zip(color) {
+                            *sum += channel * wx * wy * wz;
+                        }
+                    }
+                }
+            }
+        }
+        sum
+    }
+
+    // Bölgenin her satırı için (görüntü, satır, alt kaynak içindeki byte ofseti, satır uzunluğu).
+    fn region_rows(&self, region: &TextureRegion) -> impl Iterator<Item = (u32, u32, usize, usize)> {
+        let block_size = self.descriptor.format.block_size();
+        let (width, _) = self.mip_extent(region.mip_level);
+        let (origin, extent) = (region.origin, region.extent);
+        let row_size = extent.width as usize * block_size;
+        // Boş bölgelerin verisi de boş olabilir (bkz. validate_copy); satır ofsetleri hesaplanmaz.
+        let rows = if row_size == 0 { 0 } else { extent.height };
+        (0..extent.depth_or_array_layers).flat_map(move |z| {
+            (0..rows).map(move |y| {
+                let texel = (origin.y + y) as usize * width as usize + origin.x as usize;
+                (z, y, texel * block_size, row_size)
+            })
+        })
+    }
+
+    fn subresource_index(&self, mip: u32, layer: u32) -> Option<usize> {
+        if mip < self.descriptor.mip_level_count && layer < self.descriptor.depth_or_array_layers {
+            Some((mip * self.descriptor.depth_or_array_layers + layer) as usize)
+        } else {
+            None
+        }
+    }
+
+    fn texel_offset(&self, mip: u32, x: u32, y: u32, sample: u32) -> Option<usize> {
+        let (width, height) = self.mip_extent(mip);
+        if x >= width || y >= height || sample >= self.descriptor.sample_count {
+            return None;
+        }
+        let texel = (y as usize) * (width as usize) + x as usize;
+        Some((texel * self.descriptor.sample_count as usize + sample as usize) * self.descriptor.format.block_size())
+    }
+}
+
+// Kaynak boyuttan hedef boyuta küçültmede her hedef texel'in örttüğü kaynak texel'ler ve ağırlıkları.
+// Hedef texel kaynakta `source / destination` genişliğinde bir aralık örter; aralığa kısmen giren texel'ler
+// örtülen payları oranında katkıda bulunur. Ağırlıkların toplamı 1'dir.
+fn box_weights(source: u32, destination: u32) -> Vec<Vec<(u32, f32)>> {
+    let ratio = source as f32 / destination as f32;
+    (0..destination)
+        .map(|i| {
+            let (start, end) = (i as f32 * ratio, (i + 1) as f32 * ratio);
+            (start.floor() as u32..(end.ceil() as u32).min(source))
+                .map(|s| (s, (end.min(s as f32 + 1.0) - start.max(s as f32)) / ratio))
+                .filter(|&(_, weight)| weight > 0.0)
+                .collect()
+        })
+        .collect()
+}
+
+// Bir doku görünümünün taban mip seviyesini ve katmanını renk veya derinlik hedefi olarak kullanır.
+// Texel'ler görünümün formatıyla (örneğin Rgba8Unorm bir dokunun sRGB görünümü) kodlanır ve çözülür.
+#[derive(Debug)]
+pub struct TextureTarget<'a> {
+    storage: &'a mut TextureStorage,
+    format: TextureFormat,
+    mip: u32,
+    layer: u32,
+}
+
+impl<'a> TextureTarget<'a> {
+    pub fn new(storage: &'a mut TextureStorage, view: &TextureView) -> Self {
+        TextureTarget { storage, format: view.format(), mip: view.base_mip_level(), layer: view.base_array_layer() }
+    }
+
+    fn read_texel(&self, x: u32, y: u32, sample: u32) -> Option<[f32; 4]> {
+        let bytes = self.storage.texel_bytes(self.mip, self.layer, x, y, sample)?;
+        Some(texel::decode(self.format, bytes))
+    }
+
+    fn write_texel(&mut self, x: u32, y: u32, sample: u32, color: [f32; 4]) {
+        if let Some(bytes) = self.storage.texel_bytes_mut(self.mip, self.layer, x, y, sample) {
+            texel::encode(self.format, color, bytes);
+        }
+    }
+
+    // Örneğin tek bir kanalını, diğer kanalları koruyarak günceller.
+    fn update_channel(&mut self, x: u32, y: u32, sample: u32, channel: usize, value: f32) {
+        if let Some(mut texel) = self.read_texel(x, y, sample) {
+            texel[channel] = value;
+            self.write_texel(x, y, sample, texel);
+        }
+    }
+}
+
+impl ColorTarget for TextureTarget<'_> {
+    fn size(&self) -> (u32, u32) {
+        self.storage.mip_extent(self.mip)
+    }
+
+    fn sample_count(&self) -> u32 {
+        self.storage.descriptor.sample_count
+    }
+
+    fn read(&self, x: u32, y: u32, sample: u32) -> ColorHDRA {
+        let [r, g, b, a] = self.read_texel(x, y, sample).unwrap_or([0.0; 4]);
+        ColorHDRA::rgba(r, g, b, a)
+    }
+
+    fn write(&mut self, x: u32, y: u32, sample: u32, color: ColorHDRA) {
+        self.write_texel(x, y, sample, [color.r, color.g, color.b, color.a]);
+    }
+}
+
+// Derinlik kırmızı, stencil yeşil kanalda durur; biri yazılırken diğeri korunur.
+impl DepthStencilTarget for TextureTarget<'_> {
+    fn size(&self) -> (u32, u32) {
+        self.storage.mip_extent(self.mip)
+    }
+
+    fn sample_count(&self) -> u32 {
+        self.storage.descriptor.sample_count
+    }
+
+    fn format(&self) -> TextureFormat {
+        self.format
+    }
+
+    fn read_depth(&self, x: u32, y: u32, sample: u32) -> f32 {
+        self.read_texel(x, y, sample).map_or(1.0, |texel| texel[0])
+    }
+
+    fn write_depth(&mut self, x: u32, y: u32, sample: u32, depth: f32) {
+        if self.format.is_depth() {
+            self.update_channel(x, y, sample, 0, depth);
+        }
+    }
+
+    fn read_stencil(&self, x: u32, y: u32, sample: u32) -> u8 {
+        if !self.format.has_stencil() {
+            return 0;
+        }
+        self.read_texel(x, y, sample).map_or(0, |texel| texel[1] as u8)
+    }
+
+    fn write_stencil(&mut self, x: u32, y: u32, sample: u32, stencil: u8) {
+        if self.format.has_stencil() {
+            self.update_channel(x, y, sample, 1, stencil as f32);
+        }
+    }
+}
+
+// Testler yazılım arka ucunu varsayılan cihaz üzerinden sürer.
+#[cfg(all(test, not(feature = "null-backend")))]
+mod tests {
+    use super::*;
+    use crate::gpu::command_buffer::CommandBuffer;
+    use crate::gpu::device::Device;
+    use crate::gpu::texture::{Extent3d, Origin3d, TextureDimension, TextureUsage};
+
+    #[test]
+    fn mipmaps_of_a_3d_texture_reduce_its_depth() {
+        let device = Device::new().unwrap();
+        let texture = device
+            .create_texture(&TextureDescriptor {
+                format: TextureFormat::R32Float,
+                dimension: TextureDimension::D3,
+                width: 2,
+                height: 2,
+                depth_or_array_layers: 4,
+                mip_level_count: 2,
+                sample_count: 1,
+                usage: TextureUsage::Sampled,
+            })
+            .unwrap();
+        {
+            let mut storage = texture.native().storage.borrow_mut();
+            for z in 0..4 {
+                for (x, y) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                    storage.write_texel(0, z, x, y, 0, [z as f32, 0.0, 0.0, 1.0]);
+                }
+            }
+        }
+        let mut command_buffer = CommandBuffer::new();
+        command_buffer.begin_recording().unwrap();
+        command_buffer.generate_mipmaps(&texture).unwrap();
+        command_buffer.end_recording().unwrap();
+        device.get_queue().unwrap().submit(&[command_buffer]).unwrap();
+
+        // 2x2x4 -> 1x1x2: her dilim, önceki seviyenin iki diliminin ortalamasıdır.
+        let storage = texture.native().storage.borrow();
+        assert_eq!(storage.read_texel(1, 0, 0, 0, 0).unwrap()[0], 0.5);
+        assert_eq!(storage.read_texel(1, 1, 0, 0, 0).unwrap()[0], 2.5);
+    }
+
+    fn rgba8_array(width: u32, height: u32, layers: u32, mip_level_count: u32) -> TextureDescriptor {
+        TextureDescriptor {
+            format: TextureFormat::Rgba8Unorm,
+            dimension: TextureDimension::D2,
+            width,
+            height,
+            depth_or_array_layers: layers,
+            mip_level_count,
+            sample_count: 1,
+            usage: TextureUsage::Sampled,
+        }
+    }
+
+    #[test]
+    fn padded_rows_round_trip_and_keep_the_padding() {
+        let device = Device::new().unwrap();
+        let mut texture = device.create_texture(&rgba8_array(4, 4, 2, 1)).unwrap();
+        // 2x2 texel'lik bölge, 2. katmanda; satırlar 12 byte arayla, veri 4 byte ofsetle başlar.
+        let origin = Origin3d { x: 1, y: 2, z: 1 };
+        let extent = Extent3d { width: 2, height: 2, depth_or_array_layers: 1 };
+        let layout = TextureDataLayout { offset: 4, bytes_per_row: 12, rows_per_image: 2 };
+        let data: Vec<u8> = (0..28).collect();
+        texture.write_texture(0, origin, extent, &data, layout).unwrap();
+
+        let mut read = vec![0xAA; 28];
+        texture.read_texture(0, origin, extent, &mut read, layout).unwrap();
+        for (index, (&written, &read)) in data.iter().zip(&read).enumerate() {
+            let row_byte = index.checked_sub(4).map(|byte| byte % 12);
+            match row_byte {
+                Some(byte) if byte < 8 => assert_eq!(read, written, "byte {}", index),
+                _ => assert_eq!(read, 0xAA, "padding byte {}", index),
+            }
+        }
+
+        // Bölgenin dışı ve diğer katman yazılmamış kalır.
+        let storage = texture.native().storage.borrow();
+        assert_eq!(storage.read_texel(0, 1, 1, 2, 0).unwrap(), [4.0 / 255.0, 5.0 / 255.0, 6.0 / 255.0, 7.0 / 255.0]);
+        assert_eq!(storage.read_texel(0, 1, 0, 2, 0).unwrap(), [0.0; 4]);
+        assert_eq!(storage.read_texel(0, 0, 1, 2, 0).unwrap(), [0.0; 4]);
+    }
+
+    #[test]
+    fn layers_and_mip_levels_round_trip_through_rows_per_image() {
+        let device = Device::new().unwrap();
+        let mut texture = device.create_texture(&rgba8_array(4, 4, 2, 2)).unwrap();
+        // Mip 1 (2x2) iki katmanıyla; görüntüler arasında bir boş satır bulunur.
+        let extent = Extent3d { width: 2, height: 2, depth_or_array_layers: 2 };
+        let layout = TextureDataLayout { offset: 0, bytes_per_row: 8, rows_per_image: 3 };
+        let data: Vec<u8> = (0..40).map(|byte| byte * 3).collect();
+        texture.write_texture(1, Origin3d::default(), extent, &data, layout).unwrap();
+
+        let mut read = vec![0; 40];
+        texture.read_texture(1, Origin3d::default(), extent, &mut read, layout).unwrap();
+        for z in 0..2 {
+            for y in 0..2 {
+                let row = layout.row_offset(y, z);
+                assert_eq!(read[row..row + 8], data[row..row + 8]);
+            }
+        }
+        assert_eq!(read[16..24], [0; 8]);
+
+        // Sıkı paketlenmiş okuma aynı texel'leri verir.
+        let packed = TextureDataLayout::packed(TextureFormat::Rgba8Unorm, extent);
+        let mut tight = vec![0; 32];
+        texture.read_texture(1, Origin3d::default(), extent, &mut tight, packed).unwrap();
+        assert_eq!(tight[..16], data[..16]);
+        assert_eq!(tight[16..], data[24..40]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,790 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::gpu::error::GraphisError;
+use crate::gpu::shader::ShaderStage;
+use crate::gpu::spirv::reflect::{
+    BindingKind, EntryPoint, ExecutionModel, ImageDimension, InterfaceVariable, Interpolation, PushConstantBlock,
+    ResourceBinding, SpirvType, StorageClass, StructMember,
+};
+use crate::gpu::spirv::{built_in, decoration, execution_mode, op, MAGIC_NUMBER, MAX_VERSION};
+
+const HEADER_WORDS: usize = 5;
+
+fn invalid<T>(message: String) -> Result<T, GraphisError> {
+    Err(GraphisError::ValidationFailed(format!("invalid SPIR-V module: {}", message)))
+}
+
+// Tek bir SPIR-V komutu. `offset`, komutun modüldeki kelime (word) konumudur ve hata mesajlarında kullanılır.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Instruction {
+    pub opcode: u16,
+    pub operands: Vec<u32>,
+    pub offset: usize,
+}
+
+impl Instruction {
+    pub fn operand(&self, index: usize) -> Result<u32, GraphisError> {
+        match self.operands.get(index) {
+            Some(&word) => Ok(word),
+            None => {
+                invalid(format!("instruction with opcode {} at word {} has too few operands", self.opcode, self.offset))
+            }
+        }
+    }
+
+    // `index` kelimesinden başlayan, sıfırla sonlanan UTF-8 dizgesi ve dizgeden sonraki ilk işlenenin sırası.
+    pub fn string(&self, index: usize) -> Result<(String, usize), GraphisError> {
+        let mut bytes = Vec::new();
+        for (position, word) in self.operands.iter().enumerate().skip(index) {
+            for byte in word.to_le_bytes() {
+                if byte == 0 {
+                    return match String::from_utf8(bytes) {
+                        Ok(string) => Ok((string, position + 1)),
+                        Err(_) => invalid(format!("string at word {} is not valid UTF-8", self.offset)),
+                    };
+                }
+                bytes.push(byte);
+            }
+        }
+        invalid(format!("string at word {} is not null-terminated", self.offset))
+    }
+}
+
+// Bir dekorasyon ve değişmez (literal) işlenenleri.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Decoration {
+    pub kind: u32,
+    pub operands: Vec<u32>,
+}
+
+// Fonksiyonların dışında tanımlanmış bir değişken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GlobalVariable {
+    pub storage_class: StorageClass,
+    pub pointee: u32, // İşaret edilen tipin kimliği
+}
+
+// Ayrıştırılmış ve yansıtılmış bir SPIR-V modülü.
+#[derive(Debug, Clone)]
+pub struct SpirvModule {
+    version: (u8, u8),
+    generator: u32,
+    bound: u32,
+    entry_points: Vec<EntryPoint>,
+    bindings: Vec<ResourceBinding>,
+    push_constants: Vec<PushConstantBlock>,
+    pub(crate) instructions: Vec<Instruction>,
+    pub(crate) names: HashMap<u32, String>,
+    pub(crate) member_names: HashMap<(u32, u32), String>,
+    pub(crate) decorations: HashMap<u32, Vec<Decoration>>,
+    pub(crate) member_decorations: HashMap<(u32, u32), Vec<Decoration>>,
+    pub(crate) types: HashMap<u32, SpirvType>,
+    pub(crate) constants: HashMap<u32, Vec<u32>>, // OpConstant / OpSpecConstant değerlerinin kelimeleri
+    pub(crate) composites: HashMap<u32, Vec<u32>>, // OpConstantComposite bileşenlerinin kimlikleri
+    pub(crate) inner_types: HashMap<u32, u32>,    // İşaretçi tiplerinin işaret ettiği, dizi tiplerinin eleman tipi
+    pub(crate) variables: BTreeMap<u32, GlobalVariable>,
+    pub(crate) functions: HashSet<u32>,
+}
+
+impl SpirvModule {
+    // Bir SPIR-V ikilisini ayrıştırır. Modül her iki bayt sırasında da olabilir; sıra sihirli sayıdan anlaşılır.
+    pub fn parse(bytes: &[u8]) -> Result<Self, GraphisError> {
+        if !bytes.len().is_multiple_of(4) {
+            return invalid(format!("its size of {} bytes is not a multiple of the 4-byte word size", bytes.len()));
+        }
+        let words: Vec<u32> =
+            bytes.chunks_exact(4).map(|word| u32::from_le_bytes([word[0], word[1], word[2], word[3]])).collect();
+        Self::from_words(&words)
+    }
+
+    pub fn from_words(words: &[u32]) -> Result<Self, GraphisError> {
+        if words.len() < HEADER_WORDS {
+            return invalid(format!("it has {} word(s), fewer than the {}-word header", words.len(), HEADER_WORDS));
+        }
+        let words: Vec<u32> = match words[0] {
+            MAGIC_NUMBER => words.to_vec(),
+            magic if magic.swap_bytes() == MAGIC_NUMBER => words.iter().map(|word| word.swap_bytes()).collect(),
+            magic => return invalid(format!("bad magic number {:#010x}", magic)),
+        };
+        let version = ((words[1] >> 16) as u8, (words[1] >> 8) as u8);
+        if words[1] & 0xff00_00ff != 0 || version.0 != 1 || version > MAX_VERSION {
+            return invalid(format!(
+                "unsupported version word {:#010x} (supported: 1.0 to {}.{})",
+                words[1], MAX_VERSION.0, MAX_VERSION.1
+            ));
+        }
+        if words[3] == 0 {
+            return invalid("the id bound is 0".to_string());
+        }
+        if words[4] != 0 {
+            return invalid(format!("the reserved schema word is {}, not 0", words[4]));
+        }
+
+        let mut module = SpirvModule {
+            version,
+            generator: words[2],
+            bound: words[3],
+            entry_points: Vec::new(),
+            bindings: Vec::new(),
+            push_constants: Vec::new(),
+            instructions: split_instructions(&words)?,
+            names: HashMap::new(),
+            member_names: HashMap::new(),
+            decorations: HashMap::new(),
+            member_decorations: HashMap::new(),
+            types: HashMap::new(),
+            constants: HashMap::new(),
+            composites: HashMap::new(),
+            inner_types: HashMap::new(),
+            variables: BTreeMap::new(),
+            functions: HashSet::new(),
+        };
+        // Dekorasyonlar tiplerden önce okunur ki yapı üyeleri ve diziler yerleşim bilgileriyle birlikte kurulabilsin.
+        module.read_annotations()?;
+        module.read_definitions()?;
+        module.entry_points = module.reflect_entry_points()?;
+        module.reflect_resources()?;
+        Ok(module)
+    }
+
+    pub fn version(&self) -> (u8, u8) {
+        self.version
+    }
+
+    pub fn generator(&self) -> u32 {
+        self.generator
+    }
+
+    pub fn bound(&self) -> u32 {
+        self.bound
+    }
+
+    pub fn entry_points(&self) -> &[EntryPoint] {
+        &self.entry_points
+    }
+
+    // Verilen ada ve aşamaya sahip giriş noktası.
+    pub fn entry_point(&self, name: &str, stage: ShaderStage) -> Option<&EntryPoint> {
+        self.entry_points
+            .iter()
+            .find(|entry_point| entry_point.name == name && entry_point.execution_model.stage() == Some(stage))
+    }
+
+    // Modüldeki tüm tanımlayıcı kümesi bağlamaları, (küme, bağlama) sırasıyla.
+    pub fn bindings(&self) -> &[ResourceBinding] {
+        &self.bindings
+    }
+
+    pub fn push_constants(&self) -> &[PushConstantBlock] {
+        &self.push_constants
+    }
+
+    pub(crate) fn decoration(&self, id: u32, kind: u32) -> Option<&[u32]> {
+        find_decoration(self.decorations.get(&id), kind)
+    }
+
+    pub(crate) fn member_decoration(&self, id: u32, member: u32, kind: u32) -> Option<&[u32]> {
+        find_decoration(self.member_decorations.get(&(id, member)), kind)
+    }
+
+    fn check_id(&self, id: u32, instruction: &Instruction) -> Result<u32, GraphisError> {
+        if id == 0 || id >= self.bound {
+            return invalid(format!(
+                "instruction with opcode {} at word {} uses id %{} outside the bound {}",
+                instruction.opcode, instruction.offset, id, self.bound
+            ));
+        }
+        Ok(id)
+    }
+
+    // İsimler ve dekorasyonlar.
+    fn read_annotations(&mut self) -> Result<(), GraphisError> {
+        let mut memory_models = 0;
+        for instruction in &self.instructions {
+            match instruction.opcode {
+                op::NAME => {
+                    let target = self.check_id(instruction.operand(0)?, instruction)?;
+                    let (name, _) = instruction.string(1)?;
+                    self.names.insert(target, name);
+                }
+                op::MEMBER_NAME => {
+                    let target = self.check_id(instruction.operand(0)?, instruction)?;
+                    let (name, _) = instruction.string(2)?;
+                    self.member_names.insert((target, instruction.operand(1)?), name);
+                }
+                op::DECORATE => {
+                    let target = self.check_id(instruction.operand(0)?, instruction)?;
+                    let decoration =
+                        Decoration { kind: instruction.operand(1)?, operands: instruction.operands[2..].to_vec() };
+                    self.decorations.entry(target).or_default().push(decoration);
+                }
+                op::MEMBER_DECORATE => {
+                    let target = self.check_id(instruction.operand(0)?, instruction)?;
+                    let member = instruction.operand(1)?;
+                    let decoration =
+                        Decoration { kind: instruction.operand(2)?, operands: instruction.operands[3..].to_vec() };
+                    self.member_decorations.entry((target, member)).or_default().push(decoration);
+                }
+                op::MEMORY_MODEL => memory_models += 1,
+                _ => {}
+            }
+        }
+        if memory_models != 1 {
+            return invalid(format!("it must have exactly one OpMemoryModel, found {}", memory_models));
+        }
+        Ok(())
+    }
+
+    // Tipler, sabitler, global değişkenler ve fonksiyonlar. SPIR-V'de tipler kullanılmadan önce tanımlanır.
+    fn read_definitions(&mut self) -> Result<(), GraphisError> {
+        let instructions = std::mem::take(&mut self.instructions);
+        let mut defined = HashSet::new();
+        let mut in_function = false;
+        for instruction in &instructions {
+            let mut define = |module: &Self, index: usize| -> Result<u32, GraphisError> {
+                let id = module.check_id(instruction.operand(index)?, instruction)?;
+                if !defined.insert(id) {
+                    return invalid(format!("id %{} is defined again at word {}", id, instruction.offset));
+                }
+                Ok(id)
+            };
+            match instruction.opcode {
+                op::TYPE_VOID..=op::TYPE_FUNCTION => {
+                    let id = define(self, 0)?;
+                    let ty = self.read_type(instruction, id)?;
+                    match instruction.opcode {
+                        op::TYPE_POINTER => self.inner_types.insert(id, instruction.operand(2)?),
+                        op::TYPE_ARRAY | op::TYPE_RUNTIME_ARRAY => self.inner_types.insert(id, instruction.operand(1)?),
+                        _ => None,
+                    };
+                    self.types.insert(id, ty);
+                }
+                op::TYPE_FORWARD_POINTER => {
+                    return invalid(format!("OpTypeForwardPointer at word {} is not supported", instruction.offset));
+                }
+                op::CONSTANT | op::SPEC_CONSTANT => {
+                    self.type_at(instruction, 0)?;
+                    let id = define(self, 1)?;
+                    self.constants.insert(id, instruction.operands[2..].to_vec());
+                }
+                op::CONSTANT_COMPOSITE | op::SPEC_CONSTANT_COMPOSITE => {
+                    self.type_at(instruction, 0)?;
+                    let id = define(self, 1)?;
+                    self.composites.insert(id, instruction.operands[2..].to_vec());
+                }
+                op::CONSTANT_TRUE
+                | op::CONSTANT_FALSE
+                | op::CONSTANT_NULL
+                | op::SPEC_CONSTANT_TRUE
+                | op::SPEC_CONSTANT_FALSE => {
+                    self.type_at(instruction, 0)?;
+                    define(self, 1)?;
+                }
+                op::FUNCTION => {
+                    self.type_at(instruction, 0)?;
+                    let id = define(self, 1)?;
+                    self.functions.insert(id);
+                    in_function = true;
+                }
+                op::FUNCTION_END => in_function = false,
+                op::VARIABLE if !in_function => {
+                    let storage_class = match self.type_at(instruction, 0)? {
+                        SpirvType::Pointer { storage_class, .. } => storage_class,
+                        _ => {
+                            return invalid(format!(
+                                "OpVariable at word {} does not have a pointer type",
+                                instruction.offset
+                            ))
+                        }
+                    };
+                    let pointee = self.inner_types[&instruction.operand(0)?];
+                    let id = define(self, 1)?;
+                    if StorageClass::from_word(instruction.operand(2)?) != storage_class {
+                        return invalid(format!(
+                            "variable %{} has a storage class different from its pointer type",
+                            id
+                        ));
+                    }
+                    self.variables.insert(id, GlobalVariable { storage_class, pointee });
+                }
+                _ => {}
+            }
+        }
+        self.instructions = instructions;
+        if in_function {
+            return invalid("the last function is not terminated by OpFunctionEnd".to_string());
+        }
+        Ok(())
+    }
+
+    // `index` işleneninde başvurulan, daha önce tanımlanmış tip.
+    fn type_at(&self, instruction: &Instruction, index: usize) -> Result<SpirvType, GraphisError> {
+        let id = instruction.operand(index)?;
+        match self.types.get(&id) {
+            Some(ty) => Ok(ty.clone()),
+            None => invalid(format!(
+                "instruction with opcode {} at word {} refers to %{}, which is not a type defined before it",
+                instruction.opcode, instruction.offset, id
+            )),
+        }
+    }
+
+    fn read_type(&self, instruction: &Instruction, id: u32) -> Result<SpirvType, GraphisError> {
+        let literal = |index| instruction.operand(index);
+        let boxed = |index| self.type_at(instruction, index).map(Box::new);
+        let ty = match instruction.opcode {
+            op::TYPE_VOID => SpirvType::Void,
+            op::TYPE_BOOL => SpirvType::Bool,
+            op::TYPE_INT => SpirvType::Int { width: literal(1)?, signed: literal(2)? != 0 },
+            op::TYPE_FLOAT => SpirvType::Float { width: literal(1)? },
+            op::TYPE_VECTOR => SpirvType::Vector { component: boxed(1)?, count: literal(2)? },
+            op::TYPE_MATRIX => SpirvType::Matrix { column: boxed(1)?, count: literal(2)? },
+            op::TYPE_IMAGE => SpirvType::Image {
+                sampled_type: boxed(1)?,
+                dimension: ImageDimension::from_word(literal(2)?),
+                depth: literal(3)? == 1,
+                arrayed: literal(4)? != 0,
+                multisampled: literal(5)? != 0,
+                storage: literal(6)? == 2,
+            },
+            op::TYPE_SAMPLER => SpirvType::Sampler,
+            op::TYPE_SAMPLED_IMAGE => SpirvType::SampledImage { image: boxed(1)? },
+            op::TYPE_ARRAY => {
+                let length_id = literal(2)?;
+                let length = match self.constants.get(&length_id).and_then(|words| words.first()) {
+                    Some(&length) => length,
+                    None => {
+                        return invalid(format!(
+                            "array type %{} has a length %{} that is not a constant",
+                            id, length_id
+                        ))
+                    }
+                };
+                let stride =
+                    self.decoration(id, decoration::ARRAY_STRIDE).and_then(|operands| operands.first().copied());
+                SpirvType::Array { element: boxed(1)?, length: Some(length), stride }
+            }
+            op::TYPE_RUNTIME_ARRAY => {
+                let stride =
+                    self.decoration(id, decoration::ARRAY_STRIDE).and_then(|operands| operands.first().copied());
+                SpirvType::Array { element: boxed(1)?, length: None, stride }
+            }
+            op::TYPE_STRUCT => {
+                let mut members = Vec::new();
+                for member in 0..instruction.operands.len() as u32 - 1 {
+                    let value =
+                        |kind| self.member_decoration(id, member, kind).and_then(|operands| operands.first().copied());
+                    members.push(StructMember {
+                        name: self.member_names.get(&(id, member)).cloned(),
+                        ty: self.type_at(instruction, member as usize + 1)?,
+                        offset: value(decoration::OFFSET),
+                        matrix_stride: value(decoration::MATRIX_STRIDE),
+                        row_major: self.member_decoration(id, member, decoration::ROW_MAJOR).is_some(),
+                        built_in: value(decoration::BUILT_IN),
+                        location: value(decoration::LOCATION),
+                    });
+                }
+                SpirvType::Struct { members, buffer_block: self.decoration(id, decoration::BUFFER_BLOCK).is_some() }
+            }
+            op::TYPE_POINTER => {
+                SpirvType::Pointer { storage_class: StorageClass::from_word(literal(1)?), pointee: boxed(2)? }
+            }
+            op::TYPE_FUNCTION => SpirvType::Function {
+                result: boxed(1)?,
+                parameters: (2..instruction.operands.len())
+                    .map(|index| self.type_at(instruction, index))
+                    .collect::<Result<_, _>>()?,
+            },
+            // OpTypeOpaque, OpTypeEvent vb. çekirdek (kernel) tipleri
+            opcode => {
+                return invalid(format!(
+                    "type instruction with opcode {} at word {} is not supported",
+                    opcode, instruction.offset
+                ))
+            }
+        };
+        Ok(ty)
+    }
+
+    fn reflect_entry_points(&self) -> Result<Vec<EntryPoint>, GraphisError> {
+        let mut entry_points: Vec<EntryPoint> = Vec::new();
+        let mut workgroup_sizes = HashMap::new();
+        for instruction in &self.instructions {
+            match instruction.opcode {
+                op::ENTRY_POINT => {
+                    let execution_model = ExecutionModel::from_word(instruction.operand(0)?);
+                    let function = instruction.operand(1)?;
+                    let (name, interface_start) = instruction.string(2)?;
+                    if !self.functions.contains(&function) {
+                        return invalid(format!(
+                            "entry point {:?} refers to %{}, which is not a function",
+                            name, function
+                        ));
+                    }
+                    if entry_points.iter().any(|other| other.name == name && other.execution_model == execution_model) {
+                        return invalid(format!("entry point {:?} is declared twice for {:?}", name, execution_model));
+                    }
+                    let mut inputs = Vec::new();
+                    let mut outputs = Vec::new();
+                    for &id in &instruction.operands[interface_start..] {
+                        let variable = match self.variables.get(&id) {
+                            Some(variable) => variable,
+                            None => {
+                                return invalid(format!(
+                                    "entry point {:?} lists %{}, which is not a global variable",
+                                    name, id
+                                ))
+                            }
+                        };
+                        // SPIR-V 1.4'ten itibaren arayüz listesi giriş/çıkış dışındaki global değişkenleri de içerir.
+                        match variable.storage_class {
+                            StorageClass::Input => inputs.extend(self.interface_variables(id, variable)?),
+                            StorageClass::Output => outputs.extend(self.interface_variables(id, variable)?),
+                            _ => {}
+                        }
+                    }
+                    inputs.sort_by_key(|variable| (variable.location, variable.component));
+                    outputs.sort_by_key(|variable| (variable.location, variable.component));
+                    entry_points.push(EntryPoint {
+                        name,
+                        execution_model,
+                        function,
+                        inputs,
+                        outputs,
+                        workgroup_size: None,
+                    });
+                }
+                op::EXECUTION_MODE | op::EXECUTION_MODE_ID => {
+                    let function = instruction.operand(0)?;
+                    let size = match instruction.operand(1)? {
+                        execution_mode::LOCAL_SIZE => {
+                            [instruction.operand(2)?, instruction.operand(3)?, instruction.operand(4)?]
+                        }
+                        execution_mode::LOCAL_SIZE_ID => {
+                            let mut size = [0; 3];
+                            for (axis, value) in size.iter_mut().enumerate() {
+                                *value = self.constant_u32(instruction.operand(axis + 2)?, "LocalSizeId")?;
+                            }
+                            size
+                        }
+                        _ => continue,
+                    };
+                    workgroup_sizes.insert(function, size);
+                }
+                _ => {}
+            }
+        }
+        if entry_points.is_empty() {
+            return invalid("it declares no entry points".to_string());
+        }
+
+        // WorkgroupSize yerleşik sabiti, yürütme modlarında verilen boyutu tüm giriş noktaları için geçersiz kılar.
+        let mut workgroup_size_constant = None;
+        for (&id, constituents) in &self.composites {
+            if self.decoration(id, decoration::BUILT_IN) == Some(&[built_in::WORKGROUP_SIZE][..]) {
+                if constituents.len() != 3 {
+                    return invalid(format!("the WorkgroupSize constant %{} does not have 3 components", id));
+                }
+                let mut size = [0; 3];
+                for (axis, value) in size.iter_mut().enumerate() {
+                    *value = self.constant_u32(constituents[axis], "WorkgroupSize")?;
+                }
+                workgroup_size_constant = Some(size);
+            }
+        }
+        for entry_point in entry_points.iter_mut() {
+            if entry_point.execution_model != ExecutionModel::GLCompute {
+                continue;
+            }
+            let size = workgroup_size_constant.or_else(|| workgroup_sizes.get(&entry_point.function).copied());
+            match size {
+                Some(size) if size.iter().all(|&extent| extent > 0) => entry_point.workgroup_size = Some(size),
+                Some(size) => {
+                    return invalid(format!(
+                        "compute entry point {:?} has an empty workgroup size {:?}",
+                        entry_point.name, size
+                    ))
+                }
+                None => {
+                    return invalid(format!("compute entry point {:?} declares no workgroup size", entry_point.name))
+                }
+            }
+        }
+        Ok(entry_points)
+    }
+
+    fn constant_u32(&self, id: u32, usage: &str) -> Result<u32, GraphisError> {
+        match self.constants.get(&id).map(Vec::as_slice) {
+            Some([value]) => Ok(*value),
+            _ => invalid(format!("{} expects %{} to be a 32-bit integer constant", usage, id)),
+        }
+    }
+
+    // Bir giriş veya çıkış değişkeninin konumlu parçaları. Yerleşik değişkenler ve yalnızca yerleşik üyelerden oluşan
+    // bloklar (örneğin gl_PerVertex) atlanır; konumu olmayan diğer değişkenler geçersizdir.
+    fn interface_variables(&self, id: u32, variable: &GlobalVariable) -> Result<Vec<InterfaceVariable>, GraphisError> {
+        if self.decoration(id, decoration::BUILT_IN).is_some() {
+            return Ok(Vec::new());
+        }
+        let ty = self.types[&variable.pointee].clone();
+        let name = self.names.get(&id).cloned();
+        // Geometri/tessellation aşamalarındaki blok dizileri dizinin eleman tipine bakılarak değerlendirilir.
+        let mut block_type = &ty;
+        let mut block_id = variable.pointee;
+        while let SpirvType::Array { element, .. } = block_type {
+            block_type = element;
+            block_id = self.inner_types[&block_id];
+        }
+        // Blok üyelerinde üyenin kendi dekorasyonları, yoksa değişkeninkiler geçerlidir.
+        let interpolation = |member: Option<u32>| {
+            let has = |kind| {
+                member.is_some_and(|member| self.member_decoration(block_id, member, kind).is_some())
+                    || self.decoration(id, kind).is_some()
+            };
+            if has(decoration::FLAT) {
+                Interpolation::Flat
+            } else if has(decoration::NO_PERSPECTIVE) {
+                Interpolation::Linear
+            } else {
+                Interpolation::Perspective
+            }
+        };
+        if let Some(location) = self.decoration(id, decoration::LOCATION) {
+            return Ok(vec![InterfaceVariable {
+                id,
+                member: None,
+                name,
+                location: location.first().copied().unwrap_or(0),
+                component: self
+                    .decoration(id, decoration::COMPONENT)
+                    .and_then(|operands| operands.first().copied())
+                    .unwrap_or(0),
+                ty,
+                interpolation: interpolation(None),
+            }]);
+        }
+
+        let members = match block_type {
+            SpirvType::Struct { members, .. } => members,
+            _ => {
+                return invalid(format!(
+                    "interface variable %{} ({:?}) has no Location or BuiltIn decoration",
+                    id, name
+                ))
+            }
+        };
+        let mut variables = Vec::new();
+        for (index, member) in members.iter().enumerate() {
+            if member.built_in.is_some() {
+                continue;
+            }
+            let location = match member.location {
+                Some(location) => location,
+                None => {
+                    return invalid(format!(
+                        "member {} of interface block %{} ({:?}) has no Location or BuiltIn decoration",
+                        index, id, name
+                    ))
+                }
+            };
+            variables.push(InterfaceVariable {
+                id,
+                member: Some(index as u32),
+                name: member.name.clone(),
+                location,
+                component: self
+                    .member_decoration(block_id, index as u32, decoration::COMPONENT)
+                    .and_then(|operands| operands.first().copied())
+                    .unwrap_or(0),
+                ty: member.ty.clone(),
+                interpolation: interpolation(Some(index as u32)),
+            });
+        }
+        Ok(variables)
+    }
+
+    // Tanımlayıcı kümesi bağlamaları ve push constant blokları.
+    fn reflect_resources(&mut self) -> Result<(), GraphisError> {
+        let mut bindings = Vec::new();
+        let mut push_constants = Vec::new();
+        for (&id, variable) in &self.variables {
+            let ty = &self.types[&variable.pointee];
+            let name = self.names.get(&id).cloned();
+            if variable.storage_class == StorageClass::PushConstant {
+                let size = match ty.size() {
+                    Some(size) => size,
+                    None => return invalid(format!("push constant block %{} ({:?}) has no explicit layout", id, name)),
+                };
+                push_constants.push(PushConstantBlock { id, name, size, ty: ty.clone() });
+                continue;
+            }
+            let (element, count) = match ty {
+                SpirvType::Array { element, length, .. } => (element.as_ref(), length.unwrap_or(0)),
+                other => (other, 1),
+            };
+            let kind = match (variable.storage_class, element) {
+                (StorageClass::Uniform, SpirvType::Struct { buffer_block: true, .. }) => BindingKind::StorageBuffer,
+                (StorageClass::Uniform, _) => BindingKind::UniformBuffer,
+                (StorageClass::StorageBuffer, _) => BindingKind::StorageBuffer,
+                (StorageClass::UniformConstant, SpirvType::Sampler) => BindingKind::Sampler,
+                (StorageClass::UniformConstant, SpirvType::SampledImage { .. }) => BindingKind::CombinedTextureSampler,
+                (StorageClass::UniformConstant, SpirvType::Image { dimension, storage, .. }) => {
+                    match (dimension, storage) {
+                        (ImageDimension::Buffer, true) => BindingKind::StorageTexelBuffer,
+                        (ImageDimension::Buffer, false) => BindingKind::UniformTexelBuffer,
+                        (ImageDimension::SubpassData, _) => BindingKind::InputAttachment,
+                        (_, true) => BindingKind::StorageTexture,
+                        (_, false) => BindingKind::SampledTexture,
+                    }
+                }
+                _ => continue,
+            };
+            let set = self.decoration(id, decoration::DESCRIPTOR_SET).and_then(|operands| operands.first().copied());
+            let binding = self.decoration(id, decoration::BINDING).and_then(|operands| operands.first().copied());
+            let (set, binding) = match (set, binding) {
+                (Some(set), Some(binding)) => (set, binding),
+                _ => {
+                    return invalid(format!(
+                        "resource variable %{} ({:?}) needs both DescriptorSet and Binding decorations",
+                        id, name
+                    ))
+                }
+            };
+            if let Some(other) =
+                bindings.iter().find(|other: &&ResourceBinding| other.set == set && other.binding == binding)
+            {
+                // Aynı bağlamayı farklı giriş noktaları paylaşabilir, ancak tipleri uyuşmalıdır.
+                if other.kind != kind {
+                    return invalid(format!(
+                        "set {} binding {} is declared as both {:?} and {:?}",
+                        set, binding, other.kind, kind
+                    ));
+                }
+            }
+            bindings.push(ResourceBinding { id, name, set, binding, kind, count, ty: ty.clone() });
+        }
+        bindings.sort_by_key(|binding| (binding.set, binding.binding, binding.id));
+        self.bindings = bindings;
+        self.push_constants = push_constants;
+        Ok(())
+    }
+}
+
+fn find_decoration(decorations: Option<&Vec<Decoration>>, kind: u32) -> Option<&[u32]> {
+    decorations?.iter().find(|decoration| decoration.kind == kind).map(|decoration| decoration.operands.as_slice())
+}
+
+fn split_instructions(words: &[u32]) -> Result<Vec<Instruction>, GraphisError> {
+    let mut instructions = Vec::new();
+    let mut offset = HEADER_WORDS;
+    while offset < words.len() {
+        let word_count = (words[offset] >> 16) as usize;
+        let opcode = words[offset] as u16;
+        if word_count == 0 || offset + word_count > words.len() {
+            return invalid(format!(
+                "instruction with opcode {} at word {} has a word count of {}, which runs past the {}-word module",
+                opcode,
+                offset,
+                word_count,
+                words.len()
+            ));
+        }
+        instructions.push(Instruction { opcode, operands: words[offset + 1..offset + word_count].to_vec(), offset });
+        offset += word_count;
+    }
+    Ok(instructions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAIN: [u32; 2] = [u32::from_le_bytes(*b"main"), 0];
+
+    fn instruction(opcode: u16, operands: &[u32]) -> Vec<u32> {
+        let mut words = vec![((operands.len() as u32 + 1) << 16) | opcode as u32];
+        words.extend_from_slice(operands);
+        words
+    }
+
+    // Boş bir fragment giriş noktası: %1 main, %2 void, %3 fn() -> void, %4 etiket.
+    fn module(extra: &[Vec<u32>]) -> Vec<u32> {
+        let mut words = vec![MAGIC_NUMBER, 0x0001_0000, 0, 5, 0];
+        words.extend(instruction(op::CAPABILITY, &[1]));
+        words.extend(instruction(op::MEMORY_MODEL, &[0, 1]));
+        words.extend(instruction(op::ENTRY_POINT, &[4, 1, MAIN[0], MAIN[1]]));
+        words.extend(instruction(op::TYPE_VOID, &[2]));
+        words.extend(instruction(op::TYPE_FUNCTION, &[3, 2]));
+        words.extend(extra.concat());
+        words.extend(instruction(op::FUNCTION, &[2, 1, 0, 3]));
+        words.extend(instruction(op::LABEL, &[4]));
+        words.extend(instruction(op::RETURN, &[]));
+        words.extend(instruction(op::FUNCTION_END, &[]));
+        words
+    }
+
+    fn assert_invalid(words: &[u32], expected: &str) {
+        match SpirvModule::from_words(words) {
+            Err(GraphisError::ValidationFailed(message)) => {
+                assert!(message.contains(expected), "unexpected message: {}", message)
+            }
+            other => panic!("expected ValidationFailed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_a_minimal_module_in_either_byte_order() {
+        let words = module(&[]);
+        let module = SpirvModule::from_words(&words).unwrap();
+        assert_eq!(module.version(), (1, 0));
+        assert_eq!(module.bound(), 5);
+        assert!(module.entry_point("main", ShaderStage::Fragment).is_some());
+
+        let swapped: Vec<u8> = words.iter().flat_map(|word| word.to_be_bytes()).collect();
+        assert_eq!(SpirvModule::parse(&swapped).unwrap().entry_points(), module.entry_points());
+    }
+
+    #[test]
+    fn rejects_a_truncated_header() {
+        assert_invalid(&module(&[])[..3], "fewer than the 5-word header");
+        assert_invalid(&[], "fewer than the 5-word header");
+        match SpirvModule::parse(&[0x03, 0x02, 0x23, 0x07, 0x00, 0x00]) {
+            Err(GraphisError::ValidationFailed(message)) => assert!(message.contains("not a multiple"), "{}", message),
+            other => panic!("expected ValidationFailed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_a_bad_magic_number() {
+        let mut words = module(&[]);
+        words[0] = 0xdead_beef;
+        assert_invalid(&words, "bad magic number 0xdeadbeef");
+    }
+
+    #[test]
+    fn rejects_a_zero_word_count() {
+        let mut words = module(&[]);
+        words.insert(HEADER_WORDS, op::CAPABILITY as u32);
+        assert_invalid(&words, "word count of 0");
+    }
+
+    #[test]
+    fn rejects_a_word_count_that_overruns_the_module() {
+        let mut words = module(&[]);
+        words.push((4 << 16) | op::NAME as u32);
+        words.push(1);
+        assert_invalid(&words, "runs past the");
+    }
+
+    #[test]
+    fn rejects_ids_outside_the_bound() {
+        assert_invalid(&module(&[instruction(op::NAME, &[5, MAIN[0], MAIN[1]])]), "uses id %5 outside the bound 5");
+        assert_invalid(&module(&[instruction(op::DECORATE, &[0, decoration::FLAT])]), "uses id %0");
+        assert_invalid(&module(&[instruction(op::TYPE_BOOL, &[9])]), "uses id %9 outside the bound 5");
+    }
+
+    #[test]
+    fn rejects_a_duplicate_definition() {
+        assert_invalid(&module(&[instruction(op::TYPE_BOOL, &[2])]), "id %2 is defined again");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,300 @@
+use crate::gpu::shader::ShaderStage;
+
+// Bir SPIR-V tipinin yansıtılmış hali. Tipler kimliklerinden bağımsız olarak, değer olarak tutulur.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpirvType {
+    Void,
+    Bool,
+    Int { width: u32, signed: bool },
+    Float { width: u32 },
+    Vector { component: Box<SpirvType>, count: u32 },
+    Matrix { column: Box<SpirvType>, count: u32 },
+    // `length` çalışma zamanı boyutlu dizilerde (OpTypeRuntimeArray) None'dır.
+    Array { element: Box<SpirvType>, length: Option<u32>, stride: Option<u32> },
+    // `buffer_block`: SPIR-V 1.3 öncesinde depolama arabelleklerini işaretleyen BufferBlock dekorasyonu.
+    Struct { members: Vec<StructMember>, buffer_block: bool },
+    Image {
+        sampled_type: Box<SpirvType>,
+        dimension: ImageDimension,
+        depth: bool,
+        arrayed: bool,
+        multisampled: bool,
+        storage: bool, // Örneklenmeyen, doğrudan okunup yazılan (storage) doku
+    },
+    Sampler,
+    SampledImage { image: Box<SpirvType> },
+    Pointer { storage_class: StorageClass, pointee: Box<SpirvType> },
+    Function { result: Box<SpirvType>, parameters: Vec<SpirvType> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructMember {
+    pub name: Option<String>,
+    pub ty: SpirvType,
+    pub offset: Option<u32>,
+    pub matrix_stride: Option<u32>,
+    pub row_major: bool,
+    pub built_in: Option<u32>,
+    pub location: Option<u32>,
+}
+
+impl SpirvType {
+    // Açık yerleşimli (explicit layout) bellekteki boyut, byte cinsinden.
+    // Bool, opak tipler ve çalışma zamanı boyutlu diziler için None döner.
+    pub fn size(&self) -> Option<u32> {
+        match self {
+            SpirvType::Int { width, .. } | SpirvType::Float { width } => Some(width / 8),
+            SpirvType::Vector { component, count } => Some(component.size()? * count),
+            SpirvType::Matrix { column, count } => Some(column.size()? * count),
+            SpirvType::Array { element, length: Some(length), stride } => {
+                Some(stride.or_else(|| element.size())? * length)
+            }
+            SpirvType::Struct { members, .. } => {
+                members.iter().try_fold(0, |size, member| Some(size.max(member.offset? + member.size()?)))
+            }
+            _ => None,
+        }
+    }
+
+    // Skaler, vektör, matris ve dizilerin bileşenlerinin skaler tipi.
+    pub fn scalar_kind(&self) -> Option<ScalarKind> {
+        match self {
+            SpirvType::Bool => Some(ScalarKind::Bool),
+            SpirvType::Int { signed: true, .. } => Some(ScalarKind::Sint),
+            SpirvType::Int { signed: false, .. } => Some(ScalarKind::Uint),
+            SpirvType::Float { .. } => Some(ScalarKind::Float),
+            SpirvType::Vector { component, .. }
+            | SpirvType::Matrix { column: component, .. }
+            | SpirvType::Array { element: component, .. } => component.scalar_kind(),
+            _ => None,
+        }
+    }
+
+    // Tek bir konumdaki (location) bileşen sayısı: vektörlerde eleman sayısı, matris ve dizilerde bir sütunun veya
+    // elemanın bileşen sayısı.
+    pub fn component_count(&self) -> u32 {
+        match self {
+            SpirvType::Vector { count, .. } => *count,
+            SpirvType::Matrix { column: inner, .. } | SpirvType::Array { element: inner, .. } => inner.component_count(),
+            _ => 1,
+        }
+    }
+
+    // Bir arayüz değişkeni olarak kapladığı konum sayısı. 64 bitlik 3 ve 4 bileşenli vektörler iki konum kaplar.
+    pub fn location_count(&self) -> u32 {
+        match self {
+            SpirvType::Vector { component, count } if *count > 2 && component.size() == Some(8) => 2,
+            SpirvType::Matrix { column, count } => count * column.location_count(),
+            SpirvType::Array { element, length, .. } => length.unwrap_or(1) * element.location_count(),
+            SpirvType::Struct { members, .. } => members.iter().map(|member| member.ty.location_count()).sum(),
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarKind {
+    Bool,
+    Float,
+    Sint,
+    Uint,
+}
+
+impl StructMember {
+    // Matris üyelerinde MatrixStride, sütun (veya RowMajor ise satır) başına düşen byte sayısıdır.
+    pub fn size(&self) -> Option<u32> {
+        match (&self.ty, self.matrix_stride) {
+            (SpirvType::Matrix { column, count }, Some(stride)) => match (self.row_major, column.as_ref()) {
+                (true, SpirvType::Vector { count: rows, .. }) => Some(rows * stride),
+                _ => Some(count * stride),
+            },
+            _ => self.ty.size(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionModel {
+    Vertex,
+    TessellationControl,
+    TessellationEvaluation,
+    Geometry,
+    Fragment,
+    GLCompute,
+    Kernel,
+    Other(u32), // Ray tracing, mesh vb. modeller
+}
+
+impl ExecutionModel {
+    pub fn from_word(word: u32) -> Self {
+        match word {
+            0 => ExecutionModel::Vertex,
+            1 => ExecutionModel::TessellationControl,
+            2 => ExecutionModel::TessellationEvaluation,
+            3 => ExecutionModel::Geometry,
+            4 => ExecutionModel::Fragment,
+            5 => ExecutionModel::GLCompute,
+            6 => ExecutionModel::Kernel,
+            other => ExecutionModel::Other(other),
+        }
+    }
+
+    // Modelin karşılık geldiği shader aşaması; bu crate'in desteklemediği modeller için None.
+    pub fn stage(&self) -> Option<ShaderStage> {
+        match self {
+            ExecutionModel::Vertex => Some(ShaderStage::Vertex),
+            ExecutionModel::Fragment => Some(ShaderStage::Fragment),
+            ExecutionModel::GLCompute => Some(ShaderStage::Compute),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageClass {
+    UniformConstant,
+    Input,
+    Uniform,
+    Output,
+    Workgroup,
+    CrossWorkgroup,
+    Private,
+    Function,
+    Generic,
+    PushConstant,
+    AtomicCounter,
+    Image,
+    StorageBuffer,
+    Other(u32),
+}
+
+impl StorageClass {
+    pub fn from_word(word: u32) -> Self {
+        match word {
+            0 => StorageClass::UniformConstant,
+            1 => StorageClass::Input,
+            2 => StorageClass::Uniform,
+            3 => StorageClass::Output,
+            4 => StorageClass::Workgroup,
+            5 => StorageClass::CrossWorkgroup,
+            6 => StorageClass::Private,
+            7 => StorageClass::Function,
+            8 => StorageClass::Generic,
+            9 => StorageClass::PushConstant,
+            10 => StorageClass::AtomicCounter,
+            11 => StorageClass::Image,
+            12 => StorageClass::StorageBuffer,
+            other => StorageClass::Other(other),
+        }
+    }
+
+    pub fn to_word(self) -> u32 {
+        match self {
+            StorageClass::UniformConstant => 0,
+            StorageClass::Input => 1,
+            StorageClass::Uniform => 2,
+            StorageClass::Output => 3,
+            StorageClass::Workgroup => 4,
+            StorageClass::CrossWorkgroup => 5,
+            StorageClass::Private => 6,
+            StorageClass::Function => 7,
+            StorageClass::Generic => 8,
+            StorageClass::PushConstant => 9,
+            StorageClass::AtomicCounter => 10,
+            StorageClass::Image => 11,
+            StorageClass::StorageBuffer => 12,
+            StorageClass::Other(word) => word,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageDimension {
+    D1,
+    D2,
+    D3,
+    Cube,
+    Rect,
+    Buffer,
+    SubpassData,
+    Other(u32),
+}
+
+impl ImageDimension {
+    pub fn from_word(word: u32) -> Self {
+        match word {
+            0 => ImageDimension::D1,
+            1 => ImageDimension::D2,
+            2 => ImageDimension::D3,
+            3 => ImageDimension::Cube,
+            4 => ImageDimension::Rect,
+            5 => ImageDimension::Buffer,
+            6 => ImageDimension::SubpassData,
+            other => ImageDimension::Other(other),
+        }
+    }
+}
+
+// Aşamalar arası değişkenlerin ara değerlendirme biçimi.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    #[default]
+    Perspective,
+    Linear, // NoPerspective
+    Flat,
+}
+
+// Bir giriş noktasının konum (Location) ile tanımlanan girişi veya çıkışı. Yerleşik değişkenler listelenmez.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterfaceVariable {
+    pub id: u32,
+    pub member: Option<u32>, // Bir arayüz bloğunun üyesiyse üyenin sırası
+    pub name: Option<String>,
+    pub location: u32,
+    pub component: u32,
+    pub ty: SpirvType,
+    pub interpolation: Interpolation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryPoint {
+    pub name: String,
+    pub execution_model: ExecutionModel,
+    pub function: u32,
+    pub inputs: Vec<InterfaceVariable>,
+    pub outputs: Vec<InterfaceVariable>,
+    pub workgroup_size: Option<[u32; 3]>, // Yalnızca hesaplama (GLCompute) giriş noktalarında
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingKind {
+    UniformBuffer,
+    StorageBuffer,
+    Sampler,
+    SampledTexture,
+    StorageTexture,
+    CombinedTextureSampler,
+    UniformTexelBuffer,
+    StorageTexelBuffer,
+    InputAttachment,
+}
+
+// Bir tanımlayıcı kümesi (descriptor set) bağlaması.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceBinding {
+    pub id: u32,
+    pub name: Option<String>,
+    pub set: u32,
+    pub binding: u32,
+    pub kind: BindingKind,
+    pub count: u32, // Dizi değilse 1, çalışma zamanı boyutlu dizilerde 0
+    pub ty: SpirvType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushConstantBlock {
+    pub id: u32,
+    pub name: Option<String>,
+    pub size: u32,
+    pub ty: SpirvType,
+}
\ No newline at end of file
@@ -1,51 +1,630 @@
-use crate::rendering::pipeline::TextureFormat; // Assuming TextureFormat is defined here
-
-#[derive(Debug, Clone)]
-pub struct TextureDescriptor {
-    pub format: TextureFormat,
-    pub width: u32,
-    pub height: u32,
-    pub depth_or_array_layers: u32,
-    pub mip_level_count: u32,
-    pub sample_count: u32,
-    pub usage: TextureUsage,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum TextureUsage {
-    Sampled,
-    StorageBinding,
-    RenderAttachment,
-    TransferSrc,
-    TransferDst,
-    // Add other usage flags as needed
-}
-
-#[derive(Debug)]
-pub struct Texture {
-    pub(crate) native_texture: (), // Placeholder for now
-    pub descriptor: TextureDescriptor,
-}
-
-impl Texture {
-    pub fn new(descriptor: &TextureDescriptor) -> Self {
-        println!("Creating a texture with descriptor: {:?}", descriptor);
-        Texture {
-            native_texture: (),
-            descriptor: descriptor.clone(),
-        }
-    }
-
-    // Method for updating texture data (platform-specific implementation needed)
-    pub fn upload_data(&mut self, data: &[u8], offset: u64, size: u64) -> Result<(), &'static str> {
-        println!(
-            "Uploading {} bytes of data to texture at offset {} (platform-specific implementation needed).",
-            size, offset
-        );
-        Ok(())
-    }
-
-    // Method to create a texture view (e.g., for using a specific part of a texture)
-    // This might be needed later
-    // pub fn create_view(&self) -> TextureView { ... }
+use crate::gpu::backend::{Backend, DefaultBackend};
+use crate::gpu::error::GraphisError;
+use crate::gpu::resource::TextureHandle;
+use crate::rendering::pipeline::TextureFormat; // Assuming TextureFormat is defined here
+
+// How `depth_or_array_layers` is interpreted: array layers of a 2D texture, or depth slices of a 3D texture
+// (halved at each mip level).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TextureDimension {
+    #[default]
+    D2,
+    D3,
+}
+
+#[derive(Debug, Clone)]
+pub struct TextureDescriptor {
+    pub format: TextureFormat,
+    pub dimension: TextureDimension,
+    pub width: u32,
+    pub height: u32,
+    pub depth_or_array_layers: u32,
+    pub mip_level_count: u32,
+    pub sample_count: u32,
+    pub usage: TextureUsage,
+}
+
+impl TextureDescriptor {
+    // Size in texels of a mip level; each level halves the previous one, down to 1.
+    pub fn mip_extent(&self, mip_level: u32) -> (u32, u32) {
+        ((self.width >> mip_level).max(1), (self.height >> mip_level).max(1))
+    }
+
+    // Depth in slices of a mip level of a 3D texture. Level `m` keeps its slices in the first
+    // `mip_depth(m)` layers of that level.
+    pub fn mip_depth(&self, mip_level: u32) -> u32 {
+        (self.depth_or_array_layers >> mip_level).max(1)
+    }
+
+    // Checks that `region` lies within the texture and that `len` bytes laid out as `layout` cover it.
+    pub fn validate_copy(
+        &self,
+        region: &TextureRegion,
+        layout: &TextureDataLayout,
+        len: usize,
+    ) -> Result<(), GraphisError> {
+        if self.sample_count != 1 {
+            return Err(GraphisError::ValidationFailed(
+                "multisampled textures cannot be written or read directly".to_string(),
+            ));
+        }
+        if region.mip_level >= self.mip_level_count {
+            return Err(GraphisError::ValidationFailed(format!(
+                "mip level {} is out of range for a texture with {} level(s)",
+                region.mip_level, self.mip_level_count
+            )));
+        }
+        let (width, height) = self.mip_extent(region.mip_level);
+        let layers = match self.dimension {
+            TextureDimension::D2 => self.depth_or_array_layers,
+            TextureDimension::D3 => self.mip_depth(region.mip_level),
+        };
+        let (origin, extent) = (region.origin, region.extent);
+        let fits = |start: u32, size: u32, limit: u32| start.checked_add(size).is_some_and(|end| end <= limit);
+        if !fits(origin.x, extent.width, width)
+            || !fits(origin.y, extent.height, height)
+            || !fits(origin.z, extent.depth_or_array_layers, layers)
+        {
+            return Err(GraphisError::ValidationFailed(format!(
+                "region {:?} + {:?} exceeds mip level {} ({}x{}x{})",
+                origin, extent, region.mip_level, width, height, layers
+            )));
+        }
+        let row_size = extent.width as usize * self.format.block_size();
+        if (layout.bytes_per_row as usize) < row_size {
+            return Err(GraphisError::ValidationFailed(format!(
+                "bytes_per_row {} is smaller than a row of {} byte(s)",
+                layout.bytes_per_row, row_size
+            )));
+        }
+        if layout.rows_per_image < extent.height {
+            return Err(GraphisError::ValidationFailed(format!(
+                "rows_per_image {} is smaller than the extent height {}",
+                layout.rows_per_image, extent.height
+            )));
+        }
+        if let Some(required) = layout.required_len(extent, row_size) {
+            GraphisError::check_range(layout.offset, required, len)?;
+        }
+        Ok(())
+    }
+}
+
+// A texel coordinate within a mip level. `z` is the array layer, or the depth slice of a 3D texture.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Origin3d {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Extent3d {
+    pub width: u32,
+    pub height: u32,
+    pub depth_or_array_layers: u32,
+}
+
+// A box of texels within one mip level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureRegion {
+    pub mip_level: u32,
+    pub origin: Origin3d,
+    pub extent: Extent3d,
+}
+
+// How texel data is laid out in a linear byte slice: rows start `bytes_per_row` bytes apart and
+// images (array layers) start `rows_per_image` rows apart, beginning at `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureDataLayout {
+    pub offset: usize,
+    pub bytes_per_row: u32,
+    pub rows_per_image: u32,
+}
+
+impl TextureDataLayout {
+    // A tightly packed layout for `extent` in the given format.
+    pub fn packed(format: TextureFormat, extent: Extent3d) -> Self {
+        TextureDataLayout {
+            offset: 0,
+            bytes_per_row: extent.width * format.block_size() as u32,
+            rows_per_image: extent.height,
+        }
+    }
+
+    // Byte offset of row `y` of image `z`, relative to the start of the data.
+    pub fn row_offset(&self, y: u32, z: u32) -> usize {
+        self.offset + (z as usize * self.rows_per_image as usize + y as usize) * self.bytes_per_row as usize
+    }
+
+    // Bytes needed past `offset` to hold `extent`; None for an empty extent.
+    fn required_len(&self, extent: Extent3d, row_size: usize) -> Option<usize> {
+        if extent.width == 0 || extent.height == 0 || extent.depth_or_array_layers == 0 {
+            return None;
+        }
+        Some(self.row_offset(extent.height - 1, extent.depth_or_array_layers - 1) - self.offset + row_size)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureViewDimension {
+    D2,
+    D2Array,
+    Cube, // Six layers in +X, -X, +Y, -Y, +Z, -Z order
+    D3,   // Every depth slice of a 3D texture (halved at each mip level)
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TextureAspect {
+    #[default]
+    All,
+    DepthOnly,
+    StencilOnly,
+}
+
+// Fields left as None are derived from the texture: its format, every mip level and layer
+// from the base onwards, and a 3D dimension for 3D textures or a 2D or 2D array dimension
+// depending on the layer count.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TextureViewDescriptor {
+    pub format: Option<TextureFormat>,
+    pub dimension: Option<TextureViewDimension>,
+    pub aspect: TextureAspect,
+    pub base_mip_level: u32,
+    pub mip_level_count: Option<u32>,
+    pub base_array_layer: u32,
+    pub array_layer_count: Option<u32>,
+}
+
+// A validated subrange of a texture. Views are plain values that refer to the texture by handle,
+// so commands can carry them; a view of a destroyed texture fails when a command using it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureView {
+    texture: TextureHandle,
+    format: TextureFormat,
+    dimension: TextureViewDimension,
+    aspect: TextureAspect,
+    base_mip_level: u32,
+    mip_level_count: u32,
+    base_array_layer: u32,
+    array_layer_count: u32,
+    size: (u32, u32), // Size of the base mip level
+    sample_count: u32,
+}
+
+impl TextureView {
+    fn new(
+        texture: TextureHandle,
+        texture_descriptor: &TextureDescriptor,
+        descriptor: &TextureViewDescriptor,
+    ) -> Result<Self, GraphisError> {
+        let invalid =
+            |message: String| Err(GraphisError::ValidationFailed(format!("invalid texture view: {}", message)));
+        let texture_format = texture_descriptor.format;
+        let format = descriptor.format.unwrap_or(texture_format);
+        if !format.is_view_compatible(texture_format) {
+            return invalid(format!("{:?} cannot be viewed as {:?}", texture_format, format));
+        }
+        let mip_level_count = descriptor
+            .mip_level_count
+            .unwrap_or(texture_descriptor.mip_level_count.saturating_sub(descriptor.base_mip_level));
+        let array_layer_count = descriptor
+            .array_layer_count
+            .unwrap_or(texture_descriptor.depth_or_array_layers.saturating_sub(descriptor.base_array_layer));
+        let within =
+            |base: u32, count: u32, limit: u32| count > 0 && base.checked_add(count).is_some_and(|end| end <= limit);
+        if !within(descriptor.base_mip_level, mip_level_count, texture_descriptor.mip_level_count) {
+            return invalid(format!(
+                "mip levels {}..+{} exceed the texture's {} level(s)",
+                descriptor.base_mip_level, mip_level_count, texture_descriptor.mip_level_count
+            ));
+        }
+        if !within(descriptor.base_array_layer, array_layer_count, texture_descriptor.depth_or_array_layers) {
+            return invalid(format!(
+                "array layers {}..+{} exceed the texture's {} layer(s)",
+                descriptor.base_array_layer, array_layer_count, texture_descriptor.depth_or_array_layers
+            ));
+        }
+        let dimension = descriptor.dimension.unwrap_or(if texture_descriptor.dimension == TextureDimension::D3 {
+            TextureViewDimension::D3
+        } else if array_layer_count == 1 {
+            TextureViewDimension::D2
+        } else {
+            TextureViewDimension::D2Array
+        });
+        if (dimension == TextureViewDimension::D3) != (texture_descriptor.dimension == TextureDimension::D3) {
+            return invalid(format!(
+                "a {:?} texture cannot be viewed as {:?}",
+                texture_descriptor.dimension, dimension
+            ));
+        }
+        let size = texture_descriptor.mip_extent(descriptor.base_mip_level);
+        match dimension {
+            TextureViewDimension::D2 if array_layer_count != 1 => {
+                return invalid(format!("a 2D view has a single layer, got {}", array_layer_count));
+            }
+            TextureViewDimension::Cube if array_layer_count != 6 || size.0 != size.1 => {
+                return invalid("a cube view needs six square layers".to_string());
+            }
+            TextureViewDimension::D3
+                if descriptor.base_array_layer != 0 || array_layer_count != texture_descriptor.depth_or_array_layers =>
+            {
+                return invalid("a 3D view covers every depth slice of the texture".to_string());
+            }
+            _ => {}
+        }
+        if texture_descriptor.sample_count > 1 && dimension != TextureViewDimension::D2 {
+            return invalid(format!("multisampled textures only support 2D views, got {:?}", dimension));
+        }
+        let aspect_supported = match descriptor.aspect {
+            TextureAspect::All => true,
+            TextureAspect::DepthOnly => format.is_depth(),
+            TextureAspect::StencilOnly => format.has_stencil(),
+        };
+        if !aspect_supported {
+            return invalid(format!("{:?} has no {:?} aspect", format, descriptor.aspect));
+        }
+        Ok(TextureView {
+            texture,
+            format,
+            dimension,
+            aspect: descriptor.aspect,
+            base_mip_level: descriptor.base_mip_level,
+            mip_level_count,
+            base_array_layer: descriptor.base_array_layer,
+            array_layer_count,
+            size,
+            sample_count: texture_descriptor.sample_count,
+        })
+    }
+
+    fn whole(texture: TextureHandle, texture_descriptor: &TextureDescriptor) -> Self {
+        TextureView {
+            texture,
+            format: texture_descriptor.format,
+            dimension: match texture_descriptor.dimension {
+                TextureDimension::D3 => TextureViewDimension::D3,
+                TextureDimension::D2 if texture_descriptor.depth_or_array_layers == 1 => TextureViewDimension::D2,
+                TextureDimension::D2 => TextureViewDimension::D2Array,
+            },
+            aspect: TextureAspect::All,
+            base_mip_level: 0,
+            mip_level_count: texture_descriptor.mip_level_count,
+            base_array_layer: 0,
+            array_layer_count: texture_descriptor.depth_or_array_layers,
+            size: (texture_descriptor.width, texture_descriptor.height),
+            sample_count: texture_descriptor.sample_count,
+        }
+    }
+
+    // Handle of the viewed texture.
+    pub fn texture(&self) -> TextureHandle {
+        self.texture
+    }
+
+    pub fn format(&self) -> TextureFormat {
+        self.format
+    }
+
+    pub fn dimension(&self) -> TextureViewDimension {
+        self.dimension
+    }
+
+    pub fn aspect(&self) -> TextureAspect {
+        self.aspect
+    }
+
+    pub fn base_mip_level(&self) -> u32 {
+        self.base_mip_level
+    }
+
+    pub fn mip_level_count(&self) -> u32 {
+        self.mip_level_count
+    }
+
+    pub fn base_array_layer(&self) -> u32 {
+        self.base_array_layer
+    }
+
+    pub fn array_layer_count(&self) -> u32 {
+        self.array_layer_count
+    }
+
+    // Width and height of the view's base mip level.
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    // Attachments render into exactly one mip level of one layer, through every aspect of the format.
+    pub fn validate_attachment(&self) -> Result<(), GraphisError> {
+        let covers_format = match self.aspect {
+            TextureAspect::All => true,
+            TextureAspect::DepthOnly => !self.format.has_stencil(),
+            TextureAspect::StencilOnly => !self.format.is_depth(),
+        };
+        if self.mip_level_count != 1
+            || self.array_layer_count != 1
+            || self.dimension == TextureViewDimension::Cube
+            || self.dimension == TextureViewDimension::D3
+            || !covers_format
+        {
+            return Err(GraphisError::ValidationFailed(format!(
+                "an attachment view must select a single 2D subresource with all aspects: {:?}",
+                self
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureUsage {
+    Sampled,
+    StorageBinding,
+    RenderAttachment,
+    TransferSrc,
+    TransferDst,
+    // Add other usage flags as needed
+}
+
+#[derive(Debug)]
+pub struct Texture<B: Backend = DefaultBackend> {
+    handle: TextureHandle, // Handle used to refer to the texture in commands
+    pub(crate) native_texture: B::Texture, // Backend-specific texture object
+    pub descriptor: TextureDescriptor,
+}
+
+impl<B: Backend> Texture<B> {
+    // Textures are created through Device::create_texture, which supplies the native object.
+    pub(crate) fn new(handle: TextureHandle, native_texture: B::Texture, descriptor: &TextureDescriptor) -> Self {
+        Texture {
+            handle,
+            native_texture,
+            descriptor: descriptor.clone(),
+        }
+    }
+
+    // Handle for BindTexture and SetRenderTarget commands.
+    pub fn handle(&self) -> TextureHandle {
+        self.handle
+    }
+
+    // Access to the backend-specific texture object (e.g. the software texture storage).
+    pub fn native(&self) -> &B::Texture {
+        &self.native_texture
+    }
+
+    // Copies `data`, laid out as described by `layout`, into a box of texels of one mip level.
+    // For array textures `origin.z` and `extent.depth_or_array_layers` select the layers.
+    pub fn write_texture(
+        &mut self,
+        mip_level: u32,
+        origin: Origin3d,
+        extent: Extent3d,
+        data: &[u8],
+        layout: TextureDataLayout,
+    ) -> Result<(), GraphisError> {
+        let region = TextureRegion { mip_level, origin, extent };
+        self.descriptor.validate_copy(&region, &layout, data.len())?;
+        log::trace!(mip_level, origin:?, extent:?, len = data.len(); "Writing texture data");
+        B::write_texture(&self.native_texture, &region, data, &layout)
+    }
+
+    // Reads a box of texels of one mip level back into `data`, laid out as described by `layout`.
+    // Bytes between rows and images (padding) are left untouched.
+    pub fn read_texture(
+        &self,
+        mip_level: u32,
+        origin: Origin3d,
+        extent: Extent3d,
+        data: &mut [u8],
+        layout: TextureDataLayout,
+    ) -> Result<(), GraphisError> {
+        let region = TextureRegion { mip_level, origin, extent };
+        self.descriptor.validate_copy(&region, &layout, data.len())?;
+        log::trace!(mip_level, origin:?, extent:?, len = data.len(); "Reading texture data");
+        B::read_texture(&self.native_texture, &region, data, &layout)
+    }
+
+    // Creates a view of a subrange of the texture, optionally reinterpreting its format.
+    pub fn create_view(&self, descriptor: &TextureViewDescriptor) -> Result<TextureView, GraphisError> {
+        TextureView::new(self.handle, &self.descriptor, descriptor)
+    }
+
+    // The view of the whole texture with its own format.
+    pub fn view(&self) -> TextureView {
+        TextureView::whole(self.handle, &self.descriptor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gpu::device::Device;
+    use crate::gpu::resource::RawHandle;
+
+    // 4x4, four layers or slices and three mip levels.
+    fn descriptor(dimension: TextureDimension) -> TextureDescriptor {
+        TextureDescriptor {
+            format: TextureFormat::R32Float,
+            dimension,
+            width: 4,
+            height: 4,
+            depth_or_array_layers: 4,
+            mip_level_count: 3,
+            sample_count: 1,
+            usage: TextureUsage::Sampled,
+        }
+    }
+
+    #[test]
+    fn copies_into_3d_textures_are_bounded_by_the_mip_depth() {
+        let layout = TextureDataLayout { offset: 0, bytes_per_row: 4, rows_per_image: 1 };
+        let copy = |dimension, mip_level, z| {
+            let origin = Origin3d { x: 0, y: 0, z };
+            let extent = Extent3d { width: 1, height: 1, depth_or_array_layers: 1 };
+            descriptor(dimension).validate_copy(&TextureRegion { mip_level, origin, extent }, &layout, 4)
+        };
+        // Mip 1 of the volume has two slices and mip 2 one; array textures keep every layer at every level.
+        assert!(copy(TextureDimension::D3, 1, 1).is_ok());
+        assert!(copy(TextureDimension::D3, 1, 2).is_err());
+        assert!(copy(TextureDimension::D3, 2, 1).is_err());
+        assert!(copy(TextureDimension::D2, 2, 3).is_ok());
+    }
+
+    #[test]
+    fn view_dimension_follows_the_texture_dimension() {
+        let device = Device::new().unwrap();
+        let view = |dimension| TextureViewDescriptor { dimension: Some(dimension), ..Default::default() };
+        let volume = device.create_texture(&descriptor(TextureDimension::D3)).unwrap();
+        assert_eq!(volume.view().dimension(), TextureViewDimension::D3);
+        assert!(volume.create_view(&view(TextureViewDimension::D2Array)).is_err());
+        let array = device.create_texture(&descriptor(TextureDimension::D2)).unwrap();
+        assert_eq!(array.view().dimension(), TextureViewDimension::D2Array);
+        assert!(array.create_view(&view(TextureViewDimension::D3)).is_err());
+
+        let mut multisampled = descriptor(TextureDimension::D3);
+        multisampled.mip_level_count = 1;
+        multisampled.sample_count = 4;
+        assert!(device.create_texture(&multisampled).is_err());
+    }
+
+    #[test]
+    fn copies_reject_bad_layouts_and_regions() {
+        let texture = descriptor(TextureDimension::D2);
+        let region = |mip_level, x, width| TextureRegion {
+            mip_level,
+            origin: Origin3d { x, y: 0, z: 0 },
+            extent: Extent3d { width, height: 2, depth_or_array_layers: 2 },
+        };
+        // Two rows of two texels in each of two layers: 4 + 8 + 8 = 20 bytes with a 4 byte offset.
+        let layout = TextureDataLayout { offset: 4, bytes_per_row: 8, rows_per_image: 2 };
+        assert!(texture.validate_copy(&region(0, 2, 2), &layout, 36).is_ok());
+        assert!(matches!(
+            texture.validate_copy(&region(0, 2, 2), &layout, 35),
+            Err(GraphisError::OutOfBounds { offset: 4, len: 32, size: 35 })
+        ));
+        assert!(texture.validate_copy(&region(0, 3, 2), &layout, 36).is_err());
+        assert!(texture.validate_copy(&region(1, 0, 2), &layout, 36).is_ok());
+        assert!(texture.validate_copy(&region(1, 1, 2), &layout, 36).is_err());
+        assert!(texture.validate_copy(&region(3, 0, 1), &layout, 36).is_err());
+
+        let narrow_rows = TextureDataLayout { bytes_per_row: 7, ..layout };
+        assert!(texture.validate_copy(&region(0, 0, 2), &narrow_rows, 64).is_err());
+        let short_images = TextureDataLayout { rows_per_image: 1, ..layout };
+        assert!(texture.validate_copy(&region(0, 0, 2), &short_images, 64).is_err());
+
+        let multisampled =
+            TextureDescriptor { mip_level_count: 1, sample_count: 4, ..descriptor(TextureDimension::D2) };
+        assert!(multisampled.validate_copy(&region(0, 0, 2), &layout, 36).is_err());
+    }
+
+    #[test]
+    fn writes_validate_the_data_length() {
+        let device = Device::new().unwrap();
+        let mut texture = device.create_texture(&descriptor(TextureDimension::D2)).unwrap();
+        let extent = Extent3d { width: 4, height: 4, depth_or_array_layers: 1 };
+        let layout = TextureDataLayout::packed(TextureFormat::R32Float, extent);
+        assert!(texture.write_texture(0, Origin3d::default(), extent, &[0; 64], layout).is_ok());
+        assert!(texture.write_texture(0, Origin3d::default(), extent, &[0; 63], layout).is_err());
+        assert!(texture.read_texture(0, Origin3d::default(), extent, &mut [0; 63], layout).is_err());
+        let empty = Extent3d { width: 0, ..extent };
+        assert!(texture.write_texture(0, Origin3d::default(), empty, &[], layout).is_ok());
+    }
+
+    fn create_view(texture: &TextureDescriptor, view: TextureViewDescriptor) -> Result<TextureView, GraphisError> {
+        TextureView::new(TextureHandle(RawHandle { index: 0, generation: 0 }), texture, &view)
+    }
+
+    #[test]
+    fn views_reinterpret_only_srgb_variants_of_the_format() {
+        let texture = TextureDescriptor { format: TextureFormat::Rgba8Unorm, ..descriptor(TextureDimension::D2) };
+        let format = |format| TextureViewDescriptor { format: Some(format), ..Default::default() };
+        let srgb = create_view(&texture, format(TextureFormat::Rgba8UnormSrgb)).unwrap();
+        assert_eq!(srgb.format(), TextureFormat::Rgba8UnormSrgb);
+        assert!(create_view(&texture, format(TextureFormat::Bgra8Unorm)).is_err());
+        assert!(create_view(&texture, format(TextureFormat::R32Float)).is_err());
+    }
+
+    #[test]
+    fn view_ranges_default_to_the_rest_of_the_texture_and_must_fit() {
+        let texture = descriptor(TextureDimension::D2);
+        let rest = TextureViewDescriptor { base_mip_level: 1, base_array_layer: 2, ..Default::default() };
+        let view = create_view(&texture, rest).unwrap();
+        assert_eq!((view.mip_level_count(), view.array_layer_count()), (2, 2));
+        assert_eq!(view.size(), (2, 2));
+        assert_eq!(view.dimension(), TextureViewDimension::D2Array);
+
+        let range = |base_mip_level, mip_level_count, base_array_layer, array_layer_count| TextureViewDescriptor {
+            base_mip_level,
+            mip_level_count,
+            base_array_layer,
+            array_layer_count,
+            ..Default::default()
+        };
+        assert!(create_view(&texture, range(2, Some(1), 3, Some(1))).is_ok());
+        assert!(create_view(&texture, range(3, None, 0, None)).is_err());
+        assert!(create_view(&texture, range(1, Some(3), 0, None)).is_err());
+        assert!(create_view(&texture, range(0, Some(0), 0, None)).is_err());
+        assert!(create_view(&texture, range(0, None, 4, None)).is_err());
+        assert!(create_view(&texture, range(0, None, 2, Some(3))).is_err());
+        assert!(create_view(&texture, range(0, None, u32::MAX, Some(2))).is_err());
+    }
+
+    #[test]
+    fn view_dimensions_check_the_layer_count() {
+        let dimension = |dimension, array_layer_count| TextureViewDescriptor {
+            dimension: Some(dimension),
+            array_layer_count,
+            ..Default::default()
+        };
+        let texture = descriptor(TextureDimension::D2);
+        assert_eq!(create_view(&texture, dimension(TextureViewDimension::D2, Some(1))).unwrap().array_layer_count(), 1);
+        assert!(create_view(&texture, dimension(TextureViewDimension::D2, None)).is_err());
+        assert!(create_view(&texture, dimension(TextureViewDimension::D2Array, Some(1))).is_ok());
+
+        let cube = TextureDescriptor { depth_or_array_layers: 6, ..descriptor(TextureDimension::D2) };
+        assert!(create_view(&cube, dimension(TextureViewDimension::Cube, None)).is_ok());
+        assert!(create_view(&cube, dimension(TextureViewDimension::Cube, Some(5))).is_err());
+        let wide = TextureDescriptor { width: 8, ..cube.clone() };
+        assert!(create_view(&wide, dimension(TextureViewDimension::Cube, None)).is_err());
+
+        // 3D görünümler her zaman tüm dilimleri kapsar.
+        let volume = descriptor(TextureDimension::D3);
+        assert!(create_view(&volume, dimension(TextureViewDimension::D3, Some(2))).is_err());
+    }
+
+    #[test]
+    fn view_aspects_must_exist_in_the_format() {
+        let aspect = |aspect| TextureViewDescriptor { aspect, ..Default::default() };
+        let depth_stencil = TextureDescriptor {
+            format: TextureFormat::Depth24PlusStencil8,
+            depth_or_array_layers: 1,
+            ..descriptor(TextureDimension::D2)
+        };
+        assert!(create_view(&depth_stencil, aspect(TextureAspect::DepthOnly)).is_ok());
+        assert!(create_view(&depth_stencil, aspect(TextureAspect::StencilOnly)).is_ok());
+        let depth = TextureDescriptor { format: TextureFormat::Depth32Float, ..depth_stencil.clone() };
+        assert!(create_view(&depth, aspect(TextureAspect::StencilOnly)).is_err());
+        let color = TextureDescriptor { format: TextureFormat::Rgba8Unorm, ..depth_stencil };
+        assert!(create_view(&color, aspect(TextureAspect::DepthOnly)).is_err());
+        assert!(create_view(&color, aspect(TextureAspect::All)).is_ok());
+    }
+
+    #[test]
+    fn multisampled_textures_only_take_2d_views() {
+        let multisampled = TextureDescriptor {
+            depth_or_array_layers: 2,
+            mip_level_count: 1,
+            sample_count: 4,
+            ..descriptor(TextureDimension::D2)
+        };
+        assert!(create_view(&multisampled, TextureViewDescriptor::default()).is_err());
+        let single = TextureViewDescriptor { array_layer_count: Some(1), ..Default::default() };
+        assert_eq!(create_view(&multisampled, single).unwrap().sample_count(), 4);
+    }
 }
\ No newline at end of file
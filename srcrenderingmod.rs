@@ -1,2 +1,2 @@
-pub mod pipeline;
+pub mod pipeline;
 pub mod shader;
\ No newline at end of file
@@ -1,252 +1,1133 @@
-use crate::gpu::buffer::Buffer;
-use crate::rendering::shader::Shader;
-use bitflags::bitflags;
-
-#[derive(Debug, Default, Clone)]
-pub struct VertexAttributeDescriptor {
-    pub location: u32, // Shader'daki attribute konumu
-    pub offset: u32,   // Vertex buffer içindeki ofset (byte cinsinden)
-    pub format: VertexFormat,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum VertexFormat {
-    Float32x3, // 3 adet 32-bit kayan nokta (örneğin, pozisyon)
-    Float32x2, // 2 adet 32-bit kayan nokta (örneğin, UV koordinatları)
-    Float32x4,
-    Uint32,
-    Sint32,
-    // İhtiyaca göre diğer formatlar eklenebilir.
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct VertexBufferLayoutDescriptor {
-    pub stride: u32,                    // Bir vertex'in boyutu (byte cinsinden)
-    pub step_mode: VertexStepMode, // Vertex başına mı yoksa instance başına mı veri okunacak?
-    pub attributes: Vec<VertexAttributeDescriptor>,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum VertexStepMode {
-    Vertex,
-    Instance,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum PrimitiveTopology {
-    PointList,
-    LineList,
-    LineStrip,
-    TriangleList,
-    TriangleStrip,
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct RasterizationStateDescriptor {
-    pub front_face: FrontFace,
-    pub cull_mode: CullMode,
-    pub depth_bias: f32,
-    pub depth_bias_slope_factor: f32,
-    pub depth_bias_clamp: f32,
-    pub polygon_mode: PolygonMode,
-    pub conservative: bool,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum FrontFace {
-    Clockwise,
-    CounterClockwise,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum CullMode {
-    None,
-    Front,
-    Back,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum PolygonMode {
-    Fill,
-    Line,
-    Point,
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct DepthStencilStateDescriptor {
-    pub format: DepthStencilFormat, // İleride tanımlanacak
-    pub depth_write_enabled: bool,
-    pub depth_compare: CompareFunction, // Tanımlanmıştı
-    pub stencil_front: StencilFaceState, // İleride tanımlanacak
-    pub stencil_back: StencilFaceState,  // İleride tanımlanacak
-    pub stencil_read_mask: u32,
-    pub stencil_write_mask: u32,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum DepthStencilFormat {
-    Depth32Float,
-    Depth24PlusStencil8,
-    // İhtiyaca göre diğer formatlar
-}
-
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
-pub struct StencilFaceState {
-    pub compare: CompareFunction, // Tanımlanmıştı
-    pub fail_op: StencilOperation, // İleride tanımlanacak
-    pub depth_fail_op: StencilOperation, // İleride tanımlanacak
-    pub pass_op: StencilOperation, // İleride tanımlanacak
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum StencilOperation {
-    Keep,
-    Zero,
-    Replace,
-    IncrementClamp,
-    DecrementClamp,
-    Invert,
-    IncrementWrap,
-    DecrementWrap,
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct MultisampleStateDescriptor {
-    pub count: u32,         // Örnek sayısı (1 multisampling kapalı demektir)
-    pub mask: u32,          // Örnek maskesi
-    pub alpha_to_coverage_enabled: bool,
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct BlendStateDescriptor {
-    pub color: BlendComponent, // Renk için blend ayarları
-    pub alpha: BlendComponent, // Alfa için blend ayarları
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum BlendFactor {
-    Zero,
-    One,
-    Src,
-    OneMinusSrc,
-    Dst,
-    OneMinusDst,
-    SrcAlpha,
-    OneMinusSrcAlpha,
-    DstAlpha,
-    OneMinusDstAlpha,
-    Constant,
-    OneMinusConstant,
-    SrcAlphaSaturated,
-    Src1,
-    OneMinusSrc1,
-    Src1Alpha,
-    OneMinusSrc1Alpha,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum BlendOperation {
-    Add,
-    Subtract,
-    ReverseSubtract,
-    Min,
-    Max,
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct BlendComponent {
-    pub src_factor: BlendFactor,
-    pub dst_factor: BlendFactor,
-    pub operation: BlendOperation,
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct PipelineDescriptor {
-    pub vertex: VertexStateDescriptor,
-    pub fragment: Option<FragmentStateDescriptor>,
-    pub primitive: PrimitiveStateDescriptor,
-    pub rasterization: Option<RasterizationStateDescriptor>,
-    pub depth_stencil: Option<DepthStencilStateDescriptor>, // Eklendi
-    pub multisample: Option<MultisampleStateDescriptor>,   // Eklendi
-    // İleride blend state gibi diğer ayarlar eklenebilir. (Blend state descriptor eklendi)
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct VertexStateDescriptor {
-    pub module: Option<Shader>,
-    pub entry_point: String,
-    pub buffers: Vec<VertexBufferLayoutDescriptor>,
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct FragmentStateDescriptor {
-    pub module: Option<Shader>,
-    pub entry_point: String,
-    pub targets: Vec<ColorTargetStateDescriptor>,
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct PrimitiveStateDescriptor {
-    pub topology: PrimitiveTopology,
-    pub strip_index_format: Option<IndexFormat>,
-    pub front_face: FrontFace,
-    pub cull_mode: CullMode,
-    pub unclipped_depth: bool,
-    pub conservative: bool,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum IndexFormat {
-    Uint16,
-    Uint32,
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct ColorTargetStateDescriptor {
-    pub format: TextureFormat, // İleride tanımlanacak
-    pub blend: Option<BlendStateDescriptor>, // BlendStateDescriptor kullanıldı
-    pub write_mask: ColorWrite,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum TextureFormat {
-    // İleride tanımlanacak çeşitli texture formatları
-    Rgba8Unorm,
-    Rgba8Snorm,
-    Rgba8Uint,
-    Rgba8Sint,
-    Rgba16Float,
-    Rgba16Unorm,
-    Rgba16Snorm,
-    Rgba16Uint,
-    Rgba16Sint,
-    Rgba32Float,
-    R32Float,
-    R32Uint,
-    R32Sint,
-    Depth32Float,
-    Depth24PlusStencil8,
-    // ... diğer formatlar
-}
-
-bitflags! {
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-    pub struct ColorWrite: u32 {
-        const RED     = 0b0001;
-        const GREEN   = 0b0010;
-        const BLUE    = 0b0100;
-        const ALPHA   = 0b1000;
-        const ALL     = Self::RED.bits() | Self::GREEN.bits() | Self::BLUE.bits() | Self::ALPHA.bits();
-    }
-}
-
-#[derive(Debug)]
-pub struct Pipeline {
-    // Bu yapı, GPU üzerinde oluşturulan renderleme hattını temsil edebilir.
-    // İçinde GPU'ya özgü bir "handle" veya tanımlayıcı tutabilir.
-}
-
-impl PipelineDescriptor {
-    // İleride pipeline oluşturmak için bir metot eklenebilir.
-    // pub fn build(&self, device: &crate::gpu::Device) -> Pipeline { ... }
+use std::collections::HashSet;
+
+use crate::gpu::backend::{Backend, DefaultBackend};
+use crate::gpu::buffer::Buffer;
+use crate::gpu::commands::{self, CompareFunction};
+use crate::gpu::device::Device;
+use crate::gpu::error::GraphisError;
+use crate::gpu::resource::PipelineHandle;
+use crate::gpu::shader::{Shader, ShaderStage};
+use crate::gpu::spirv::{EntryPoint, InterfaceVariable, Interpolation, ScalarKind, SpirvType};
+use bitflags::bitflags;
+
+#[derive(Debug, Default, Clone)]
+pub struct VertexAttributeDescriptor {
+    pub location: u32, // Shader'daki attribute konumu
+    pub offset: u32,   // Vertex buffer içindeki ofset (byte cinsinden)
+    pub format: VertexFormat,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum VertexFormat {
+    #[default]
+    Float32x3, // 3 adet 32-bit kayan nokta (örneğin, pozisyon)
+    Float32x2, // 2 adet 32-bit kayan nokta (örneğin, UV koordinatları)
+    Float32x4,
+    Uint32,
+    Sint32,
+    // İhtiyaca göre diğer formatlar eklenebilir.
+}
+
+impl VertexFormat {
+    // Formatın vertex buffer içinde kapladığı alan (byte cinsinden).
+    pub fn size(&self) -> u32 {
+        match self {
+            VertexFormat::Float32x3 => 12,
+            VertexFormat::Float32x2 => 8,
+            VertexFormat::Float32x4 => 16,
+            VertexFormat::Uint32 | VertexFormat::Sint32 => 4,
+        }
+    }
+
+    pub fn component_count(&self) -> u32 {
+        match self {
+            VertexFormat::Float32x3 => 3,
+            VertexFormat::Float32x2 => 2,
+            VertexFormat::Float32x4 => 4,
+            VertexFormat::Uint32 | VertexFormat::Sint32 => 1,
+        }
+    }
+
+    // Bu formattaki bir niteliği okuyan vertex shader girişinin skaler tipi.
+    pub fn scalar_kind(&self) -> ScalarKind {
+        match self {
+            VertexFormat::Float32x3 | VertexFormat::Float32x2 | VertexFormat::Float32x4 => ScalarKind::Float,
+            VertexFormat::Uint32 => ScalarKind::Uint,
+            VertexFormat::Sint32 => ScalarKind::Sint,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct VertexBufferLayoutDescriptor {
+    pub stride: u32,                    // Bir vertex'in boyutu (byte cinsinden)
+    pub step_mode: VertexStepMode, // Vertex başına mı yoksa instance başına mı veri okunacak?
+    pub attributes: Vec<VertexAttributeDescriptor>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum VertexStepMode {
+    #[default]
+    Vertex,
+    Instance,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PrimitiveTopology {
+    PointList,
+    LineList,
+    LineStrip,
+    #[default]
+    TriangleList,
+    TriangleStrip,
+}
+
+impl PrimitiveTopology {
+    pub fn is_strip(&self) -> bool {
+        matches!(self, PrimitiveTopology::LineStrip | PrimitiveTopology::TriangleStrip)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RasterizationStateDescriptor {
+    pub front_face: FrontFace,
+    pub cull_mode: CullMode,
+    pub depth_bias: f32,
+    pub depth_bias_slope_factor: f32,
+    pub depth_bias_clamp: f32,
+    pub polygon_mode: PolygonMode,
+    pub conservative: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FrontFace {
+    Clockwise,
+    #[default]
+    CounterClockwise,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CullMode {
+    #[default]
+    None,
+    Front,
+    Back,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PolygonMode {
+    #[default]
+    Fill,
+    Line,
+    Point,
+}
+
+#[derive(Debug, Clone)]
+pub struct DepthStencilStateDescriptor {
+    pub format: DepthStencilFormat, // İleride tanımlanacak
+    pub depth_write_enabled: bool,
+    pub depth_compare: CompareFunction, // Tanımlanmıştı
+    pub stencil_front: StencilFaceState, // İleride tanımlanacak
+    pub stencil_back: StencilFaceState,  // İleride tanımlanacak
+    pub stencil_read_mask: u32,
+    pub stencil_write_mask: u32,
+}
+
+// Maskeler varsayılan olarak tüm bitleri kapsar; aksi halde stencil testi her zaman 0 ile karşılaştırılırdı.
+impl Default for DepthStencilStateDescriptor {
+    fn default() -> Self {
+        DepthStencilStateDescriptor {
+            format: DepthStencilFormat::default(),
+            depth_write_enabled: false,
+            depth_compare: CompareFunction::default(),
+            stencil_front: StencilFaceState::default(),
+            stencil_back: StencilFaceState::default(),
+            stencil_read_mask: u32::MAX,
+            stencil_write_mask: u32::MAX,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DepthStencilFormat {
+    Depth16Unorm,
+    #[default]
+    Depth32Float,
+    Depth24PlusStencil8,
+    Depth32FloatStencil8,
+    Stencil8,
+}
+
+impl DepthStencilFormat {
+    // Derinlik hedefi olarak bağlanan dokunun sahip olması gereken format.
+    pub fn texture_format(&self) -> TextureFormat {
+        match self {
+            DepthStencilFormat::Depth16Unorm => TextureFormat::Depth16Unorm,
+            DepthStencilFormat::Depth32Float => TextureFormat::Depth32Float,
+            DepthStencilFormat::Depth24PlusStencil8 => TextureFormat::Depth24PlusStencil8,
+            DepthStencilFormat::Depth32FloatStencil8 => TextureFormat::Depth32FloatStencil8,
+            DepthStencilFormat::Stencil8 => TextureFormat::Stencil8,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StencilFaceState {
+    pub compare: CompareFunction, // Tanımlanmıştı
+    pub fail_op: StencilOperation, // İleride tanımlanacak
+    pub depth_fail_op: StencilOperation, // İleride tanımlanacak
+    pub pass_op: StencilOperation, // İleride tanımlanacak
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StencilOperation {
+    #[default]
+    Keep,
+    Zero,
+    Replace,
+    IncrementClamp,
+    DecrementClamp,
+    Invert,
+    IncrementWrap,
+    DecrementWrap,
+}
+
+impl StencilOperation {
+    // Saklı stencil değerinin işlemden sonraki hali; yazma maskesi çağıran tarafından uygulanır.
+    pub fn apply(&self, stored: u8, reference: u8) -> u8 {
+        match self {
+            StencilOperation::Keep => stored,
+            StencilOperation::Zero => 0,
+            StencilOperation::Replace => reference,
+            StencilOperation::IncrementClamp => stored.saturating_add(1),
+            StencilOperation::DecrementClamp => stored.saturating_sub(1),
+            StencilOperation::Invert => !stored,
+            StencilOperation::IncrementWrap => stored.wrapping_add(1),
+            StencilOperation::DecrementWrap => stored.wrapping_sub(1),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MultisampleStateDescriptor {
+    pub count: u32,         // Örnek sayısı (1 multisampling kapalı demektir)
+    pub mask: u32,          // Örnek maskesi
+    pub alpha_to_coverage_enabled: bool,
+}
+
+// Varsayılan durum tek örneklidir ve maske tüm örnekleri kapsar; aksi halde kapsam her zaman boş kalırdı.
+impl Default for MultisampleStateDescriptor {
+    fn default() -> Self {
+        MultisampleStateDescriptor { count: 1, mask: u32::MAX, alpha_to_coverage_enabled: false }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct BlendStateDescriptor {
+    pub color: BlendComponent, // Renk için blend ayarları
+    pub alpha: BlendComponent, // Alfa için blend ayarları
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendFactor {
+    Zero,
+    One,
+    Src,
+    OneMinusSrc,
+    Dst,
+    OneMinusDst,
+    SrcAlpha,
+    OneMinusSrcAlpha,
+    DstAlpha,
+    OneMinusDstAlpha,
+    Constant,
+    OneMinusConstant,
+    SrcAlphaSaturated,
+    Src1,
+    OneMinusSrc1,
+    Src1Alpha,
+    OneMinusSrc1Alpha,
+}
+
+// Pipeline faktörleri, komutlardaki (OpenGL adlandırmalı) faktörlerin bir alt kümesidir.
+impl From<BlendFactor> for commands::BlendFactor {
+    fn from(factor: BlendFactor) -> Self {
+        match factor {
+            BlendFactor::Zero => commands::BlendFactor::Zero,
+            BlendFactor::One => commands::BlendFactor::One,
+            BlendFactor::Src => commands::BlendFactor::SrcColor,
+            BlendFactor::OneMinusSrc => commands::BlendFactor::OneMinusSrcColor,
+            BlendFactor::Dst => commands::BlendFactor::DstColor,
+            BlendFactor::OneMinusDst => commands::BlendFactor::OneMinusDstColor,
+            BlendFactor::SrcAlpha => commands::BlendFactor::SrcAlpha,
+            BlendFactor::OneMinusSrcAlpha => commands::BlendFactor::OneMinusSrcAlpha,
+            BlendFactor::DstAlpha => commands::BlendFactor::DstAlpha,
+            BlendFactor::OneMinusDstAlpha => commands::BlendFactor::OneMinusDstAlpha,
+            BlendFactor::Constant => commands::BlendFactor::ConstantColor,
+            BlendFactor::OneMinusConstant => commands::BlendFactor::OneMinusConstantColor,
+            BlendFactor::SrcAlphaSaturated => commands::BlendFactor::SrcAlphaSaturate,
+            BlendFactor::Src1 => commands::BlendFactor::Src1Color,
+            BlendFactor::OneMinusSrc1 => commands::BlendFactor::OneMinusSrc1Color,
+            BlendFactor::Src1Alpha => commands::BlendFactor::Src1Alpha,
+            BlendFactor::OneMinusSrc1Alpha => commands::BlendFactor::OneMinusSrc1Alpha,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendOperation {
+    Add,
+    Subtract,
+    ReverseSubtract,
+    Min,
+    Max,
+}
+
+impl From<BlendOperation> for commands::BlendOperation {
+    fn from(operation: BlendOperation) -> Self {
+        match operation {
+            BlendOperation::Add => commands::BlendOperation::Add,
+            BlendOperation::Subtract => commands::BlendOperation::Subtract,
+            BlendOperation::ReverseSubtract => commands::BlendOperation::ReverseSubtract,
+            BlendOperation::Min => commands::BlendOperation::Min,
+            BlendOperation::Max => commands::BlendOperation::Max,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BlendComponent {
+    pub src_factor: BlendFactor,
+    pub dst_factor: BlendFactor,
+    pub operation: BlendOperation,
+}
+
+// Varsayılan bileşen, kaynağı olduğu gibi yazar (One, Zero, Add).
+impl Default for BlendComponent {
+    fn default() -> Self {
+        BlendComponent {
+            src_factor: BlendFactor::One,
+            dst_factor: BlendFactor::Zero,
+            operation: BlendOperation::Add,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PipelineDescriptor<B: Backend = DefaultBackend> {
+    pub vertex: VertexStateDescriptor<B>,
+    pub fragment: Option<FragmentStateDescriptor<B>>,
+    pub primitive: PrimitiveStateDescriptor,
+    pub rasterization: Option<RasterizationStateDescriptor>,
+    pub depth_stencil: Option<DepthStencilStateDescriptor>, // Eklendi
+    pub multisample: Option<MultisampleStateDescriptor>,   // Eklendi
+    // İleride blend state gibi diğer ayarlar eklenebilir. (Blend state descriptor eklendi)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct VertexStateDescriptor<B: Backend = DefaultBackend> {
+    pub module: Option<Shader<B>>,
+    pub entry_point: String,
+    pub buffers: Vec<VertexBufferLayoutDescriptor>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct FragmentStateDescriptor<B: Backend = DefaultBackend> {
+    pub module: Option<Shader<B>>,
+    pub entry_point: String,
+    pub targets: Vec<ColorTargetStateDescriptor>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PrimitiveStateDescriptor {
+    pub topology: PrimitiveTopology,
+    pub strip_index_format: Option<IndexFormat>,
+    pub front_face: FrontFace,
+    pub cull_mode: CullMode,
+    pub unclipped_depth: bool,
+    pub conservative: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexFormat {
+    Uint16,
+    Uint32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ColorTargetStateDescriptor {
+    pub format: TextureFormat, // İleride tanımlanacak
+    pub blend: Option<BlendStateDescriptor>, // BlendStateDescriptor kullanıldı
+    pub write_mask: ColorWrite,
+}
+
+// Varsayılan hedef tüm kanalları yazar; ColorWrite'ın varsayılanı boş maskedir.
+impl Default for ColorTargetStateDescriptor {
+    fn default() -> Self {
+        ColorTargetStateDescriptor { format: TextureFormat::default(), blend: None, write_mask: ColorWrite::ALL }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TextureFormat {
+    // 8 bit kanallı formatlar
+    R8Unorm,
+    Rg8Unorm,
+    Rg8Snorm,
+    Rg8Uint,
+    Rg8Sint,
+    #[default]
+    Rgba8Unorm,
+    Rgba8UnormSrgb, // Renkler sRGB kodlu saklanır; okurken doğrusala, yazarken sRGB'ye çevrilir
+    Rgba8Snorm,
+    Rgba8Uint,
+    Rgba8Sint,
+    Bgra8Unorm, // Kanallar bellekte B, G, R, A sırasıyla durur
+    Bgra8UnormSrgb,
+    // 16 bit kanallı formatlar
+    Rg16Unorm,
+    Rg16Snorm,
+    Rg16Uint,
+    Rg16Sint,
+    Rg16Float,
+    Rgba16Float,
+    Rgba16Unorm,
+    Rgba16Snorm,
+    Rgba16Uint,
+    Rgba16Sint,
+    // 32 bit kanallı formatlar
+    R32Float,
+    R32Uint,
+    R32Sint,
+    Rg32Float,
+    Rg32Uint,
+    Rg32Sint,
+    Rgba32Float,
+    // Paketlenmiş formatlar (texel başına tek bir 32 bit sözcük, en düşük bitten başlayarak)
+    Rgb10a2Unorm, // R, G, B 10 bit; A 2 bit
+    Rg11b10Float, // İşaretsiz kayan noktalı: R, G 11 bit (5 üs, 6 mantis); B 10 bit (5 üs, 5 mantis)
+    Rgb9e5Ufloat, // Her kanal 9 bit mantis, ortak 5 bit üs
+    // Derinlik ve stencil formatları
+    Depth16Unorm,
+    Depth32Float,
+    Depth24PlusStencil8,
+    Depth32FloatStencil8, // Derinlik 4 byte, stencil 1 byte; texel 8 byte'a hizalanır
+    Stencil8,
+}
+
+impl TextureFormat {
+    // Bir texel'in bellekte kapladığı byte sayısı.
+    pub fn block_size(&self) -> usize {
+        match self {
+            TextureFormat::R8Unorm | TextureFormat::Stencil8 => 1,
+            TextureFormat::Rg8Unorm
+            | TextureFormat::Rg8Snorm
+            | TextureFormat::Rg8Uint
+            | TextureFormat::Rg8Sint
+            | TextureFormat::Depth16Unorm => 2,
+            TextureFormat::Rgba8Unorm
+            | TextureFormat::Rgba8UnormSrgb
+            | TextureFormat::Rgba8Snorm
+            | TextureFormat::Rgba8Uint
+            | TextureFormat::Rgba8Sint
+            | TextureFormat::Bgra8Unorm
+            | TextureFormat::Bgra8UnormSrgb
+            | TextureFormat::Rg16Unorm
+            | TextureFormat::Rg16Snorm
+            | TextureFormat::Rg16Uint
+            | TextureFormat::Rg16Sint
+            | TextureFormat::Rg16Float
+            | TextureFormat::R32Float
+            | TextureFormat::R32Uint
+            | TextureFormat::R32Sint
+            | TextureFormat::Rgb10a2Unorm
+            | TextureFormat::Rg11b10Float
+            | TextureFormat::Rgb9e5Ufloat
+            | TextureFormat::Depth32Float
+            | TextureFormat::Depth24PlusStencil8 => 4,
+            TextureFormat::Rgba16Float
+            | TextureFormat::Rgba16Unorm
+            | TextureFormat::Rgba16Snorm
+            | TextureFormat::Rgba16Uint
+            | TextureFormat::Rgba16Sint
+            | TextureFormat::Rg32Float
+            | TextureFormat::Rg32Uint
+            | TextureFormat::Rg32Sint
+            | TextureFormat::Depth32FloatStencil8 => 8,
+            TextureFormat::Rgba32Float => 16,
+        }
+    }
+
+    // Formatın sakladığı kanal sayısı; derinlik-stencil formatlarında derinlik ve stencil birer kanal sayılır.
+    pub fn channel_count(&self) -> usize {
+        match self {
+            TextureFormat::R8Unorm
+            | TextureFormat::R32Float
+            | TextureFormat::R32Uint
+            | TextureFormat::R32Sint
+            | TextureFormat::Depth16Unorm
+            | TextureFormat::Depth32Float
+            | TextureFormat::Stencil8 => 1,
+            TextureFormat::Rg8Unorm
+            | TextureFormat::Rg8Snorm
+            | TextureFormat::Rg8Uint
+            | TextureFormat::Rg8Sint
+            | TextureFormat::Rg16Unorm
+            | TextureFormat::Rg16Snorm
+            | TextureFormat::Rg16Uint
+            | TextureFormat::Rg16Sint
+            | TextureFormat::Rg16Float
+            | TextureFormat::Rg32Float
+            | TextureFormat::Rg32Uint
+            | TextureFormat::Rg32Sint
+            | TextureFormat::Depth24PlusStencil8
+            | TextureFormat::Depth32FloatStencil8 => 2,
+            TextureFormat::Rg11b10Float | TextureFormat::Rgb9e5Ufloat => 3,
+            TextureFormat::Rgba8Unorm
+            | TextureFormat::Rgba8UnormSrgb
+            | TextureFormat::Rgba8Snorm
+            | TextureFormat::Rgba8Uint
+            | TextureFormat::Rgba8Sint
+            | TextureFormat::Bgra8Unorm
+            | TextureFormat::Bgra8UnormSrgb
+            | TextureFormat::Rgba16Float
+            | TextureFormat::Rgba16Unorm
+            | TextureFormat::Rgba16Snorm
+            | TextureFormat::Rgba16Uint
+            | TextureFormat::Rgba16Sint
+            | TextureFormat::Rgba32Float
+            | TextureFormat::Rgb10a2Unorm => 4,
+        }
+    }
+
+    pub fn is_srgb(&self) -> bool {
+        matches!(self, TextureFormat::Rgba8UnormSrgb | TextureFormat::Bgra8UnormSrgb)
+    }
+
+    // Formatın sRGB kodlaması olmayan karşılığı (diğer formatlar için kendisi).
+    pub fn remove_srgb_suffix(&self) -> TextureFormat {
+        match self {
+            TextureFormat::Rgba8UnormSrgb => TextureFormat::Rgba8Unorm,
+            TextureFormat::Bgra8UnormSrgb => TextureFormat::Bgra8Unorm,
+            format => *format,
+        }
+    }
+
+    // Aynı bellek düzenini paylaşan ve birbirinin görünümü (view) olarak yorumlanabilen formatlar:
+    // yalnızca sRGB kodlaması farklı olanlar.
+    pub fn is_view_compatible(&self, other: TextureFormat) -> bool {
+        self.remove_srgb_suffix() == other.remove_srgb_suffix()
+    }
+
+    // Formatın derinlik bileşeni olup olmadığı.
+    pub fn is_depth(&self) -> bool {
+        matches!(
+            self,
+            TextureFormat::Depth16Unorm
+                | TextureFormat::Depth32Float
+                | TextureFormat::Depth24PlusStencil8
+                | TextureFormat::Depth32FloatStencil8
+        )
+    }
+
+    pub fn has_stencil(&self) -> bool {
+        matches!(
+            self,
+            TextureFormat::Depth24PlusStencil8 | TextureFormat::Depth32FloatStencil8 | TextureFormat::Stencil8
+        )
+    }
+
+    // Derinlik-stencil hedefi olarak kullanılan formatlar (derinlik, stencil veya ikisi birden).
+    pub fn is_depth_stencil(&self) -> bool {
+        self.is_depth() || self.has_stencil()
+    }
+
+    // Tamsayı formatlarında karıştırma (blending) yapılamaz.
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            TextureFormat::Rg8Uint
+                | TextureFormat::Rg8Sint
+                | TextureFormat::Rgba8Uint
+                | TextureFormat::Rgba8Sint
+                | TextureFormat::Rg16Uint
+                | TextureFormat::Rg16Sint
+                | TextureFormat::Rgba16Uint
+                | TextureFormat::Rgba16Sint
+                | TextureFormat::R32Uint
+                | TextureFormat::R32Sint
+                | TextureFormat::Rg32Uint
+                | TextureFormat::Rg32Sint
+        )
+    }
+
+    // Bu formattaki bir renk hedefine yazan fragment shader çıkışının skaler tipi.
+    pub fn scalar_kind(&self) -> ScalarKind {
+        match self {
+            TextureFormat::Rg8Uint
+            | TextureFormat::Rgba8Uint
+            | TextureFormat::Rg16Uint
+            | TextureFormat::Rgba16Uint
+            | TextureFormat::R32Uint
+            | TextureFormat::Rg32Uint => ScalarKind::Uint,
+            TextureFormat::Rg8Sint
+            | TextureFormat::Rgba8Sint
+            | TextureFormat::Rg16Sint
+            | TextureFormat::Rgba16Sint
+            | TextureFormat::R32Sint
+            | TextureFormat::Rg32Sint => ScalarKind::Sint,
+            _ => ScalarKind::Float,
+        }
+    }
+
+    // Formatın doğrusal filtreyle örneklenip örneklenemeyeceği. Tamsayı formatlar filtrelenemez; derinlik
+    // formatları yalnızca karşılaştırma sampler'larıyla filtrelenir.
+    pub fn is_filterable(&self) -> bool {
+        !self.is_integer() && !self.is_depth_stencil()
+    }
+
+    // Formatın renk hedefi (color attachment) olarak kullanılıp kullanılamayacağı.
+    // Snorm formatları, ortak üslü format ve derinlik-stencil formatları renk hedefi olamaz.
+    pub fn is_renderable(&self) -> bool {
+        !self.is_depth_stencil()
+            && !matches!(
+                self,
+                TextureFormat::Rg8Snorm
+                    | TextureFormat::Rgba8Snorm
+                    | TextureFormat::Rg16Snorm
+                    | TextureFormat::Rgba16Snorm
+                    | TextureFormat::Rgb9e5Ufloat
+            )
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    pub struct ColorWrite: u32 {
+        const RED     = 0b0001;
+        const GREEN   = 0b0010;
+        const BLUE    = 0b0100;
+        const ALPHA   = 0b1000;
+        const ALL     = Self::RED.bits() | Self::GREEN.bits() | Self::BLUE.bits() | Self::ALPHA.bits();
+    }
+}
+
+// Doğrulanmış bir tanımlayıcıdan oluşturulan renderleme hattı.
+// İsteğe bağlı durumlar (rasterization, multisample) varsayılan değerleriyle çözümlenmiş olarak tutulur.
+#[derive(Debug, Clone)]
+pub struct Pipeline<B: Backend = DefaultBackend> {
+    handle: PipelineHandle, // BindShaderProgram komutunda kullanılan tanımlayıcı
+    pub vertex: VertexStateDescriptor<B>,
+    pub fragment: Option<FragmentStateDescriptor<B>>,
+    pub primitive: PrimitiveStateDescriptor,
+    pub rasterization: RasterizationStateDescriptor,
+    pub depth_stencil: Option<DepthStencilStateDescriptor>,
+    pub multisample: MultisampleStateDescriptor,
+}
+
+impl<B: Backend> Pipeline<B> {
+    // Tanımlayıcıyı doğrulamadan çözümler; doğrulama Device::create_pipeline içinde yapılır.
+    pub(crate) fn resolve(handle: PipelineHandle, descriptor: &PipelineDescriptor<B>) -> Self {
+        let rasterization = descriptor.rasterization.clone().unwrap_or_else(|| RasterizationStateDescriptor {
+            front_face: descriptor.primitive.front_face,
+            cull_mode: descriptor.primitive.cull_mode,
+            conservative: descriptor.primitive.conservative,
+            ..Default::default()
+        });
+        let multisample = descriptor.multisample.clone().unwrap_or_default();
+        Pipeline {
+            handle,
+            vertex: descriptor.vertex.clone(),
+            fragment: descriptor.fragment.clone(),
+            primitive: descriptor.primitive.clone(),
+            rasterization,
+            depth_stencil: descriptor.depth_stencil.clone(),
+            multisample,
+        }
+    }
+
+    // BindShaderProgram komutunda kullanılan tanımlayıcı.
+    pub fn handle(&self) -> PipelineHandle {
+        self.handle
+    }
+
+    pub fn color_targets(&self) -> &[ColorTargetStateDescriptor] {
+        self.fragment.as_ref().map_or(&[], |fragment| fragment.targets.as_slice())
+    }
+}
+
+impl<B: Backend> PipelineDescriptor<B> {
+    // Tanımlayıcıyı doğrular ve cihaz üzerinde bir pipeline oluşturur.
+    pub fn build(&self, device: &Device<B>) -> Result<Pipeline<B>, GraphisError> {
+        device.create_pipeline(self)
+    }
+
+    // Tanımlayıcının kendi içinde tutarlı olup olmadığını kontrol eder.
+    pub fn validate(&self) -> Result<(), GraphisError> {
+        self.validate_vertex_state()?;
+        self.validate_primitive_state()?;
+        self.validate_fragment_state()?;
+        self.validate_interfaces()?;
+        if let Some(multisample) = &self.multisample {
+            if !matches!(multisample.count, 1 | 2 | 4 | 8) {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "multisample count must be 1, 2, 4 or 8, got {}",
+                    multisample.count
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_vertex_state(&self) -> Result<(), GraphisError> {
+        let module = self.vertex.module.as_ref().ok_or_else(|| {
+            GraphisError::ValidationFailed("vertex state has no shader module".to_string())
+        })?;
+        check_stage(module, ShaderStage::Vertex, &self.vertex.entry_point)?;
+
+        let mut locations = HashSet::new();
+        for (slot, layout) in self.vertex.buffers.iter().enumerate() {
+            // Çizim komutları örnek (instance) sayısı taşımaz; örnek başına veriler okunamaz.
+            if layout.step_mode == VertexStepMode::Instance && !layout.attributes.is_empty() {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "vertex buffer {} steps per instance, but instanced drawing is not supported",
+                    slot
+                )));
+            }
+            for attribute in &layout.attributes {
+                if !locations.insert(attribute.location) {
+                    return Err(GraphisError::ValidationFailed(format!(
+                        "vertex attribute location {} is used more than once",
+                        attribute.location
+                    )));
+                }
+                let end = attribute.offset as u64 + attribute.format.size() as u64;
+                if end > layout.stride as u64 {
+                    return Err(GraphisError::ValidationFailed(format!(
+                        "vertex attribute at location {} ({:?} at offset {}) does not fit in the stride {} of buffer {}",
+                        attribute.location, attribute.format, attribute.offset, layout.stride, slot
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_primitive_state(&self) -> Result<(), GraphisError> {
+        if self.primitive.strip_index_format.is_some() && !self.primitive.topology.is_strip() {
+            return Err(GraphisError::ValidationFailed(format!(
+                "strip_index_format is only valid with strip topologies, not {:?}",
+                self.primitive.topology
+            )));
+        }
+        // Ön yüz, ayıklama ve tutucu (conservative) rasterleştirme iki durumda da bulunur; rasterization durumu
+        // verildiğinde ikisi aynı olmalıdır, aksi halde hangisinin geçerli olduğu belirsiz kalır.
+        if let Some(rasterization) = &self.rasterization {
+            let primitive = &self.primitive;
+            if (rasterization.front_face, rasterization.cull_mode, rasterization.conservative)
+                != (primitive.front_face, primitive.cull_mode, primitive.conservative)
+            {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "rasterization state ({:?}, cull {:?}, conservative {}) disagrees with the primitive state \
+                     ({:?}, cull {:?}, conservative {})",
+                    rasterization.front_face,
+                    rasterization.cull_mode,
+                    rasterization.conservative,
+                    primitive.front_face,
+                    primitive.cull_mode,
+                    primitive.conservative
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_fragment_state(&self) -> Result<(), GraphisError> {
+        let fragment = match &self.fragment {
+            Some(fragment) => fragment,
+            None => return Ok(()),
+        };
+        let module = fragment.module.as_ref().ok_or_else(|| {
+            GraphisError::ValidationFailed("fragment state has no shader module".to_string())
+        })?;
+        check_stage(module, ShaderStage::Fragment, &fragment.entry_point)?;
+
+        // Render geçişleri tek renk ekini desteklediğinden fragment aşaması da tek hedefe yazar.
+        if fragment.targets.len() > 1 {
+            return Err(GraphisError::Unsupported(format!(
+                "pipelines support a single color target, got {}",
+                fragment.targets.len()
+            )));
+        }
+        for (index, target) in fragment.targets.iter().enumerate() {
+            if !target.format.is_renderable() {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "color target {} uses the non-renderable format {:?}",
+                    index, target.format
+                )));
+            }
+            if target.blend.is_some() && target.format.is_integer() {
+                return Err(GraphisError::ValidationFailed(format!(
+                    "color target {} enables blending on the integer format {:?}",
+                    index, target.format
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    // Vertex düzeninin, aşamaların giriş ve çıkışlarının ve renk hedeflerinin birbiriyle uyumu. Gerçek bir GPU'da
+    // bu uyumsuzluklar hata vermez, yalnızca bozuk görüntü olarak ortaya çıkar.
+    fn validate_interfaces(&self) -> Result<(), GraphisError> {
+        let invalid = |message: String| Err(GraphisError::ValidationFailed(message));
+        let vertex = match entry_point(&self.vertex.module, &self.vertex.entry_point, ShaderStage::Vertex) {
+            Some(vertex) => vertex,
+            None => return Ok(()),
+        };
+
+        let attributes: Vec<_> = self.vertex.buffers.iter().flat_map(|layout| &layout.attributes).collect();
+        for attribute in &attributes {
+            match vertex.inputs.iter().find(|input| covers(input, attribute.location)) {
+                None => {
+                    return invalid(format!(
+                        "vertex attribute at location {} ({:?}) has no matching input in vertex entry point {:?}",
+                        attribute.location, attribute.format, vertex.name
+                    ))
+                }
+                Some(input) if input.ty.scalar_kind() != Some(attribute.format.scalar_kind()) => {
+                    return invalid(format!(
+                        "vertex attribute at location {} is {:?} but the vertex shader reads it as {}",
+                        attribute.location,
+                        attribute.format,
+                        describe(&input.ty)
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+        for input in &vertex.inputs {
+            let mut locations = input.location..input.location + input.ty.location_count();
+            let missing = locations.find(|&location| attributes.iter().all(|attribute| attribute.location != location));
+            if let Some(location) = missing {
+                return invalid(format!(
+                    "vertex shader input {:?} at location {} is not provided by any vertex attribute",
+                    input.name, location
+                ));
+            }
+        }
+
+        let fragment_state = match &self.fragment {
+            Some(fragment_state) => fragment_state,
+            None => return Ok(()),
+        };
+        let fragment = match entry_point(&fragment_state.module, &fragment_state.entry_point, ShaderStage::Fragment) {
+            Some(fragment) => fragment,
+            None => return Ok(()),
+        };
+        for input in &fragment.inputs {
+            let output = vertex
+                .outputs
+                .iter()
+                .find(|output| output.location == input.location && output.component == input.component);
+            match output {
+                None => {
+                    return invalid(format!(
+                        "fragment shader input {:?} at location {} is not written by the vertex shader",
+                        input.name, input.location
+                    ))
+                }
+                Some(output)
+                    if output.ty.scalar_kind() != input.ty.scalar_kind()
+                        || output.ty.component_count() != input.ty.component_count()
+                        || output.ty.location_count() != input.ty.location_count() =>
+                {
+                    return invalid(format!(
+                        "vertex shader output at location {} is {} but the fragment shader reads {}",
+                        input.location,
+                        describe(&output.ty),
+                        describe(&input.ty)
+                    ))
+                }
+                Some(_) => {}
+            }
+            // Tamsayılar ara değerlendirilemez; Vulkan bu girişlerin Flat olmasını şart koşar.
+            let integer = matches!(input.ty.scalar_kind(), Some(ScalarKind::Sint | ScalarKind::Uint));
+            if integer && input.interpolation != Interpolation::Flat {
+                return invalid(format!(
+                    "integer fragment shader input at location {} must be decorated Flat",
+                    input.location
+                ));
+            }
+        }
+
+        // Yazma maskesi boş olan hedefler için çıkış gerekmez.
+        for (index, target) in fragment_state.targets.iter().enumerate() {
+            if target.write_mask.is_empty() {
+                continue;
+            }
+            match fragment.outputs.iter().find(|output| covers(output, index as u32)) {
+                None => {
+                    return invalid(format!(
+                        "color target {} ({:?}) is not written by any output of fragment entry point {:?}",
+                        index, target.format, fragment.name
+                    ))
+                }
+                Some(output) if output.ty.scalar_kind() != Some(target.format.scalar_kind()) => {
+                    return invalid(format!(
+                        "color target {} has the {:?} format but the fragment shader writes {}",
+                        index,
+                        target.format,
+                        describe(&output.ty)
+                    ))
+                }
+                Some(output) if (output.ty.component_count() as usize) < target.format.channel_count() => {
+                    return invalid(format!(
+                        "color target {} has {} channel(s) but the fragment shader writes only {}",
+                        index,
+                        target.format.channel_count(),
+                        describe(&output.ty)
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+fn entry_point<'a, B: Backend>(module: &'a Option<Shader<B>>, name: &str, stage: ShaderStage) -> Option<&'a EntryPoint> {
+    module.as_ref()?.module()?.entry_point(name, stage)
+}
+
+// Değişkenin (matris ve diziler için birden fazla olabilen) konumlarından biri `location` mı?
+fn covers(variable: &InterfaceVariable, location: u32) -> bool {
+    (variable.location..variable.location + variable.ty.location_count()).contains(&location)
+}
+
+fn describe(ty: &SpirvType) -> String {
+    match ty.scalar_kind() {
+        Some(kind) => format!("a {}-component {:?} value", ty.component_count(), kind),
+        None => format!("{:?}", ty),
+    }
+}
+
+// Yerel shader'ların giriş noktası yoktur; giriş noktası adı yok sayılır.
+fn check_stage<B: Backend>(module: &Shader<B>, expected: ShaderStage, entry_point: &str) -> Result<(), GraphisError> {
+    if module.descriptor.stage != expected {
+        return Err(GraphisError::ValidationFailed(format!(
+            "shader {:?} is a {:?} shader but is used as the {:?} stage",
+            module.descriptor.label, module.descriptor.stage, expected
+        )));
+    }
+    let Some(spirv) = module.module() else { return Ok(()) };
+    if entry_point.is_empty() {
+        return Err(GraphisError::ValidationFailed(format!(
+            "{:?} stage has an empty entry point",
+            expected
+        )));
+    }
+    if spirv.entry_point(entry_point, expected).is_none() {
+        return Err(GraphisError::ValidationFailed(format!(
+            "shader {:?} has no {:?} entry point named {:?}",
+            module.descriptor.label, expected, entry_point
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gpu::frontend;
+    use crate::gpu::shader::{NativeShader, ShaderDescriptor, ShaderSource};
+    use crate::gpu::software::shading::{FragmentInput, ShadedVertex, VertexInput};
+    use crate::gpu::spirv::{decoration, op};
+
+    fn shader(native: NativeShader) -> Shader {
+        let stage = native.stage();
+        Shader::new(ShaderDescriptor { label: None, source: ShaderSource::Native(native), stage }).unwrap()
+    }
+
+    // Yalnızca yerel bir vertex shader'ı olan tanım; testler geri kalan durumu tanım üzerinde değiştirir.
+    fn descriptor() -> PipelineDescriptor {
+        let vertex = NativeShader::vertex(|_: &VertexInput<'_>| ShadedVertex {
+            position: [0.0, 0.0, 0.0, 1.0],
+            point_size: 1.0,
+            varyings: Vec::new(),
+        });
+        let mut descriptor = PipelineDescriptor::default();
+        descriptor.vertex.module = Some(shader(vertex));
+        descriptor
+    }
+
+    #[test]
+    fn instance_step_mode_is_rejected() {
+        let mut descriptor = descriptor();
+        descriptor.vertex.buffers.push(VertexBufferLayoutDescriptor {
+            stride: 16,
+            step_mode: VertexStepMode::Instance,
+            attributes: vec![VertexAttributeDescriptor { location: 0, offset: 0, format: VertexFormat::Float32x4 }],
+        });
+        assert!(matches!(descriptor.validate(), Err(GraphisError::ValidationFailed(_))));
+        descriptor.vertex.buffers[0].step_mode = VertexStepMode::Vertex;
+        assert!(descriptor.validate().is_ok());
+    }
+
+    #[test]
+    fn more_than_one_color_target_is_unsupported() {
+        let mut descriptor = descriptor();
+        let fragment = NativeShader::fragment(|_: &FragmentInput<'_>| None);
+        descriptor.fragment = Some(FragmentStateDescriptor {
+            module: Some(shader(fragment)),
+            targets: vec![ColorTargetStateDescriptor::default(); 2],
+            ..Default::default()
+        });
+        assert!(matches!(descriptor.validate(), Err(GraphisError::Unsupported(_))));
+        descriptor.fragment.as_mut().unwrap().targets.truncate(1);
+        assert!(descriptor.validate().is_ok());
+    }
+
+    #[test]
+    fn rasterization_state_must_agree_with_the_primitive_state() {
+        let mut descriptor = descriptor();
+        descriptor.primitive.cull_mode = CullMode::Back;
+        descriptor.rasterization = Some(RasterizationStateDescriptor { depth_bias: 1.0, ..Default::default() });
+        assert!(matches!(descriptor.validate(), Err(GraphisError::ValidationFailed(_))));
+        descriptor.rasterization.as_mut().unwrap().cull_mode = CullMode::Back;
+        assert!(descriptor.validate().is_ok());
+        // Rasterization durumu verilmediğinde ayıklama ilkel durumundan alınır.
+        descriptor.rasterization = None;
+        let pipeline = Device::new().unwrap().create_pipeline(&descriptor).unwrap();
+        assert_eq!(pipeline.rasterization.cull_mode, CullMode::Back);
+        assert_eq!(pipeline.multisample.count, 1);
+    }
+
+    fn glsl(stage: ShaderStage, source: &str) -> Shader {
+        let source = ShaderSource::Glsl { source: format!("#version 450\n{}", source), stage };
+        Shader::new(ShaderDescriptor { label: None, source, stage }).unwrap()
+    }
+
+    // `attributes` ilk tamponda 16 byte arayla durur; parçacık shader'ı verilirse tek renk hedefinin formatıyla gelir.
+    fn interface(
+        attributes: &[(u32, VertexFormat)],
+        vertex: &str,
+        fragment: Option<(Shader, TextureFormat)>,
+    ) -> Result<(), GraphisError> {
+        let mut descriptor = PipelineDescriptor::default();
+        descriptor.vertex.module = Some(glsl(ShaderStage::Vertex, vertex));
+        descriptor.vertex.entry_point = "main".to_string();
+        descriptor.vertex.buffers.push(VertexBufferLayoutDescriptor {
+            stride: 64,
+            step_mode: VertexStepMode::Vertex,
+            attributes: (attributes.iter().enumerate())
+                .map(|(index, &(location, format))| VertexAttributeDescriptor {
+                    location,
+                    offset: index as u32 * 16,
+                    format,
+                })
+                .collect(),
+        });
+        descriptor.fragment = fragment.map(|(module, format)| FragmentStateDescriptor {
+            module: Some(module),
+            entry_point: "main".to_string(),
+            targets: vec![ColorTargetStateDescriptor { format, ..Default::default() }],
+        });
+        descriptor.validate()
+    }
+
+    fn mismatch(result: Result<(), GraphisError>, expected: &str) {
+        match result {
+            Err(GraphisError::ValidationFailed(message)) => assert!(message.contains(expected), "{}", message),
+            other => panic!("expected a validation error containing {:?}, got {:?}", expected, other),
+        }
+    }
+
+    const VERTEX: &str = "layout(location = 0) in vec4 position;
+layout(location = 1) in uint id;
+layout(location = 0) out vec3 color;
+layout(location = 1) flat out uint object;
+void main() {
+    color = position.xyz;
+    object = id;
+    gl_Position = position;
+}";
+
+    #[test]
+    fn vertex_attributes_must_match_the_vertex_shader_inputs() {
+        let attributes = [(0, VertexFormat::Float32x4), (1, VertexFormat::Uint32)];
+        assert!(interface(&attributes, VERTEX, None).is_ok());
+        // Formatın shader'ın okuduğundan az bileşeni olması sorun değildir.
+        assert!(interface(&[(0, VertexFormat::Float32x2), (1, VertexFormat::Uint32)], VERTEX, None).is_ok());
+        mismatch(
+            interface(&[(0, VertexFormat::Float32x4), (1, VertexFormat::Sint32)], VERTEX, None),
+            "location 1 is Sint32 but the vertex shader reads it as a 1-component Uint value",
+        );
+        mismatch(interface(&attributes[..1], VERTEX, None), "input Some(\"id\") at location 1 is not provided");
+        mismatch(
+            interface(&[attributes[0], attributes[1], (2, VertexFormat::Float32x4)], VERTEX, None),
+            "location 2 (Float32x4) has no matching input",
+        );
+    }
+
+    #[test]
+    fn fragment_inputs_must_match_the_vertex_shader_outputs() {
+        let attributes = [(0, VertexFormat::Float32x4), (1, VertexFormat::Uint32)];
+        let source = |inputs: &str| format!("{}\nlayout(location = 0) out vec4 target;\nvoid main() {{}}", inputs);
+        let fragment = |inputs: &str| {
+            let module = glsl(ShaderStage::Fragment, &source(inputs));
+            interface(&attributes, VERTEX, Some((module, TextureFormat::Rgba8Unorm)))
+        };
+        assert!(fragment("layout(location = 0) in vec3 color;\nlayout(location = 1) flat in uint object;").is_ok());
+        assert!(fragment("").is_ok());
+        mismatch(
+            fragment("layout(location = 2) in vec3 normal;"),
+            "input Some(\"normal\") at location 2 is not written",
+        );
+        mismatch(
+            fragment("layout(location = 0) in vec4 color;"),
+            "location 0 is a 3-component Float value but the fragment shader reads a 4-component Float value",
+        );
+        mismatch(fragment("layout(location = 1) flat in int object;"), "reads a 1-component Sint value");
+        // Ön uçlar tamsayı girişlerini kendiliğinden Flat yapar; Flat süslemesi SPIR-V'den kaldırılınca girdi reddedilir.
+        assert!(fragment("layout(location = 1) in uint object;").is_ok());
+        let mut words =
+            frontend::glsl::compile(&source("layout(location = 1) in uint object;"), ShaderStage::Fragment).unwrap();
+        let mut index = 5;
+        while index < words.len() {
+            if words[index] & 0xFFFF == u32::from(op::DECORATE) && words[index + 2] == decoration::FLAT {
+                words[index + 2] = decoration::NO_PERSPECTIVE;
+            }
+            index += (words[index] >> 16) as usize;
+        }
+        let bytes = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+        let stage = ShaderStage::Fragment;
+        let module = Shader::new(ShaderDescriptor { label: None, source: ShaderSource::SpirV(bytes), stage }).unwrap();
+        mismatch(
+            interface(&attributes, VERTEX, Some((module, TextureFormat::Rgba8Unorm))),
+            "location 1 must be decorated Flat",
+        );
+    }
+
+    #[test]
+    fn fragment_outputs_must_cover_the_color_target() {
+        let attributes = [(0, VertexFormat::Float32x4), (1, VertexFormat::Uint32)];
+        let fragment = |output: &str, format| {
+            let module = glsl(ShaderStage::Fragment, &format!("{}\nvoid main() {{}}", output));
+            interface(&attributes, VERTEX, Some((module, format)))
+        };
+        assert!(fragment("layout(location = 0) out vec4 target;", TextureFormat::R32Float).is_ok());
+        assert!(fragment("layout(location = 0) out uvec2 target;", TextureFormat::Rg32Uint).is_ok());
+        mismatch(
+            fragment("layout(location = 1) out vec4 target;", TextureFormat::R32Float),
+            "color target 0 (R32Float) is not written",
+        );
+        mismatch(
+            fragment("layout(location = 0) out vec4 target;", TextureFormat::Rgba8Sint),
+            "Rgba8Sint format but the fragment shader writes a 4-component Float value",
+        );
+        mismatch(
+            fragment("layout(location = 0) out vec2 target;", TextureFormat::Rgba8Unorm),
+            "4 channel(s) but the fragment shader writes only a 2-component Float value",
+        );
+    }
 }
\ No newline at end of file
@@ -1,42 +1,66 @@
-use crate::gpu::buffer::Buffer;
-use crate::gpu::commands::RenderCommand;
-use crate::rendering::pipeline::Pipeline;
-use crate::rendering::shader::Shader;
-
-#[derive(Debug)]
-pub struct RenderHandler {
-    // İleride GPU cihazı, komut kuyruğu gibi kaynakları tutabiliriz.
-    // device: GpuDevice,
-    // queue: CommandQueue,
-    pub pipeline: Option<Pipeline>,
-    pub vertex_shader: Option<Shader>,
-    pub fragment_shader: Option<Shader>,
-    // Diğer renderleme ile ilgili durumlar...
-}
-
-impl RenderHandler {
-    pub fn new() -> Self {
-        RenderHandler {
-            pipeline: None,
-            vertex_shader: None,
-            fragment_shader: None,
-            // Diğer alanlar varsayılan değerleriyle başlatılabilir.
-        }
-    }
-
-    pub fn set_pipeline(&mut self, pipeline: Pipeline) {
-        self.pipeline = Some(pipeline);
-    }
-
-    pub fn set_shaders(&mut self, vertex_shader: Shader, fragment_shader: Shader) {
-        self.vertex_shader = Some(vertex_shader);
-        self.fragment_shader = Some(fragment_shader);
-    }
-
-    pub fn submit_commands(&self, commands: &[RenderCommand]) {
-        println!("Render komutları gönderiliyor: {:?}", commands);
-        // Burada gerçekte GPU'ya komut gönderme mantığı yer alacak.
-    }
-
-    // İleride renderleme döngüsü, kaynak yönetimi gibi fonksiyonlar eklenebilir.
+use crate::gpu::buffer::Buffer;
+use crate::gpu::command_buffer::CommandBuffer;
+use crate::gpu::commands::RenderCommand;
+use crate::gpu::error::GraphisError;
+use crate::gpu::queue::Queue;
+use crate::gpu::render_pass::RenderPassDescriptor;
+use crate::gpu::shader::Shader;
+use crate::rendering::pipeline::Pipeline;
+
+#[derive(Debug)]
+pub struct RenderHandler {
+    // İleride GPU cihazı, komut kuyruğu gibi kaynakları tutabiliriz.
+    // device: GpuDevice,
+    // queue: CommandQueue,
+    pub pipeline: Option<Pipeline>,
+    pub vertex_shader: Option<Shader>,
+    pub fragment_shader: Option<Shader>,
+    // Ayarlanmışsa komutlar bu kuyruğa gönderilir (örneğin CPU referans arka ucunda çalıştırılır).
+    pub queue: Option<Queue>,
+    // Diğer renderleme ile ilgili durumlar...
+}
+
+impl RenderHandler {
+    pub fn new() -> Self {
+        RenderHandler {
+            pipeline: None,
+            vertex_shader: None,
+            fragment_shader: None,
+            queue: None,
+            // Diğer alanlar varsayılan değerleriyle başlatılabilir.
+        }
+    }
+
+    pub fn set_pipeline(&mut self, pipeline: Pipeline) {
+        self.pipeline = Some(pipeline);
+    }
+
+    pub fn set_shaders(&mut self, vertex_shader: Shader, fragment_shader: Shader) {
+        self.vertex_shader = Some(vertex_shader);
+        self.fragment_shader = Some(fragment_shader);
+    }
+
+    pub fn set_queue(&mut self, queue: Queue) {
+        self.queue = Some(queue);
+    }
+
+    // Komutlar, o anda bağlı hedeflere çizen eksiz bir render geçişi içinde kaydedilir.
+    pub fn submit_commands(&mut self, commands: &[RenderCommand]) -> Result<(), GraphisError> {
+        log::trace!(count = commands.len(); "Render komutları gönderiliyor");
+        // Burada gerçekte GPU'ya komut gönderme mantığı yer alacak.
+        let Some(queue) = self.queue.as_ref() else {
+            return Ok(());
+        };
+        let mut command_buffer = CommandBuffer::new();
+        command_buffer.begin_recording()?;
+        let mut render_pass = command_buffer.begin_render_pass(&RenderPassDescriptor::default())?;
+        for command in commands {
+            render_pass.submit_command(*command)?;
+        }
+        render_pass.end();
+        command_buffer.end_recording()?;
+        queue.submit(&[command_buffer])
+    }
+
+    // İleride renderleme döngüsü, kaynak yönetimi gibi fonksiyonlar eklenebilir.
 }
\ No newline at end of file